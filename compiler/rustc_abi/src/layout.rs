@@ -347,7 +347,7 @@ struct TmpLayout<FieldIdx: Idx, VariantIdx: Idx> {
             let (field_index, niche, (niche_start, niche_scalar)) = variants[largest_variant_index]
                 .iter()
                 .enumerate()
-                .filter_map(|(j, field)| Some((j, field.largest_niche?)))
+                .filter_map(|(j, field)| Some((j, niche_for_count(dl, field, count)?)))
                 .max_by_key(|(_, niche)| niche.available(dl))
                 .and_then(|(j, niche)| Some((j, niche, niche.reserve(dl, count)?)))?;
             let niche_offset =
@@ -1230,3 +1230,25 @@ fn format_field_niches<
     }
     s
 }
+
+/// Returns the niche of `layout` to store `count` niche values in.
+///
+/// This is its largest niche, except for pairs of pointers such as `&dyn Trait`: the vtable
+/// pointer has a larger niche than the data pointer, but when the null value of the data pointer
+/// is enough, it's used instead, so that e.g. `Option<&dyn Trait>` keeps the attributes of the
+/// data pointer, like `noalias` and `dereferenceable_or_null`.
+fn niche_for_count<FieldIdx: Idx, VariantIdx: Idx>(
+    dl: &TargetDataLayout,
+    layout: &LayoutS<FieldIdx, VariantIdx>,
+    count: u128,
+) -> Option<Niche> {
+    if let Abi::ScalarPair(a, b) = layout.abi {
+        if let (Primitive::Pointer(_), Primitive::Pointer(_)) = (a.primitive(), b.primitive()) {
+            match Niche::from_scalar(dl, Size::ZERO, a) {
+                Some(niche) if niche.available(dl) >= count => return Some(niche),
+                _ => {}
+            }
+        }
+    }
+    layout.largest_niche
+}
@@ -92,7 +92,8 @@ fn allocator_fn(&self, method: &AllocatorMethod) -> Stmt {
     }
 
     fn call_allocator(&self, method: Symbol, mut args: ThinVec<P<Expr>>) -> P<Expr> {
-        let method = self.cx.std_path(&[sym::alloc, sym::GlobalAlloc, method]);
+        // Go through the wrappers reporting the call to the allocation hook, if one is set.
+        let method = self.cx.std_path(&[sym::alloc, sym::hooked, method]);
         let method = self.cx.expr_path(self.cx.path(self.ty_span, method));
         let allocator = self.cx.path_ident(self.ty_span, self.global);
         let allocator = self.cx.expr_path(allocator);
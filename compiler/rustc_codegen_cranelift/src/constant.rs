@@ -149,7 +149,7 @@ pub(crate) fn codegen_const_value<'tcx>(
                         fx.bcx.ins().func_addr(fx.pointer_type, local_func_id)
                     }
                     GlobalAlloc::VTable(ty, trait_ref) => {
-                        let alloc_id = fx.tcx.vtable_allocation((ty, trait_ref));
+                        let alloc_id = fx.tcx.codegen_vtable_allocation((ty, trait_ref));
                         let alloc = fx.tcx.global_alloc(alloc_id).unwrap_memory();
                         // FIXME: factor this common code with the `Memory` arm into a function?
                         let data_id = data_id_for_alloc_id(
@@ -399,7 +399,7 @@ fn define_all_allocs(tcx: TyCtxt<'_>, module: &mut dyn Module, cx: &mut Constant
                     data_id_for_alloc_id(cx, module, alloc_id, target_alloc.inner().mutability)
                 }
                 GlobalAlloc::VTable(ty, trait_ref) => {
-                    let alloc_id = tcx.vtable_allocation((ty, trait_ref));
+                    let alloc_id = tcx.codegen_vtable_allocation((ty, trait_ref));
                     data_id_for_alloc_id(cx, module, alloc_id, Mutability::Not)
                 }
                 GlobalAlloc::Static(def_id) => {
@@ -203,6 +203,9 @@ fn codegen_crate(
         need_metadata_module: bool,
     ) -> Box<dyn Any> {
         tcx.sess.abort_if_errors();
+        if tcx.sess.opts.unstable_opts.relative_vtables {
+            tcx.sess.fatal("-Zrelative-vtables is not supported by rustc_codegen_cranelift");
+        }
         let config = self.config.borrow().clone().unwrap();
         match config.codegen_mode {
             CodegenMode::Aot => driver::aot::run_aot(tcx, config, metadata, need_metadata_module),
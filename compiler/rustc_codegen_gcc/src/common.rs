@@ -219,7 +219,7 @@ fn scalar_to_backend(&self, cv: Scalar, layout: abi::Scalar, ty: Type<'gcc>) ->
                             self.get_fn_addr(fn_instance)
                         },
                         GlobalAlloc::VTable(ty, trait_ref) => {
-                            let alloc = self.tcx.global_alloc(self.tcx.vtable_allocation((ty, trait_ref))).unwrap_memory();
+                            let alloc = self.tcx.global_alloc(self.tcx.codegen_vtable_allocation((ty, trait_ref))).unwrap_memory();
                             let init = const_alloc_to_gcc(self, alloc);
                             self.static_addr_of(init, alloc.inner().align, None)
                         }
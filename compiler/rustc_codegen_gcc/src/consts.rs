@@ -55,6 +55,10 @@ fn static_addr_of(&self, cv: RValue<'gcc>, align: Align, kind: Option<&str>) ->
         global_value
     }
 
+    fn static_relative_vtable(&self, _header: &[RValue<'gcc>], _entries: &[Option<RValue<'gcc>>], _align: Align) -> RValue<'gcc> {
+        unreachable!("`-Zrelative-vtables` is rejected when the backend is initialized");
+    }
+
     fn codegen_static(&self, def_id: DefId, is_mutable: bool) {
         let attrs = self.tcx.codegen_fn_attrs(def_id);
 
@@ -47,6 +47,10 @@ fn into_diagnostic_arg(self) -> DiagnosticArgValue<'static> {
 #[diag(codegen_gcc_lto_not_supported)]
 pub(crate) struct LTONotSupported;
 
+#[derive(Diagnostic)]
+#[diag(codegen_gcc_relative_vtables_not_supported)]
+pub(crate) struct RelativeVtablesNotSupported;
+
 #[derive(Diagnostic)]
 #[diag(codegen_gcc_unwinding_inline_asm)]
 pub(crate) struct UnwindingInlineAsm {
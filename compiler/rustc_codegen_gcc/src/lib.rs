@@ -91,7 +91,7 @@
 use gccjit::{TargetInfo, Version};
 #[cfg(not(feature="master"))]
 use gccjit::CType;
-use errors::LTONotSupported;
+use errors::{LTONotSupported, RelativeVtablesNotSupported};
 use rustc_ast::expand::allocator::AllocatorKind;
 use rustc_codegen_ssa::{CodegenResults, CompiledModule, ModuleCodegen};
 use rustc_codegen_ssa::base::codegen_crate;
@@ -194,6 +194,9 @@ fn init(&self, sess: &Session) {
         if sess.lto() == Lto::Thin {
             sess.emit_warning(LTONotSupported {});
         }
+        if sess.opts.unstable_opts.relative_vtables {
+            sess.emit_fatal(RelativeVtablesNotSupported);
+        }
 
         #[cfg(not(feature="master"))]
         {
@@ -7,6 +7,7 @@
 use crate::value::Value;
 
 use rustc_ast::Mutability;
+use rustc_codegen_ssa::meth;
 use rustc_codegen_ssa::traits::*;
 use rustc_data_structures::stable_hasher::{Hash128, HashStable, StableHasher};
 use rustc_hir::def_id::DefId;
@@ -269,10 +270,16 @@ fn scalar_to_backend(&self, cv: Scalar, layout: abi::Scalar, llty: &'ll Type) ->
                         self.get_fn_addr(fn_instance.polymorphize(self.tcx)),
                         self.data_layout().instruction_address_space,
                     ),
+                    GlobalAlloc::VTable(ty, trait_ref)
+                        if self.sess().opts.unstable_opts.relative_vtables =>
+                    {
+                        // The allocation doesn't hold the method entries of relative vtables.
+                        (meth::get_vtable(self, ty, trait_ref), AddressSpace::DATA)
+                    }
                     GlobalAlloc::VTable(ty, trait_ref) => {
                         let alloc = self
                             .tcx
-                            .global_alloc(self.tcx.vtable_allocation((ty, trait_ref)))
+                            .global_alloc(self.tcx.codegen_vtable_allocation((ty, trait_ref)))
                             .unwrap_memory();
                         let init = const_alloc_to_llvm(self, alloc);
                         let value = self.static_addr_of(init, alloc.inner().align, None);
@@ -362,6 +362,37 @@ fn static_addr_of(&self, cv: &'ll Value, align: Align, kind: Option<&str>) -> &'
         gv
     }
 
+    fn static_relative_vtable(
+        &self,
+        header: &[&'ll Value],
+        entries: &[Option<&'ll Value>],
+        align: Align,
+    ) -> &'ll Value {
+        let header = self.const_struct(header, false);
+        let offsets_ty = self.type_array(self.type_i32(), entries.len() as u64);
+        let llty = self.type_struct(&[self.val_ty(header), offsets_ty], false);
+
+        // The offsets refer to the global itself, so it has to be created before its initializer.
+        let gv = self.static_addr_of_mut(self.const_undef(llty), align, Some("vtable"));
+        unsafe {
+            let base = llvm::LLVMConstPtrToInt(gv, self.type_isize());
+            let offsets: Vec<_> = entries
+                .iter()
+                .map(|entry| match *entry {
+                    Some(target) => {
+                        let target = llvm::LLVMConstPtrToInt(target, self.type_isize());
+                        llvm::LLVMConstTrunc(llvm::LLVMConstSub(target, base), self.type_i32())
+                    }
+                    None => self.const_i32(0),
+                })
+                .collect();
+            let offsets = self.const_array(self.type_i32(), &offsets);
+            llvm::LLVMSetInitializer(gv, self.const_struct(&[header, offsets], false));
+            llvm::LLVMSetGlobalConstant(gv, True);
+        }
+        gv
+    }
+
     fn codegen_static(&self, def_id: DefId, is_mutable: bool) {
         unsafe {
             let attrs = self.tcx.codegen_fn_attrs(def_id);
@@ -23,7 +23,7 @@
 };
 use rustc_middle::ty::{self, Instance, Ty, TyCtxt};
 use rustc_middle::{bug, span_bug};
-use rustc_session::config::{BranchProtection, CFGuard, CFProtection};
+use rustc_session::config::{BranchProtection, CFGuard, CFProtection, Lto};
 use rustc_session::config::{CrateType, DebugInfo, PAuthKey, PacRet};
 use rustc_session::Session;
 use rustc_span::source_map::Spanned;
@@ -330,7 +330,7 @@ pub unsafe fn create_module<'ll>(
         )
     }
 
-    if sess.opts.unstable_opts.virtual_function_elimination {
+    if sess.opts.unstable_opts.virtual_function_elimination && sess.lto() == Lto::Fat {
         llvm::LLVMRustAddModuleFlag(
             llmod,
             llvm::LLVMModFlagBehavior::Error,
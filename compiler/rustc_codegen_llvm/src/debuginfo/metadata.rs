@@ -33,7 +33,7 @@
     self, AdtKind, Instance, ParamEnv, PolyExistentialTraitRef, Ty, TyCtxt, Visibility,
 };
 use rustc_session::config::{self, DebugInfo, Lto};
-use rustc_span::symbol::Symbol;
+use rustc_span::symbol::{sym, Symbol};
 use rustc_span::FileName;
 use rustc_span::{self, FileNameDisplayPreference, SourceFile};
 use rustc_symbol_mangling::typeid_for_trait_ref;
@@ -1312,20 +1312,21 @@ fn build_vtable_type_di_node<'ll, 'tcx>(
 ) -> &'ll DIType {
     let tcx = cx.tcx;
 
-    let vtable_entries = if let Some(poly_trait_ref) = poly_trait_ref {
-        let trait_ref = poly_trait_ref.with_self_ty(tcx, ty);
-        let trait_ref = tcx.erase_regions(trait_ref);
-
-        tcx.vtable_entries(trait_ref)
-    } else {
-        TyCtxt::COMMON_VTABLE_ENTRIES
-    };
+    let vtable_entries = tcx.codegen_vtable_entries_for_ty(ty, poly_trait_ref);
 
     // All function pointers are described as opaque pointers. This could be improved in the future
     // by describing them as actual function pointers.
     let void_pointer_ty = Ty::new_imm_ptr(tcx, tcx.types.unit);
     let void_pointer_type_di_node = type_di_node(cx, void_pointer_ty);
     let usize_di_node = type_di_node(cx, tcx.types.usize);
+    // With `-Zrelative-vtables`, methods and super-trait vtables are described by their offset
+    // from the vtable instead.
+    let relative_offset_type_di_node = tcx
+        .sess
+        .opts
+        .unstable_opts
+        .relative_vtables
+        .then(|| (type_di_node(cx, tcx.types.i32), cx.size_and_align_of(tcx.types.i32)));
     let (pointer_size, pointer_align) = cx.size_and_align_of(void_pointer_ty);
     // If `usize` is not pointer-sized and -aligned then the size and alignment computations
     // for the vtable as a whole would be wrong. Let's make sure this holds even on weird
@@ -1335,7 +1336,7 @@ fn build_vtable_type_di_node<'ll, 'tcx>(
     let vtable_type_name =
         compute_debuginfo_vtable_name(cx.tcx, ty, poly_trait_ref, VTableNameKind::Type);
     let unique_type_id = UniqueTypeId::for_vtable_ty(tcx, ty, poly_trait_ref);
-    let size = pointer_size * vtable_entries.len() as u64;
+    let size = tcx.vtable_entry_offset(vtable_entries.len());
 
     // This gets mapped to a DW_AT_containing_type attribute which allows GDB to correlate
     // the vtable to the type it is for.
@@ -1357,7 +1358,7 @@ fn build_vtable_type_di_node<'ll, 'tcx>(
                 .iter()
                 .enumerate()
                 .filter_map(|(index, vtable_entry)| {
-                    let (field_name, field_type_di_node) = match vtable_entry {
+                    let (field_name, mut field_type_di_node) = match vtable_entry {
                         ty::VtblEntry::MetadataDropInPlace => {
                             ("drop_in_place".to_string(), void_pointer_type_di_node)
                         }
@@ -1375,13 +1376,22 @@ fn build_vtable_type_di_node<'ll, 'tcx>(
                         ty::VtblEntry::Vacant => return None,
                     };
 
-                    let field_offset = pointer_size * index as u64;
+                    let mut size_and_align = (pointer_size, pointer_align);
+                    if let ty::VtblEntry::Method(_) | ty::VtblEntry::TraitVPtr(_) = vtable_entry
+                        && let Some((type_di_node, relative_size_and_align)) =
+                            relative_offset_type_di_node
+                    {
+                        field_type_di_node = type_di_node;
+                        size_and_align = relative_size_and_align;
+                    }
+
+                    let field_offset = tcx.vtable_entry_offset(index);
 
                     Some(build_field_di_node(
                         cx,
                         vtable_type_di_node,
                         &field_name,
-                        (pointer_size, pointer_align),
+                        size_and_align,
                         field_offset,
                         DIFlags::FlagZero,
                         field_type_di_node,
@@ -1418,24 +1428,30 @@ enum VCallVisibility {
 
     let lto = cx.sess().lto();
 
-    // Since LLVM requires full LTO for the virtual function elimination optimization to apply,
-    // only the `Lto::Fat` cases are relevant currently.
-    let vcall_visibility = match (lto, trait_vis, single_cgu) {
-        // If there is not LTO and the visibility in public, we have to assume that the vtable can
-        // be seen from anywhere. With multiple CGUs, the vtable is quasi-public.
-        (Lto::No | Lto::ThinLocal, Visibility::Public, _)
-        | (Lto::No, Visibility::Restricted(_), false) => VCallVisibility::Public,
-        // With LTO and a quasi-public visibility, the usages of the functions of the vtable are
-        // all known by the `LinkageUnit`.
-        // FIXME: LLVM only supports this optimization for `Lto::Fat` currently. Once it also
-        // supports `Lto::Thin` the `VCallVisibility` may have to be adjusted for those.
-        (Lto::Fat | Lto::Thin, Visibility::Public, _)
-        | (Lto::ThinLocal | Lto::Thin | Lto::Fat, Visibility::Restricted(_), false) => {
-            VCallVisibility::LinkageUnit
+    // The vtables of `#[stable_vtable]` traits may be used by code compiled separately, like
+    // plugins, so the calls through them can't all be known.
+    let vcall_visibility = if cx.tcx.has_attr(trait_def_id, sym::stable_vtable) {
+        VCallVisibility::Public
+    } else {
+        // Since LLVM requires full LTO for the virtual function elimination optimization to apply,
+        // only the `Lto::Fat` cases are relevant currently.
+        match (lto, trait_vis, single_cgu) {
+            // If there is not LTO and the visibility in public, we have to assume that the vtable
+            // can be seen from anywhere. With multiple CGUs, the vtable is quasi-public.
+            (Lto::No | Lto::ThinLocal, Visibility::Public, _)
+            | (Lto::No, Visibility::Restricted(_), false) => VCallVisibility::Public,
+            // With LTO and a quasi-public visibility, the usages of the functions of the vtable are
+            // all known by the `LinkageUnit`.
+            // FIXME: LLVM only supports this optimization for `Lto::Fat` currently. Once it also
+            // supports `Lto::Thin` the `VCallVisibility` may have to be adjusted for those.
+            (Lto::Fat | Lto::Thin, Visibility::Public, _)
+            | (Lto::ThinLocal | Lto::Thin | Lto::Fat, Visibility::Restricted(_), false) => {
+                VCallVisibility::LinkageUnit
+            }
+            // If there is only one CGU, private vtables can only be seen by that CGU/translation
+            // unit and therefore we know of all usages of functions in the vtable.
+            (_, Visibility::Restricted(_), true) => VCallVisibility::TranslationUnit,
         }
-        // If there is only one CGU, private vtables can only be seen by that CGU/translation unit
-        // and therefore we know of all usages of functions in the vtable.
-        (_, Visibility::Restricted(_), true) => VCallVisibility::TranslationUnit,
     };
 
     let trait_ref_typeid = typeid_for_trait_ref(cx.tcx, trait_ref);
@@ -1,6 +1,6 @@
 use crate::traits::*;
 
-use rustc_middle::ty::{self, GenericArgKind, Ty};
+use rustc_middle::ty::{self, GenericArgKind, Instance, Ty, TyCtxt};
 use rustc_session::config::Lto;
 use rustc_symbol_mangling::typeid_for_trait_ref;
 use rustc_target::abi::call::FnAbi;
@@ -24,7 +24,9 @@ pub fn get_fn<Bx: BuilderMethods<'a, 'tcx>>(
         debug!("get_fn({llvtable:?}, {ty:?}, {self:?})");
         let llty = bx.fn_ptr_backend_type(fn_abi);
 
-        if bx.cx().sess().opts.unstable_opts.virtual_function_elimination
+        if self.is_relative(bx.tcx()) {
+            self.get_relative(bx, llvtable)
+        } else if bx.cx().sess().opts.unstable_opts.virtual_function_elimination
             && bx.cx().sess().lto() == Lto::Fat
         {
             let typeid = bx
@@ -60,6 +62,48 @@ pub fn get_usize<Bx: BuilderMethods<'a, 'tcx>>(
         bx.set_invariant_load(ptr);
         ptr
     }
+
+    /// Loads the pointer to a supertrait vtable, for trait upcasting coercions.
+    pub fn get_vptr<Bx: BuilderMethods<'a, 'tcx>>(
+        self,
+        bx: &mut Bx,
+        llvtable: Bx::Value,
+    ) -> Bx::Value {
+        if self.is_relative(bx.tcx()) {
+            return self.get_relative(bx, llvtable);
+        }
+
+        let ptr_ty = bx.type_ptr();
+        let ptr_align = bx.tcx().data_layout.pointer_align.abi;
+        let gep = bx.inbounds_gep(ptr_ty, llvtable, &[bx.const_usize(self.0)]);
+        let new_vptr = bx.load(ptr_ty, gep, ptr_align);
+        bx.nonnull_metadata(new_vptr);
+        // VTable loads are invariant.
+        bx.set_invariant_load(new_vptr);
+        new_vptr
+    }
+
+    /// Whether this entry is an offset rather than a pointer, see `TyCtxt::vtable_entry_offset`.
+    fn is_relative(self, tcx: TyCtxt<'tcx>) -> bool {
+        tcx.sess.opts.unstable_opts.relative_vtables
+            && self.0 >= TyCtxt::COMMON_VTABLE_ENTRIES.len() as u64
+    }
+
+    /// Loads the offset of a method or supertrait vtable from a relative vtable, and applies it.
+    fn get_relative<Bx: BuilderMethods<'a, 'tcx>>(
+        self,
+        bx: &mut Bx,
+        llvtable: Bx::Value,
+    ) -> Bx::Value {
+        let offset = bx.tcx().vtable_entry_offset(self.0 as usize);
+        let i32_align = bx.tcx().data_layout.i32_align.abi;
+        let gep = bx.inbounds_gep(bx.type_i8(), llvtable, &[bx.const_usize(offset.bytes())]);
+        let relative = bx.load(bx.type_i32(), gep, i32_align);
+        // VTable loads are invariant.
+        bx.set_invariant_load(relative);
+        let relative = bx.sext(relative, bx.type_isize());
+        bx.inbounds_gep(bx.type_i8(), llvtable, &[relative])
+    }
 }
 
 /// This takes a valid `self` receiver type and extracts the principal trait
@@ -97,13 +141,57 @@ pub fn get_vtable<'tcx, Cx: CodegenMethods<'tcx>>(
         return val;
     }
 
-    let vtable_alloc_id = tcx.vtable_allocation((ty, trait_ref));
-    let vtable_allocation = tcx.global_alloc(vtable_alloc_id).unwrap_memory();
-    let vtable_const = cx.const_data_from_alloc(vtable_allocation);
-    let align = cx.data_layout().pointer_align.abi;
-    let vtable = cx.static_addr_of(vtable_const, align, Some("vtable"));
+    let vtable = if tcx.sess.opts.unstable_opts.relative_vtables {
+        get_relative_vtable(cx, ty, trait_ref)
+    } else {
+        let vtable_alloc_id = tcx.codegen_vtable_allocation((ty, trait_ref));
+        let vtable_allocation = tcx.global_alloc(vtable_alloc_id).unwrap_memory();
+        let vtable_const = cx.const_data_from_alloc(vtable_allocation);
+        let align = cx.data_layout().pointer_align.abi;
+        cx.static_addr_of(vtable_const, align, Some("vtable"))
+    };
 
     cx.create_vtable_debuginfo(ty, trait_ref, vtable);
     cx.vtables().borrow_mut().insert((ty, trait_ref), vtable);
     vtable
 }
+
+/// Creates a vtable for `-Zrelative-vtables`. Its method and supertrait entries are offsets from
+/// the vtable itself, which the allocation from the `vtable_allocation` query can't express, so
+/// it is built from the vtable entries instead.
+fn get_relative_vtable<'tcx, Cx: CodegenMethods<'tcx>>(
+    cx: &Cx,
+    ty: Ty<'tcx>,
+    trait_ref: Option<ty::PolyExistentialTraitRef<'tcx>>,
+) -> Cx::Value {
+    let tcx = cx.tcx();
+    let layout = cx.layout_of(ty);
+    let drop_in_place = Instance::resolve_drop_in_place_for_vtable(tcx, ty).polymorphize(tcx);
+    let header = [
+        cx.get_fn_addr(drop_in_place),
+        cx.const_usize(layout.size.bytes()),
+        cx.const_usize(layout.align.abi.bytes()),
+    ];
+
+    let vtable_entries = tcx.codegen_vtable_entries_for_ty(ty, trait_ref);
+    let entries: Vec<_> = vtable_entries[TyCtxt::COMMON_VTABLE_ENTRIES.len()..]
+        .iter()
+        .map(|entry| match *entry {
+            ty::VtblEntry::Method(instance) => Some(cx.get_fn_addr(instance.polymorphize(tcx))),
+            ty::VtblEntry::TraitVPtr(trait_ref) => {
+                let super_trait_ref = trait_ref
+                    .map_bound(|trait_ref| ty::ExistentialTraitRef::erase_self_ty(tcx, trait_ref));
+                Some(get_vtable(cx, ty, Some(super_trait_ref)))
+            }
+            ty::VtblEntry::Vacant => None,
+            ty::VtblEntry::MetadataDropInPlace
+            | ty::VtblEntry::MetadataSize
+            | ty::VtblEntry::MetadataAlign => {
+                bug!("unexpected vtable entry {entry:?} after the common entries")
+            }
+        })
+        .collect();
+
+    let align = cx.data_layout().pointer_align.abi;
+    cx.static_relative_vtable(&header, &entries, align)
+}
@@ -157,6 +157,7 @@ fn do_call<Bx: BuilderMethods<'a, 'tcx>>(
         destination: Option<(ReturnDest<'tcx, Bx::Value>, mir::BasicBlock)>,
         mut unwind: mir::UnwindAction,
         copied_constant_arguments: &[PlaceRef<'tcx, <Bx as BackendTypes>::Value>],
+        instance: Option<Instance<'tcx>>,
         mergeable_succ: bool,
     ) -> MergingSucc {
         // If there is a cleanup block and the function we're calling can unwind, then
@@ -210,6 +211,7 @@ fn do_call<Bx: BuilderMethods<'a, 'tcx>>(
                 ret_llbb,
                 unwind_block,
                 self.funclet(fx),
+                instance,
             );
             if fx.mir[self.bb].is_cleanup {
                 bx.apply_attrs_to_cleanup_callsite(invokeret);
@@ -225,7 +227,8 @@ fn do_call<Bx: BuilderMethods<'a, 'tcx>>(
             }
             MergingSucc::False
         } else {
-            let llret = bx.call(fn_ty, fn_attrs, Some(fn_abi), fn_ptr, llargs, self.funclet(fx));
+            let llret =
+                bx.call(fn_ty, fn_attrs, Some(fn_abi), fn_ptr, llargs, self.funclet(fx), instance);
             if fx.mir[self.bb].is_cleanup {
                 bx.apply_attrs_to_cleanup_callsite(llret);
             }
@@ -468,7 +471,7 @@ fn codegen_drop_terminator(
             args1 = [place.llval];
             &args1[..]
         };
-        let (drop_fn, fn_abi) =
+        let (drop_fn, fn_abi, drop_instance) =
             match ty.kind() {
                 // FIXME(eddyb) perhaps move some of this logic into
                 // `Instance::resolve_drop_in_place`?
@@ -500,6 +503,7 @@ fn codegen_drop_terminator(
                         meth::VirtualIndex::from_index(ty::COMMON_VTABLE_ENTRIES_DROPINPLACE)
                             .get_fn(bx, vtable, ty, fn_abi),
                         fn_abi,
+                        virtual_drop,
                     )
                 }
                 ty::Dynamic(_, _, ty::DynStar) => {
@@ -542,9 +546,14 @@ fn codegen_drop_terminator(
                         meth::VirtualIndex::from_index(ty::COMMON_VTABLE_ENTRIES_DROPINPLACE)
                             .get_fn(bx, meta.immediate(), ty, fn_abi),
                         fn_abi,
+                        virtual_drop,
                     )
                 }
-                _ => (bx.get_fn_addr(drop_fn), bx.fn_abi_of_instance(drop_fn, ty::List::empty())),
+                _ => (
+                    bx.get_fn_addr(drop_fn),
+                    bx.fn_abi_of_instance(drop_fn, ty::List::empty()),
+                    drop_fn,
+                ),
             };
         helper.do_call(
             self,
@@ -555,6 +564,7 @@ fn codegen_drop_terminator(
             Some((ReturnDest::Nothing, target)),
             unwind,
             &[],
+            Some(drop_instance),
             mergeable_succ,
         )
     }
@@ -634,7 +644,8 @@ fn codegen_assert_terminator(
         let (fn_abi, llfn) = common::build_langcall(bx, Some(span), lang_item);
 
         // Codegen the actual panic invoke/call.
-        let merging_succ = helper.do_call(self, bx, fn_abi, llfn, &args, None, unwind, &[], false);
+        let merging_succ =
+            helper.do_call(self, bx, fn_abi, llfn, &args, None, unwind, &[], None, false);
         assert_eq!(merging_succ, MergingSucc::False);
         MergingSucc::False
     }
@@ -662,6 +673,7 @@ fn codegen_terminate_terminator(
             None,
             mir::UnwindAction::Unreachable,
             &[],
+            None,
             false,
         );
         assert_eq!(merging_succ, MergingSucc::False);
@@ -724,6 +736,7 @@ fn codegen_panic_intrinsic(
                     target.as_ref().map(|bb| (ReturnDest::Nothing, *bb)),
                     unwind,
                     &[],
+                    None,
                     mergeable_succ,
                 )
             } else {
@@ -1045,6 +1058,7 @@ fn codegen_call_terminator(
             target.as_ref().map(|&target| (ret_dest, target)),
             unwind,
             &copied_constant_arguments,
+            instance,
             mergeable_succ,
         )
     }
@@ -1589,7 +1603,7 @@ fn terminate_block(&mut self, reason: UnwindTerminateReason) -> Bx::BasicBlock {
         let (fn_abi, fn_ptr) = common::build_langcall(&bx, None, reason.lang_item());
         let fn_ty = bx.fn_decl_backend_type(fn_abi);
 
-        let llret = bx.call(fn_ty, None, Some(fn_abi), fn_ptr, &[], funclet.as_ref());
+        let llret = bx.call(fn_ty, None, Some(fn_abi), fn_ptr, &[], funclet.as_ref(), None);
         bx.apply_attrs_to_cleanup_callsite(llret);
 
         bx.unreachable();
@@ -365,6 +365,14 @@ pub fn codegen_set_discr<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
                     let niche_llval = if niche_value == 0 {
                         // HACK(eddyb): using `c_null` as it works on all types.
                         bx.cx().const_null(niche_llty)
+                    } else if let Abi::Scalar(scalar) = niche.layout.abi
+                        && let Pointer(_) = scalar.primitive()
+                    {
+                        // The misaligned addresses used as the niche of vtable pointers.
+                        let int_llty =
+                            bx.type_from_integer(bx.tcx().data_layout.ptr_sized_integer());
+                        let niche_llval = bx.cx().const_uint_big(int_llty, niche_value);
+                        bx.inttoptr(niche_llval, niche_llty)
                     } else {
                         bx.cx().const_uint_big(niche_llty, niche_value)
                     };
@@ -6,6 +6,15 @@ pub trait StaticMethods: BackendTypes {
     fn static_addr_of(&self, cv: Self::Value, align: Align, kind: Option<&str>) -> Self::Value;
     fn codegen_static(&self, def_id: DefId, is_mutable: bool);
 
+    /// Creates a global for a vtable with `-Zrelative-vtables`, holding the `header` values
+    /// followed by the 32-bit offset from the global to each of the `entries`, or zero for `None`.
+    fn static_relative_vtable(
+        &self,
+        header: &[Self::Value],
+        entries: &[Option<Self::Value>],
+        align: Align,
+    ) -> Self::Value;
+
     /// Mark the given global value as "used", to prevent the compiler and linker from potentially
     /// removing a static variable that may otherwise appear unused.
     fn add_used_global(&self, global: Self::Value);
@@ -191,11 +191,15 @@ pub fn read_discriminant(
                     Err(dbg_val) => {
                         // So this is a pointer then, and casting to an int failed.
                         // Can only happen during CTFE.
-                        // The niche must be just 0, and the ptr not null, then we know this is
+                        // The ptr must be above the niche values (i.e. not null, or aligned for
+                        // larger niches like those of vtable pointers), then we know this is
                         // okay. Everything else, we conservatively reject.
-                        let ptr_valid = niche_start == 0
-                            && variants_start == variants_end
-                            && !self.scalar_may_be_null(tag_val)?;
+                        let niche_end =
+                            niche_start.checked_add(u128::from(variants_end - variants_start) + 1);
+                        let ptr_valid = match niche_end {
+                            Some(niche_end) => !self.scalar_may_be_below(tag_val, niche_end)?,
+                            None => false,
+                        };
                         if !ptr_valid {
                             throw_ub!(InvalidTag(dbg_val))
                         }
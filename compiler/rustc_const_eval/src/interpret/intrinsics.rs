@@ -63,6 +63,17 @@ pub(crate) fn eval_nullary_intrinsic<'tcx>(
             ensure_monomorphic_enough(tcx, tp_ty)?;
             ConstValue::from_u128(tcx.type_id_hash(tp_ty).as_u128())
         }
+        sym::vtable_layout_hash => {
+            ensure_monomorphic_enough(tcx, tp_ty)?;
+            let hash = match tp_ty.kind() {
+                ty::Dynamic(predicates, ..) => predicates
+                    .principal_def_id()
+                    .filter(|&def_id| tcx.has_attr(def_id, sym::stable_vtable))
+                    .map_or(0, |def_id| tcx.stable_vtable_layout_hash(def_id)),
+                _ => 0,
+            };
+            ConstValue::from_u64(hash)
+        }
         sym::variant_count => match tp_ty.kind() {
             // Correctly handles non-monomorphic calls, so there is no need for ensure_monomorphic_enough.
             ty::Adt(adt, _) => ConstValue::from_target_usize(adt.variants().len() as u64, &tcx),
@@ -143,13 +154,15 @@ pub fn emulate_intrinsic(
             | sym::needs_drop
             | sym::type_id
             | sym::type_name
-            | sym::variant_count => {
+            | sym::variant_count
+            | sym::vtable_layout_hash => {
                 let gid = GlobalId { instance, promoted: None };
                 let ty = match intrinsic_name {
                     sym::pref_align_of | sym::variant_count => self.tcx.types.usize,
                     sym::needs_drop => self.tcx.types.bool,
                     sym::type_id => self.tcx.types.u128,
                     sym::type_name => Ty::new_static_str(self.tcx.tcx),
+                    sym::vtable_layout_hash => self.tcx.types.u64,
                     _ => bug!(),
                 };
                 let val = self.ctfe_query(|tcx| {
@@ -1258,6 +1258,34 @@ pub fn scalar_may_be_null(&self, scalar: Scalar<M::Provenance>) -> InterpResult<
         })
     }
 
+    /// Test if this value might be less than `bound`.
+    ///
+    /// A pointer that isn't null is at least as large as its alignment, which is what makes the
+    /// misaligned values below the alignment of vtable pointers usable as a niche.
+    pub fn scalar_may_be_below(
+        &self,
+        scalar: Scalar<M::Provenance>,
+        bound: u128,
+    ) -> InterpResult<'tcx, bool> {
+        Ok(match scalar.try_to_int() {
+            Ok(int) => int.assert_bits(int.size()) < bound,
+            Err(_) => {
+                // Can only happen during CTFE.
+                if self.scalar_may_be_null(scalar)? {
+                    return Ok(true);
+                }
+                let ptr = scalar.to_pointer(self)?;
+                match self.ptr_try_get_alloc_id(ptr) {
+                    Ok((alloc_id, offset, _)) => {
+                        let (_size, align, _kind) = self.get_alloc_info(alloc_id);
+                        u128::from(align.restrict_for_offset(offset).bytes()) < bound
+                    }
+                    Err(_offset) => bug!("a non-int scalar is always a pointer"),
+                }
+            }
+        })
+    }
+
     /// Turning a "maybe pointer" into a proper pointer (and some information
     /// about where it points), or an absolute address.
     ///
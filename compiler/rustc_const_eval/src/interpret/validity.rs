@@ -608,7 +608,8 @@ fn visit_scalar(
             Err(_) => {
                 // So this is a pointer then, and casting to an int failed.
                 // Can only happen during CTFE.
-                // We support 2 kinds of ranges here: full range, and excluding zero.
+                // We support 3 kinds of ranges here: full range, excluding zero, and excluding
+                // the misaligned values below the alignment (for vtable pointers).
                 if start == 1 && end == max_value {
                     // Only null is the niche. So make sure the ptr is NOT null.
                     if self.ecx.scalar_may_be_null(scalar)? {
@@ -619,6 +620,8 @@ fn visit_scalar(
                     } else {
                         return Ok(());
                     }
+                } else if end == max_value && !self.ecx.scalar_may_be_below(scalar, start)? {
+                    return Ok(());
                 } else if scalar_layout.is_always_valid(self.ecx) {
                     // Easy. (This is reachable if `enforce_number_validity` is set.)
                     return Ok(());
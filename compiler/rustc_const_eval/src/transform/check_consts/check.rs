@@ -219,6 +219,18 @@ pub struct Checker<'mir, 'tcx> {
 
     error_emitted: Option<ErrorGuaranteed>,
     secondary_errors: Vec<Diagnostic>,
+
+    /// Where the outcome of the check is recorded instead of emitting errors, if this is a dry run.
+    dry_run: Option<DryRun>,
+}
+
+/// The outcome of const-checking a body without emitting any errors, see [`Checker::dry_run`].
+#[derive(Debug, Default)]
+pub struct DryRun {
+    /// The spans of the operations that are not allowed in the body's const context.
+    pub errors: Vec<Span>,
+    /// The unstable features, enabled in this crate, that the body relies on.
+    pub features: Vec<Symbol>,
 }
 
 impl<'mir, 'tcx> Deref for Checker<'mir, 'tcx> {
@@ -238,9 +250,22 @@ pub fn new(ccx: &'mir ConstCx<'mir, 'tcx>) -> Self {
             local_has_storage_dead: None,
             error_emitted: None,
             secondary_errors: Vec::new(),
+            dry_run: None,
         }
     }
 
+    /// Const-checks the body without emitting any errors, and returns what would have been
+    /// reported instead.
+    ///
+    /// This is meant for tools checking whether a body could be made const, which set
+    /// `const_kind` of the `ConstCx` to the hypothetical const context. Like `mir_const_qualif`,
+    /// it expects the body of `mir_const`, from before promotion and drop elaboration.
+    pub fn dry_run(ccx: &'mir ConstCx<'mir, 'tcx>) -> DryRun {
+        let mut checker = Checker { dry_run: Some(DryRun::default()), ..Checker::new(ccx) };
+        checker.check_body();
+        checker.dry_run.unwrap()
+    }
+
     pub fn check_body(&mut self) {
         let ConstCx { tcx, body, .. } = *self.ccx;
         let def_id = self.ccx.def_id();
@@ -248,7 +273,11 @@ pub fn check_body(&mut self) {
         // `async` functions cannot be `const fn`. This is checked during AST lowering, so there's
         // no need to emit duplicate errors here.
         if self.ccx.is_async() || body.coroutine.is_some() {
-            tcx.sess.span_delayed_bug(body.span, "`async` functions cannot be `const fn`");
+            if let Some(dry_run) = &mut self.dry_run {
+                dry_run.errors.push(body.span);
+            } else {
+                tcx.sess.span_delayed_bug(body.span, "`async` functions cannot be `const fn`");
+            }
             return;
         }
 
@@ -275,6 +304,11 @@ pub fn check_body(&mut self) {
             self.visit_body(body);
         }
 
+        // A dry run records every error as it is found, and emits nothing.
+        if self.dry_run.is_some() {
+            return;
+        }
+
         // If we got through const-checking without emitting any "primary" errors, emit any
         // "secondary" errors if they occurred.
         let secondary_errors = mem::take(&mut self.secondary_errors);
@@ -326,7 +360,13 @@ pub fn check_op_spanned<O: NonConstOp<'tcx>>(&mut self, op: O, span: Span) {
             Status::Unstable(gate) if self.tcx.features().active(gate) => {
                 let unstable_in_stable = self.ccx.is_const_stable_const_fn()
                     && !super::rustc_allow_const_fn_unstable(self.tcx, self.def_id(), gate);
-                if unstable_in_stable {
+                if let Some(dry_run) = &mut self.dry_run {
+                    if unstable_in_stable {
+                        dry_run.errors.push(span);
+                    } else if !dry_run.features.contains(&gate) {
+                        dry_run.features.push(gate);
+                    }
+                } else if unstable_in_stable {
                     emit_unstable_in_stable_error(self.ccx, span, gate);
                 }
 
@@ -337,6 +377,11 @@ pub fn check_op_spanned<O: NonConstOp<'tcx>>(&mut self, op: O, span: Span) {
             Status::Forbidden => None,
         };
 
+        if let Some(dry_run) = &mut self.dry_run {
+            dry_run.errors.push(span);
+            return;
+        }
+
         if self.tcx.sess.opts.unstable_opts.unleash_the_miri_inside_of_you {
             self.tcx.sess.miri_unleashed_feature(span, gate);
             return;
@@ -357,9 +402,13 @@ pub fn check_op_spanned<O: NonConstOp<'tcx>>(&mut self, op: O, span: Span) {
 
     fn check_static(&mut self, def_id: DefId, span: Span) {
         if self.tcx.is_thread_local_static(def_id) {
-            self.tcx
-                .sess
-                .span_delayed_bug(span, "tls access is checked in `Rvalue::ThreadLocalRef`");
+            if let Some(dry_run) = &mut self.dry_run {
+                dry_run.errors.push(span);
+            } else {
+                self.tcx
+                    .sess
+                    .span_delayed_bug(span, "tls access is checked in `Rvalue::ThreadLocalRef`");
+            }
         }
         self.check_op_spanned(ops::StaticAccess, span)
     }
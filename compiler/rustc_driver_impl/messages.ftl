@@ -1,3 +1,6 @@
+driver_impl_couldnt_dump_vtable_layouts =
+    unexpected error occurred while dumping vtable layouts: {$error}
+
 driver_impl_ice = the compiler unexpectedly panicked. this is a bug.
 driver_impl_ice_bug_report = we would appreciate a bug report: {$bug_report_url}
 driver_impl_ice_bug_report_internal_feature = using internal features is not supported and expected to cause internal compiler errors when used incorrectly
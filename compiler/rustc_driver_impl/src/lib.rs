@@ -35,14 +35,16 @@
 use rustc_metadata::creader::MetadataLoader;
 use rustc_metadata::locator;
 use rustc_session::config::{nightly_options, CG_OPTIONS, Z_OPTIONS};
-use rustc_session::config::{ErrorOutputType, Input, OutFileName, OutputType, TrimmedDefPaths};
+use rustc_session::config::{
+    ErrorOutputType, Input, OutFileName, OutputType, SwitchWithOptPath, TrimmedDefPaths,
+};
 use rustc_session::getopts::{self, Matches};
 use rustc_session::lint::{Lint, LintId};
 use rustc_session::{config, EarlyErrorHandler, Session};
 use rustc_span::def_id::LOCAL_CRATE;
 use rustc_span::source_map::FileLoader;
 use rustc_span::symbol::sym;
-use rustc_span::FileName;
+use rustc_span::{FileName, Symbol};
 use rustc_target::json::ToJson;
 use rustc_target::spec::{Target, TargetTriple};
 
@@ -52,9 +54,9 @@
 use std::ffi::OsString;
 use std::fmt::Write as _;
 use std::fs::{self, File};
-use std::io::{self, IsTerminal, Read, Write};
+use std::io::{self, BufWriter, IsTerminal, Read, Write};
 use std::panic::{self, catch_unwind, PanicInfo};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{self, Command, Stdio};
 use std::str;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -96,8 +98,8 @@ pub(super) fn install() {}
 }
 
 use crate::session_diagnostics::{
-    RLinkEmptyVersionNumber, RLinkEncodingVersionMismatch, RLinkRustcVersionMismatch,
-    RLinkWrongFileType, RlinkNotAFile, RlinkUnableToRead,
+    CouldntDumpVtableLayouts, RLinkEmptyVersionNumber, RLinkEncodingVersionMismatch,
+    RLinkRustcVersionMismatch, RLinkWrongFileType, RlinkNotAFile, RlinkUnableToRead,
 };
 
 rustc_fluent_macro::fluent_messages! { "../messages.ftl" }
@@ -460,6 +462,16 @@ fn run_compiler(
                 sess.code_stats.print_vtable_sizes(crate_name);
             }
 
+            if let SwitchWithOptPath::Enabled(ref path) =
+                sess.opts.unstable_opts.dump_vtable_layouts
+            {
+                let crate_name = queries.global_ctxt()?.enter(|tcx| tcx.crate_name(LOCAL_CRATE));
+
+                if let Err(err) = dump_vtable_layouts(sess, crate_name, path) {
+                    sess.emit_fatal(CouldntDumpVtableLayouts { error: err.to_string() });
+                }
+            }
+
             Ok(Some(linker))
         })?;
 
@@ -482,6 +494,48 @@ fn run_compiler(
     })
 }
 
+/// Writes the vtable layouts recorded during codegen to a JSON file in the given output
+/// directory, or the current one.
+fn dump_vtable_layouts(
+    sess: &Session,
+    crate_name: Symbol,
+    output_directory: &Option<PathBuf>,
+) -> io::Result<()> {
+    let output_directory = if let Some(ref directory) = output_directory {
+        fs::create_dir_all(directory)?;
+        directory.as_path()
+    } else {
+        Path::new(".")
+    };
+
+    let layouts: Vec<_> = sess
+        .code_stats
+        .take_vtable_layouts()
+        .into_iter()
+        .map(|layout| {
+            let entries: Vec<_> = layout
+                .entries
+                .into_iter()
+                .map(|entry| serde_json::json!({ "offset": entry.offset, "entry": entry.description }))
+                .collect();
+            serde_json::json!({
+                "type": layout.type_description,
+                "trait": layout.trait_description,
+                "size": layout.size,
+                "align": layout.align,
+                "type_size": layout.type_size,
+                "type_align": layout.type_align,
+                "entries": entries,
+            })
+        })
+        .collect();
+
+    let output_path = output_directory.join(format!("{crate_name}.vtable_layouts.json"));
+    let file = BufWriter::new(File::create(output_path)?);
+    serde_json::to_writer_pretty(file, &layouts)?;
+    Ok(())
+}
+
 // Extract output directory and file from matches.
 fn make_output(matches: &getopts::Matches) -> (Option<PathBuf>, Option<OutFileName>) {
     let odir = matches.opt_str("out-dir").map(|o| PathBuf::from(&o));
@@ -474,6 +474,9 @@ pub struct BuiltinAttribute {
     // RFC 2397
     gated!(do_not_recommend, Normal, template!(Word), WarnFollowing, experimental!(do_not_recommend)),
 
+    // `#[stable_vtable]`
+    gated!(stable_vtable, Normal, template!(Word), WarnFollowing, experimental!(stable_vtable)),
+
     // `#[cfi_encoding = ""]`
     gated!(
         cfi_encoding, Normal, template!(NameValueStr: "encoding"), ErrorPreceding,
@@ -222,6 +222,8 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (internal, rustc_attrs, "1.0.0", None, None),
     /// Allows using the `#[stable]` and `#[unstable]` attributes.
     (internal, staged_api, "1.0.0", None, None),
+    /// Allows using `#[stable_vtable]` to freeze the vtable layout of a trait.
+    (unstable, stable_vtable, "CURRENT_RUSTC_VERSION", None, None),
     /// Added for testing E0705; perma-unstable.
     (internal, test_2018_feature, "1.31.0", None, Some(Edition::Edition2018)),
     /// Added for testing unstable lints; perma-unstable.
@@ -318,6 +318,14 @@ hir_analysis_simd_ffi_highly_experimental = use of SIMD type{$snip} in FFI is hi
 hir_analysis_specialization_trait = implementing `rustc_specialization_trait` traits is unstable
     .help = add `#![feature(min_specialization)]` to the crate attributes to enable
 
+hir_analysis_stable_vtable_not_object_safe = `#[stable_vtable]` can only be applied to object safe traits
+
+hir_analysis_stable_vtable_relative_vtables = `#[stable_vtable]` traits can't be used with `-Zrelative-vtables`
+    .note = relative vtables don't have the stable vtable layout
+
+hir_analysis_stable_vtable_unstable_supertrait = the supertrait `{$supertrait}` of a `#[stable_vtable]` trait must also be `#[stable_vtable]`
+    .note = the vtable of a trait includes the entries of its supertraits
+
 hir_analysis_start_function_parameters = `#[start]` function is not allowed to have type parameters
     .label = `#[start]` function cannot have type parameters
 
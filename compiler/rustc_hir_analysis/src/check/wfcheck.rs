@@ -1,5 +1,6 @@
 use crate::autoderef::Autoderef;
 use crate::constrained_generic_params::{identify_constrained_generic_params, Parameter};
+use crate::errors;
 
 use rustc_ast as ast;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexSet};
@@ -1134,9 +1135,41 @@ fn check_trait(tcx: TyCtxt<'_>, item: &hir::Item<'_>) -> Result<(), ErrorGuarant
     if let hir::ItemKind::Trait(..) = item.kind {
         check_gat_where_clauses(tcx, item.owner_id.def_id);
     }
+
+    if tcx.has_attr(def_id, sym::stable_vtable) {
+        check_stable_vtable(tcx, def_id);
+    }
     res
 }
 
+/// Checks that the vtable layout of a `#[stable_vtable]` trait can be frozen: the trait has to
+/// have a vtable, the layout of its supertraits has to be frozen too, and the entries have to be
+/// pointers.
+fn check_stable_vtable(tcx: TyCtxt<'_>, trait_def_id: LocalDefId) {
+    let span = tcx.def_span(trait_def_id);
+    if !tcx.check_is_object_safe(trait_def_id.to_def_id()) {
+        tcx.sess.emit_err(errors::StableVtableNotObjectSafe { span });
+        return;
+    }
+
+    if tcx.sess.opts.unstable_opts.relative_vtables {
+        tcx.sess.emit_err(errors::StableVtableRelativeVtables { span });
+    }
+
+    // Auto traits have no vtable entries.
+    for &(predicate, span) in tcx.super_predicates_of(trait_def_id).predicates {
+        if let Some(super_trait) = predicate.as_trait_clause()
+            && !tcx.trait_is_auto(super_trait.def_id())
+            && !tcx.has_attr(super_trait.def_id(), sym::stable_vtable)
+        {
+            tcx.sess.emit_err(errors::StableVtableUnstableSupertrait {
+                span,
+                supertrait: tcx.def_path_str(super_trait.def_id()),
+            });
+        }
+    }
+}
+
 /// Checks all associated type defaults of trait `trait_def_id`.
 ///
 /// Assuming the defaults are used, check that all predicates (bounds on the
@@ -859,6 +859,30 @@ pub(crate) struct ConstSpecialize {
     pub span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(hir_analysis_stable_vtable_not_object_safe)]
+pub(crate) struct StableVtableNotObjectSafe {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_analysis_stable_vtable_relative_vtables)]
+#[note]
+pub(crate) struct StableVtableRelativeVtables {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_analysis_stable_vtable_unstable_supertrait)]
+#[note]
+pub(crate) struct StableVtableUnstableSupertrait {
+    #[primary_span]
+    pub span: Span,
+    pub supertrait: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(hir_analysis_static_specialize)]
 pub(crate) struct StaticSpecialize {
@@ -447,6 +447,9 @@ fn write_out_deps(tcx: TyCtxt<'_>, outputs: &OutputFilenames, out_filenames: &[P
         if let Some(ref profile_sample) = sess.opts.unstable_opts.profile_sample_use {
             files.push(normalize_path(profile_sample.as_path().to_path_buf()));
         }
+        if let Some(ref vtable_profile) = sess.opts.unstable_opts.profile_guided_vtable_layout {
+            files.push(normalize_path(vtable_profile.as_path().to_path_buf()));
+        }
 
         // Debugger visualizer files
         for debugger_visualizer in tcx.debugger_visualizers(LOCAL_CRATE) {
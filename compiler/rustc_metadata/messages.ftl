@@ -91,6 +91,10 @@ metadata_found_staticlib =
 metadata_framework_only_windows =
     link kind `raw-dylib` is only supported on Windows targets
 
+metadata_global_allocator_with_dylib =
+    the `#[global_allocator]` in {$allocator_crate} would be ignored, as {$dylib_crate} is linked dynamically and provides the allocator
+    .help = link {$dylib_crate} statically instead, e.g. by not passing `-C prefer-dynamic`
+
 metadata_global_alloc_required =
     no global memory allocator found but one is required; link to std or add `#[global_allocator]` to a static item that implements the GlobalAlloc trait
 
@@ -53,12 +53,12 @@
 
 use crate::creader::CStore;
 use crate::errors::{
-    BadPanicStrategy, CrateDepMultiple, IncompatiblePanicInDropStrategy, LibRequired,
-    RequiredPanicStrategy, RlibRequired, RustcLibRequired, TwoPanicRuntimes,
+    BadPanicStrategy, CrateDepMultiple, GlobalAllocatorWithDylib, IncompatiblePanicInDropStrategy,
+    LibRequired, RequiredPanicStrategy, RlibRequired, RustcLibRequired, TwoPanicRuntimes,
 };
 
 use rustc_data_structures::fx::FxHashMap;
-use rustc_hir::def_id::CrateNum;
+use rustc_hir::def_id::{CrateNum, LOCAL_CRATE};
 use rustc_middle::middle::dependency_format::{Dependencies, DependencyList, Linkage};
 use rustc_middle::ty::TyCtxt;
 use rustc_session::config::CrateType;
@@ -416,4 +416,42 @@ fn verify_ok(tcx: TyCtxt<'_>, list: &[Linkage]) {
             }
         }
     }
+
+    // When a dependency is linked dynamically, the allocator shim of the dylib
+    // containing one is used instead of generating one for the final artifact,
+    // so a `#[global_allocator]` which is linked in statically would silently be
+    // ignored. Reject it instead.
+    if list.iter().any(|&linkage| linkage == Linkage::Dynamic) {
+        let statically_linked_allocator = if tcx.has_global_allocator(LOCAL_CRATE) {
+            Some(LOCAL_CRATE)
+        } else {
+            list.iter().enumerate().find_map(|(i, &linkage)| {
+                let cnum = CrateNum::new(i + 1);
+                (linkage == Linkage::Static && tcx.has_global_allocator(cnum)).then_some(cnum)
+            })
+        };
+        if let Some(allocator_cnum) = statically_linked_allocator {
+            sess.emit_err(GlobalAllocatorWithDylib {
+                allocator_crate: tcx.crate_name(allocator_cnum),
+                dylib_crate: tcx.crate_name(allocator_shim_dylib(tcx, list)),
+            });
+        }
+    }
+}
+
+/// Returns the dynamically linked crate whose allocator shim is used, i.e. the
+/// first one that had all of its own dependencies linked in statically, as
+/// dylibs only contain a shim then. This is usually std.
+fn allocator_shim_dylib(tcx: TyCtxt<'_>, list: &[Linkage]) -> CrateNum {
+    let mut dylibs = list
+        .iter()
+        .enumerate()
+        .filter(|&(_, &linkage)| linkage == Linkage::Dynamic)
+        .map(|(i, _)| CrateNum::new(i + 1));
+    let first = dylibs.clone().next().unwrap();
+    dylibs
+        .find(|&cnum| {
+            tcx.dylib_dependency_formats(cnum).iter().all(|&(_, style)| style != RequireDynamic)
+        })
+        .unwrap_or(first)
 }
@@ -47,6 +47,14 @@ pub struct TwoPanicRuntimes {
     pub cur_name: Symbol,
 }
 
+#[derive(Diagnostic)]
+#[diag(metadata_global_allocator_with_dylib)]
+#[help]
+pub struct GlobalAllocatorWithDylib {
+    pub allocator_crate: Symbol,
+    pub dylib_crate: Symbol,
+}
+
 #[derive(Diagnostic)]
 #[diag(metadata_bad_panic_strategy)]
 pub struct BadPanicStrategy {
@@ -272,6 +272,7 @@ fn into_args(self) -> (DefId, SimplifiedType) {
     }
 
     associated_types_for_impl_traits_in_associated_fn => { table_defaulted_array }
+    vtable_method_order => { table_defaulted_array }
 
     visibility => { cdata.get_visibility(def_id.index) }
     adt_def => { cdata.get_adt_def(def_id.index, tcx) }
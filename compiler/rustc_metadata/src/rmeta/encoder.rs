@@ -1419,6 +1419,8 @@ fn encode_def_ids(&mut self) {
             if let DefKind::Trait = def_kind {
                 record!(self.tables.trait_def[def_id] <- self.tcx.trait_def(def_id));
                 record!(self.tables.super_predicates_of[def_id] <- self.tcx.super_predicates_of(def_id));
+                let vtable_method_order = self.tcx.vtable_method_order(def_id);
+                record_defaulted_array!(self.tables.vtable_method_order[def_id] <- vtable_method_order);
 
                 let module_children = self.tcx.module_children_local(local_id);
                 record_array!(self.tables.module_children_non_reexports[def_id] <-
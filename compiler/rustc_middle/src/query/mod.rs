@@ -1264,6 +1264,39 @@
         desc { |tcx| "finding all existential vtable entries for trait `{}`", tcx.def_path_str(key) }
     }
 
+    /// The number of calls of each trait method, keyed by its path, read from the file passed to
+    /// `-Zprofile-guided-vtable-layout`.
+    query vtable_method_call_counts(_: ()) -> &'tcx FxHashMap<String, u64> {
+        arena_cache
+        eval_always
+        desc { "reading the vtable method call counts" }
+    }
+
+    /// The order of the own vtable entries of a trait, if `-Zprofile-guided-vtable-layout` moved
+    /// its most called methods first. Empty if the entries are in definition order.
+    ///
+    /// This is decided by the crate defining the trait, so that all crates agree on the layout.
+    query vtable_method_order(key: DefId) -> &'tcx [DefId] {
+        desc { |tcx| "computing the vtable method order for trait `{}`", tcx.def_path_str(key) }
+        separate_provide_extern
+    }
+
+    /// The methods of local traits that the MIR of this crate mentions with a trait object or a
+    /// type that isn't known yet as the `Self` type, so they may be called through a vtable.
+    ///
+    /// Used by `-Zvirtual-function-elimination` without fat LTO to find the vtable entries that
+    /// can never be called.
+    query virtually_called_trait_methods(_: ()) -> &'tcx DefIdSet {
+        arena_cache
+        desc { "finding the trait methods that may be called through a vtable" }
+    }
+
+    /// A hash of the vtable layout of a `#[stable_vtable]` trait, which the `vtable_layout_hash`
+    /// intrinsic returns so that separately built code can check that it agrees on the layout.
+    query stable_vtable_layout_hash(key: DefId) -> u64 {
+        desc { |tcx| "hashing the vtable layout of trait `{}`", tcx.def_path_str(key) }
+    }
+
     query vtable_entries(key: ty::PolyTraitRef<'tcx>)
                         -> &'tcx [ty::VtblEntry<'tcx>] {
         desc { |tcx| "finding all vtable entries for trait `{}`", tcx.def_path_str(key.def_id()) }
@@ -1274,6 +1307,8 @@
             key.1, key.0 }
     }
 
+    /// The vtable of a type for a trait object, keyed on the principal trait only: auto traits
+    /// don't add any entries, so e.g. `dyn Trait` and `dyn Trait + Send` share one vtable.
     query vtable_allocation(key: (Ty<'tcx>, Option<ty::PolyExistentialTraitRef<'tcx>>)) -> mir::interpret::AllocId {
         desc { |tcx| "vtable const allocation for <{} as {}>",
             key.0,
@@ -1281,6 +1316,15 @@
         }
     }
 
+    /// The vtable that is codegened, which differs from `vtable_allocation` in the entries that
+    /// `-Zvirtual-function-elimination` leaves vacant.
+    query codegen_vtable_allocation(key: (Ty<'tcx>, Option<ty::PolyExistentialTraitRef<'tcx>>)) -> mir::interpret::AllocId {
+        desc { |tcx| "codegened vtable allocation for <{} as {}>",
+            key.0,
+            key.1.map(|trait_ref| format!("{trait_ref}")).unwrap_or("_".to_owned())
+        }
+    }
+
     query codegen_select_candidate(
         key: (ty::ParamEnv<'tcx>, ty::TraitRef<'tcx>)
     ) -> Result<&'tcx ImplSource<'tcx, ()>, CodegenObligationError> {
@@ -64,7 +64,10 @@ pub enum InstanceDef<'tcx> {
     /// Because this is a required part of the function's ABI but can't be tracked
     /// as a property of the function pointer, we use a single "caller location"
     /// (the definition of the function itself).
-    ReifyShim(DefId),
+    ///
+    /// With CFI and KCFI, every function written into a vtable goes through this shim too, see
+    /// `Instance::resolve_for_vtable`.
+    ReifyShim(DefId, ReifyReason),
 
     /// `<fn() as FnTrait>::call_*` (generated `FnTrait` implementation for `fn()` pointers).
     ///
@@ -154,13 +157,24 @@ pub fn upstream_monomorphization(&self, tcx: TyCtxt<'tcx>) -> Option<CrateNum> {
     }
 }
 
+/// Why a `ReifyShim` was created, which tells apart the shims of a function for `fn` pointers
+/// and for vtables.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[derive(TyEncodable, TyDecodable, HashStable)]
+pub enum ReifyReason {
+    /// The shim is reified to a `fn` pointer.
+    FnPtr,
+    /// The shim is written into a vtable.
+    Vtable,
+}
+
 impl<'tcx> InstanceDef<'tcx> {
     #[inline]
     pub fn def_id(self) -> DefId {
         match self {
             InstanceDef::Item(def_id)
             | InstanceDef::VTableShim(def_id)
-            | InstanceDef::ReifyShim(def_id)
+            | InstanceDef::ReifyShim(def_id, _)
             | InstanceDef::FnPtrShim(def_id, _)
             | InstanceDef::Virtual(def_id, _)
             | InstanceDef::Intrinsic(def_id)
@@ -307,7 +321,8 @@ fn fmt_instance(
     match instance.def {
         InstanceDef::Item(_) => Ok(()),
         InstanceDef::VTableShim(_) => write!(f, " - shim(vtable)"),
-        InstanceDef::ReifyShim(_) => write!(f, " - shim(reify)"),
+        InstanceDef::ReifyShim(_, ReifyReason::FnPtr) => write!(f, " - shim(reify)"),
+        InstanceDef::ReifyShim(_, ReifyReason::Vtable) => write!(f, " - shim(reify-vtable)"),
         InstanceDef::ThreadLocalShim(_) => write!(f, " - shim(tls)"),
         InstanceDef::Intrinsic(_) => write!(f, " - intrinsic"),
         InstanceDef::Virtual(_, num) => write!(f, " - virtual#{num}"),
@@ -431,11 +446,11 @@ pub fn resolve_for_fn_ptr(
             match resolved.def {
                 InstanceDef::Item(def) if resolved.def.requires_caller_location(tcx) => {
                     debug!(" => fn pointer created for function with #[track_caller]");
-                    resolved.def = InstanceDef::ReifyShim(def);
+                    resolved.def = InstanceDef::ReifyShim(def, ReifyReason::FnPtr);
                 }
                 InstanceDef::Virtual(def_id, _) => {
                     debug!(" => fn pointer created for virtual call");
-                    resolved.def = InstanceDef::ReifyShim(def_id);
+                    resolved.def = InstanceDef::ReifyShim(def_id, ReifyReason::FnPtr);
                 }
                 _ => {}
             }
@@ -495,22 +510,35 @@ pub fn resolve_for_vtable(
                                 // Create a shim for the `FnOnce/FnMut/Fn` method we are calling
                                 // - unlike functions, invoking a closure always goes through a
                                 // trait.
-                                resolved = Instance { def: InstanceDef::ReifyShim(def_id), args };
+                                resolved = Instance {
+                                    def: InstanceDef::ReifyShim(def_id, ReifyReason::Vtable),
+                                    args,
+                                };
                             } else {
                                 debug!(
                                     " => vtable fn pointer created for function with #[track_caller]: {:?}", def
                                 );
-                                resolved.def = InstanceDef::ReifyShim(def);
+                                resolved.def = InstanceDef::ReifyShim(def, ReifyReason::Vtable);
                             }
                         }
                     }
                     InstanceDef::Virtual(def_id, _) => {
                         debug!(" => vtable fn pointer created for virtual call");
-                        resolved.def = InstanceDef::ReifyShim(def_id);
+                        resolved.def = InstanceDef::ReifyShim(def_id, ReifyReason::Vtable);
                     }
                     _ => {}
                 }
 
+                // With CFI and KCFI, calls through a vtable are checked against a type id that
+                // only knows the trait of the method, while calls through `fn` pointers are
+                // checked against the type id of the signature of the function. So the vtable
+                // gets a shim with the former, and the function keeps the latter.
+                if needs_vtable_shims(tcx) && !matches!(resolved.def, InstanceDef::ReifyShim(..)) {
+                    debug!(" => vtable fn pointer created for CFI");
+                    resolved =
+                        Instance { def: InstanceDef::ReifyShim(def_id, ReifyReason::Vtable), args };
+                }
+
                 resolved
             })
         }
@@ -536,6 +564,18 @@ pub fn resolve_drop_in_place(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> ty::Instance<'t
         Instance::expect_resolve(tcx, ty::ParamEnv::reveal_all(), def_id, args)
     }
 
+    /// Returns the `drop_in_place` written into the vtables of `ty`. Like the methods, it's a shim
+    /// with CFI and KCFI, see `resolve_for_vtable`.
+    pub fn resolve_drop_in_place_for_vtable(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> ty::Instance<'tcx> {
+        if needs_vtable_shims(tcx) {
+            let def_id = tcx.require_lang_item(LangItem::DropInPlace, None);
+            let args = tcx.mk_args(&[ty.into()]);
+            Instance { def: InstanceDef::ReifyShim(def_id, ReifyReason::Vtable), args }
+        } else {
+            Instance::resolve_drop_in_place(tcx, ty)
+        }
+    }
+
     #[instrument(level = "debug", skip(tcx), ret)]
     pub fn fn_once_adapter_instance(
         tcx: TyCtxt<'tcx>,
@@ -644,6 +684,12 @@ pub fn polymorphize(self, tcx: TyCtxt<'tcx>) -> Self {
     }
 }
 
+/// Whether the functions written into vtables go through a `ReifyShim`, so that they can have
+/// different CFI type ids than the functions themselves.
+fn needs_vtable_shims(tcx: TyCtxt<'_>) -> bool {
+    tcx.sess.is_sanitizer_cfi_enabled() || tcx.sess.is_sanitizer_kcfi_enabled()
+}
+
 fn polymorphize<'tcx>(
     tcx: TyCtxt<'tcx>,
     instance: ty::InstanceDef<'tcx>,
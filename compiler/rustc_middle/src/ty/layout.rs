@@ -1028,16 +1028,21 @@ fn ty_and_layout_pointee_info_at(
                     // If the niche is a pointer, it's either valid (according
                     // to its type), or null (which the niche field's scalar
                     // validity range encodes). This allows using
-                    // `dereferenceable_or_null` for e.g., `Option<&T>`, and
-                    // this will continue to work as long as we don't start
-                    // using more niches than just null (e.g., the first page of
-                    // the address space, or unaligned pointers).
+                    // `dereferenceable_or_null` for e.g., `Option<&T>`. This
+                    // only works if null is the only niche value in use, which
+                    // isn't the case for enums that also use the unaligned
+                    // values of vtable pointers.
                     Variants::Multiple {
-                        tag_encoding: TagEncoding::Niche { untagged_variant, .. },
+                        tag_encoding:
+                            TagEncoding::Niche { untagged_variant, ref niche_variants, niche_start },
                         tag_field,
                         ..
                     } if this.fields.offset(tag_field) == offset => {
-                        Some(this.for_variant(cx, untagged_variant))
+                        if niche_start == 0 && niche_variants.start() == niche_variants.end() {
+                            Some(this.for_variant(cx, untagged_variant))
+                        } else {
+                            None
+                        }
                     }
                     _ => Some(this),
                 };
@@ -440,6 +440,7 @@ fn fmt<Infcx: InferCtxtLike<Interner = TyCtxt<'tcx>>>(
     crate::ty::ClosureKind,
     crate::ty::ParamConst,
     crate::ty::ParamTy,
+    crate::ty::ReifyReason,
     interpret::Scalar,
     interpret::AllocId,
     rustc_target::abi::Size,
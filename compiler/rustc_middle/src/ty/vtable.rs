@@ -1,8 +1,14 @@
 use std::fmt;
 
 use crate::mir::interpret::{alloc_range, AllocId, Allocation, Pointer, Scalar};
+use crate::ty::print::with_no_trimmed_paths;
 use crate::ty::{self, Instance, PolyTraitRef, Ty, TyCtxt};
 use rustc_ast::Mutability;
+use rustc_hir::def_id::DefId;
+use rustc_session::code_stats::{VTableEntryInfo, VTableLayoutInfo};
+use rustc_session::config::{CrateType, Lto};
+use rustc_span::sym;
+use rustc_target::abi::Size;
 
 #[derive(Clone, Copy, PartialEq, HashStable)]
 pub enum VtblEntry<'tcx> {
@@ -45,21 +51,139 @@ impl<'tcx> TyCtxt<'tcx> {
 pub const COMMON_VTABLE_ENTRIES_SIZE: usize = 1;
 pub const COMMON_VTABLE_ENTRIES_ALIGN: usize = 2;
 
+impl<'tcx> TyCtxt<'tcx> {
+    /// Returns the entries of the vtable for `ty` as an object of `poly_trait_ref`, or just the
+    /// common entries if there is no principal trait.
+    pub fn vtable_entries_for_ty(
+        self,
+        ty: Ty<'tcx>,
+        poly_trait_ref: Option<ty::PolyExistentialTraitRef<'tcx>>,
+    ) -> &'tcx [VtblEntry<'tcx>] {
+        if let Some(poly_trait_ref) = poly_trait_ref {
+            let trait_ref = poly_trait_ref.with_self_ty(self, ty);
+            let trait_ref = self.erase_regions(trait_ref);
+
+            self.vtable_entries(trait_ref)
+        } else {
+            TyCtxt::COMMON_VTABLE_ENTRIES
+        }
+    }
+
+    /// Like `vtable_entries_for_ty`, but with the entries of the methods that
+    /// `-Zvirtual-function-elimination` found can never be called left vacant.
+    ///
+    /// This is only for the vtables that end up in the binary, not for the ones const evaluation
+    /// sees: finding the eliminated methods needs the MIR of the whole crate, which includes
+    /// constants that may need a vtable to be evaluated.
+    pub fn codegen_vtable_entries_for_ty(
+        self,
+        ty: Ty<'tcx>,
+        poly_trait_ref: Option<ty::PolyExistentialTraitRef<'tcx>>,
+    ) -> &'tcx [VtblEntry<'tcx>] {
+        let entries = self.vtable_entries_for_ty(ty, poly_trait_ref);
+        let is_eliminated = |entry: &VtblEntry<'tcx>| match *entry {
+            VtblEntry::Method(instance) => self.is_eliminated_vtable_method(instance),
+            _ => false,
+        };
+        if entries.iter().any(is_eliminated) {
+            self.arena.alloc_from_iter(entries.iter().map(|entry| {
+                if is_eliminated(entry) {
+                    VtblEntry::Vacant
+                } else {
+                    *entry
+                }
+            }))
+        } else {
+            entries
+        }
+    }
+
+    /// Returns whether `-Zvirtual-function-elimination` found that the vtable entry for this
+    /// method can never be called, so it can be left vacant.
+    ///
+    /// With fat LTO, LLVM does this itself and sees all the callers, so this is only done without
+    /// it. Only methods of local traits that no other crate can call are considered, and they are
+    /// eliminated if no MIR of this crate may call them through a vtable.
+    pub fn is_eliminated_vtable_method(self, instance: Instance<'tcx>) -> bool {
+        if !self.sess.opts.unstable_opts.virtual_function_elimination || self.sess.lto() == Lto::Fat
+        {
+            return false;
+        }
+
+        let Some(item) = self.opt_associated_item(instance.def_id()) else {
+            return false;
+        };
+        let trait_method = match item.container {
+            ty::TraitContainer => item.def_id,
+            ty::ImplContainer => match item.trait_item_def_id {
+                Some(trait_item_def_id) => trait_item_def_id,
+                None => return false,
+            },
+        };
+        let Some(trait_def_id) = self.trait_of_item(trait_method).and_then(DefId::as_local) else {
+            return false;
+        };
+        // Other code may call the methods through vtables with a stable layout.
+        if self.has_attr(trait_def_id, sym::stable_vtable) {
+            return false;
+        }
+
+        let only_executables =
+            self.crate_types().iter().all(|crate_type| *crate_type == CrateType::Executable);
+        if !only_executables && self.effective_visibilities(()).is_reachable(trait_def_id) {
+            return false;
+        }
+
+        !self.virtually_called_trait_methods(()).contains(&trait_method)
+    }
+
+    /// Returns the offset of the vtable entry with the given index from the start of the vtable.
+    /// For the number of entries, this is the size of the vtable.
+    ///
+    /// Entries are pointer-sized, except with `-Zrelative-vtables`, where the entries after the
+    /// common ones are 32-bit offsets from the start of the vtable to the method or supertrait
+    /// vtable, instead of pointers to them.
+    pub fn vtable_entry_offset(self, idx: usize) -> Size {
+        let ptr_size = self.data_layout.pointer_size;
+        let common = TyCtxt::COMMON_VTABLE_ENTRIES.len();
+        if self.sess.opts.unstable_opts.relative_vtables && idx > common {
+            ptr_size * u64::try_from(common).unwrap()
+                + Size::from_bytes(4) * u64::try_from(idx - common).unwrap()
+        } else {
+            ptr_size * u64::try_from(idx).unwrap()
+        }
+    }
+}
+
 /// Retrieves an allocation that represents the contents of a vtable.
 /// Since this is a query, allocations are cached and not duplicated.
 pub(super) fn vtable_allocation_provider<'tcx>(
     tcx: TyCtxt<'tcx>,
     key: (Ty<'tcx>, Option<ty::PolyExistentialTraitRef<'tcx>>),
 ) -> AllocId {
-    let (ty, poly_trait_ref) = key;
+    build_vtable_allocation(tcx, key, false)
+}
 
-    let vtable_entries = if let Some(poly_trait_ref) = poly_trait_ref {
-        let trait_ref = poly_trait_ref.with_self_ty(tcx, ty);
-        let trait_ref = tcx.erase_regions(trait_ref);
+/// Like `vtable_allocation_provider`, but for the vtable that is codegened, see
+/// `TyCtxt::codegen_vtable_entries_for_ty`.
+pub(super) fn codegen_vtable_allocation_provider<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    key: (Ty<'tcx>, Option<ty::PolyExistentialTraitRef<'tcx>>),
+) -> AllocId {
+    build_vtable_allocation(tcx, key, true)
+}
 
-        tcx.vtable_entries(trait_ref)
+fn build_vtable_allocation<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    key: (Ty<'tcx>, Option<ty::PolyExistentialTraitRef<'tcx>>),
+    for_codegen: bool,
+) -> AllocId {
+    let (ty, poly_trait_ref) = key;
+
+    let vtable_entries = if for_codegen {
+        tcx.codegen_vtable_entries_for_ty(ty, poly_trait_ref)
     } else {
-        TyCtxt::COMMON_VTABLE_ENTRIES
+        tcx.vtable_entries_for_ty(ty, poly_trait_ref)
     };
 
     let layout = tcx
@@ -72,18 +196,18 @@ pub(super) fn vtable_allocation_provider<'tcx>(
     let ptr_size = tcx.data_layout.pointer_size;
     let ptr_align = tcx.data_layout.pointer_align.abi;
 
-    let vtable_size = ptr_size * u64::try_from(vtable_entries.len()).unwrap();
+    let vtable_size = tcx.vtable_entry_offset(vtable_entries.len());
     let mut vtable = Allocation::uninit(vtable_size, ptr_align);
 
     // No need to do any alignment checks on the memory accesses below, because we know the
     // allocation is correctly aligned as we created it above. Also we're only offsetting by
     // multiples of `ptr_align`, which means that it will stay aligned to `ptr_align`.
 
+    let relative = tcx.sess.opts.unstable_opts.relative_vtables;
     for (idx, entry) in vtable_entries.iter().enumerate() {
-        let idx: u64 = u64::try_from(idx).unwrap();
         let scalar = match entry {
             VtblEntry::MetadataDropInPlace => {
-                let instance = ty::Instance::resolve_drop_in_place(tcx, ty);
+                let instance = ty::Instance::resolve_drop_in_place_for_vtable(tcx, ty);
                 let fn_alloc_id = tcx.reserve_and_set_fn_alloc(instance);
                 let fn_ptr = Pointer::from(fn_alloc_id);
                 Scalar::from_pointer(fn_ptr, &tcx)
@@ -91,6 +215,9 @@ pub(super) fn vtable_allocation_provider<'tcx>(
             VtblEntry::MetadataSize => Scalar::from_uint(size, ptr_size),
             VtblEntry::MetadataAlign => Scalar::from_uint(align, ptr_size),
             VtblEntry::Vacant => continue,
+            // These are offsets from the vtable itself, which only the codegen backend knows the
+            // address of, so it fills them in. See `rustc_codegen_ssa::meth::get_vtable`.
+            VtblEntry::Method(_) | VtblEntry::TraitVPtr(_) if relative => continue,
             VtblEntry::Method(instance) => {
                 // Prepare the fn ptr we write into the vtable.
                 let instance = instance.polymorphize(tcx);
@@ -101,16 +228,58 @@ pub(super) fn vtable_allocation_provider<'tcx>(
             VtblEntry::TraitVPtr(trait_ref) => {
                 let super_trait_ref = trait_ref
                     .map_bound(|trait_ref| ty::ExistentialTraitRef::erase_self_ty(tcx, trait_ref));
-                let supertrait_alloc_id = tcx.vtable_allocation((ty, Some(super_trait_ref)));
+                let supertrait_key = (ty, Some(super_trait_ref));
+                let supertrait_alloc_id = if for_codegen {
+                    tcx.codegen_vtable_allocation(supertrait_key)
+                } else {
+                    tcx.vtable_allocation(supertrait_key)
+                };
                 let vptr = Pointer::from(supertrait_alloc_id);
                 Scalar::from_pointer(vptr, &tcx)
             }
         };
         vtable
-            .write_scalar(&tcx, alloc_range(ptr_size * idx, ptr_size), scalar)
+            .write_scalar(&tcx, alloc_range(tcx.vtable_entry_offset(idx), ptr_size), scalar)
             .expect("failed to build vtable representation");
     }
 
+    // Only the codegened vtables are reported, so that each is reported once.
+    if for_codegen && tcx.sess.opts.unstable_opts.dump_vtable_layouts.enabled() {
+        let entries = vtable_entries
+            .iter()
+            .enumerate()
+            .map(|(idx, entry)| VTableEntryInfo {
+                offset: tcx.vtable_entry_offset(idx).bytes(),
+                description: with_no_trimmed_paths!(format!("{entry:?}")),
+            })
+            .collect();
+        tcx.sess.code_stats.record_vtable_layout(VTableLayoutInfo {
+            type_description: with_no_trimmed_paths!(ty.to_string()),
+            trait_description: poly_trait_ref
+                .map(|trait_ref| with_no_trimmed_paths!(trait_ref.to_string())),
+            size: vtable_size.bytes(),
+            align: ptr_align.bytes(),
+            type_size: size,
+            type_align: align,
+            entries,
+        });
+    }
+
+    if for_codegen
+        && tcx.sess.opts.unstable_opts.print_vtable_sizes
+        && let Some(trait_ref) = poly_trait_ref
+    {
+        let vacant_entries =
+            vtable_entries.iter().filter(|entry| matches!(entry, VtblEntry::Vacant)).count();
+        let trait_name = with_no_trimmed_paths!(tcx.def_path_str(trait_ref.def_id()));
+        tcx.sess.code_stats.record_vtable_instance(
+            trait_ref.def_id(),
+            &trait_name,
+            vtable_size.bytes(),
+            vacant_entries,
+        );
+    }
+
     vtable.mutability = Mutability::Not;
     tcx.reserve_and_set_memory_alloc(tcx.mk_const_alloc(vtable))
 }
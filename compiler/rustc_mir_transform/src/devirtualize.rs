@@ -0,0 +1,210 @@
+//! Turns calls through a trait object into direct calls when the concrete type behind the trait
+//! object is known.
+//!
+//! We consider patterns of the form
+//!   _a = &x (or any other value of type `&T` or `*const T`)
+//!   _b = move? _a as &dyn Trait (PointerCoercion(Unsize))
+//!   _c = &(*_b) (optionally, or any number of copies and reborrows)
+//!   _d = <dyn Trait as Trait>::method(move? _c, ...)
+//! where all the locals are only assigned once, and `T` is fully known. The vtable of `_b` is then
+//! the one of `T`, so we look up the method in its entries and call it directly with `_a` as the
+//! receiver:
+//!   _d = <T as Trait>::method(copy _a, ...)
+//! which the inliner can then inline.
+//!
+//! Only shared references and raw pointers are handled, as the receiver is copied out of the
+//! coercion, which isn't possible for mutable references. The receiver also has to be the same
+//! kind of pointer as `_a`, so a raw pointer reborrowed as a reference isn't passed as is.
+
+use rustc_index::bit_set::BitSet;
+use rustc_index::IndexVec;
+use rustc_middle::mir::*;
+use rustc_middle::ty::adjustment::PointerCoercion;
+use rustc_middle::ty::{self, Instance, InstanceDef, Ty, TyCtxt, TypeVisitableExt, VtblEntry};
+
+use crate::ssa::SsaLocals;
+use crate::MirPass;
+
+pub struct Devirtualize;
+
+impl<'tcx> MirPass<'tcx> for Devirtualize {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 2
+    }
+
+    #[instrument(level = "trace", skip(self, tcx, body))]
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        debug!(def_id = ?body.source.def_id());
+        devirtualize_calls(tcx, body);
+    }
+}
+
+/// The pointer an unsizing coercion to a trait object was done from.
+#[derive(Clone, Debug)]
+struct Coercion<'tcx> {
+    /// The pointer to the concrete type, either a constant or a copy of an SSA local.
+    source: Operand<'tcx>,
+    /// The type of the pointer.
+    pointer_ty: Ty<'tcx>,
+    /// The type the pointer points to.
+    concrete_ty: Ty<'tcx>,
+    location: Location,
+}
+
+fn devirtualize_calls<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+    let param_env = tcx.param_env_reveal_all_normalized(body.source.def_id());
+    let ssa = SsaLocals::new(body);
+
+    let mut coercions = IndexVec::from_elem(None, &body.local_decls);
+    for (local, rvalue, location) in ssa.assignments(body) {
+        let coercion = match rvalue {
+            // Reborrowing a trait object keeps its vtable. This is how method receivers are made.
+            Rvalue::Ref(_, BorrowKind::Shared, place) | Rvalue::AddressOf(_, place)
+                if let [ProjectionElem::Deref] = place.projection[..] =>
+            {
+                coercions[ssa.copy_classes()[place.local]].clone()
+            }
+            _ => trait_object_coercion(tcx, body, &ssa, rvalue, location),
+        };
+        if coercion.is_some() {
+            debug!(?local, ?coercion);
+            coercions[local] = coercion;
+        }
+    }
+
+    let mut reused_locals = BitSet::new_empty(body.local_decls.len());
+    let mut coercions_to_copy = Vec::new();
+    for (bb, data) in body.basic_blocks.as_mut_preserves_cfg().iter_enumerated_mut() {
+        let span = data.terminator().source_info.span;
+        let TerminatorKind::Call { func, args, .. } = &mut data.terminator_mut().kind else {
+            continue;
+        };
+        let Some(Operand::Copy(receiver) | Operand::Move(receiver)) = args.first() else {
+            continue;
+        };
+        let Some(receiver) = receiver.as_local() else { continue };
+        let Some(coercion) = &coercions[ssa.copy_classes()[receiver]] else { continue };
+        if !same_pointer_kind(body.local_decls[receiver].ty, coercion.pointer_ty) {
+            continue;
+        }
+        let Some(instance) = resolve_virtual_call(tcx, param_env, func, coercion.concrete_ty)
+        else {
+            continue;
+        };
+        debug!(?bb, ?receiver, ?instance);
+
+        *func = Operand::function_handle(tcx, instance.def_id(), instance.args, span);
+        args[0] = coercion.source.clone();
+        if let Some(place) = coercion.source.place() {
+            reused_locals.insert(place.local);
+        }
+        coercions_to_copy.push(coercion.location);
+    }
+
+    if reused_locals.is_empty() {
+        return;
+    }
+
+    // The coercions may have moved out of the pointers we now copy again, and the pointers may
+    // be dead by the time of the call, so make sure they stay initialized and live.
+    let basic_blocks = body.basic_blocks.as_mut_preserves_cfg();
+    for location in coercions_to_copy {
+        let statement = &mut basic_blocks[location.block].statements[location.statement_index];
+        if let StatementKind::Assign(box (_, Rvalue::Cast(_, operand, _))) = &mut statement.kind
+            && let Operand::Move(place) = *operand
+        {
+            *operand = Operand::Copy(place);
+        }
+    }
+    for data in basic_blocks {
+        for statement in &mut data.statements {
+            if let StatementKind::StorageLive(local) | StatementKind::StorageDead(local) =
+                statement.kind
+                && reused_locals.contains(local)
+            {
+                statement.make_nop();
+            }
+        }
+    }
+}
+
+/// Returns the pointer that `rvalue` coerces to a trait object, if the type it points to is known
+/// and the pointer can be copied again at the call.
+fn trait_object_coercion<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &Body<'tcx>,
+    ssa: &SsaLocals,
+    rvalue: &Rvalue<'tcx>,
+    location: Location,
+) -> Option<Coercion<'tcx>> {
+    let Rvalue::Cast(CastKind::PointerCoercion(PointerCoercion::Unsize), operand, target_ty) =
+        rvalue
+    else {
+        return None;
+    };
+    let source_ty = operand.ty(body, tcx);
+    let concrete_ty = match *source_ty.kind() {
+        ty::Ref(_, pointee, Mutability::Not) | ty::RawPtr(ty::TypeAndMut { ty: pointee, .. }) => {
+            pointee
+        }
+        _ => return None,
+    };
+    let target_pointee = target_ty.builtin_deref(true)?.ty;
+    if !target_pointee.is_trait() || concrete_ty.is_trait() || concrete_ty.has_param() {
+        return None;
+    }
+
+    let source = match operand {
+        Operand::Constant(_) => operand.clone(),
+        Operand::Copy(place) | Operand::Move(place) => {
+            let local = place.as_local()?;
+            if !ssa.is_ssa(local) {
+                return None;
+            }
+            Operand::Copy(local.into())
+        }
+    };
+    Some(Coercion { source, pointer_ty: source_ty, concrete_ty, location })
+}
+
+/// Whether `a` and `b` are both shared references, or both raw pointers of the same mutability.
+fn same_pointer_kind<'tcx>(a: Ty<'tcx>, b: Ty<'tcx>) -> bool {
+    match (a.kind(), b.kind()) {
+        (ty::Ref(_, _, a), ty::Ref(_, _, b)) => a == b,
+        (ty::RawPtr(a), ty::RawPtr(b)) => a.mutbl == b.mutbl,
+        _ => false,
+    }
+}
+
+/// If `func` is a method called through a trait object, returns the method that its vtable
+/// contains for `concrete_ty`.
+fn resolve_virtual_call<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    func: &Operand<'tcx>,
+    concrete_ty: Ty<'tcx>,
+) -> Option<Instance<'tcx>> {
+    let ty::FnDef(def_id, args) = *func.constant()?.const_.ty().kind() else { return None };
+    let dyn_ty = args.first()?.as_type()?;
+    let ty::Dynamic(predicates, ..) = *dyn_ty.kind() else { return None };
+    if dyn_ty.has_param() {
+        return None;
+    }
+
+    let Ok(Some(Instance { def: InstanceDef::Virtual(_, idx), .. })) =
+        Instance::resolve(tcx, param_env, def_id, args)
+    else {
+        return None;
+    };
+    // Not `codegen_vtable_entries_for_ty`, as the entries it leaves vacant for
+    // `-Zvirtual-function-elimination` depend on the optimized MIR of the crate, this body
+    // included. Calling a method directly is fine even if its entry is vacant.
+    let trait_ref = predicates.principal()?.with_self_ty(tcx, concrete_ty);
+    let entries = tcx.vtable_entries(tcx.erase_regions(trait_ref));
+    match entries.get(idx)? {
+        VtblEntry::Method(instance) if matches!(instance.def, InstanceDef::Item(_)) => {
+            Some(*instance)
+        }
+        _ => None,
+    }
+}
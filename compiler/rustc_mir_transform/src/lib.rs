@@ -74,6 +74,7 @@
 mod deduplicate_blocks;
 mod deref_separator;
 mod dest_prop;
+mod devirtualize;
 pub mod dump_mir;
 mod early_otherwise_branch;
 mod elaborate_box_derefs;
@@ -565,6 +566,7 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
         &[
             &check_alignment::CheckAlignment,
             &lower_slice_len::LowerSliceLenCalls, // has to be done before inlining, otherwise actual call will be almost always inlined. Also simple, so can just do first
+            &devirtualize::Devirtualize, // has to be done before inlining, so that the direct calls can be inlined
             &inline::Inline,
             // Substitutions during inlining may introduce switch on enums with uninhabited branches.
             &uninhabited_enum_branching::UninhabitedEnumBranching,
@@ -1123,8 +1123,9 @@ fn create_mono_items_for_vtable_methods<'tcx>(
             let poly_trait_ref = principal.with_self_ty(tcx, impl_ty);
             assert!(!poly_trait_ref.has_escaping_bound_vars());
 
-            // Walk all methods of the trait, including those of its supertraits
-            let entries = tcx.vtable_entries(poly_trait_ref);
+            // Walk all methods of the trait, including those of its supertraits. Methods that
+            // virtual function elimination made vacant are never called, so skip them too.
+            let entries = tcx.codegen_vtable_entries_for_ty(impl_ty, Some(principal));
             let methods = entries
                 .iter()
                 .filter_map(|entry| match entry {
@@ -1145,7 +1146,8 @@ fn create_mono_items_for_vtable_methods<'tcx>(
         }
 
         // Also add the destructor.
-        visit_drop_use(tcx, impl_ty, false, source, output);
+        let instance = Instance::resolve_drop_in_place_for_vtable(tcx, impl_ty);
+        visit_instance_use(tcx, instance, false, source, output);
     }
 }
 
@@ -1376,7 +1378,7 @@ fn collect_alloc<'tcx>(tcx: TyCtxt<'tcx>, alloc_id: AllocId, output: &mut MonoIt
             }
         }
         GlobalAlloc::VTable(ty, trait_ref) => {
-            let alloc_id = tcx.vtable_allocation((ty, trait_ref));
+            let alloc_id = tcx.codegen_vtable_allocation((ty, trait_ref));
             collect_alloc(tcx, alloc_id, output)
         }
     }
@@ -21,6 +21,7 @@
 mod partitioning;
 mod polymorphize;
 mod util;
+mod virtual_calls;
 
 rustc_fluent_macro::fluent_messages! { "../messages.ftl" }
 
@@ -50,4 +51,5 @@ fn custom_coerce_unsize_info<'tcx>(
 pub fn provide(providers: &mut Providers) {
     partitioning::provide(providers);
     polymorphize::provide(providers);
+    virtual_calls::provide(providers);
 }
@@ -0,0 +1,70 @@
+//! Finds the trait methods that may be called through a vtable, for
+//! `-Zvirtual-function-elimination` without fat LTO.
+//!
+//! A method can only be called through a vtable if the MIR mentions it with a trait object as the
+//! `Self` type, or with a type that isn't known yet and may be instantiated with one. For methods
+//! of traits that no other crate can see, this is all the MIR that can call them, so the vtable
+//! entries of the other methods can be left vacant and their bodies don't need to be codegened.
+
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefIdSet;
+use rustc_middle::mir::visit::Visitor;
+use rustc_middle::mir::{ConstOperand, Location};
+use rustc_middle::query::Providers;
+use rustc_middle::ty::{self, TyCtxt, TypeVisitableExt};
+
+struct VirtualCallVisitor<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    methods: DefIdSet,
+}
+
+impl<'tcx> Visitor<'tcx> for VirtualCallVisitor<'tcx> {
+    fn visit_constant(&mut self, constant: &ConstOperand<'tcx>, location: Location) {
+        if let ty::FnDef(def_id, args) = *constant.const_.ty().kind() {
+            if def_id.is_local() && self.tcx.trait_of_item(def_id).is_some() {
+                let self_ty = args.type_at(0);
+                if matches!(self_ty.kind(), ty::Dynamic(..))
+                    || self_ty.has_param()
+                    || self_ty.has_aliases()
+                {
+                    self.methods.insert(def_id);
+                }
+            }
+        }
+        self.super_constant(constant, location);
+    }
+}
+
+fn virtually_called_trait_methods(tcx: TyCtxt<'_>, (): ()) -> DefIdSet {
+    let mut visitor = VirtualCallVisitor { tcx, methods: DefIdSet::default() };
+
+    for &def_id in tcx.mir_keys(()) {
+        let def_kind = tcx.def_kind(def_id);
+        if matches!(def_kind, DefKind::Fn | DefKind::AssocFn | DefKind::Closure) {
+            visitor.visit_body(tcx.optimized_mir(def_id));
+        }
+        if matches!(
+            def_kind,
+            DefKind::Const
+                | DefKind::AssocConst
+                | DefKind::AnonConst
+                | DefKind::InlineConst
+                | DefKind::Static(..)
+        ) || tcx.is_const_fn_raw(def_id.to_def_id())
+            || tcx.is_const_default_method(def_id.to_def_id())
+        {
+            visitor.visit_body(tcx.mir_for_ctfe(def_id));
+        }
+        if !matches!(def_kind, DefKind::Ctor(..)) {
+            for promoted in tcx.promoted_mir(def_id) {
+                visitor.visit_body(promoted);
+            }
+        }
+    }
+
+    visitor.methods
+}
+
+pub fn provide(providers: &mut Providers) {
+    providers.virtually_called_trait_methods = virtually_called_trait_methods;
+}
@@ -22,20 +22,32 @@
 //
 //  - `check_crate` finally emits the diagnostics based on the data generated
 //    in the last step
+//
+// Before any of this happens, `$crate::...` paths written inside the bodies of
+// `#[macro_export] macro_rules!` items are resolved from the crate root and the
+// imports they go through are marked as used. Those paths are only resolved when
+// the macro is expanded, possibly in a downstream crate, so otherwise an import
+// that only exists for the benefit of an exported macro would be reported as unused.
+// Bindings of any visibility are followed: public imports are never reported
+// anyway, and when a downstream expansion goes through a private one, the
+// privacy error (E0603) is reported there.
 
 use crate::imports::ImportKind;
 use crate::module_to_string;
-use crate::Resolver;
+use crate::{BindingKey, Resolver};
 
 use rustc_ast as ast;
+use rustc_ast::token::{self, Token};
+use rustc_ast::tokenstream::{TokenStream, TokenTree};
 use rustc_ast::visit::{self, Visitor};
+use rustc_ast::{attr, ItemKind};
 use rustc_data_structures::fx::{FxHashMap, FxIndexMap};
 use rustc_data_structures::unord::UnordSet;
 use rustc_errors::{pluralize, MultiSpan};
-use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def::{DefKind, Namespace, Res};
 use rustc_session::lint::builtin::{MACRO_USE_EXTERN_CRATE, UNUSED_EXTERN_CRATES, UNUSED_IMPORTS};
 use rustc_session::lint::BuiltinLintDiagnostics;
-use rustc_span::symbol::{kw, Ident};
+use rustc_span::symbol::{kw, sym, Ident};
 use rustc_span::{Span, DUMMY_SP};
 
 struct UnusedImport<'a> {
@@ -188,6 +200,64 @@ fn visit_use_tree(&mut self, use_tree: &'a ast::UseTree, id: ast::NodeId, nested
     }
 }
 
+/// Collects the `$crate::a::b` paths written in the bodies of `#[macro_export] macro_rules!`
+/// items, as the segments following `$crate`.
+#[derive(Default)]
+struct MacroExportPathCollector {
+    paths: Vec<Vec<Ident>>,
+}
+
+impl MacroExportPathCollector {
+    fn collect_paths(&mut self, tokens: &TokenStream) {
+        let mut trees = tokens.trees().peekable();
+        while let Some(tree) = trees.next() {
+            match tree {
+                TokenTree::Token(Token { kind: token::Dollar, .. }, _) => {
+                    let Some(TokenTree::Token(krate, _)) = trees.peek() else { continue };
+                    if !krate.is_keyword(kw::Crate) {
+                        continue;
+                    }
+                    trees.next();
+
+                    let mut path = Vec::new();
+                    while let Some(TokenTree::Token(Token { kind: token::ModSep, .. }, _)) =
+                        trees.peek()
+                    {
+                        trees.next();
+                        match trees.peek() {
+                            Some(TokenTree::Token(token, _)) => match token.ident() {
+                                Some((ident, _)) => {
+                                    trees.next();
+                                    path.push(ident);
+                                }
+                                None => break,
+                            },
+                            _ => break,
+                        }
+                    }
+                    if !path.is_empty() {
+                        self.paths.push(path);
+                    }
+                }
+                TokenTree::Token(..) => {}
+                TokenTree::Delimited(_, _, tts) => self.collect_paths(tts),
+            }
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for MacroExportPathCollector {
+    fn visit_item(&mut self, item: &'a ast::Item) {
+        if let ItemKind::MacroDef(ref def) = item.kind
+            && def.macro_rules
+            && attr::contains_name(&item.attrs, sym::macro_export)
+        {
+            self.collect_paths(&def.body.tokens);
+        }
+        visit::walk_item(self, item);
+    }
+}
+
 enum UnusedSpanResult {
     Used,
     FlatUnused(Span, Span),
@@ -280,10 +350,39 @@ fn calc_unused_spans(
 }
 
 impl Resolver<'_, '_> {
+    /// Marks the imports that a `$crate::...` path from an exported macro would go through
+    /// as used, following the path from the crate root as far as it resolves to modules.
+    fn record_macro_export_path_use(&mut self, path: &[Ident]) {
+        let Some((last, init)) = path.split_last() else { return };
+        let mut module = self.graph_root;
+        for &ident in init {
+            let binding =
+                self.resolution(module, BindingKey::new(ident, Namespace::TypeNS)).borrow().binding;
+            let Some(binding) = binding else { return };
+            self.record_use(ident, binding, false);
+            match binding.module() {
+                Some(next) => module = next,
+                None => return,
+            }
+        }
+        for ns in [Namespace::TypeNS, Namespace::ValueNS, Namespace::MacroNS] {
+            let binding = self.resolution(module, BindingKey::new(*last, ns)).borrow().binding;
+            if let Some(binding) = binding {
+                self.record_use(*last, binding, false);
+            }
+        }
+    }
+
     pub(crate) fn check_unused(&mut self, krate: &ast::Crate) {
         let tcx = self.tcx;
         let mut maybe_unused_extern_crates = FxHashMap::default();
 
+        let mut macro_export_paths = MacroExportPathCollector::default();
+        visit::walk_crate(&mut macro_export_paths, krate);
+        for path in &macro_export_paths.paths {
+            self.record_macro_export_path_use(path);
+        }
+
         for import in self.potentially_unused_imports.iter() {
             match import.kind {
                 _ if import.used.get()
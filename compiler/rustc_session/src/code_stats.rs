@@ -85,10 +85,56 @@ pub struct VTableSizeInfo {
     pub upcasting_cost_percent: f64,
 }
 
+/// Statistics about the vtables of a trait which were instantiated in this crate, i.e. for each
+/// type that was coerced to a trait object of the trait.
+#[derive(Default)]
+pub struct VTableInstancesInfo {
+    pub trait_name: String,
+
+    /// Number of distinct vtables.
+    pub vtables: usize,
+
+    /// Total size of the vtables, in bytes.
+    pub bytes: u64,
+
+    /// Total number of `Vacant` entries in the vtables, i.e. entries of methods which can't be
+    /// called for the type, which still take up space.
+    pub vacant_entries: usize,
+}
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct VTableEntryInfo {
+    /// Offset of the entry from the start of the vtable, in bytes.
+    pub offset: u64,
+    /// What the entry holds, e.g. `MetadataSize` or `Method(<S as Trait>::method)`.
+    pub description: String,
+}
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct VTableLayoutInfo {
+    pub type_description: String,
+
+    /// The trait the vtable is for, or `None` for vtables which only hold the common entries
+    /// (e.g. for `dyn Send`).
+    pub trait_description: Option<String>,
+
+    /// Size and align of the vtable itself.
+    pub size: u64,
+    pub align: u64,
+
+    /// Size and align of the type, as stored in the `MetadataSize` and `MetadataAlign` entries.
+    pub type_size: u64,
+    pub type_align: u64,
+
+    pub entries: Vec<VTableEntryInfo>,
+}
+
 #[derive(Default)]
 pub struct CodeStats {
     type_sizes: Lock<FxHashSet<TypeSizeInfo>>,
     vtable_sizes: Lock<FxHashMap<DefId, VTableSizeInfo>>,
+    vtable_instances: Lock<FxHashMap<DefId, VTableInstancesInfo>>,
+    vtable_layouts: Lock<FxHashSet<VTableLayoutInfo>>,
 }
 
 impl CodeStats {
@@ -130,6 +176,42 @@ pub fn record_vtable_size(&self, trait_did: DefId, trait_name: &str, info: VTabl
         );
     }
 
+    pub fn record_vtable_instance(
+        &self,
+        trait_did: DefId,
+        trait_name: &str,
+        bytes: u64,
+        vacant_entries: usize,
+    ) {
+        let mut vtable_instances = self.vtable_instances.lock();
+        let info = vtable_instances.entry(trait_did).or_insert_with(|| VTableInstancesInfo {
+            trait_name: trait_name.to_string(),
+            ..Default::default()
+        });
+        info.vtables += 1;
+        info.bytes += bytes;
+        info.vacant_entries += vacant_entries;
+    }
+
+    pub fn record_vtable_layout(&self, info: VTableLayoutInfo) {
+        self.vtable_layouts.lock().insert(info);
+    }
+
+    /// Takes the recorded vtable layouts, sorted by type and trait.
+    pub fn take_vtable_layouts(&self) -> Vec<VTableLayoutInfo> {
+        // We will soon sort, so the initial order does not matter.
+        #[allow(rustc::potential_query_instability)]
+        let mut layouts =
+            std::mem::take(&mut *self.vtable_layouts.lock()).into_iter().collect::<Vec<_>>();
+
+        layouts.sort_by(|a, b| {
+            a.type_description
+                .cmp(&b.type_description)
+                .then_with(|| a.trait_description.cmp(&b.trait_description))
+        });
+        layouts
+    }
+
     pub fn print_type_sizes(&self) {
         let type_sizes = self.type_sizes.borrow();
         // We will soon sort, so the initial order does not matter.
@@ -232,27 +314,43 @@ pub fn print_vtable_sizes(&self, crate_name: Symbol) {
         // We will soon sort, so the initial order does not matter.
         #[allow(rustc::potential_query_instability)]
         let mut infos =
-            std::mem::take(&mut *self.vtable_sizes.lock()).into_values().collect::<Vec<_>>();
+            std::mem::take(&mut *self.vtable_sizes.lock()).into_iter().collect::<Vec<_>>();
+        let mut instances = std::mem::take(&mut *self.vtable_instances.lock());
 
         // Primary sort: cost % in reverse order (from largest to smallest)
         // Secondary sort: trait_name
-        infos.sort_by(|a, b| {
+        infos.sort_by(|(_, a), (_, b)| {
             a.upcasting_cost_percent
                 .total_cmp(&b.upcasting_cost_percent)
                 .reverse()
                 .then_with(|| a.trait_name.cmp(&b.trait_name))
         });
 
-        for VTableSizeInfo {
-            trait_name,
-            entries,
-            entries_ignoring_upcasting,
-            entries_for_upcasting,
-            upcasting_cost_percent,
-        } in infos
+        for (trait_did, info) in infos {
+            let VTableSizeInfo {
+                trait_name,
+                entries,
+                entries_ignoring_upcasting,
+                entries_for_upcasting,
+                upcasting_cost_percent,
+            } = info;
+            let VTableInstancesInfo { vtables, bytes, vacant_entries, .. } =
+                instances.remove(&trait_did).unwrap_or_default();
+            println!(
+                r#"print-vtable-sizes {{ "crate_name": "{crate_name}", "trait_name": "{trait_name}", "entries": "{entries}", "entries_ignoring_upcasting": "{entries_ignoring_upcasting}", "entries_for_upcasting": "{entries_for_upcasting}", "upcasting_cost_percent": "{upcasting_cost_percent}", "instantiated_vtables": "{vtables}", "instantiated_bytes": "{bytes}", "vacant_entries": "{vacant_entries}" }}"#
+            );
+        }
+
+        // The remaining vtables are of traits defined in other crates, like `Debug` or `Fn`, which
+        // only have the instantiated statistics.
+        #[allow(rustc::potential_query_instability)]
+        let mut foreign_instances = instances.into_values().collect::<Vec<_>>();
+        foreign_instances.sort_by(|a, b| a.trait_name.cmp(&b.trait_name));
+
+        for VTableInstancesInfo { trait_name, vtables, bytes, vacant_entries } in foreign_instances
         {
             println!(
-                r#"print-vtable-sizes {{ "crate_name": "{crate_name}", "trait_name": "{trait_name}", "entries": "{entries}", "entries_ignoring_upcasting": "{entries_ignoring_upcasting}", "entries_for_upcasting": "{entries_for_upcasting}", "upcasting_cost_percent": "{upcasting_cost_percent}" }}"#
+                r#"print-vtable-sizes {{ "crate_name": "{crate_name}", "trait_name": "{trait_name}", "instantiated_vtables": "{vtables}", "instantiated_bytes": "{bytes}", "vacant_entries": "{vacant_entries}" }}"#
             );
         }
     }
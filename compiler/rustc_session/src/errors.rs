@@ -122,8 +122,8 @@ pub struct CannotMixAndMatchSanitizers {
 pub struct SplitLtoUnitRequiresLto;
 
 #[derive(Diagnostic)]
-#[diag(session_unstable_virtual_function_elimination)]
-pub struct UnstableVirtualFunctionElimination;
+#[diag(session_relative_vtables_virtual_function_elimination)]
+pub struct RelativeVtablesVirtualFunctionElimination;
 
 #[derive(Diagnostic)]
 #[diag(session_unsupported_dwarf_version)]
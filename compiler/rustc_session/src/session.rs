@@ -1631,11 +1631,13 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
         sess.emit_err(errors::SplitLtoUnitRequiresLto);
     }
 
-    // VFE requires LTO.
-    if sess.lto() != config::Lto::Fat {
-        if sess.opts.unstable_opts.virtual_function_elimination {
-            sess.emit_err(errors::UnstableVirtualFunctionElimination);
-        }
+    // Without fat LTO, VFE is done on the MIR and only makes vtable entries vacant, but with it
+    // LLVM needs to see the vtable entries as pointers.
+    if sess.opts.unstable_opts.relative_vtables
+        && sess.opts.unstable_opts.virtual_function_elimination
+        && sess.lto() == config::Lto::Fat
+    {
+        sess.emit_err(errors::RelativeVtablesVirtualFunctionElimination);
     }
 
     if sess.opts.unstable_opts.stack_protector != StackProtector::None {
@@ -835,6 +835,7 @@
         hexagon_target_feature,
         hidden,
         homogeneous_aggregate,
+        hooked,
         host,
         html_favicon_url,
         html_logo_url,
@@ -1567,6 +1568,7 @@
         sse,
         sse4a_target_feature,
         stable,
+        stable_vtable,
         staged_api,
         start,
         state,
@@ -1770,6 +1772,7 @@
         vreg,
         vreg_low16,
         vtable_align,
+        vtable_layout_hash,
         vtable_size,
         warn,
         wasm_abi,
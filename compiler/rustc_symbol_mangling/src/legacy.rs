@@ -72,8 +72,14 @@ pub(super) fn mangle<'tcx>(
         let _ = printer.write_str("{{vtable-shim}}");
     }
 
-    if let ty::InstanceDef::ReifyShim(..) = instance.def {
-        let _ = printer.write_str("{{reify-shim}}");
+    match instance.def {
+        ty::InstanceDef::ReifyShim(_, ty::ReifyReason::FnPtr) => {
+            let _ = printer.write_str("{{reify-shim}}");
+        }
+        ty::InstanceDef::ReifyShim(_, ty::ReifyReason::Vtable) => {
+            let _ = printer.write_str("{{reify-vtable-shim}}");
+        }
+        _ => {}
     }
 
     printer.path.finish(hash)
@@ -1092,6 +1092,57 @@ pub fn typeid_for_fnsig<'tcx>(
     typeid
 }
 
+/// Returns the type the receiver of `instance` is encoded as if it is a call through a vtable or a
+/// function written into one.
+///
+/// Through a vtable, the receiver is passed as a thin pointer to the erased type whatever its
+/// declared type is, and the caller only knows the trait the method is from. So both the shims
+/// written into vtables and the calls through them encode the receiver as `*mut dyn Trait`, with
+/// just the trait the method is from. The `Fn*` traits are all encoded as `FnOnce`, since the same
+/// closure body can be in the vtables of all of them, and `drop_in_place`, which is in the header
+/// of every vtable, as `*mut dyn Drop`. The functions themselves keep their own type id, so calls
+/// through function pointers still match them.
+fn vtable_receiver_ty<'tcx>(tcx: TyCtxt<'tcx>, instance: &Instance<'tcx>) -> Option<Ty<'tcx>> {
+    let def_id = instance.def_id();
+    let trait_def_id = match instance.def {
+        ty::InstanceDef::Virtual(..)
+        | ty::InstanceDef::VTableShim(..)
+        | ty::InstanceDef::ReifyShim(_, ty::ReifyReason::Vtable)
+            if tcx.lang_items().drop_in_place_fn() == Some(def_id) =>
+        {
+            tcx.lang_items().drop_trait()?
+        }
+        ty::InstanceDef::Virtual(..) => tcx.trait_of_item(def_id)?,
+        ty::InstanceDef::VTableShim(..)
+        | ty::InstanceDef::ReifyShim(_, ty::ReifyReason::Vtable)
+            if tcx.is_closure(def_id) && !tcx.is_coroutine(def_id) =>
+        {
+            tcx.lang_items().fn_once_trait()?
+        }
+        ty::InstanceDef::VTableShim(..)
+        | ty::InstanceDef::ReifyShim(_, ty::ReifyReason::Vtable) => {
+            let trait_method = match tcx.impl_of_method(def_id) {
+                Some(_) => tcx.associated_item(def_id).trait_item_def_id?,
+                None => def_id,
+            };
+            tcx.trait_of_item(trait_method)?
+        }
+        _ => return None,
+    };
+    let trait_def_id = if tcx.is_fn_trait(trait_def_id) {
+        tcx.lang_items().fn_once_trait()?
+    } else {
+        trait_def_id
+    };
+
+    let trait_ref =
+        ty::ExistentialTraitRef::erase_self_ty(tcx, ty::TraitRef::identity(tcx, trait_def_id));
+    let predicates = tcx.mk_poly_existential_predicates(&[ty::Binder::dummy(
+        ExistentialPredicate::Trait(trait_ref),
+    )]);
+    Some(Ty::new_mut_ptr(tcx, Ty::new_dynamic(tcx, predicates, tcx.lifetimes.re_erased, ty::Dyn)))
+}
+
 /// Returns a type metadata identifier for the specified Instance using the Itanium C++ ABI with
 /// vendor extended type qualifiers and types for Rust types that are not used at the FFI boundary.
 pub fn typeid_for_instance<'tcx>(
@@ -1105,36 +1156,10 @@ pub fn typeid_for_instance<'tcx>(
             bug!("typeid_for_instance: couldn't get fn_abi of instance {:?}", instance)
         });
 
-    // If this instance is a method and self is a reference, get the impl it belongs to
-    let impl_def_id = tcx.impl_of_method(instance.def_id());
-    if impl_def_id.is_some() && !fn_abi.args.is_empty() && fn_abi.args[0].layout.ty.is_ref() {
-        // If this impl is not an inherent impl, get the trait it implements
-        if let Some(trait_ref) = tcx.impl_trait_ref(impl_def_id.unwrap()) {
-            // Transform the concrete self into a reference to a trait object
-            let existential_predicate = trait_ref.map_bound(|trait_ref| {
-                ty::ExistentialPredicate::Trait(ty::ExistentialTraitRef::erase_self_ty(
-                    tcx, trait_ref,
-                ))
-            });
-            let existential_predicates = tcx.mk_poly_existential_predicates(&[ty::Binder::dummy(
-                existential_predicate.skip_binder(),
-            )]);
-            // Is the concrete self mutable?
-            let self_ty = if fn_abi.args[0].layout.ty.is_mutable_ptr() {
-                Ty::new_mut_ref(
-                    tcx,
-                    tcx.lifetimes.re_erased,
-                    Ty::new_dynamic(tcx, existential_predicates, tcx.lifetimes.re_erased, ty::Dyn),
-                )
-            } else {
-                Ty::new_imm_ref(
-                    tcx,
-                    tcx.lifetimes.re_erased,
-                    Ty::new_dynamic(tcx, existential_predicates, tcx.lifetimes.re_erased, ty::Dyn),
-                )
-            };
-
-            // Replace the concrete self in an fn_abi clone by the reference to a trait object
+    // If this instance may be called through a vtable, get the type its receiver is encoded as
+    if !fn_abi.args.is_empty() {
+        if let Some(self_ty) = vtable_receiver_ty(tcx, instance) {
+            // Replace the receiver in an fn_abi clone by the pointer to a trait object
             let mut fn_abi = fn_abi.clone();
             // HACK(rcvalle): It is okay to not replace or update the entire ArgAbi here because the
             //   other fields are never used.
@@ -44,7 +44,8 @@ pub(super) fn mangle<'tcx>(
     let shim_kind = match instance.def {
         ty::InstanceDef::ThreadLocalShim(_) => Some("tls"),
         ty::InstanceDef::VTableShim(_) => Some("vtable"),
-        ty::InstanceDef::ReifyShim(_) => Some("reify"),
+        ty::InstanceDef::ReifyShim(_, ty::ReifyReason::FnPtr) => Some("reify"),
+        ty::InstanceDef::ReifyShim(_, ty::ReifyReason::Vtable) => Some("reify_vtable"),
 
         _ => None,
     };
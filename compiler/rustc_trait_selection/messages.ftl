@@ -19,9 +19,13 @@ trait_selection_closure_kind_requirement = the requirement to implement `{$expec
 
 trait_selection_dump_vtable_entries = vtable entries for `{$trait_ref}`: {$entries}
 
+trait_selection_dump_vtable_shared = this vtable is also used for {$traits}
+
 trait_selection_empty_on_clause_in_rustc_on_unimplemented = empty `on`-clause in `#[rustc_on_unimplemented]`
     .label = empty on-clause here
 
+trait_selection_failed_to_read_vtable_profile = failed to read vtable profile `{$path}`: {$err}
+
 trait_selection_ignored_diagnostic_option = `{$option_name}` is ignored due to previous definition of `{$option_name}`
     .other_label = `{$option_name}` is first declared here
     .label = `{$option_name}` is already declared here
@@ -35,6 +39,9 @@ trait_selection_malformed_on_unimplemented_attr = malformed `on_unimplemented` a
     .help = only `message`, `note` and `label` are allowed as options
     .label = invalid option found here
 
+trait_selection_malformed_vtable_profile = malformed line {$line} in vtable profile `{$path}`
+    .note = expected a method path followed by its call count
+
 trait_selection_missing_options_for_on_unimplemented_attr = missing options for `on_unimplemented` attribute
     .help = at least one of the `message`, `note` and `label` options are expected
 
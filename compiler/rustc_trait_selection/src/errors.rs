@@ -6,6 +6,7 @@
 use rustc_macros::Diagnostic;
 use rustc_middle::ty::{self, ClosureKind, PolyTraitRef, Ty};
 use rustc_span::{Span, Symbol};
+use std::path::Path;
 
 #[derive(Diagnostic)]
 #[diag(trait_selection_dump_vtable_entries)]
@@ -14,6 +15,29 @@ pub struct DumpVTableEntries<'a> {
     pub span: Span,
     pub trait_ref: PolyTraitRef<'a>,
     pub entries: String,
+    #[subdiagnostic]
+    pub shared: Option<DumpVTableShared>,
+}
+
+#[derive(Subdiagnostic)]
+#[note(trait_selection_dump_vtable_shared)]
+pub struct DumpVTableShared {
+    pub traits: String,
+}
+
+#[derive(Diagnostic)]
+#[diag(trait_selection_failed_to_read_vtable_profile)]
+pub struct FailedToReadVtableProfile<'a> {
+    pub path: &'a Path,
+    pub err: std::io::Error,
+}
+
+#[derive(Diagnostic)]
+#[diag(trait_selection_malformed_vtable_profile)]
+#[note]
+pub struct MalformedVtableProfile<'a> {
+    pub path: &'a Path,
+    pub line: usize,
 }
 
 #[derive(Diagnostic)]
@@ -1,21 +1,28 @@
-use crate::errors::DumpVTableEntries;
+use crate::errors::{
+    DumpVTableEntries, DumpVTableShared, FailedToReadVtableProfile, MalformedVtableProfile,
+};
 use crate::traits::{impossible_predicates, is_vtable_safe_method};
-use rustc_hir::def_id::DefId;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::stable_hasher::{Hash64, StableHasher};
+use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_hir::lang_items::LangItem;
 use rustc_infer::traits::util::PredicateSet;
 use rustc_infer::traits::ImplSource;
 use rustc_middle::query::Providers;
 use rustc_middle::traits::BuiltinImplSource;
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::visit::TypeVisitableExt;
 use rustc_middle::ty::GenericArgs;
 use rustc_middle::ty::{self, GenericParamDefKind, ToPredicate, Ty, TyCtxt, VtblEntry};
 use rustc_span::{sym, Span};
 use smallvec::SmallVec;
 
-use std::fmt::Debug;
+use std::cmp::Reverse;
+use std::fmt::{Debug, Write};
+use std::hash::Hash;
 use std::ops::ControlFlow;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum VtblSegment<'tcx> {
     MetadataDSA,
     TraitOwnEntries { trait_ref: ty::PolyTraitRef<'tcx>, emit_vptr: bool },
@@ -27,14 +34,21 @@ pub fn prepare_vtable_segments<'tcx, T>(
     trait_ref: ty::PolyTraitRef<'tcx>,
     segment_visitor: impl FnMut(VtblSegment<'tcx>) -> ControlFlow<T>,
 ) -> Option<T> {
-    prepare_vtable_segments_inner(tcx, trait_ref, segment_visitor).break_value()
+    prepare_vtable_segments_inner(tcx, trait_ref, &mut FxHashMap::default(), segment_visitor)
+        .break_value()
 }
 
+/// The segments of the vtables of the super traits checked by [`is_vtable_prefix`] while preparing
+/// the segments of a vtable, so that each of them is only prepared once. Without this, the work
+/// would grow exponentially with the depth of diamond-shaped trait hierarchies.
+type SuperVtableSegments<'tcx> = FxHashMap<ty::PolyTraitRef<'tcx>, Vec<VtblSegment<'tcx>>>;
+
 /// Helper for [`prepare_vtable_segments`] that returns `ControlFlow`,
 /// such that we can use `?` in the body.
 fn prepare_vtable_segments_inner<'tcx, T>(
     tcx: TyCtxt<'tcx>,
     trait_ref: ty::PolyTraitRef<'tcx>,
+    super_vtables: &mut SuperVtableSegments<'tcx>,
     mut segment_visitor: impl FnMut(VtblSegment<'tcx>) -> ControlFlow<T>,
 ) -> ControlFlow<T> {
     // The following constraints holds for the final arrangement.
@@ -83,6 +97,11 @@ fn prepare_vtable_segments_inner<'tcx, T>(
 
     // emit dsa segment first.
     segment_visitor(VtblSegment::MetadataDSA)?;
+    // The segments emitted so far, to find the super traits whose vtable is a prefix of this one.
+    let mut emitted = vec![VtblSegment::MetadataDSA];
+    // The layout of `#[stable_vtable]` traits is part of their ABI, so it is not affected by the
+    // flags that leave out vptrs.
+    let stable_layout = tcx.has_attr(trait_ref.def_id(), sym::stable_vtable);
 
     let mut emit_vptr_on_new_entry = false;
     let mut visited = PredicateSet::new(tcx);
@@ -150,10 +169,14 @@ fn prepare_vtable_segments_inner<'tcx, T>(
 
         // emit innermost item, move to next sibling and stop there if possible, otherwise jump to outer level.
         while let Some((inner_most_trait_ref, emit_vptr, mut siblings)) = stack.pop() {
-            segment_visitor(VtblSegment::TraitOwnEntries {
-                trait_ref: inner_most_trait_ref,
-                emit_vptr: emit_vptr && !tcx.sess.opts.unstable_opts.no_trait_vptr,
-            })?;
+            let emit_vptr = emit_vptr
+                && (stable_layout
+                    || (!tcx.sess.opts.unstable_opts.no_trait_vptr
+                        && !is_vtable_prefix(tcx, inner_most_trait_ref, &emitted, super_vtables)));
+            let segment =
+                VtblSegment::TraitOwnEntries { trait_ref: inner_most_trait_ref, emit_vptr };
+            emitted.push(segment.clone());
+            segment_visitor(segment)?;
 
             // If we've emitted (fed to `segment_visitor`) a trait that has methods present in the vtable,
             // we'll need to emit vptrs from now on.
@@ -183,19 +206,82 @@ fn prepare_vtable_segments_inner<'tcx, T>(
     }
 }
 
+/// Whether the vtable of `trait_ref` is a prefix of the vtable made of the `emitted` segments,
+/// once the own entries of `trait_ref` are appended to it.
+fn is_vtable_prefix<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    trait_ref: ty::PolyTraitRef<'tcx>,
+    emitted: &[VtblSegment<'tcx>],
+    super_vtables: &mut SuperVtableSegments<'tcx>,
+) -> bool {
+    if tcx.sess.opts.unstable_opts.no_vtable_prefix_sharing {
+        return false;
+    }
+
+    let segments = match super_vtables.get(&trait_ref) {
+        Some(segments) => segments,
+        None => {
+            let mut segments = vec![];
+            let _ = prepare_vtable_segments_inner(tcx, trait_ref, super_vtables, |segment| {
+                segments.push(segment);
+                ControlFlow::<()>::Continue(())
+            });
+            super_vtables.entry(trait_ref).or_insert(segments)
+        }
+    };
+    // The last segment holds the own entries of `trait_ref`, which are emitted right after.
+    let (_, super_segments) = segments.split_last().unwrap();
+
+    if has_own_existential_vtable_entries(tcx, trait_ref.def_id()) {
+        // The own entries have to directly follow the super trait entries.
+        super_segments == emitted
+    } else {
+        emitted.starts_with(super_segments)
+    }
+}
+
 /// Turns option of iterator into an iterator (this is just flatten)
 fn maybe_iter<I: Iterator>(i: Option<I>) -> impl Iterator<Item = I::Item> {
     // Flatten is bad perf-vise, we could probably implement a special case here that is better
     i.into_iter().flatten()
 }
 
+/// Dumps the entries of a vtable along with their offsets, and the concrete size and alignment of
+/// the type, for `#[rustc_dump_vtable]`. `shared` lists the super traits whose vtable is a prefix
+/// of this one, so upcasting to them reuses the same vtable.
 fn dump_vtable_entries<'tcx>(
     tcx: TyCtxt<'tcx>,
     sp: Span,
     trait_ref: ty::PolyTraitRef<'tcx>,
     entries: &[VtblEntry<'tcx>],
+    shared: &[ty::PolyTraitRef<'tcx>],
 ) {
-    tcx.sess.emit_err(DumpVTableEntries { span: sp, trait_ref, entries: format!("{entries:#?}") });
+    let self_ty = tcx.instantiate_bound_regions_with_erased(trait_ref.self_ty());
+    let layout = tcx.layout_of(ty::ParamEnv::reveal_all().and(self_ty)).ok();
+
+    let mut formatted = String::from("[\n");
+    for (idx, entry) in entries.iter().enumerate() {
+        let offset = tcx.vtable_entry_offset(idx).bytes();
+        let _ = match (entry, layout) {
+            (VtblEntry::MetadataSize, Some(layout)) => {
+                writeln!(formatted, "    {offset}: MetadataSize = {},", layout.size.bytes())
+            }
+            (VtblEntry::MetadataAlign, Some(layout)) => {
+                writeln!(formatted, "    {offset}: MetadataAlign = {},", layout.align.abi.bytes())
+            }
+            _ => writeln!(formatted, "    {offset}: {entry:?},"),
+        };
+    }
+    formatted.push(']');
+
+    let shared = (!shared.is_empty()).then(|| DumpVTableShared {
+        traits: shared
+            .iter()
+            .map(|trait_ref| format!("`{trait_ref}`"))
+            .collect::<Vec<_>>()
+            .join(", "),
+    });
+    tcx.sess.emit_err(DumpVTableEntries { span: sp, trait_ref, entries: formatted, shared });
 }
 
 fn has_own_existential_vtable_entries(tcx: TyCtxt<'_>, trait_def_id: DefId) -> bool {
@@ -203,9 +289,91 @@ fn has_own_existential_vtable_entries(tcx: TyCtxt<'_>, trait_def_id: DefId) -> b
 }
 
 fn own_existential_vtable_entries(tcx: TyCtxt<'_>, trait_def_id: DefId) -> &[DefId] {
+    let order = tcx.vtable_method_order(trait_def_id);
+    if !order.is_empty() {
+        return order;
+    }
     tcx.arena.alloc_from_iter(own_existential_vtable_entries_iter(tcx, trait_def_id))
 }
 
+fn vtable_method_call_counts(tcx: TyCtxt<'_>, (): ()) -> FxHashMap<String, u64> {
+    let mut counts = FxHashMap::default();
+    let Some(path) = &tcx.sess.opts.unstable_opts.profile_guided_vtable_layout else {
+        return counts;
+    };
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            tcx.sess.emit_err(FailedToReadVtableProfile { path, err });
+            return counts;
+        }
+    };
+
+    // Each line holds the path of a trait method and how often it was called through a vtable,
+    // separated by whitespace.
+    for (index, line) in contents.lines().enumerate() {
+        let mut fields = line.split_whitespace();
+        match (fields.next(), fields.next().map(str::parse::<u64>), fields.next()) {
+            (None, _, _) => {}
+            (Some(method), Some(Ok(count)), None) => {
+                *counts.entry(method.to_owned()).or_default() += count;
+            }
+            _ => {
+                tcx.sess.emit_err(MalformedVtableProfile { path, line: index + 1 });
+            }
+        }
+    }
+    counts
+}
+
+/// Moves the most called methods of a trait to the front of its own vtable entries, so that
+/// they are likely to share a cache line with the metadata entries.
+///
+/// The methods of `#[stable_vtable]` traits always stay in definition order.
+fn vtable_method_order(tcx: TyCtxt<'_>, trait_def_id: LocalDefId) -> &[DefId] {
+    let counts = tcx.vtable_method_call_counts(());
+    if counts.is_empty() || tcx.has_attr(trait_def_id, sym::stable_vtable) {
+        return &[];
+    }
+
+    let mut entries: Vec<_> = own_existential_vtable_entries_iter(tcx, trait_def_id.to_def_id())
+        .map(|def_id| {
+            let path = with_no_trimmed_paths!(tcx.def_path_str(def_id));
+            (def_id, counts.get(&path).copied().unwrap_or(0))
+        })
+        .collect();
+    if entries.windows(2).all(|pair| pair[0].1 >= pair[1].1) {
+        return &[];
+    }
+    // The sort is stable, so methods with the same count stay in definition order.
+    entries.sort_by_key(|&(_, count)| Reverse(count));
+    tcx.arena.alloc_from_iter(entries.into_iter().map(|(def_id, _)| def_id))
+}
+
+/// Hashes the layout of the vtables of a `#[stable_vtable]` trait: the traits that the entries
+/// come from, the name and signature of each method, and where the vptrs are.
+fn stable_vtable_layout_hash(tcx: TyCtxt<'_>, trait_def_id: DefId) -> u64 {
+    let trait_ref = ty::Binder::dummy(ty::TraitRef::identity(tcx, trait_def_id));
+    let mut hasher = StableHasher::new();
+    tcx.data_layout.pointer_size.bytes().hash(&mut hasher);
+    let _ = prepare_vtable_segments(tcx, trait_ref, |segment| {
+        match segment {
+            VtblSegment::MetadataDSA => TyCtxt::COMMON_VTABLE_ENTRIES.len().hash(&mut hasher),
+            VtblSegment::TraitOwnEntries { trait_ref, emit_vptr } => {
+                with_no_trimmed_paths!(trait_ref.to_string()).hash(&mut hasher);
+                for &def_id in tcx.own_existential_vtable_entries(trait_ref.def_id()) {
+                    tcx.item_name(def_id).as_str().hash(&mut hasher);
+                    let sig = tcx.fn_sig(def_id).instantiate_identity();
+                    with_no_trimmed_paths!(sig.to_string()).hash(&mut hasher);
+                }
+                emit_vptr.hash(&mut hasher);
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    hasher.finish::<Hash64>().as_u64()
+}
+
 fn own_existential_vtable_entries_iter(
     tcx: TyCtxt<'_>,
     trait_def_id: DefId,
@@ -240,7 +408,10 @@ fn vtable_entries<'tcx>(
 ) -> &'tcx [VtblEntry<'tcx>] {
     debug!("vtable_entries({:?})", trait_ref);
 
+    let root_trait_ref = trait_ref;
     let mut entries = vec![];
+    // Super traits which reuse this vtable, for `#[rustc_dump_vtable]`.
+    let mut shared = vec![];
 
     let vtable_segment_callback = |segment| -> ControlFlow<()> {
         match segment {
@@ -302,6 +473,8 @@ fn vtable_entries<'tcx>(
 
                 if emit_vptr {
                     entries.push(VtblEntry::TraitVPtr(trait_ref));
+                } else if trait_ref != root_trait_ref {
+                    shared.push(trait_ref);
                 }
             }
         }
@@ -313,7 +486,7 @@ fn vtable_entries<'tcx>(
 
     if tcx.has_attr(trait_ref.def_id(), sym::rustc_dump_vtable) {
         let sp = tcx.def_span(trait_ref.def_id());
-        dump_vtable_entries(tcx, sp, trait_ref, &entries);
+        dump_vtable_entries(tcx, sp, trait_ref, &entries, &shared);
     }
 
     tcx.arena.alloc_from_iter(entries)
@@ -401,6 +574,9 @@ pub(crate) fn count_own_vtable_entries<'tcx>(
 pub(super) fn provide(providers: &mut Providers) {
     *providers = Providers {
         own_existential_vtable_entries,
+        stable_vtable_layout_hash,
+        vtable_method_call_counts,
+        vtable_method_order,
         vtable_entries,
         vtable_trait_upcasting_coercion_new_vptr_slot,
         ..*providers
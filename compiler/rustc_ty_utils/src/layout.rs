@@ -120,6 +120,13 @@ fn layout_of_uncached<'tcx>(
         Scalar::Initialized { value, valid_range: WrappingRange::full(size) }
     };
     let scalar = |value: Primitive| tcx.mk_layout(LayoutS::scalar(cx, scalar_unit(value)));
+    // Vtables are aligned to pointers, so besides null, the misaligned addresses below the
+    // alignment are never valid vtable pointers either, and can be used as a niche.
+    let vtable_unit = || {
+        let mut vtable = scalar_unit(Pointer(AddressSpace::DATA));
+        vtable.valid_range_mut().start = dl.pointer_align.abi.bytes().into();
+        vtable
+    };
 
     let univariant = |fields: &IndexSlice<FieldIdx, Layout<'_>>, repr: &ReprOptions, kind| {
         Ok(tcx.mk_layout(univariant_uninterned(cx, ty, fields, repr, kind)?))
@@ -206,7 +213,15 @@ fn layout_of_uncached<'tcx>(
                     return Err(error(cx, LayoutError::Unknown(pointee)));
                 };
 
-                metadata
+                // `DynMetadata` wraps a plain reference, which doesn't know that it points to a
+                // vtable.
+                if let ty::Adt(def, _) = metadata_ty.kind()
+                    && tcx.lang_items().dyn_metadata() == Some(def.did())
+                {
+                    vtable_unit()
+                } else {
+                    metadata
+                }
             } else {
                 let unsized_part = tcx.struct_tail_erasing_lifetimes(pointee, param_env);
 
@@ -215,11 +230,7 @@ fn layout_of_uncached<'tcx>(
                         return Ok(tcx.mk_layout(LayoutS::scalar(cx, data_ptr)));
                     }
                     ty::Slice(_) | ty::Str => scalar_unit(Int(dl.ptr_sized_integer(), false)),
-                    ty::Dynamic(..) => {
-                        let mut vtable = scalar_unit(Pointer(AddressSpace::DATA));
-                        vtable.valid_range_mut().start = 1;
-                        vtable
-                    }
+                    ty::Dynamic(..) => vtable_unit(),
                     _ => {
                         return Err(error(cx, LayoutError::Unknown(pointee)));
                     }
@@ -233,9 +244,7 @@ fn layout_of_uncached<'tcx>(
         ty::Dynamic(_, _, ty::DynStar) => {
             let mut data = scalar_unit(Pointer(AddressSpace::DATA));
             data.valid_range_mut().start = 0;
-            let mut vtable = scalar_unit(Pointer(AddressSpace::DATA));
-            vtable.valid_range_mut().start = 1;
-            tcx.mk_layout(cx.scalar_pair(data, vtable))
+            tcx.mk_layout(cx.scalar_pair(data, vtable_unit()))
         }
 
         // Arrays and slices.
@@ -300,4 +300,8 @@ impl<T: ?Sized + Error> Error for ThinBox<T> {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         self.deref().source()
     }
+
+    fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        self.deref().provide(request);
+    }
 }
@@ -2710,6 +2710,96 @@ pub fn upper_bound_mut<Q>(&mut self, bound: Bound<&Q>) -> CursorMut<'_, K, V, A>
             alloc: &mut *self.alloc,
         }
     }
+
+    /// Returns a [`RangeCursor`] over the elements of the map that lie within
+    /// `range`, pointing at the first such element.
+    ///
+    /// If the range contains no elements then a cursor pointing at the "ghost"
+    /// non-element is returned.
+    ///
+    /// Unlike [`BTreeMap::range`], this does not panic if the start of the
+    /// range is greater than its end; such a range is simply empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_cursors)]
+    ///
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1, "a"), (2, "b"), (3, "c"), (4, "d")]);
+    /// let mut cursor = map.range_cursor(2..4);
+    /// assert_eq!(cursor.key(), Some(&2));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.key(), Some(&3));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.key(), None);
+    /// cursor.move_next();
+    /// assert_eq!(cursor.key(), Some(&2));
+    /// ```
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn range_cursor<T: ?Sized, R>(&self, range: R) -> RangeCursor<'_, K, V, T, R>
+    where
+        T: Ord,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
+    {
+        let mut cursor = RangeCursor {
+            cursor: Cursor { current: None, root: self.root.as_ref() },
+            range,
+            _marker: PhantomData,
+        };
+        cursor.move_next();
+        cursor
+    }
+
+    /// Returns a [`RangeCursorMut`] over the elements of the map that lie
+    /// within `range`, pointing at the first such element.
+    ///
+    /// If the range contains no elements then a cursor pointing at the "ghost"
+    /// non-element is returned.
+    ///
+    /// Unlike [`BTreeMap::range_mut`], this does not panic if the start of the
+    /// range is greater than its end; such a range is simply empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_cursors)]
+    ///
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::from([(1, "a"), (3, "c"), (5, "e")]);
+    /// let mut cursor = map.range_cursor_mut(2..5);
+    /// assert_eq!(cursor.key(), Some(&3));
+    /// assert!(cursor.insert_after(4, "d").is_ok());
+    /// // 6 lies outside of the range.
+    /// assert!(cursor.insert_after(6, "f").is_err());
+    /// assert_eq!(cursor.remove_current(), Some((3, "c")));
+    /// assert_eq!(cursor.key(), Some(&4));
+    /// assert_eq!(map, BTreeMap::from([(1, "a"), (4, "d"), (5, "e")]));
+    /// ```
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn range_cursor_mut<T: ?Sized, R>(&mut self, range: R) -> RangeCursorMut<'_, K, V, T, R, A>
+    where
+        T: Ord,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
+    {
+        let (_, dormant_root) = DormantMutRef::new(&mut self.root);
+        let mut cursor = RangeCursorMut {
+            cursor: CursorMut {
+                current: None,
+                root: dormant_root,
+                length: &mut self.length,
+                alloc: &mut *self.alloc,
+            },
+            range,
+            _marker: PhantomData,
+        };
+        cursor.move_next();
+        cursor
+    }
 }
 
 /// A cursor over a `BTreeMap`.
@@ -3146,28 +3236,30 @@ pub unsafe fn insert_before_unchecked(&mut self, key: K, value: V) {
     /// If the cursor is pointing at the "ghost" non-element then the new element is
     /// inserted at the front of the `BTreeMap`.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This function panics if:
+    /// If the given key is not properly ordered, the map is left unchanged and
+    /// an [`UnorderedKeyError`] is returned. This happens if:
     /// - the given key compares less than or equal to the current element (if
     ///   any).
     /// - the given key compares greater than or equal to the next element (if
     ///   any).
     #[unstable(feature = "btree_cursors", issue = "107540")]
-    pub fn insert_after(&mut self, key: K, value: V) {
+    pub fn insert_after(&mut self, key: K, value: V) -> Result<(), UnorderedKeyError> {
         if let Some(current) = self.key() {
             if &key <= current {
-                panic!("key must be ordered above the current element");
+                return Err(UnorderedKeyError {});
             }
         }
         if let Some((next, _)) = self.peek_next() {
             if &key >= next {
-                panic!("key must be ordered below the next element");
+                return Err(UnorderedKeyError {});
             }
         }
         unsafe {
             self.insert_after_unchecked(key, value);
         }
+        Ok(())
     }
 
     /// Inserts a new element into the `BTreeMap` before the current one.
@@ -3175,28 +3267,30 @@ pub fn insert_after(&mut self, key: K, value: V) {
     /// If the cursor is pointing at the "ghost" non-element then the new element is
     /// inserted at the end of the `BTreeMap`.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This function panics if:
+    /// If the given key is not properly ordered, the map is left unchanged and
+    /// an [`UnorderedKeyError`] is returned. This happens if:
     /// - the given key compares greater than or equal to the current element
     ///   (if any).
     /// - the given key compares less than or equal to the previous element (if
     ///   any).
     #[unstable(feature = "btree_cursors", issue = "107540")]
-    pub fn insert_before(&mut self, key: K, value: V) {
+    pub fn insert_before(&mut self, key: K, value: V) -> Result<(), UnorderedKeyError> {
         if let Some(current) = self.key() {
             if &key >= current {
-                panic!("key must be ordered below the current element");
+                return Err(UnorderedKeyError {});
             }
         }
         if let Some((prev, _)) = self.peek_prev() {
             if &key <= prev {
-                panic!("key must be ordered above the previous element");
+                return Err(UnorderedKeyError {});
             }
         }
         unsafe {
             self.insert_before_unchecked(key, value);
         }
+        Ok(())
     }
 
     /// Removes the current element from the `BTreeMap`.
@@ -3248,5 +3342,522 @@ pub fn remove_current_and_move_back(&mut self) -> Option<(K, V)> {
     }
 }
 
+/// Error type returned by [`CursorMut::insert_before`] and
+/// [`CursorMut::insert_after`] if the key being inserted is not properly
+/// ordered with regards to adjacent keys.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[unstable(feature = "btree_cursors", issue = "107540")]
+pub struct UnorderedKeyError {}
+
+#[unstable(feature = "btree_cursors", issue = "107540")]
+impl fmt::Display for UnorderedKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "key is not properly ordered relative to neighbors")
+    }
+}
+
+#[unstable(feature = "btree_cursors", issue = "107540")]
+impl core::error::Error for UnorderedKeyError {}
+
+/// A cursor over a range of a `BTreeMap`.
+///
+/// A `RangeCursor` is like a [`Cursor`] that is confined to the elements whose
+/// keys lie within a range. Its "ghost" non-element sits between the last and
+/// first elements of the range rather than of the whole map: moving past
+/// either end of the range moves the cursor to the "ghost" non-element, and
+/// moving away from the "ghost" non-element seeks to the corresponding end of
+/// the range.
+///
+/// A `RangeCursor` is created with the [`BTreeMap::range_cursor`] method.
+#[unstable(feature = "btree_cursors", issue = "107540")]
+pub struct RangeCursor<'a, K: 'a, V: 'a, T: ?Sized, R> {
+    cursor: Cursor<'a, K, V>,
+    range: R,
+    _marker: PhantomData<fn(&T)>,
+}
+
+#[unstable(feature = "btree_cursors", issue = "107540")]
+impl<K, V, T: ?Sized, R: Clone> Clone for RangeCursor<'_, K, V, T, R> {
+    fn clone(&self) -> Self {
+        RangeCursor { cursor: self.cursor.clone(), range: self.range.clone(), _marker: PhantomData }
+    }
+}
+
+#[unstable(feature = "btree_cursors", issue = "107540")]
+impl<K: Debug, V: Debug, T: ?Sized, R> Debug for RangeCursor<'_, K, V, T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RangeCursor").field(&self.cursor.key_value()).finish()
+    }
+}
+
+/// A cursor over a range of a `BTreeMap` with editing operations.
+///
+/// A `RangeCursorMut` is like a [`CursorMut`] that is confined to the elements
+/// whose keys lie within a range, in the same way as a [`RangeCursor`]. The
+/// editing operations additionally reject keys that lie outside of the range.
+///
+/// A `RangeCursorMut` is created with the [`BTreeMap::range_cursor_mut`]
+/// method.
+#[unstable(feature = "btree_cursors", issue = "107540")]
+pub struct RangeCursorMut<
+    'a,
+    K: 'a,
+    V: 'a,
+    T: ?Sized,
+    R,
+    #[unstable(feature = "allocator_api", issue = "32838")] A = Global,
+> {
+    cursor: CursorMut<'a, K, V, A>,
+    range: R,
+    _marker: PhantomData<fn(&T)>,
+}
+
+#[unstable(feature = "btree_cursors", issue = "107540")]
+impl<K: Debug, V: Debug, T: ?Sized, R, A> Debug for RangeCursorMut<'_, K, V, T, R, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RangeCursorMut").field(&self.cursor.key_value()).finish()
+    }
+}
+
+fn range_contains<T, K, R>(range: &R, key: &K) -> bool
+where
+    T: ?Sized + Ord,
+    K: Borrow<T>,
+    R: RangeBounds<T>,
+{
+    range.contains::<T>(key.borrow())
+}
+
+impl<'a, K, V, T, R> RangeCursor<'a, K, V, T, R>
+where
+    K: Borrow<T>,
+    T: ?Sized + Ord,
+    R: RangeBounds<T>,
+{
+    /// Keeps `kv` only if its key lies within the range.
+    fn filter(
+        &self,
+        kv: Handle<NodeRef<marker::Immut<'a>, K, V, marker::LeafOrInternal>, marker::KV>,
+    ) -> Option<Handle<NodeRef<marker::Immut<'a>, K, V, marker::LeafOrInternal>, marker::KV>> {
+        range_contains::<T, _, _>(&self.range, kv.into_kv().0).then_some(kv)
+    }
+
+    fn next_kv(
+        &self,
+    ) -> Option<Handle<NodeRef<marker::Immut<'a>, K, V, marker::LeafOrInternal>, marker::KV>> {
+        let next = match self.cursor.current {
+            None => self
+                .cursor
+                .root?
+                .reborrow()
+                .lower_bound(SearchBound::from_range(self.range.start_bound()))
+                .next_kv()
+                .ok(),
+            Some(current) => current.next_leaf_edge().next_kv().ok(),
+        };
+        self.filter(next?)
+    }
+
+    fn prev_kv(
+        &self,
+    ) -> Option<Handle<NodeRef<marker::Immut<'a>, K, V, marker::LeafOrInternal>, marker::KV>> {
+        let prev = match self.cursor.current {
+            None => self
+                .cursor
+                .root?
+                .reborrow()
+                .upper_bound(SearchBound::from_range(self.range.end_bound()))
+                .next_back_kv()
+                .ok(),
+            Some(current) => current.next_back_leaf_edge().next_back_kv().ok(),
+        };
+        self.filter(prev?)
+    }
+
+    /// Moves the cursor to the next element of the range.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the first element of the range. If it is pointing to the last
+    /// element of the range then this will move it to the "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn move_next(&mut self) {
+        self.cursor.current = self.next_kv();
+    }
+
+    /// Moves the cursor to the previous element of the range.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the last element of the range. If it is pointing to the first
+    /// element of the range then this will move it to the "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn move_prev(&mut self) {
+        self.cursor.current = self.prev_kv();
+    }
+
+    /// Returns a reference to the key of the element that the cursor is
+    /// currently pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn key(&self) -> Option<&'a K> {
+        self.cursor.key()
+    }
+
+    /// Returns a reference to the value of the element that the cursor is
+    /// currently pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn value(&self) -> Option<&'a V> {
+        self.cursor.value()
+    }
+
+    /// Returns a reference to the key and value of the element that the cursor
+    /// is currently pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn key_value(&self) -> Option<(&'a K, &'a V)> {
+        self.cursor.key_value()
+    }
+
+    /// Returns a reference to the next element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the first element of the range. If it is pointing to the last
+    /// element of the range then this returns `None`.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn peek_next(&self) -> Option<(&'a K, &'a V)> {
+        self.next_kv().map(|kv| kv.into_kv())
+    }
+
+    /// Returns a reference to the previous element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the last element of the range. If it is pointing to the first
+    /// element of the range then this returns `None`.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn peek_prev(&self) -> Option<(&'a K, &'a V)> {
+        self.prev_kv().map(|kv| kv.into_kv())
+    }
+
+    /// Returns a cursor over the whole map pointing to the current element.
+    ///
+    /// If the range cursor is pointing to its "ghost" non-element then so is
+    /// the returned cursor.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn as_cursor(&self) -> Cursor<'a, K, V> {
+        self.cursor.clone()
+    }
+}
+
+impl<'a, K, V, T, R, A> RangeCursorMut<'a, K, V, T, R, A>
+where
+    K: Borrow<T>,
+    T: ?Sized + Ord,
+    R: RangeBounds<T>,
+{
+    /// Moves the inner cursor to the "ghost" non-element if it points to an
+    /// element outside of the range.
+    fn clamp(&mut self) {
+        if self.cursor.key().is_some_and(|key| !range_contains::<T, _, _>(&self.range, key)) {
+            self.cursor.current = None;
+        }
+    }
+
+    /// Moves the cursor to the next element of the range.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the first element of the range. If it is pointing to the last
+    /// element of the range then this will move it to the "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn move_next(&mut self) {
+        match self.cursor.current.take() {
+            None => {
+                // SAFETY: The previous borrow of root has ended.
+                self.cursor.current =
+                    unsafe { self.cursor.root.reborrow() }.as_mut().and_then(|root| {
+                        root.borrow_mut()
+                            .lower_bound(SearchBound::from_range(self.range.start_bound()))
+                            .next_kv()
+                            .ok()
+                    });
+            }
+            Some(current) => {
+                self.cursor.current = current.next_leaf_edge().next_kv().ok();
+            }
+        }
+        self.clamp();
+    }
+
+    /// Moves the cursor to the previous element of the range.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the last element of the range. If it is pointing to the first
+    /// element of the range then this will move it to the "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn move_prev(&mut self) {
+        match self.cursor.current.take() {
+            None => {
+                // SAFETY: The previous borrow of root has ended.
+                self.cursor.current =
+                    unsafe { self.cursor.root.reborrow() }.as_mut().and_then(|root| {
+                        root.borrow_mut()
+                            .upper_bound(SearchBound::from_range(self.range.end_bound()))
+                            .next_back_kv()
+                            .ok()
+                    });
+            }
+            Some(current) => {
+                self.cursor.current = current.next_back_leaf_edge().next_back_kv().ok();
+            }
+        }
+        self.clamp();
+    }
+
+    /// Returns a reference to the key of the element that the cursor is
+    /// currently pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn key(&self) -> Option<&K> {
+        self.cursor.key()
+    }
+
+    /// Returns a reference to the value of the element that the cursor is
+    /// currently pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn value(&self) -> Option<&V> {
+        self.cursor.value()
+    }
+
+    /// Returns a reference to the key and value of the element that the cursor
+    /// is currently pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn key_value(&self) -> Option<(&K, &V)> {
+        self.cursor.key_value()
+    }
+
+    /// Returns a mutable reference to the value of the element that the cursor
+    /// is currently pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn value_mut(&mut self) -> Option<&mut V> {
+        self.cursor.value_mut()
+    }
+
+    /// Returns a reference to the key and mutable reference to the value of the
+    /// element that the cursor is currently pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn key_value_mut(&mut self) -> Option<(&K, &mut V)> {
+        self.cursor.key_value_mut()
+    }
+
+    /// Returns a reference to the key and value of the next element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the first element of the range. If it is pointing to the last
+    /// element of the range then this returns `None`.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn peek_next(&mut self) -> Option<(&K, &mut V)> {
+        let (k, v) = match self.cursor.current {
+            None => {
+                // SAFETY: The previous borrow of root has ended.
+                unsafe { self.cursor.root.reborrow() }
+                    .as_mut()?
+                    .borrow_mut()
+                    .lower_bound(SearchBound::from_range(self.range.start_bound()))
+                    .next_kv()
+                    .ok()?
+                    .into_kv_valmut()
+            }
+            // SAFETY: We're not using this to mutate the tree.
+            Some(ref mut current) => {
+                unsafe { current.reborrow_mut() }.next_leaf_edge().next_kv().ok()?.into_kv_valmut()
+            }
+        };
+        range_contains::<T, _, _>(&self.range, k).then_some((k, v))
+    }
+
+    /// Returns a reference to the key and value of the previous element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the last element of the range. If it is pointing to the first
+    /// element of the range then this returns `None`.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn peek_prev(&mut self) -> Option<(&K, &mut V)> {
+        let (k, v) = match self.cursor.current.as_mut() {
+            None => {
+                // SAFETY: The previous borrow of root has ended.
+                unsafe { self.cursor.root.reborrow() }
+                    .as_mut()?
+                    .borrow_mut()
+                    .upper_bound(SearchBound::from_range(self.range.end_bound()))
+                    .next_back_kv()
+                    .ok()?
+                    .into_kv_valmut()
+            }
+            Some(current) => {
+                // SAFETY: We're not using this to mutate the tree.
+                unsafe { current.reborrow_mut() }
+                    .next_back_leaf_edge()
+                    .next_back_kv()
+                    .ok()?
+                    .into_kv_valmut()
+            }
+        };
+        range_contains::<T, _, _>(&self.range, k).then_some((k, v))
+    }
+
+    /// Returns a read-only cursor over the whole map pointing to the current
+    /// element.
+    ///
+    /// If the range cursor is pointing to its "ghost" non-element then so is
+    /// the returned cursor.
+    ///
+    /// The lifetime of the returned `Cursor` is bound to that of the
+    /// `RangeCursorMut`, which means it cannot outlive the `RangeCursorMut`
+    /// and that the `RangeCursorMut` is frozen for the lifetime of the
+    /// `Cursor`.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn as_cursor(&self) -> Cursor<'_, K, V> {
+        self.cursor.as_cursor()
+    }
+}
+
+// Now the tree editing operations
+impl<'a, K, V, T, R, A> RangeCursorMut<'a, K, V, T, R, A>
+where
+    K: Borrow<T> + Ord,
+    T: ?Sized + Ord,
+    R: RangeBounds<T>,
+    A: Allocator + Clone,
+{
+    /// Inserts a new element into the range after the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new element is
+    /// inserted at the front of the range. The cursor is not moved.
+    ///
+    /// # Errors
+    ///
+    /// If the given key is not properly ordered, the map is left unchanged and
+    /// an [`UnorderedKeyError`] is returned. This happens if:
+    /// - the given key lies outside of the range.
+    /// - the given key compares less than or equal to the current element (if
+    ///   any).
+    /// - the given key compares greater than or equal to the next element (if
+    ///   any).
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn insert_after(&mut self, key: K, value: V) -> Result<(), UnorderedKeyError> {
+        if !range_contains::<T, _, _>(&self.range, &key) {
+            return Err(UnorderedKeyError {});
+        }
+        if self.cursor.current.is_some() {
+            return self.cursor.insert_after(key, value);
+        }
+
+        // Insert after the last element before the range, which may be the
+        // map's own "ghost" non-element.
+        let before_start = match self.range.start_bound() {
+            Bound::Included(start) => Bound::Excluded(start),
+            Bound::Excluded(start) => Bound::Included(start),
+            Bound::Unbounded => {
+                return self.cursor.insert_after(key, value);
+            }
+        };
+        // SAFETY: The previous borrow of root has ended.
+        self.cursor.current = unsafe { self.cursor.root.reborrow() }.as_mut().and_then(|root| {
+            root.borrow_mut().upper_bound(SearchBound::from_range(before_start)).next_back_kv().ok()
+        });
+        let result = self.cursor.insert_after(key, value);
+        self.cursor.current = None;
+        result
+    }
+
+    /// Inserts a new element into the range before the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new element is
+    /// inserted at the end of the range. The cursor is not moved.
+    ///
+    /// # Errors
+    ///
+    /// If the given key is not properly ordered, the map is left unchanged and
+    /// an [`UnorderedKeyError`] is returned. This happens if:
+    /// - the given key lies outside of the range.
+    /// - the given key compares greater than or equal to the current element
+    ///   (if any).
+    /// - the given key compares less than or equal to the previous element (if
+    ///   any).
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn insert_before(&mut self, key: K, value: V) -> Result<(), UnorderedKeyError> {
+        if !range_contains::<T, _, _>(&self.range, &key) {
+            return Err(UnorderedKeyError {});
+        }
+        if self.cursor.current.is_some() {
+            return self.cursor.insert_before(key, value);
+        }
+
+        // Insert before the first element after the range, which may be the
+        // map's own "ghost" non-element.
+        let after_end = match self.range.end_bound() {
+            Bound::Included(end) => Bound::Excluded(end),
+            Bound::Excluded(end) => Bound::Included(end),
+            Bound::Unbounded => {
+                return self.cursor.insert_before(key, value);
+            }
+        };
+        // SAFETY: The previous borrow of root has ended.
+        self.cursor.current = unsafe { self.cursor.root.reborrow() }.as_mut().and_then(|root| {
+            root.borrow_mut().lower_bound(SearchBound::from_range(after_end)).next_kv().ok()
+        });
+        let result = self.cursor.insert_before(key, value);
+        self.cursor.current = None;
+        result
+    }
+
+    /// Removes the current element from the `BTreeMap`.
+    ///
+    /// The element that was removed is returned, and the cursor is
+    /// moved to point to the next element in the range.
+    ///
+    /// If the cursor is currently pointing to the "ghost" non-element then no element
+    /// is removed and `None` is returned. The cursor is not moved in this case.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn remove_current(&mut self) -> Option<(K, V)> {
+        let kv = self.cursor.remove_current()?;
+        self.clamp();
+        Some(kv)
+    }
+
+    /// Removes the current element from the `BTreeMap`.
+    ///
+    /// The element that was removed is returned, and the cursor is
+    /// moved to point to the previous element in the range.
+    ///
+    /// If the cursor is currently pointing to the "ghost" non-element then no element
+    /// is removed and `None` is returned. The cursor is not moved in this case.
+    #[unstable(feature = "btree_cursors", issue = "107540")]
+    pub fn remove_current_and_move_back(&mut self) -> Option<(K, V)> {
+        let kv = self.cursor.remove_current_and_move_back()?;
+        self.clamp();
+        Some(kv)
+    }
+}
+
 #[cfg(test)]
 mod tests;
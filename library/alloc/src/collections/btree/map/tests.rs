@@ -2376,16 +2376,16 @@ fn test_cursor_mut() {
     let mut map = BTreeMap::from([(1, 'a'), (3, 'c'), (5, 'e')]);
     let mut cur = map.lower_bound_mut(Bound::Excluded(&3));
     assert_eq!(cur.key(), Some(&5));
-    cur.insert_before(4, 'd');
+    cur.insert_before(4, 'd').unwrap();
     assert_eq!(cur.key(), Some(&5));
     assert_eq!(cur.peek_prev(), Some((&4, &mut 'd')));
     cur.move_next();
     assert_eq!(cur.key(), None);
-    cur.insert_before(6, 'f');
+    cur.insert_before(6, 'f').unwrap();
     assert_eq!(cur.key(), None);
     assert_eq!(cur.remove_current(), None);
     assert_eq!(cur.key(), None);
-    cur.insert_after(0, '?');
+    cur.insert_after(0, '?').unwrap();
     assert_eq!(cur.key(), None);
     assert_eq!(map, BTreeMap::from([(0, '?'), (1, 'a'), (3, 'c'), (4, 'd'), (5, 'e'), (6, 'f')]));
 
@@ -2398,68 +2398,146 @@ fn test_cursor_mut() {
     assert_eq!(map, BTreeMap::from([(0, '?'), (1, 'a'), (3, 'c'), (4, 'd')]));
 }
 
-#[should_panic(expected = "key must be ordered above the previous element")]
 #[test]
 fn test_cursor_mut_insert_before_1() {
     let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
     let mut cur = map.upper_bound_mut(Bound::Included(&2));
-    cur.insert_before(0, 'd');
+    assert_eq!(cur.insert_before(0, 'd'), Err(UnorderedKeyError {}));
+    assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]));
 }
 
-#[should_panic(expected = "key must be ordered above the previous element")]
 #[test]
 fn test_cursor_mut_insert_before_2() {
     let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
     let mut cur = map.upper_bound_mut(Bound::Included(&2));
-    cur.insert_before(1, 'd');
+    assert_eq!(cur.insert_before(1, 'd'), Err(UnorderedKeyError {}));
+    assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]));
 }
 
-#[should_panic(expected = "key must be ordered below the current element")]
 #[test]
 fn test_cursor_mut_insert_before_3() {
     let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
     let mut cur = map.upper_bound_mut(Bound::Included(&2));
-    cur.insert_before(2, 'd');
+    assert_eq!(cur.insert_before(2, 'd'), Err(UnorderedKeyError {}));
+    assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]));
 }
 
-#[should_panic(expected = "key must be ordered below the current element")]
 #[test]
 fn test_cursor_mut_insert_before_4() {
     let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
     let mut cur = map.upper_bound_mut(Bound::Included(&2));
-    cur.insert_before(3, 'd');
+    assert_eq!(cur.insert_before(3, 'd'), Err(UnorderedKeyError {}));
+    assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]));
 }
 
-#[should_panic(expected = "key must be ordered above the current element")]
 #[test]
 fn test_cursor_mut_insert_after_1() {
     let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
     let mut cur = map.upper_bound_mut(Bound::Included(&2));
-    cur.insert_after(1, 'd');
+    assert_eq!(cur.insert_after(1, 'd'), Err(UnorderedKeyError {}));
+    assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]));
 }
 
-#[should_panic(expected = "key must be ordered above the current element")]
 #[test]
 fn test_cursor_mut_insert_after_2() {
     let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
     let mut cur = map.upper_bound_mut(Bound::Included(&2));
-    cur.insert_after(2, 'd');
+    assert_eq!(cur.insert_after(2, 'd'), Err(UnorderedKeyError {}));
+    assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]));
 }
 
-#[should_panic(expected = "key must be ordered below the next element")]
 #[test]
 fn test_cursor_mut_insert_after_3() {
     let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
     let mut cur = map.upper_bound_mut(Bound::Included(&2));
-    cur.insert_after(3, 'd');
+    assert_eq!(cur.insert_after(3, 'd'), Err(UnorderedKeyError {}));
+    assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]));
 }
 
-#[should_panic(expected = "key must be ordered below the next element")]
 #[test]
 fn test_cursor_mut_insert_after_4() {
     let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
     let mut cur = map.upper_bound_mut(Bound::Included(&2));
-    cur.insert_after(4, 'd');
+    assert_eq!(cur.insert_after(4, 'd'), Err(UnorderedKeyError {}));
+    assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]));
+}
+
+#[test]
+fn test_range_cursor() {
+    let map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'), (5, 'e')]);
+
+    let mut cur = map.range_cursor(2..=4);
+    assert_eq!(cur.key(), Some(&2));
+    assert_eq!(cur.peek_prev(), None);
+    cur.move_prev();
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.peek_prev(), Some((&4, &'d')));
+    assert_eq!(cur.peek_next(), Some((&2, &'b')));
+    cur.move_prev();
+    assert_eq!(cur.key(), Some(&4));
+    assert_eq!(cur.peek_next(), None);
+    cur.move_next();
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.as_cursor().key(), None);
+
+    let mut cur = map.range_cursor((Bound::Excluded(&1), Bound::Excluded(&2)));
+    assert_eq!(cur.key(), None);
+    cur.move_next();
+    assert_eq!(cur.key(), None);
+    cur.move_prev();
+    assert_eq!(cur.key(), None);
+
+    let cur = map.range_cursor(4..2);
+    assert_eq!(cur.key(), None);
+
+    let empty = BTreeMap::<i32, i32>::new();
+    let mut cur = empty.range_cursor(..);
+    assert_eq!(cur.key(), None);
+    cur.move_next();
+    assert_eq!(cur.key(), None);
+}
+
+#[test]
+fn test_range_cursor_mut() {
+    let mut map = BTreeMap::from([(1, 'a'), (3, 'c'), (5, 'e'), (7, 'g')]);
+
+    let mut cur = map.range_cursor_mut(2..7);
+    assert_eq!(cur.key(), Some(&3));
+    *cur.value_mut().unwrap() = 'C';
+    assert_eq!(cur.insert_before(1, '!'), Err(UnorderedKeyError {}));
+    assert_eq!(cur.insert_before(2, 'b'), Ok(()));
+    assert_eq!(cur.key(), Some(&3));
+    assert_eq!(cur.peek_prev(), Some((&2, &mut 'b')));
+    cur.move_prev();
+    cur.move_prev();
+    assert_eq!(cur.key(), None);
+    // From the "ghost" non-element, insertions happen at the ends of the range.
+    assert_eq!(cur.insert_after(2, '?'), Err(UnorderedKeyError {}));
+    assert_eq!(cur.insert_before(7, '?'), Err(UnorderedKeyError {}));
+    assert_eq!(cur.insert_before(6, 'f'), Ok(()));
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.peek_prev(), Some((&6, &mut 'f')));
+    cur.move_prev();
+    assert_eq!(cur.remove_current(), Some((6, 'f')));
+    assert_eq!(cur.key(), None);
+    cur.move_prev();
+    assert_eq!(cur.remove_current_and_move_back(), Some((5, 'e')));
+    assert_eq!(cur.key(), Some(&3));
+    assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'C'), (7, 'g')]));
+
+    let mut cur = map.range_cursor_mut(3..);
+    assert_eq!(cur.remove_current_and_move_back(), Some((3, 'C')));
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.insert_after(4, 'd'), Ok(()));
+    assert_eq!(cur.peek_next(), Some((&4, &mut 'd')));
+    assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b'), (4, 'd'), (7, 'g')]));
+
+    let mut empty = BTreeMap::new();
+    let mut cur = empty.range_cursor_mut(0..10);
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.insert_after(10, 'x'), Err(UnorderedKeyError {}));
+    assert_eq!(cur.insert_after(5, 'x'), Ok(()));
+    assert_eq!(empty, BTreeMap::from([(5, 'x')]));
 }
 
 #[test]
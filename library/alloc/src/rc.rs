@@ -249,6 +249,7 @@
 use core::any::Any;
 use core::borrow;
 use core::cell::Cell;
+use core::clone::CloneToUninit;
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
@@ -269,8 +270,6 @@
 
 #[cfg(not(no_global_oom_handling))]
 use crate::alloc::handle_alloc_error;
-#[cfg(not(no_global_oom_handling))]
-use crate::alloc::WriteCloneIntoRaw;
 use crate::alloc::{AllocError, Allocator, Global, Layout};
 use crate::borrow::{Cow, ToOwned};
 #[cfg(not(no_global_oom_handling))]
@@ -1653,13 +1652,16 @@ pub fn ptr_eq(this: &Self, other: &Self) -> bool {
     }
 }
 
-impl<T: Clone, A: Allocator + Clone> Rc<T, A> {
+impl<T: ?Sized + CloneToUninit, A: Allocator + Clone> Rc<T, A> {
     /// Makes a mutable reference into the given `Rc`.
     ///
     /// If there are other `Rc` pointers to the same allocation, then `make_mut` will
     /// [`clone`] the inner value to a new allocation to ensure unique ownership.  This is also
     /// referred to as clone-on-write.
     ///
+    /// `T` may be unsized, such as a slice or `str`, in which case the clone is written into a
+    /// new allocation of the same size.
+    ///
     /// However, if there are no other `Rc` pointers to this allocation, but some [`Weak`]
     /// pointers, then the [`Weak`] pointers will be disassociated and the inner value will not
     /// be cloned.
@@ -1704,31 +1706,56 @@ impl<T: Clone, A: Allocator + Clone> Rc<T, A> {
     /// assert!(76 == *data);
     /// assert!(weak.upgrade().is_none());
     /// ```
+    ///
+    /// Shared slices and strings are cloned into a new allocation:
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    ///
+    /// let mut data: Rc<[i32]> = Rc::new([1, 2, 3]);
+    /// let other_data = Rc::clone(&data);
+    ///
+    /// Rc::make_mut(&mut data)[0] = 10; // Clones inner data
+    ///
+    /// assert_eq!(*data, [10, 2, 3]);
+    /// assert_eq!(*other_data, [1, 2, 3]);
+    ///
+    /// let mut name: Rc<str> = Rc::from("rc");
+    /// Rc::make_mut(&mut name).make_ascii_uppercase(); // Won't clone anything
+    /// assert_eq!(&*name, "RC");
+    /// ```
     #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[stable(feature = "rc_unique", since = "1.4.0")]
     pub fn make_mut(this: &mut Self) -> &mut T {
+        let size_of_val = size_of_val::<T>(&**this);
+
         if Rc::strong_count(this) != 1 {
             // Gotta clone the data, there are other Rcs.
             // Pre-allocate memory to allow writing the cloned value directly.
-            let mut rc = Self::new_uninit_in(this.alloc.clone());
+            // `in_progress` frees it again if the clone panics.
+            let this_data_ref: &T = &**this;
+            let mut in_progress = UniqueRcUninit::new(this_data_ref, this.alloc.clone());
             unsafe {
-                let data = Rc::get_mut_unchecked(&mut rc);
-                (**this).write_clone_into_raw(data.as_mut_ptr());
-                *this = rc.assume_init();
+                this_data_ref.clone_to_uninit(in_progress.data_ptr());
+                *this = in_progress.into_rc();
             }
         } else if Rc::weak_count(this) != 0 {
             // Can just steal the data, all that's left is Weaks
-            let mut rc = Self::new_uninit_in(this.alloc.clone());
+            let mut in_progress = UniqueRcUninit::new(&**this, this.alloc.clone());
             unsafe {
-                let data = Rc::get_mut_unchecked(&mut rc);
-                data.as_mut_ptr().copy_from_nonoverlapping(&**this, 1);
+                // Copy value as bytes
+                ptr::copy_nonoverlapping(
+                    &**this as *const T as *const u8,
+                    in_progress.data_ptr() as *mut u8,
+                    size_of_val,
+                );
 
                 this.inner().dec_strong();
                 // Remove implicit strong-weak ref (no need to craft a fake
                 // Weak here -- we know other Weaks can clean up for us)
                 this.inner().dec_weak();
-                ptr::write(this, rc.assume_init());
+                ptr::write(this, in_progress.into_rc());
             }
         }
         // This unsafety is ok because we're guaranteed that the pointer
@@ -1738,7 +1765,9 @@ pub fn make_mut(this: &mut Self) -> &mut T {
         // reference to the allocation.
         unsafe { &mut this.ptr.as_mut().value }
     }
+}
 
+impl<T: Clone, A: Allocator + Clone> Rc<T, A> {
     /// If we have the only reference to `T` then unwrap it. Otherwise, clone `T` and return the
     /// clone.
     ///
@@ -3385,6 +3414,65 @@ fn data_offset_align(align: usize) -> usize {
     layout.size() + layout.padding_needed_for(align)
 }
 
+/// A unique owning pointer to a [`RcBox`] **that does not imply the contents are initialized,**
+/// but will deallocate it (without dropping the value) when dropped.
+///
+/// This is a helper for [`Rc::make_mut()`] to ensure correct cleanup on panic.
+#[cfg(not(no_global_oom_handling))]
+struct UniqueRcUninit<T: ?Sized, A: Allocator> {
+    ptr: NonNull<RcBox<T>>,
+    layout_for_value: Layout,
+    alloc: Option<A>,
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T: ?Sized, A: Allocator> UniqueRcUninit<T, A> {
+    /// Allocates a RcBox with layout suitable to contain `for_value` or a clone of it.
+    fn new(for_value: &T, alloc: A) -> UniqueRcUninit<T, A> {
+        let layout = Layout::for_value(for_value);
+        // SAFETY: `for_value` is a valid reference, so its metadata describes a valid layout.
+        let ptr = unsafe { Rc::allocate_for_ptr_in(for_value, &alloc) };
+        // SAFETY: `allocate_for_ptr_in` never returns null.
+        let ptr = unsafe { NonNull::new_unchecked(ptr) };
+        Self { ptr, layout_for_value: layout, alloc: Some(alloc) }
+    }
+
+    /// Returns the pointer to be written into to initialize the [`Rc`].
+    fn data_ptr(&mut self) -> *mut T {
+        // SAFETY: `self.ptr` points to a live allocation from `new`.
+        unsafe { ptr::addr_of_mut!((*self.ptr.as_ptr()).value) }
+    }
+
+    /// Upgrade this into a normal [`Rc`].
+    ///
+    /// # Safety
+    ///
+    /// The data must have been initialized (by writing to [`Self::data_ptr()`]).
+    unsafe fn into_rc(mut self) -> Rc<T, A> {
+        let ptr = self.ptr;
+        let alloc = self.alloc.take().unwrap();
+        mem::forget(self);
+        // SAFETY: The pointer is valid as per `UniqueRcUninit::new`, and the caller is responsible
+        // for having initialized the data.
+        unsafe { Rc::from_inner_in(ptr, alloc) }
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T: ?Sized, A: Allocator> Drop for UniqueRcUninit<T, A> {
+    fn drop(&mut self) {
+        // SAFETY:
+        // * new() produced a pointer safe to deallocate.
+        // * We own the pointer unless into_rc() was called, which forgets us.
+        unsafe {
+            self.alloc
+                .take()
+                .unwrap()
+                .deallocate(self.ptr.cast(), rcbox_layout_for_value_layout(self.layout_for_value));
+        }
+    }
+}
+
 /// A uniquely owned `Rc`
 ///
 /// This represents an `Rc` that is known to be uniquely owned -- that is, have exactly one strong
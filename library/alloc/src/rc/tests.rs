@@ -321,6 +321,62 @@ fn test_cowrc_clone_weak() {
     assert!(cow1_weak.upgrade().is_none());
 }
 
+#[test]
+fn test_cowrc_clone_make_mut_unsized() {
+    let mut cow0: Rc<[String]> = Rc::from([String::from("a"), String::from("b")]);
+    let cow1 = cow0.clone();
+
+    Rc::make_mut(&mut cow0)[0].push('!');
+    assert_eq!(*cow0, ["a!", "b"]);
+    assert_eq!(*cow1, ["a", "b"]);
+
+    let mut cow2: Rc<str> = Rc::from("rc");
+    let cow3_weak = Rc::downgrade(&cow2);
+    Rc::make_mut(&mut cow2).make_ascii_uppercase();
+    assert_eq!(&*cow2, "RC");
+    assert!(cow3_weak.upgrade().is_none());
+}
+
+#[test]
+fn test_cowrc_clone_make_mut_slice_panic() {
+    use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct PanicOnSecondClone(usize);
+
+    impl Clone for PanicOnSecondClone {
+        fn clone(&self) -> Self {
+            if self.0 == 1 {
+                panic!("clone panicked");
+            }
+            PanicOnSecondClone(self.0)
+        }
+    }
+
+    impl Drop for PanicOnSecondClone {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, SeqCst);
+        }
+    }
+
+    let mut cow0: Rc<[PanicOnSecondClone]> =
+        Rc::from([PanicOnSecondClone(0), PanicOnSecondClone(1), PanicOnSecondClone(2)]);
+    let cow1 = cow0.clone();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        Rc::make_mut(&mut cow0);
+    }));
+    assert!(result.is_err());
+    // Only the first element was cloned before the panic, and it was dropped again.
+    assert_eq!(DROPS.load(SeqCst), 1);
+    assert_eq!(Rc::strong_count(&cow1), 2);
+
+    drop(cow0);
+    drop(cow1);
+    assert_eq!(DROPS.load(SeqCst), 4);
+}
+
 #[test]
 fn test_show() {
     let foo = Rc::new(75);
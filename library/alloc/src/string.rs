@@ -1972,6 +1972,66 @@ pub fn leak<'a>(self) -> &'a mut str {
         let slice = self.vec.leak();
         unsafe { from_utf8_unchecked_mut(slice) }
     }
+
+    /// Converts this `String` into a <code>[Box]<[str]></code> if that can be done without
+    /// reallocating, i.e. if the `String` has no excess capacity.
+    ///
+    /// [`into_boxed_str`] always succeeds, but shrinking the allocation to fit may move the
+    /// contents to a new one. This returns the `String` unchanged instead.
+    ///
+    /// [`into_boxed_str`]: Self::into_boxed_str
+    /// [str]: prim@str "str"
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` if its capacity is greater than its length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(string_box_str_helpers)]
+    ///
+    /// let mut s = String::with_capacity(16);
+    /// s.push_str("hello");
+    /// let s = s.into_boxed_str_with_capacity_check().unwrap_err();
+    /// assert_eq!(s, "hello");
+    ///
+    /// let b = String::from("hello").into_boxed_str_with_capacity_check().unwrap();
+    /// assert_eq!(&*b, "hello");
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "string_box_str_helpers", issue = "none")]
+    #[inline]
+    pub fn into_boxed_str_with_capacity_check(self) -> Result<Box<str>, String> {
+        if self.capacity() == self.len() { Ok(self.into_boxed_str()) } else { Err(self) }
+    }
+
+    /// Consumes and leaks the `String`, returning a `&'static str` that owns exactly its
+    /// contents.
+    ///
+    /// This is meant for string interners and other data that is kept for the remainder of the
+    /// program's life. Unlike [`leak`], any excess capacity is released first, so none of the
+    /// leaked memory goes unused, and the returned reference is shared, so it can be handed out
+    /// freely without transmuting lifetimes.
+    ///
+    /// [`leak`]: Self::leak
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(string_box_str_helpers)]
+    ///
+    /// let mut s = String::with_capacity(64);
+    /// s.push_str("symbol");
+    /// let interned: &'static str = s.leak_interned();
+    /// assert_eq!(interned, "symbol");
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "string_box_str_helpers", issue = "none")]
+    #[inline]
+    pub fn leak_interned(self) -> &'static str {
+        Box::leak(self.into_boxed_str())
+    }
 }
 
 impl FromUtf8Error {
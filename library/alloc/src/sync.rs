@@ -10,6 +10,7 @@
 
 use core::any::Any;
 use core::borrow;
+use core::clone::CloneToUninit;
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
@@ -32,8 +33,6 @@
 
 #[cfg(not(no_global_oom_handling))]
 use crate::alloc::handle_alloc_error;
-#[cfg(not(no_global_oom_handling))]
-use crate::alloc::WriteCloneIntoRaw;
 use crate::alloc::{AllocError, Allocator, Global, Layout};
 use crate::borrow::{Cow, ToOwned};
 use crate::boxed::Box;
@@ -2058,13 +2057,16 @@ fn deref(&self) -> &T {
 #[unstable(feature = "receiver_trait", issue = "none")]
 impl<T: ?Sized> Receiver for Arc<T> {}
 
-impl<T: Clone, A: Allocator + Clone> Arc<T, A> {
+impl<T: ?Sized + CloneToUninit, A: Allocator + Clone> Arc<T, A> {
     /// Makes a mutable reference into the given `Arc`.
     ///
     /// If there are other `Arc` pointers to the same allocation, then `make_mut` will
     /// [`clone`] the inner value to a new allocation to ensure unique ownership.  This is also
     /// referred to as clone-on-write.
     ///
+    /// `T` may be unsized, such as a slice or `str`, in which case the clone is written into a
+    /// new allocation of the same size.
+    ///
     /// However, if there are no other `Arc` pointers to this allocation, but some [`Weak`]
     /// pointers, then the [`Weak`] pointers will be dissociated and the inner value will not
     /// be cloned.
@@ -2109,10 +2111,30 @@ impl<T: Clone, A: Allocator + Clone> Arc<T, A> {
     /// assert!(76 == *data);
     /// assert!(weak.upgrade().is_none());
     /// ```
+    ///
+    /// Shared slices and strings are cloned into a new allocation:
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// let mut data: Arc<[i32]> = Arc::new([1, 2, 3]);
+    /// let other_data = Arc::clone(&data);
+    ///
+    /// Arc::make_mut(&mut data)[0] = 10; // Clones inner data
+    ///
+    /// assert_eq!(*data, [10, 2, 3]);
+    /// assert_eq!(*other_data, [1, 2, 3]);
+    ///
+    /// let mut name: Arc<str> = Arc::from("arc");
+    /// Arc::make_mut(&mut name).make_ascii_uppercase(); // Won't clone anything
+    /// assert_eq!(&*name, "ARC");
+    /// ```
     #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[stable(feature = "arc_unique", since = "1.4.0")]
     pub fn make_mut(this: &mut Self) -> &mut T {
+        let size_of_val = size_of_val::<T>(&**this);
+
         // Note that we hold both a strong reference and a weak reference.
         // Thus, releasing our strong reference only will not, by itself, cause
         // the memory to be deallocated.
@@ -2124,11 +2146,12 @@ pub fn make_mut(this: &mut Self) -> &mut T {
         if this.inner().strong.compare_exchange(1, 0, Acquire, Relaxed).is_err() {
             // Another strong pointer exists, so we must clone.
             // Pre-allocate memory to allow writing the cloned value directly.
-            let mut arc = Self::new_uninit_in(this.alloc.clone());
+            // `in_progress` frees it again if the clone panics.
+            let this_data_ref: &T = &**this;
+            let mut in_progress = UniqueArcUninit::new(this_data_ref, this.alloc.clone());
             unsafe {
-                let data = Arc::get_mut_unchecked(&mut arc);
-                (**this).write_clone_into_raw(data.as_mut_ptr());
-                *this = arc.assume_init();
+                this_data_ref.clone_to_uninit(in_progress.data_ptr());
+                *this = in_progress.into_arc();
             }
         } else if this.inner().weak.load(Relaxed) != 1 {
             // Relaxed suffices in the above because this is fundamentally an
@@ -2148,11 +2171,15 @@ pub fn make_mut(this: &mut Self) -> &mut T {
             let _weak = Weak { ptr: this.ptr, alloc: this.alloc.clone() };
 
             // Can just steal the data, all that's left is Weaks
-            let mut arc = Self::new_uninit_in(this.alloc.clone());
+            let mut in_progress = UniqueArcUninit::new(&**this, this.alloc.clone());
             unsafe {
-                let data = Arc::get_mut_unchecked(&mut arc);
-                data.as_mut_ptr().copy_from_nonoverlapping(&**this, 1);
-                ptr::write(this, arc.assume_init());
+                // Copy value as bytes
+                ptr::copy_nonoverlapping(
+                    &**this as *const T as *const u8,
+                    in_progress.data_ptr() as *mut u8,
+                    size_of_val,
+                );
+                ptr::write(this, in_progress.into_arc());
             }
         } else {
             // We were the sole reference of either kind; bump back up the
@@ -2164,7 +2191,9 @@ pub fn make_mut(this: &mut Self) -> &mut T {
         // either unique to begin with, or became one upon cloning the contents.
         unsafe { Self::get_mut_unchecked(this) }
     }
+}
 
+impl<T: Clone, A: Allocator + Clone> Arc<T, A> {
     /// If we have the only reference to `T` then unwrap it. Otherwise, clone `T` and return the
     /// clone.
     ///
@@ -3582,6 +3611,65 @@ fn data_offset_align(align: usize) -> usize {
     layout.size() + layout.padding_needed_for(align)
 }
 
+/// A unique owning pointer to an [`ArcInner`] **that does not imply the contents are initialized,**
+/// but will deallocate it (without dropping the value) when dropped.
+///
+/// This is a helper for [`Arc::make_mut()`] to ensure correct cleanup on panic.
+#[cfg(not(no_global_oom_handling))]
+struct UniqueArcUninit<T: ?Sized, A: Allocator> {
+    ptr: NonNull<ArcInner<T>>,
+    layout_for_value: Layout,
+    alloc: Option<A>,
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T: ?Sized, A: Allocator> UniqueArcUninit<T, A> {
+    /// Allocates an ArcInner with layout suitable to contain `for_value` or a clone of it.
+    fn new(for_value: &T, alloc: A) -> UniqueArcUninit<T, A> {
+        let layout = Layout::for_value(for_value);
+        // SAFETY: `for_value` is a valid reference, so its metadata describes a valid layout.
+        let ptr = unsafe { Arc::allocate_for_ptr_in(for_value, &alloc) };
+        // SAFETY: `allocate_for_ptr_in` never returns null.
+        let ptr = unsafe { NonNull::new_unchecked(ptr) };
+        Self { ptr, layout_for_value: layout, alloc: Some(alloc) }
+    }
+
+    /// Returns the pointer to be written into to initialize the [`Arc`].
+    fn data_ptr(&mut self) -> *mut T {
+        // SAFETY: `self.ptr` points to a live allocation from `new`.
+        unsafe { ptr::addr_of_mut!((*self.ptr.as_ptr()).data) }
+    }
+
+    /// Upgrade this into a normal [`Arc`].
+    ///
+    /// # Safety
+    ///
+    /// The data must have been initialized (by writing to [`Self::data_ptr()`]).
+    unsafe fn into_arc(mut self) -> Arc<T, A> {
+        let ptr = self.ptr;
+        let alloc = self.alloc.take().unwrap();
+        mem::forget(self);
+        // SAFETY: The pointer is valid as per `UniqueArcUninit::new`, and the caller is responsible
+        // for having initialized the data.
+        unsafe { Arc::from_inner_in(ptr, alloc) }
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<T: ?Sized, A: Allocator> Drop for UniqueArcUninit<T, A> {
+    fn drop(&mut self) {
+        // SAFETY:
+        // * new() produced a pointer safe to deallocate.
+        // * We own the pointer unless into_arc() was called, which forgets us.
+        unsafe {
+            self.alloc.take().unwrap().deallocate(
+                self.ptr.cast(),
+                arcinner_layout_for_value_layout(self.layout_for_value),
+            );
+        }
+    }
+}
+
 #[stable(feature = "arc_error", since = "1.52.0")]
 impl<T: core::error::Error + ?Sized> core::error::Error for Arc<T> {
     #[allow(deprecated, deprecated_in_future)]
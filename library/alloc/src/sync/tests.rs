@@ -274,6 +274,60 @@ fn test_cowarc_clone_weak() {
     assert!(cow1_weak.upgrade().is_none());
 }
 
+#[test]
+fn test_cowarc_clone_make_mut_unsized() {
+    let mut cow0: Arc<[String]> = Arc::from([String::from("a"), String::from("b")]);
+    let cow1 = cow0.clone();
+
+    Arc::make_mut(&mut cow0)[0].push('!');
+    assert_eq!(*cow0, ["a!", "b"]);
+    assert_eq!(*cow1, ["a", "b"]);
+
+    let mut cow2: Arc<str> = Arc::from("arc");
+    let cow3_weak = Arc::downgrade(&cow2);
+    Arc::make_mut(&mut cow2).make_ascii_uppercase();
+    assert_eq!(&*cow2, "ARC");
+    assert!(cow3_weak.upgrade().is_none());
+}
+
+#[test]
+fn test_cowarc_clone_make_mut_slice_panic() {
+    static DROPS: atomic::AtomicUsize = atomic::AtomicUsize::new(0);
+
+    struct PanicOnSecondClone(usize);
+
+    impl Clone for PanicOnSecondClone {
+        fn clone(&self) -> Self {
+            if self.0 == 1 {
+                panic!("clone panicked");
+            }
+            PanicOnSecondClone(self.0)
+        }
+    }
+
+    impl Drop for PanicOnSecondClone {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, SeqCst);
+        }
+    }
+
+    let mut cow0: Arc<[PanicOnSecondClone]> =
+        Arc::from([PanicOnSecondClone(0), PanicOnSecondClone(1), PanicOnSecondClone(2)]);
+    let cow1 = cow0.clone();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        Arc::make_mut(&mut cow0);
+    }));
+    assert!(result.is_err());
+    // Only the first element was cloned before the panic, and it was dropped again.
+    assert_eq!(DROPS.load(SeqCst), 1);
+    assert_eq!(Arc::strong_count(&cow1), 2);
+
+    drop(cow0);
+    drop(cow1);
+    assert_eq!(DROPS.load(SeqCst), 4);
+}
+
 #[test]
 fn test_live() {
     let x = Arc::new(5);
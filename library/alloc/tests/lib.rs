@@ -32,6 +32,7 @@
 #![feature(slice_group_by)]
 #![feature(slice_partition_dedup)]
 #![feature(string_remove_matches)]
+#![feature(string_box_str_helpers)]
 #![feature(const_btree_len)]
 #![feature(const_trait_impl)]
 #![feature(const_str_from_utf8)]
@@ -39,6 +40,7 @@
 #![feature(pointer_is_aligned)]
 #![feature(slice_flatten)]
 #![feature(thin_box)]
+#![feature(error_generic_member_access)]
 #![feature(strict_provenance)]
 #![feature(drain_keep_rest)]
 #![allow(internal_features)]
@@ -699,6 +699,30 @@ fn test_into_boxed_str() {
     assert_eq!(&*ys, "hello my name is bob");
 }
 
+#[test]
+fn test_into_boxed_str_with_capacity_check() {
+    let mut xs = String::with_capacity(32);
+    xs.push_str("hello");
+    let xs = xs.into_boxed_str_with_capacity_check().unwrap_err();
+    assert_eq!(xs, "hello");
+    assert!(xs.capacity() >= 32);
+
+    let mut xs = xs;
+    xs.shrink_to_fit();
+    let ptr = xs.as_ptr();
+    let ys = xs.into_boxed_str_with_capacity_check().unwrap();
+    assert_eq!(&*ys, "hello");
+    assert_eq!(ys.as_ptr(), ptr);
+}
+
+#[test]
+fn test_leak_interned() {
+    let mut xs = String::with_capacity(32);
+    xs.push_str("interned");
+    let ys: &'static str = xs.leak_interned();
+    assert_eq!(ys, "interned");
+}
+
 #[test]
 fn test_reserve_exact() {
     // This is all the same as test_reserve
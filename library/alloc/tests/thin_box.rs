@@ -1,6 +1,7 @@
-use core::fmt::Debug;
+use core::fmt::{self, Debug};
 use core::mem::size_of;
 use std::boxed::ThinBox;
+use std::error::{request_ref, Error, Request};
 
 #[test]
 fn want_niche_optimization() {
@@ -26,6 +27,28 @@ trait Tr {}
     assert!(is_thin::<i32>());
 }
 
+#[test]
+fn provide_through_dyn() {
+    #[derive(Debug)]
+    struct TestError(u16);
+
+    impl fmt::Display for TestError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("test error")
+        }
+    }
+
+    impl Error for TestError {
+        fn provide<'a>(&'a self, request: &mut Request<'a>) {
+            request.provide_ref::<u16>(&self.0);
+        }
+    }
+
+    let err = ThinBox::<dyn Error>::new_unsize(TestError(404));
+    assert_eq!(request_ref::<u16>(&err), Some(&404));
+    assert_eq!(request_ref::<u32>(&err), None);
+}
+
 #[allow(dead_code)]
 fn assert_covariance() {
     fn thin_box<'new>(b: ThinBox<[&'static str]>) -> ThinBox<[&'new str]> {
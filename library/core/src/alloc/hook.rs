@@ -0,0 +1,182 @@
+use crate::alloc::Layout;
+#[cfg(target_has_atomic = "ptr")]
+use crate::mem;
+#[cfg(target_has_atomic = "ptr")]
+use crate::ptr;
+#[cfg(target_has_atomic = "ptr")]
+use crate::sync::atomic::{AtomicPtr, Ordering};
+
+#[cfg(target_has_atomic = "ptr")]
+static HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// The operation of the global allocator reported to the allocation hook.
+///
+/// *See also the function [`set_alloc_hook`].*
+#[unstable(feature = "alloc_hook", issue = "none")]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum AllocKind {
+    /// Memory was allocated with [`GlobalAlloc::alloc`](super::GlobalAlloc::alloc).
+    Alloc,
+    /// Zeroed memory was allocated with
+    /// [`GlobalAlloc::alloc_zeroed`](super::GlobalAlloc::alloc_zeroed).
+    AllocZeroed,
+    /// Memory is about to be deallocated with
+    /// [`GlobalAlloc::dealloc`](super::GlobalAlloc::dealloc).
+    Dealloc,
+    /// Memory was reallocated with [`GlobalAlloc::realloc`](super::GlobalAlloc::realloc), from
+    /// the block at `old_ptr`, which was `old_size` bytes long.
+    Realloc {
+        /// The block that was reallocated.
+        #[unstable(feature = "alloc_hook", issue = "none")]
+        old_ptr: *mut u8,
+        /// The size of the block that was reallocated.
+        #[unstable(feature = "alloc_hook", issue = "none")]
+        old_size: usize,
+    },
+}
+
+/// Registers an allocation hook, replacing any that was previously registered.
+///
+/// The allocation hook is called for every allocation, deallocation and reallocation made through
+/// the global allocator, be it the one registered with the `#[global_allocator]` attribute or the
+/// `std` crate's default. It lets heap profilers and leak checkers observe the heap without
+/// replacing the allocator.
+///
+/// The allocation hook is a global resource. [`take_alloc_hook`] may be used to retrieve a
+/// previously registered hook and wrap or discard it.
+///
+/// # What the provided `hook` function should expect
+///
+/// The hook function is provided with the [`Layout`] of the memory block, the [`AllocKind`] of
+/// the operation and the address of the block:
+///
+/// - after an allocation, the address of the allocated block, which is null if the allocation
+///   failed;
+/// - before a deallocation, the address of the block about to be deallocated;
+/// - after a reallocation, the new layout and the address of the new block, which is null if the
+///   reallocation failed and the old block is still allocated.
+///
+/// The hook is called on the thread making the allocation, with no lock held. It must not
+/// allocate through the global allocator itself, since that would call it again, recursively:
+/// hooks that need to allocate have to detect reentrancy themselves, e.g. with a thread-local
+/// flag.
+///
+/// When no hook is registered, the global allocator only pays for checking that none is.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(alloc_hook)]
+///
+/// use std::alloc::{set_alloc_hook, take_alloc_hook, AllocKind, Layout};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn count_allocations(_: Layout, kind: AllocKind, _: *mut u8) {
+///     if let AllocKind::Alloc | AllocKind::AllocZeroed = kind {
+///         ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// set_alloc_hook(count_allocations);
+/// let boxed = Box::new(1);
+/// take_alloc_hook();
+/// assert!(ALLOCATIONS.load(Ordering::Relaxed) >= 1);
+/// # drop(boxed);
+/// ```
+#[cfg(target_has_atomic = "ptr")]
+#[unstable(feature = "alloc_hook", issue = "none")]
+pub fn set_alloc_hook(hook: fn(Layout, AllocKind, *mut u8)) {
+    HOOK.store(hook as *mut (), Ordering::Relaxed);
+}
+
+/// Unregisters the current allocation hook, returning it.
+///
+/// *See also the function [`set_alloc_hook`].*
+#[cfg(target_has_atomic = "ptr")]
+#[unstable(feature = "alloc_hook", issue = "none")]
+pub fn take_alloc_hook() -> Option<fn(Layout, AllocKind, *mut u8)> {
+    let hook = HOOK.swap(ptr::null_mut(), Ordering::Relaxed);
+    // SAFETY: non-null values of `HOOK` are only ever stored by `set_alloc_hook`, from a
+    // `fn(Layout, AllocKind, *mut u8)`.
+    if hook.is_null() { None } else { Some(unsafe { mem::transmute(hook) }) }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+#[inline]
+fn call_hook(layout: Layout, kind: AllocKind, ptr: *mut u8) {
+    // A relaxed load is enough, the hook is a function pointer and no data comes along with it.
+    let hook = HOOK.load(Ordering::Relaxed);
+    if !hook.is_null() {
+        // SAFETY: see `take_alloc_hook`.
+        let hook: fn(Layout, AllocKind, *mut u8) = unsafe { mem::transmute(hook) };
+        hook(layout, kind, ptr);
+    }
+}
+
+#[cfg(not(target_has_atomic = "ptr"))]
+#[inline]
+fn call_hook(_layout: Layout, _kind: AllocKind, _ptr: *mut u8) {}
+
+/// Wrappers around the methods of [`GlobalAlloc`] reporting each call to the allocation hook.
+///
+/// The functions generated by `#[global_allocator]`, and the `std` crate's default ones, call the
+/// global allocator through these.
+///
+/// [`GlobalAlloc`]: super::GlobalAlloc
+#[doc(hidden)]
+#[unstable(feature = "alloc_hook_internals", issue = "none")]
+pub mod hooked {
+    use super::{call_hook, AllocKind};
+    use crate::alloc::{GlobalAlloc, Layout};
+
+    /// Calls [`GlobalAlloc::alloc`], then the allocation hook.
+    #[inline]
+    #[unstable(feature = "alloc_hook_internals", issue = "none")]
+    pub unsafe fn alloc<A: GlobalAlloc>(allocator: &A, layout: Layout) -> *mut u8 {
+        // SAFETY: the caller must uphold the safety contract of `GlobalAlloc::alloc`.
+        let ptr = unsafe { allocator.alloc(layout) };
+        call_hook(layout, AllocKind::Alloc, ptr);
+        ptr
+    }
+
+    /// Calls the allocation hook, then [`GlobalAlloc::dealloc`].
+    #[inline]
+    #[unstable(feature = "alloc_hook_internals", issue = "none")]
+    pub unsafe fn dealloc<A: GlobalAlloc>(allocator: &A, ptr: *mut u8, layout: Layout) {
+        call_hook(layout, AllocKind::Dealloc, ptr);
+        // SAFETY: the caller must uphold the safety contract of `GlobalAlloc::dealloc`.
+        unsafe { allocator.dealloc(ptr, layout) }
+    }
+
+    /// Calls [`GlobalAlloc::alloc_zeroed`], then the allocation hook.
+    #[inline]
+    #[unstable(feature = "alloc_hook_internals", issue = "none")]
+    pub unsafe fn alloc_zeroed<A: GlobalAlloc>(allocator: &A, layout: Layout) -> *mut u8 {
+        // SAFETY: the caller must uphold the safety contract of `GlobalAlloc::alloc_zeroed`.
+        let ptr = unsafe { allocator.alloc_zeroed(layout) };
+        call_hook(layout, AllocKind::AllocZeroed, ptr);
+        ptr
+    }
+
+    /// Calls [`GlobalAlloc::realloc`], then the allocation hook.
+    #[inline]
+    #[unstable(feature = "alloc_hook_internals", issue = "none")]
+    pub unsafe fn realloc<A: GlobalAlloc>(
+        allocator: &A,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        // SAFETY: the caller must uphold the safety contract of `GlobalAlloc::realloc`.
+        let new_ptr = unsafe { allocator.realloc(ptr, layout, new_size) };
+        // SAFETY: the contract of `GlobalAlloc::realloc` requires `new_size`, rounded up to
+        // `layout.align()`, not to overflow `isize`.
+        let new_layout = unsafe { Layout::from_size_align_unchecked(new_size, layout.align()) };
+        let kind = AllocKind::Realloc { old_ptr: ptr, old_size: layout.size() };
+        call_hook(new_layout, kind, new_ptr);
+        new_ptr
+    }
+}
@@ -3,10 +3,19 @@
 #![stable(feature = "alloc_module", since = "1.28.0")]
 
 mod global;
+mod hook;
 mod layout;
 
 #[stable(feature = "global_alloc", since = "1.28.0")]
 pub use self::global::GlobalAlloc;
+#[doc(hidden)]
+#[unstable(feature = "alloc_hook_internals", issue = "none")]
+pub use self::hook::hooked;
+#[unstable(feature = "alloc_hook", issue = "none")]
+pub use self::hook::AllocKind;
+#[cfg(target_has_atomic = "ptr")]
+#[unstable(feature = "alloc_hook", issue = "none")]
+pub use self::hook::{set_alloc_hook, take_alloc_hook};
 #[stable(feature = "alloc_layout", since = "1.28.0")]
 pub use self::layout::Layout;
 #[stable(feature = "alloc_layout", since = "1.28.0")]
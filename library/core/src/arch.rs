@@ -4,6 +4,25 @@
 #[stable(feature = "simd_arch", since = "1.27.0")]
 pub use crate::core_arch::arch::*;
 
+mod cpu_features;
+
+#[cfg(target_has_atomic = "ptr")]
+#[unstable(feature = "arch_select", issue = "none")]
+pub use cpu_features::arch_select;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+pub use cpu_features::cpu_feature;
+#[cfg(target_has_atomic = "ptr")]
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+pub use cpu_features::{
+    clear_cpu_feature_cache, set_cpu_feature_hook, set_default_cpu_feature_hook,
+    take_cpu_feature_hook,
+};
+#[unstable(feature = "cpu_features_snapshot", issue = "none")]
+pub use cpu_features::{cpu_features, CpuFeatures};
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+pub use cpu_features::{detect_cpu_feature, is_cpu_feature_detected};
+
 /// Inline assembly.
 ///
 /// Refer to [rust by example] for a usage guide and the [reference] for
@@ -0,0 +1,431 @@
+//! Runtime detection of CPU features, through a hook registered by the runtime.
+
+use crate::fmt;
+#[cfg(target_has_atomic = "ptr")]
+use crate::mem;
+#[cfg(target_has_atomic = "ptr")]
+use crate::ptr;
+#[cfg(target_has_atomic = "ptr")]
+use crate::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+#[cfg(target_has_atomic = "ptr")]
+static FEATURE_HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+#[cfg(target_has_atomic = "ptr")]
+static FEATURE_CACHE: FeatureCache = FeatureCache::new();
+
+/// Registers the function used to detect CPU features at runtime, returning the function that was
+/// previously registered, if any.
+///
+/// The hook is provided with the index of a feature in `std_detect`'s list of features for the
+/// current architecture, and returns whether the CPU the program runs on supports it. Before a
+/// hook is registered, and after it is removed with [`take_cpu_feature_hook`],
+/// [`detect_cpu_feature`] reports every feature as unsupported.
+///
+/// The hook is a global resource shared by all the crates of the program. The standard library
+/// registers its own with [`set_default_cpu_feature_hook`] the first time a feature is detected
+/// through `std::arch`, unless another one was registered before. Registering it clears the
+/// results cached by [`detect_cpu_feature`].
+///
+/// Runtimes that cannot register the hook before it is needed, e.g. bare-metal ones that run no
+/// code before `main`, may instead define the function it falls back to. This is only supported on
+/// ELF targets, such as Linux, the BSDs and the bare-metal `none` targets:
+///
+/// ```ignore (requires a runtime that does not register the hook)
+/// #[no_mangle]
+/// extern "C" fn __rust_cpu_feature_detect(feature: u32) -> bool {
+///     // ...
+/// #   false
+/// }
+/// ```
+#[cfg(target_has_atomic = "ptr")]
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+pub fn set_cpu_feature_hook(hook: fn(u32) -> bool) -> Option<fn(u32) -> bool> {
+    let previous = hook_from_ptr(FEATURE_HOOK.swap(hook as *mut (), Ordering::AcqRel));
+    FEATURE_CACHE.clear();
+    previous
+}
+
+/// Unregisters the current CPU feature detection hook, returning it.
+///
+/// *See also the function [`set_cpu_feature_hook`].*
+#[cfg(target_has_atomic = "ptr")]
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+pub fn take_cpu_feature_hook() -> Option<fn(u32) -> bool> {
+    let previous = hook_from_ptr(FEATURE_HOOK.swap(ptr::null_mut(), Ordering::AcqRel));
+    FEATURE_CACHE.clear();
+    previous
+}
+
+/// Registers the function used to detect CPU features at runtime, unless one is already registered,
+/// and returns whether it was registered.
+///
+/// This is meant for runtimes that provide a default hook, so that they do not replace the one the
+/// program may have registered with [`set_cpu_feature_hook`] before.
+#[cfg(target_has_atomic = "ptr")]
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+pub fn set_default_cpu_feature_hook(hook: fn(u32) -> bool) -> bool {
+    let registered = FEATURE_HOOK
+        .compare_exchange(ptr::null_mut(), hook as *mut (), Ordering::AcqRel, Ordering::Acquire)
+        .is_ok();
+    if registered {
+        FEATURE_CACHE.clear();
+    }
+    registered
+}
+
+/// Forgets the results cached by [`detect_cpu_feature`], so that the hook is queried again.
+///
+/// This is only useful for hooks whose results change over time, such as the ones tests use to
+/// fake the features of the CPU.
+#[cfg(target_has_atomic = "ptr")]
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+pub fn clear_cpu_feature_cache() {
+    FEATURE_CACHE.clear();
+}
+
+/// Returns whether the CPU the program runs on supports the feature with the given index, as
+/// reported by the hook registered with [`set_cpu_feature_hook`].
+///
+/// The hook is queried at most once per feature; its result is cached until the hook changes or
+/// [`clear_cpu_feature_cache`] is called. If no hook is registered, the
+/// `__rust_cpu_feature_detect` function is called instead if the program defines one, and `false`
+/// is returned otherwise.
+#[inline]
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+pub fn detect_cpu_feature(feature: u32) -> bool {
+    #[cfg(target_has_atomic = "ptr")]
+    let detected = FEATURE_CACHE.get_or_detect(feature, call_hook);
+    #[cfg(not(target_has_atomic = "ptr"))]
+    let detected = call_weak_hook(feature);
+    detected
+}
+
+/// Returns the set of CPU features detected at runtime.
+///
+/// This queries [`detect_cpu_feature`] for every feature index once, so it is meant to be called
+/// rarely, e.g. to log the features of the CPU when a program starts. Features enabled at compile
+/// time are only in the set if the hook reports them too.
+#[unstable(feature = "cpu_features_snapshot", issue = "none")]
+pub fn cpu_features() -> CpuFeatures {
+    let mut bits = [0; FEATURE_WORDS];
+    for feature in 0..FEATURE_CAPACITY {
+        if detect_cpu_feature(feature) {
+            bits[(feature / usize::BITS) as usize] |= 1 << (feature % usize::BITS);
+        }
+    }
+    CpuFeatures { bits }
+}
+
+/// A snapshot of the CPU features detected at runtime, returned by [`cpu_features`].
+///
+/// Features are identified by the same indices as in [`detect_cpu_feature`]. The [`Display`]
+/// implementation lists the indices of the detected features, separated by commas.
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[unstable(feature = "cpu_features_snapshot", issue = "none")]
+pub struct CpuFeatures {
+    bits: [usize; FEATURE_WORDS],
+}
+
+impl CpuFeatures {
+    /// Returns whether the feature with the given index was detected.
+    #[unstable(feature = "cpu_features_snapshot", issue = "none")]
+    pub fn contains(&self, feature: u32) -> bool {
+        match self.bits.get((feature / usize::BITS) as usize) {
+            Some(bits) => bits & (1 << (feature % usize::BITS)) != 0,
+            None => false,
+        }
+    }
+
+    fn features(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..FEATURE_CAPACITY).filter(|&feature| self.contains(feature))
+    }
+}
+
+#[unstable(feature = "cpu_features_snapshot", issue = "none")]
+impl fmt::Debug for CpuFeatures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.features()).finish()
+    }
+}
+
+#[unstable(feature = "cpu_features_snapshot", issue = "none")]
+impl fmt::Display for CpuFeatures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, feature) in self.features().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{feature}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The indices of the x86 and x86-64 CPU features, as passed to [`detect_cpu_feature`].
+///
+/// They follow the order of `std_detect`'s list of features for these architectures.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+pub mod cpu_feature {
+    /// The index of the `aes` feature.
+    pub const AES: u32 = 0;
+    /// The index of the `pclmulqdq` feature.
+    pub const PCLMULQDQ: u32 = 1;
+    /// The index of the `rdrand` feature.
+    pub const RDRAND: u32 = 2;
+    /// The index of the `rdseed` feature.
+    pub const RDSEED: u32 = 3;
+    /// The index of the `tsc` feature.
+    pub const TSC: u32 = 4;
+    /// The index of the `mmx` feature.
+    pub const MMX: u32 = 5;
+    /// The index of the `sse` feature.
+    pub const SSE: u32 = 6;
+    /// The index of the `sse2` feature.
+    pub const SSE2: u32 = 7;
+    /// The index of the `sse3` feature.
+    pub const SSE3: u32 = 8;
+    /// The index of the `ssse3` feature.
+    pub const SSSE3: u32 = 9;
+    /// The index of the `sse4.1` feature.
+    pub const SSE4_1: u32 = 10;
+    /// The index of the `sse4.2` feature.
+    pub const SSE4_2: u32 = 11;
+    /// The index of the `sse4a` feature.
+    pub const SSE4A: u32 = 12;
+    /// The index of the `sha` feature.
+    pub const SHA: u32 = 13;
+    /// The index of the `avx` feature.
+    pub const AVX: u32 = 14;
+    /// The index of the `avx2` feature.
+    pub const AVX2: u32 = 15;
+}
+
+/// Returns whether the CPU the program runs on supports a feature, without querying the hook if the
+/// feature is enabled at compile time.
+///
+/// `$name` is the name of the feature, as accepted by `#[cfg(target_feature)]`, and `$index` its
+/// index, as passed to [`detect_cpu_feature`], e.g. one of the constants of
+/// `core::arch::cpu_feature` on x86. Features enabled with `-Ctarget-feature`, or by the target,
+/// are statically known to be supported, so the check is a constant `true` for them; the other
+/// features are detected at runtime.
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+#[allow_internal_unstable(cpu_feature_hook)]
+#[rustc_macro_transparency = "semitransparent"]
+pub macro is_cpu_feature_detected($name:literal, $index:expr $(,)?) {
+    $crate::cfg!(target_feature = $name) || $crate::arch::detect_cpu_feature($index)
+}
+
+/// Defines a function that dispatches to the first implementation whose condition holds on the
+/// CPU the program runs on.
+///
+/// The conditions are evaluated on the first call only: the selected implementation is cached as
+/// a function pointer, so later calls cost an atomic load and an indirect call. The conditions are
+/// usually [`is_cpu_feature_detected!`] checks, and the implementation after `_` is used when none
+/// of them holds. The arguments of the function must be plain identifiers.
+///
+/// Implementations using `#[target_feature]` are `unsafe` functions, which can be given as
+/// closures calling them, e.g. `|values| unsafe { sum_avx2(values) }`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(arch_select, cpu_feature_hook)]
+///
+/// use std::arch::{arch_select, cpu_feature, is_cpu_feature_detected};
+///
+/// fn sum_unrolled(values: &[u32]) -> u32 {
+///     values.chunks(4).map(|chunk| chunk.iter().sum::<u32>()).sum()
+/// }
+///
+/// fn sum_scalar(values: &[u32]) -> u32 {
+///     values.iter().sum()
+/// }
+///
+/// #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// arch_select! {
+///     /// Returns the sum of `values`.
+///     pub fn sum(values: &[u32]) -> u32 {
+///         is_cpu_feature_detected!("avx2", cpu_feature::AVX2) => sum_unrolled,
+///         _ => sum_scalar,
+///     }
+/// }
+///
+/// #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+/// use sum_scalar as sum;
+///
+/// assert_eq!(sum(&[1, 2, 3, 4, 5]), 15);
+/// ```
+#[cfg(target_has_atomic = "ptr")]
+#[unstable(feature = "arch_select", issue = "none")]
+#[allow_internal_unstable(arch_select)]
+#[allow_internal_unsafe]
+pub macro arch_select {
+    (@select _ => $default:expr $(,)?) => {
+        $default
+    },
+    (@select $cond:expr => $imp:expr, $($rest:tt)*) => {
+        if $cond { $imp } else { $crate::arch::arch_select!(@select $($rest)*) }
+    },
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $arg_ty:ty),* $(,)?) $(-> $ret:ty)? {
+            $($arms:tt)*
+        }
+    ) => {
+        $(#[$attr])*
+        $vis fn $name($($arg: $arg_ty),*) $(-> $ret)? {
+            type Impl = fn($($arg_ty),*) $(-> $ret)?;
+
+            static SELECTED: $crate::sync::atomic::AtomicPtr<()> =
+                $crate::sync::atomic::AtomicPtr::new($crate::ptr::null_mut());
+
+            fn select() -> Impl {
+                $crate::arch::arch_select!(@select $($arms)*)
+            }
+
+            let selected = SELECTED.load($crate::sync::atomic::Ordering::Relaxed);
+            let selected: Impl = if selected.is_null() {
+                let selected = select();
+                SELECTED.store(selected as *mut (), $crate::sync::atomic::Ordering::Relaxed);
+                selected
+            } else {
+                // SAFETY: `SELECTED` only ever holds the `Impl` returned by `select`.
+                unsafe { $crate::mem::transmute::<*mut (), Impl>(selected) }
+            };
+            selected($($arg),*)
+        }
+    },
+}
+
+#[cfg(target_has_atomic = "ptr")]
+fn call_hook(feature: u32) -> bool {
+    match hook_from_ptr(FEATURE_HOOK.load(Ordering::Acquire)) {
+        Some(hook) => hook(feature),
+        None => call_weak_hook(feature),
+    }
+}
+
+cfg_if! {
+    // `extern_weak` references to undefined symbols only resolve to null with ELF, so the fallback
+    // is only called on the targets known to use it. In particular, COFF (Windows and UEFI) and
+    // Mach-O (Apple) have no such weak references.
+    if #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "illumos",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+        all(target_os = "none", not(target_arch = "bpf")),
+    ))] {
+        /// Calls the `__rust_cpu_feature_detect` function, if the program defines one.
+        fn call_weak_hook(feature: u32) -> bool {
+            extern "C" {
+                #[linkage = "extern_weak"]
+                static __rust_cpu_feature_detect: Option<unsafe extern "C" fn(u32) -> bool>;
+            }
+
+            // SAFETY: the symbol is either undefined, and then null, or the function runtimes are
+            // documented to define with this signature.
+            match unsafe { __rust_cpu_feature_detect } {
+                Some(hook) => unsafe { hook(feature) },
+                None => false,
+            }
+        }
+    } else {
+        fn call_weak_hook(_feature: u32) -> bool {
+            false
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+#[inline]
+fn hook_from_ptr(hook: *mut ()) -> Option<fn(u32) -> bool> {
+    // The hook is stored as a pointer rather than as an integer, so that it keeps the provenance
+    // of the function pointer, which on some targets (e.g. CHERI) is required to call it.
+    if hook.is_null() {
+        None
+    } else {
+        // SAFETY: non-null values of `FEATURE_HOOK` are only ever stored by
+        // `set_cpu_feature_hook`, from a `fn(u32) -> bool`.
+        Some(unsafe { mem::transmute::<*mut (), fn(u32) -> bool>(hook) })
+    }
+}
+
+/// The number of features the cache and [`CpuFeatures`] have room for.
+const FEATURE_CAPACITY: u32 = 128;
+
+/// The number of words of each bitset of features.
+const FEATURE_WORDS: usize = (FEATURE_CAPACITY / usize::BITS) as usize;
+
+/// The results of the hook, one bit per feature.
+#[cfg(target_has_atomic = "ptr")]
+struct FeatureCache {
+    /// Whether the result for each feature is in `value`.
+    known: [AtomicUsize; FEATURE_WORDS],
+    /// The result for each feature whose bit is set in `known`.
+    value: [AtomicUsize; FEATURE_WORDS],
+    /// The number of times the cache was cleared, so that results detected before it was cleared,
+    /// e.g. with the previous hook, are not cached after it.
+    generation: AtomicUsize,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl FeatureCache {
+    const fn new() -> Self {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const ZERO: AtomicUsize = AtomicUsize::new(0);
+        FeatureCache {
+            known: [ZERO; FEATURE_WORDS],
+            value: [ZERO; FEATURE_WORDS],
+            generation: AtomicUsize::new(0),
+        }
+    }
+
+    #[inline]
+    fn get_or_detect(&self, feature: u32, detect: fn(u32) -> bool) -> bool {
+        let word = (feature / usize::BITS) as usize;
+        let (Some(known), Some(value)) = (self.known.get(word), self.value.get(word)) else {
+            // Features that do not fit in the cache are queried every time.
+            return detect(feature);
+        };
+        let bit = 1 << (feature % usize::BITS);
+        if known.load(Ordering::Acquire) & bit != 0 {
+            return value.load(Ordering::Relaxed) & bit != 0;
+        }
+
+        let generation = self.generation.load(Ordering::Acquire);
+        let detected = detect(feature);
+        if detected {
+            value.fetch_or(bit, Ordering::Relaxed);
+        } else {
+            value.fetch_and(!bit, Ordering::Relaxed);
+        }
+        // Publishes the value stored above to the threads that see the feature as known. This
+        // synchronizes with `clear` if it ran in the meantime, so that its new generation is seen
+        // below.
+        known.fetch_or(bit, Ordering::AcqRel);
+        if self.generation.load(Ordering::Relaxed) != generation {
+            // The cache was cleared while the hook was queried, so `detected` may come from the
+            // previous hook: it must not outlive the call.
+            known.fetch_and(!bit, Ordering::Relaxed);
+        }
+        detected
+    }
+
+    fn clear(&self) {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+        // Publishes the new generation to the threads that publish a result after this.
+        for known in &self.known {
+            known.store(0, Ordering::Release);
+        }
+    }
+}
@@ -126,6 +126,18 @@ fn cause(&self) -> Option<&dyn Error> {
     /// Used in conjunction with [`Request::provide_value`] and [`Request::provide_ref`] to extract
     /// references to member variables from `dyn Error` trait objects.
     ///
+    /// `provide` is part of the `dyn Error` vtable like any other method, so a request made
+    /// through a trait object, e.g. a `&dyn Error` or `Box<dyn Error>`, is answered by the
+    /// implementation of the erased concrete type. No downcasting is needed to reach its context.
+    ///
+    /// A request is only answered by the error it is made on, not by its [`source`](Error::source).
+    /// Types that wrap an error transparently, without adding a layer of their own, forward
+    /// `provide` to it: this includes `&E`, `Box<E>` and `Arc<E>`. `io::Error` provides its
+    /// `ErrorKind`, and only forwards the request if it wraps a custom error, i.e. one created
+    /// with `io::Error::new` or `io::Error::other`; errors made from an OS error code or an
+    /// `ErrorKind` have nothing else to provide. To look further down a chain of errors, make the
+    /// request of each source in turn, as shown below.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -179,6 +191,56 @@ fn cause(&self) -> Option<&dyn Error> {
     ///     assert!(request_ref::<MyLittleTeaPot>(dyn_error).is_none());
     /// }
     /// ```
+    ///
+    /// Finding context anywhere in the chain of sources:
+    ///
+    /// ```rust
+    /// #![feature(error_generic_member_access)]
+    /// #![feature(error_iter)]
+    /// use std::error::{request_value, Error, Request};
+    /// use std::fmt;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq)]
+    /// struct StatusCode(u16);
+    ///
+    /// #[derive(Debug)]
+    /// struct HttpError(StatusCode);
+    ///
+    /// impl fmt::Display for HttpError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "request failed with status {}", self.0.0)
+    ///     }
+    /// }
+    ///
+    /// impl Error for HttpError {
+    ///     fn provide<'a>(&'a self, request: &mut Request<'a>) {
+    ///         request.provide_value(self.0);
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct FetchError(HttpError);
+    ///
+    /// impl fmt::Display for FetchError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "failed to fetch the index")
+    ///     }
+    /// }
+    ///
+    /// impl Error for FetchError {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let err: Box<dyn Error> = Box::new(FetchError(HttpError(StatusCode(404))));
+    ///
+    /// // `FetchError` itself doesn't provide a status code...
+    /// assert_eq!(request_value::<StatusCode>(&*err), None);
+    /// // ...but one of its sources does.
+    /// let status = err.sources().find_map(|source| request_value::<StatusCode>(source));
+    /// assert_eq!(status, Some(StatusCode(404)));
+    /// ```
     #[unstable(feature = "error_generic_member_access", issue = "99301")]
     #[allow(unused_variables)]
     fn provide<'a>(&'a self, request: &mut Request<'a>) {}
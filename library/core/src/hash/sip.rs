@@ -307,7 +307,26 @@ fn write(&mut self, msg: &[u8]) {
     fn write_str(&mut self, s: &str) {
         // This hasher works byte-wise, and `0xFF` cannot show up in a `str`,
         // so just hashing the one extra byte is enough to be prefix-free.
-        self.write(s.as_bytes());
+        let bytes = s.as_bytes();
+        let length = bytes.len();
+
+        // Fast path for short strings, such as most map keys: if the string
+        // and its `0xFF` terminator don't complete the buffered tail, there is
+        // nothing to compress, so append them to the tail in one go instead
+        // of going through `write` twice. The result is the same either way.
+        // There is no SIMD variant selected at runtime: the rounds are a
+        // serial chain over the four state words, so short inputs have
+        // nothing to vectorize.
+        if self.ntail + length + 1 < 8 {
+            // SAFETY: `length < 7`, and `bytes` is exactly `length` bytes long.
+            let data = unsafe { u8to64_le(bytes, 0, length) } | (0xFF << (8 * length));
+            self.tail |= data << (8 * self.ntail);
+            self.ntail += length + 1;
+            self.length += length + 1;
+            return;
+        }
+
+        self.write(bytes);
         self.write_u8(0xFF);
     }
 
@@ -1065,6 +1065,24 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     #[rustc_nounwind]
     pub fn type_id<T: ?Sized + 'static>() -> u128;
 
+    /// Gets a hash of the vtable layout of the trait object type `T`, if its principal trait is
+    /// marked `#[stable_vtable]`, or 0 otherwise.
+    ///
+    /// Code that passes such trait objects to separately compiled code, like a plugin system,
+    /// can export this value and check that both sides agree on the layout of the vtable.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    ///
+    /// This intrinsic does not have a stable counterpart.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_vtable_layout_hash", issue = "none")]
+    #[rustc_safe_intrinsic]
+    #[rustc_nounwind]
+    pub fn vtable_layout_hash<T: ?Sized>() -> u64;
+
     /// A guard for unsafe functions that cannot ever be executed if `T` is uninhabited:
     /// This will statically either panic, or do nothing.
     ///
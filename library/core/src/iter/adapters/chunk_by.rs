@@ -0,0 +1,127 @@
+use crate::{
+    fmt,
+    iter::{ExactSizeIterator, FusedIterator},
+};
+
+/// An iterator over runs of consecutive elements of another iterator.
+///
+/// This `struct` is created by [`Iterator::chunk_by`]. See its documentation
+/// for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+#[derive(Clone)]
+pub struct ChunkBy<I, P> {
+    // Positioned at the start of the next chunk. We fuse the inner iterator,
+    // because a chunk can't continue past a `None`: once the iterator returns
+    // one, this is set to `None` and `ChunkBy` returns `None` forever.
+    iter: Option<I>,
+    pred: P,
+}
+
+impl<I, P> ChunkBy<I, P> {
+    pub(in crate::iter) fn new(iter: I, pred: P) -> Self {
+        Self { iter: Some(iter), pred }
+    }
+}
+
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+impl<I, P> Iterator for ChunkBy<I, P>
+where
+    I: Iterator + Clone,
+    P: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = ChunkByRun<I>;
+
+    fn next(&mut self) -> Option<ChunkByRun<I>> {
+        let iter = self.iter.as_mut()?;
+        let start = iter.clone();
+        let Some(mut prev) = iter.next() else {
+            self.iter = None;
+            return None;
+        };
+
+        let mut len = 1;
+        loop {
+            // The first element of the next chunk is only known once it has been
+            // yielded, so keep a copy of the iterator from before to rewind to.
+            let before = iter.clone();
+            match iter.next() {
+                Some(next) if (self.pred)(&prev, &next) => {
+                    prev = next;
+                    len += 1;
+                }
+                Some(_) => {
+                    *iter = before;
+                    break;
+                }
+                None => {
+                    self.iter = None;
+                    break;
+                }
+            }
+        }
+
+        Some(ChunkByRun { iter: start, remaining: len })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Some(ref iter) = self.iter else { return (0, Some(0)) };
+        // Every chunk holds at least one element, and all of them could be on
+        // their own.
+        let (lo, hi) = iter.size_hint();
+        (lo.min(1), hi)
+    }
+}
+
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+impl<I, P> FusedIterator for ChunkBy<I, P>
+where
+    I: Iterator + Clone,
+    P: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+impl<I: fmt::Debug, P> fmt::Debug for ChunkBy<I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkBy").field("iter", &self.iter).finish()
+    }
+}
+
+/// A run of consecutive elements yielded by [`ChunkBy`].
+///
+/// This `struct` is created by [`Iterator::chunk_by`]. See its documentation
+/// for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct ChunkByRun<I> {
+    // A copy of the inner iterator from the start of this chunk.
+    iter: I,
+    remaining: usize,
+}
+
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+impl<I: Iterator> Iterator for ChunkByRun<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+impl<I: Iterator> ExactSizeIterator for ChunkByRun<I> {}
+
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+impl<I: Iterator> FusedIterator for ChunkByRun<I> {}
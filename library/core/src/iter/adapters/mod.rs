@@ -5,6 +5,7 @@
 mod array_chunks;
 mod by_ref_sized;
 mod chain;
+mod chunk_by;
 mod cloned;
 mod copied;
 mod cycle;
@@ -41,6 +42,9 @@
 #[unstable(feature = "std_internals", issue = "none")]
 pub use self::by_ref_sized::ByRefSized;
 
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+pub use self::chunk_by::{ChunkBy, ChunkByRun};
+
 #[stable(feature = "iter_cloned", since = "1.1.0")]
 pub use self::cloned::Cloned;
 
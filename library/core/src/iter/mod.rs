@@ -434,6 +434,8 @@ fn $fold<AAA, FFF>(mut self, init: AAA, fold: FFF) -> AAA
 pub use self::adapters::ArrayChunks;
 #[unstable(feature = "std_internals", issue = "none")]
 pub use self::adapters::ByRefSized;
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+pub use self::adapters::{ChunkBy, ChunkByRun};
 #[stable(feature = "iter_cloned", since = "1.1.0")]
 pub use self::adapters::Cloned;
 #[stable(feature = "iter_copied", since = "1.36.0")]
@@ -6,7 +6,8 @@
 use super::super::try_process;
 use super::super::ByRefSized;
 use super::super::TrustedRandomAccessNoCoerce;
-use super::super::{ArrayChunks, Chain, Cloned, Copied, Cycle, Enumerate, Filter, FilterMap, Fuse};
+use super::super::{ArrayChunks, Chain, ChunkBy, Cloned, Copied, Cycle, Enumerate, Filter};
+use super::super::{FilterMap, Fuse};
 use super::super::{FlatMap, Flatten};
 use super::super::{FromIterator, Intersperse, IntersperseWith, Product, Sum, Zip};
 use super::super::{
@@ -1750,6 +1751,79 @@ fn map_windows<F, R, const N: usize>(self, f: F) -> MapWindows<Self, F, N>
         MapWindows::new(self, f)
     }
 
+    /// Returns an iterator over runs of consecutive elements, using `pred` to
+    /// separate them.
+    ///
+    /// `pred` is called on each pair of neighboring elements, and a new chunk
+    /// starts wherever it returns `false`. This is the iterator counterpart of
+    /// [`slice::group_by()`]; grouping consecutive elements by a key is done by
+    /// comparing the keys of both elements in `pred`.
+    ///
+    /// Each chunk is itself an iterator over its elements, which it replays from
+    /// a copy of `self` taken at the start of the chunk. No allocation is
+    /// involved, but every element is yielded twice by the underlying iterator,
+    /// so this is best suited to iterators that are cheap to clone, such as
+    /// [`slice::iter()`] or [`str::chars()`], and it relies on a clone of `self`
+    /// yielding the same elements as `self`.
+    ///
+    /// The returned iterator implements [`FusedIterator`], since a chunk can't
+    /// continue past a `None` returned by `self`.
+    ///
+    /// [`slice::group_by()`]: slice::group_by
+    /// [`slice::iter()`]: slice::iter
+    /// [`str::chars()`]: str::chars
+    /// [`FusedIterator`]: crate::iter::FusedIterator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_chunk_by)]
+    ///
+    /// let mut iter = [1, 1, 2, 3, 3, 3].iter().chunk_by(|a, b| a == b);
+    ///
+    /// assert_eq!(iter.next().unwrap().collect::<Vec<_>>(), [&1, &1]);
+    /// assert_eq!(iter.next().unwrap().collect::<Vec<_>>(), [&2]);
+    /// assert_eq!(iter.next().unwrap().collect::<Vec<_>>(), [&3, &3, &3]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    ///
+    /// Splitting a string into runs of characters of the same kind:
+    ///
+    /// ```
+    /// #![feature(iter_chunk_by)]
+    ///
+    /// let runs: Vec<String> = "abc123 de4"
+    ///     .chars()
+    ///     .chunk_by(|a, b| a.is_alphabetic() == b.is_alphabetic())
+    ///     .map(Iterator::collect)
+    ///     .collect();
+    ///
+    /// assert_eq!(runs, ["abc", "123 ", "de", "4"]);
+    /// ```
+    ///
+    /// Finding the length of the longest increasing run:
+    ///
+    /// ```
+    /// #![feature(iter_chunk_by)]
+    ///
+    /// let longest = [1, 2, 3, 1, 2, 5, 8, 0].iter()
+    ///     .chunk_by(|a, b| a < b)
+    ///     .map(|chunk| chunk.len())
+    ///     .max();
+    ///
+    /// assert_eq!(longest, Some(4));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+    #[rustc_do_not_const_check]
+    fn chunk_by<P>(self, pred: P) -> ChunkBy<Self, P>
+    where
+        Self: Sized + Clone,
+        P: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        ChunkBy::new(self, pred)
+    }
+
     /// Creates an iterator which ends after the first [`None`].
     ///
     /// After an iterator returns [`None`], future calls may or may not yield
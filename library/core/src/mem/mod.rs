@@ -23,7 +23,7 @@
 
 mod transmutability;
 #[unstable(feature = "transmutability", issue = "99571")]
-pub use transmutability::{Assume, BikeshedIntrinsicFrom};
+pub use transmutability::{assert_transmutable, Assume, BikeshedIntrinsicFrom};
 
 #[stable(feature = "rust1", since = "1.0.0")]
 #[doc(inline)]
@@ -1298,11 +1298,19 @@ impl<T> SizedTypeProperties for T {}
 ///
 /// Structs, enums, unions and tuples are supported.
 ///
-/// Nested field accesses may be used, but not array indexes.
+/// Nested field accesses may be used, but not array indexes. Each step of the chain
+/// may go through a generic type, as long as its fields are known at the call site.
 ///
 /// Enum variants may be traversed as if they were fields. Variants themselves do
 /// not have an offset.
 ///
+/// The offset of a field in an enum variant only means anything while the enum
+/// holds that variant: the same bytes are used by the fields of the other variants,
+/// and possibly by a niche that encodes the discriminant. Code going through a
+/// pointer to such a field must check the discriminant first. With a [primitive
+/// representation], the tag is an integer of that type at offset 0, so it can be
+/// read from the same pointer; see the examples below.
+///
 /// Visibility is respected - all types and fields must be visible to the call site:
 ///
 /// ```
@@ -1355,6 +1363,7 @@ impl<T> SizedTypeProperties for T {}
 /// ```
 ///
 /// [explicit `repr` attribute]: https://doc.rust-lang.org/reference/type-layout.html#representations
+/// [primitive representation]: https://doc.rust-lang.org/reference/type-layout.html#primitive-representations
 ///
 /// # Examples
 ///
@@ -1395,6 +1404,74 @@ impl<T> SizedTypeProperties for T {}
 ///
 /// assert_eq!(mem::offset_of!(Option<&u8>, Some.0), 0);
 /// ```
+///
+/// Reading a field of an enum variant through a pointer, once the tag says that the
+/// enum holds that variant:
+///
+/// ```
+/// #![feature(offset_of)]
+/// #![feature(offset_of_enum)]
+///
+/// use std::mem;
+///
+/// #[repr(u8)]
+/// enum Message {
+///     Ping = 0,
+///     Data { len: u16, payload: [u8; 4] } = 1,
+/// }
+///
+/// /// Returns a pointer to the payload if `msg` is a `Message::Data`.
+/// ///
+/// /// # Safety
+/// ///
+/// /// `msg` must point to a valid `Message`.
+/// unsafe fn payload(msg: *const Message) -> Option<*const [u8; 4]> {
+///     // `repr(u8)` puts a `u8` tag at the start of every variant.
+///     let tag = unsafe { msg.cast::<u8>().read() };
+///     (tag == 1).then(|| unsafe { msg.byte_add(mem::offset_of!(Message, Data.payload)).cast() })
+/// }
+///
+/// let data = Message::Data { len: 4, payload: *b"rust" };
+/// assert_eq!(unsafe { payload(&data).map(|p| *p) }, Some(*b"rust"));
+/// assert_eq!(unsafe { payload(&Message::Ping) }, None);
+/// ```
+///
+/// Going from a field back to the struct containing it, through a generic type:
+///
+/// ```
+/// #![feature(offset_of)]
+///
+/// use std::{mem, ptr};
+///
+/// #[repr(C)]
+/// struct Links {
+///     prev: *mut Links,
+///     next: *mut Links,
+/// }
+///
+/// #[repr(C)]
+/// struct Node<T> {
+///     value: T,
+///     links: Links,
+/// }
+///
+/// /// # Safety
+/// ///
+/// /// `links` must point to the `links` field of a `Node<T>`.
+/// unsafe fn node_of<T>(links: *mut Links) -> *mut Node<T> {
+///     unsafe { links.byte_sub(mem::offset_of!(Node<T>, links)).cast() }
+/// }
+///
+/// let mut node = Node { value: 7u64, links: Links { prev: ptr::null_mut(), next: ptr::null_mut() } };
+/// let node_ptr = ptr::addr_of_mut!(node);
+/// let links = unsafe { node_ptr.byte_add(mem::offset_of!(Node<u64>, links)).cast::<Links>() };
+///
+/// assert_eq!(unsafe { node_of::<u64>(links) }, node_ptr);
+/// assert_eq!(
+///     mem::offset_of!(Node<u64>, links.next),
+///     mem::offset_of!(Node<u64>, links) + mem::offset_of!(Links, next),
+/// );
+/// ```
 #[unstable(feature = "offset_of", issue = "106655")]
 #[allow_internal_unstable(builtin_syntax, hint_must_use)]
 pub macro offset_of($Container:ty, $($fields:tt).+ $(,)?) {
@@ -15,6 +15,47 @@ pub unsafe trait BikeshedIntrinsicFrom<Src, Context, const ASSUME: Assume = { As
 {
 }
 
+/// Checks at compile time that every value of `Src` can be reinterpreted as a valid value of `Dst`.
+///
+/// This is the [`BikeshedIntrinsicFrom`] analysis with [`Assume::SAFETY`]: sizes and bit validity
+/// are checked, but library invariants guarded by field privacy are not, so the caller remains
+/// responsible for those before actually transmuting. The check is done entirely at compile time,
+/// through the bound on `Dst`, and the function generates no code at runtime. Since it is a
+/// `const fn`, it can be used in a `const` block to turn a layout assumption into a compile error,
+/// for example in code that reinterprets serialized bytes.
+///
+/// The check happens when the call is type-checked. In generic code, where the types aren't known
+/// yet, add the equivalent `Dst: BikeshedIntrinsicFrom<Src, (), { Assume::SAFETY }>` bound to the
+/// enclosing item instead.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(transmutability)]
+/// #![feature(inline_const)]
+/// use std::mem::assert_transmutable;
+///
+/// const { assert_transmutable::<u32, [u8; 4]>() };
+/// const { assert_transmutable::<bool, u8>() };
+/// ```
+///
+/// Not every `u8` is a valid `bool`:
+///
+/// ```compile_fail,E0277
+/// #![feature(transmutability)]
+/// #![feature(inline_const)]
+/// use std::mem::assert_transmutable;
+///
+/// const { assert_transmutable::<u8, bool>() };
+/// ```
+#[unstable(feature = "transmutability", issue = "99571")]
+#[inline(always)]
+pub const fn assert_transmutable<Src, Dst>()
+where
+    Dst: BikeshedIntrinsicFrom<Src, (), { Assume::SAFETY }>,
+{
+}
+
 /// What transmutation safety conditions shall the compiler assume that *you* are checking?
 #[unstable(feature = "transmutability", issue = "99571")]
 #[lang = "transmute_opts"]
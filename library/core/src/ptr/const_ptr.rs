@@ -285,6 +285,84 @@ pub fn map_addr(self, f: impl FnOnce(usize) -> usize) -> Self {
         self.with_addr(f(self.addr()))
     }
 
+    /// Creates a new pointer by mapping `self`'s address to a new one, or returns `None` if `f`
+    /// does.
+    ///
+    /// This is a convenience for [`with_addr`][pointer::with_addr] with checked address
+    /// arithmetic, such as `ptr.map_addr_checked(|addr| addr.checked_add(len))`, see that method
+    /// for details.
+    ///
+    /// This API and its claimed semantics are part of the Strict Provenance experiment,
+    /// see the [module documentation][crate::ptr] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_tagging)]
+    /// let ptr = usize::MAX as *const u8;
+    /// assert!(ptr.map_addr_checked(|addr| addr.checked_add(1)).is_none());
+    /// assert!(ptr.map_addr_checked(|addr| addr.checked_sub(1)).is_some());
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ptr_tagging", issue = "none")]
+    pub fn map_addr_checked(self, f: impl FnOnce(usize) -> Option<usize>) -> Option<Self> {
+        f(self.addr()).map(|addr| self.with_addr(addr))
+    }
+
+    /// Stores `tag` in the low bits of the address, replacing any tag already there.
+    ///
+    /// Those bits are always zero in a pointer aligned for `T`, so they can hold a tag smaller
+    /// than `align_of::<T>()`. Both the pointer and the tag can be recovered with
+    /// [`untag`][pointer::untag]. Only the address changes, so unlike a round trip through an
+    /// integer, the pointer keeps its provenance and can be dereferenced again once untagged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tag` is not less than `align_of::<T>()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_tagging)]
+    /// let value = 17u32;
+    /// let ptr: *const u32 = &value;
+    ///
+    /// let tagged = ptr.tag_lower_bits(3);
+    /// let (untagged, tag) = tagged.untag();
+    /// assert_eq!(tag, 3);
+    /// assert_eq!(untagged, ptr);
+    /// assert_eq!(unsafe { *untagged }, 17);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ptr_tagging", issue = "none")]
+    pub fn tag_lower_bits(self, tag: usize) -> Self
+    where
+        T: Sized,
+    {
+        let mask = mem::align_of::<T>() - 1;
+        assert!(tag <= mask, "tag does not fit in the alignment bits of the pointer");
+        self.map_addr(|addr| (addr & !mask) | tag)
+    }
+
+    /// Splits a pointer tagged with [`tag_lower_bits`][pointer::tag_lower_bits] into the
+    /// untagged pointer and the tag.
+    ///
+    /// This clears the low bits of the address that are always zero in a pointer aligned for `T`,
+    /// keeping the pointer's provenance, and returns what they held. An untagged pointer has a
+    /// tag of zero.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "ptr_tagging", issue = "none")]
+    pub fn untag(self) -> (Self, usize)
+    where
+        T: Sized,
+    {
+        let mask = mem::align_of::<T>() - 1;
+        (self.map_addr(|addr| addr & !mask), self.addr() & mask)
+    }
+
     /// Decompose a (possibly wide) pointer into its address and metadata components.
     ///
     /// The pointer can be later reconstructed with [`from_raw_parts`].
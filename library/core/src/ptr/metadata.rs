@@ -135,6 +135,41 @@ pub const fn from_raw_parts_mut<T: ?Sized>(
     unsafe { PtrRepr { components: PtrComponents { data_address, metadata } }.mut_ptr }
 }
 
+/// Returns the layout of the trait object that `ptr` points to, as stored in its vtable.
+///
+/// Unlike [`Layout::for_value`], this doesn't need a reference to the value, and unlike
+/// [`Layout::for_value_raw`], it is safe: only the metadata of `ptr` is used, so `ptr` may be
+/// dangling or unaligned.
+///
+/// This is the same as [`DynMetadata::layout`] on the metadata of `ptr`.
+///
+/// [`Layout::for_value`]: crate::alloc::Layout::for_value
+/// [`Layout::for_value_raw`]: crate::alloc::Layout::for_value_raw
+///
+/// # Example
+///
+/// ```
+/// #![feature(ptr_metadata)]
+///
+/// use std::alloc::Layout;
+/// use std::fmt::Debug;
+/// use std::ptr;
+///
+/// let value: &dyn Debug = &5_u64;
+/// // A dangling pointer with the same vtable.
+/// let dangling = ptr::from_raw_parts::<dyn Debug>(ptr::null(), ptr::metadata(value));
+///
+/// assert_eq!(ptr::layout_of_dyn(dangling), Layout::new::<u64>());
+/// ```
+#[unstable(feature = "ptr_metadata", issue = "81513")]
+#[inline]
+pub fn layout_of_dyn<Dyn: ?Sized>(ptr: *const Dyn) -> crate::alloc::Layout
+where
+    Dyn: Pointee<Metadata = DynMetadata<Dyn>>,
+{
+    metadata(ptr).layout()
+}
+
 #[repr(C)]
 union PtrRepr<T: ?Sized> {
     const_ptr: *const T,
@@ -405,7 +405,9 @@
 
 mod metadata;
 #[unstable(feature = "ptr_metadata", issue = "81513")]
-pub use metadata::{from_raw_parts, from_raw_parts_mut, metadata, DynMetadata, Pointee, Thin};
+pub use metadata::{
+    from_raw_parts, from_raw_parts_mut, layout_of_dyn, metadata, DynMetadata, Pointee, Thin,
+};
 
 mod non_null;
 #[stable(feature = "nonnull", since = "1.25.0")]
@@ -0,0 +1,285 @@
+//! Defines [`Atomic`].
+
+use crate::fmt;
+use crate::marker::PhantomData;
+use crate::mem;
+use crate::ptr;
+use crate::sync::atomic::{AtomicPtr, Ordering};
+
+/// A `T` that can be shared between threads, loaded and stored atomically.
+///
+/// This is a generalization of the integer atomics in [`core::sync::atomic`] to any [`Copy`] type
+/// that fits in a `usize`, such as function pointers, `Option<NonZeroU32>` or small fieldless
+/// enums. Values are stored in an [`AtomicPtr`] by copying their bytes, so there is no need to
+/// transmute them to and from an integer by hand, and references and function pointers keep their
+/// provenance on the way through.
+///
+/// Because of that, `T` must not contain uninitialized bytes, which is why [`Atomic::new`] is
+/// unsafe: see its documentation. Using a `T` larger than a `usize` is a compile-time error.
+///
+/// All methods take an [`Ordering`] argument with the same meaning as for the integer atomics.
+///
+/// # Examples
+///
+/// A hook that can be replaced at runtime:
+///
+/// ```
+/// #![feature(atomic_cell)]
+/// use std::sync::Atomic;
+/// use std::sync::atomic::Ordering;
+///
+/// fn default_hook(msg: &str) -> usize {
+///     msg.len()
+/// }
+///
+/// // SAFETY: function pointers, and `Option`s of them, have no uninitialized bytes.
+/// static HOOK: Atomic<Option<fn(&str) -> usize>> = unsafe { Atomic::new(None) };
+///
+/// fn run_hook(msg: &str) -> usize {
+///     HOOK.load(Ordering::Acquire).unwrap_or(default_hook)(msg)
+/// }
+///
+/// assert_eq!(run_hook("hello"), 5);
+/// HOOK.store(Some(|msg| msg.len() * 2), Ordering::Release);
+/// assert_eq!(run_hook("hello"), 10);
+/// ```
+#[unstable(feature = "atomic_cell", issue = "none")]
+pub struct Atomic<T: Copy> {
+    // A pointer rather than a `usize`, so that the provenance of pointer-like values is kept.
+    bits: AtomicPtr<()>,
+    _marker: PhantomData<T>,
+}
+
+// Values are only ever copied in and out of an `Atomic`, so sharing one is like sending `T`.
+#[unstable(feature = "atomic_cell", issue = "none")]
+unsafe impl<T: Copy + Send> Sync for Atomic<T> {}
+
+/// A `T` overlapping the pointer it is stored as.
+#[repr(C)]
+union Bits<T: Copy> {
+    value: T,
+    bits: *mut (),
+}
+
+impl<T: Copy> Atomic<T> {
+    const SIZE_OK: () = assert!(
+        mem::size_of::<T>() <= mem::size_of::<usize>(),
+        "`Atomic<T>` requires `T` to be no larger than `usize`"
+    );
+
+    /// Creates a new `Atomic` holding `value`.
+    ///
+    /// # Safety
+    ///
+    /// No value of `T` may contain uninitialized bytes. These include padding, the bytes of
+    /// `MaybeUninit` fields, and the bytes of an enum variant that only other variants use, such
+    /// as the payload of `Option::<u8>::None`. Types like integers, `bool`, `char`, function
+    /// pointers, references and fieldless enums, as well as `Option`s of function pointers,
+    /// references and `NonZero` integers, have none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_cell)]
+    /// use std::sync::Atomic;
+    ///
+    /// // SAFETY: `char` has no uninitialized bytes.
+    /// let atomic = unsafe { Atomic::new('a') };
+    /// ```
+    #[unstable(feature = "atomic_cell", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const unsafe fn new(value: T) -> Self {
+        let () = Self::SIZE_OK;
+        Self { bits: AtomicPtr::new(Self::to_bits(value)), _marker: PhantomData }
+    }
+
+    /// Consumes the atomic and returns the contained value.
+    ///
+    /// This is safe because passing `self` by value guarantees that no other threads are
+    /// concurrently accessing the atomic data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_cell)]
+    /// use std::sync::Atomic;
+    ///
+    /// // SAFETY: `char` has no uninitialized bytes.
+    /// let atomic = unsafe { Atomic::new('a') };
+    /// assert_eq!(atomic.into_inner(), 'a');
+    /// ```
+    #[unstable(feature = "atomic_cell", issue = "none")]
+    #[inline]
+    pub const fn into_inner(self) -> T {
+        Self::from_bits(self.bits.into_inner())
+    }
+
+    /// Loads the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`](Ordering::Release) or [`AcqRel`](Ordering::AcqRel).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_cell)]
+    /// use std::sync::Atomic;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// // SAFETY: `char` has no uninitialized bytes.
+    /// let atomic = unsafe { Atomic::new('a') };
+    /// assert_eq!(atomic.load(Ordering::Relaxed), 'a');
+    /// ```
+    #[unstable(feature = "atomic_cell", issue = "none")]
+    #[inline]
+    pub fn load(&self, order: Ordering) -> T {
+        Self::from_bits(self.bits.load(order))
+    }
+
+    /// Stores `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Acquire`](Ordering::Acquire) or [`AcqRel`](Ordering::AcqRel).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_cell)]
+    /// use std::sync::Atomic;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// // SAFETY: `char` has no uninitialized bytes.
+    /// let atomic = unsafe { Atomic::new('a') };
+    /// atomic.store('b', Ordering::Relaxed);
+    /// assert_eq!(atomic.load(Ordering::Relaxed), 'b');
+    /// ```
+    #[unstable(feature = "atomic_cell", issue = "none")]
+    #[inline]
+    pub fn store(&self, value: T, order: Ordering) {
+        self.bits.store(Self::to_bits(value), order);
+    }
+
+    /// Stores `value`, returning the previous value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_cell)]
+    /// use std::sync::Atomic;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// // SAFETY: `char` has no uninitialized bytes.
+    /// let atomic = unsafe { Atomic::new('a') };
+    /// assert_eq!(atomic.swap('b', Ordering::Relaxed), 'a');
+    /// assert_eq!(atomic.load(Ordering::Relaxed), 'b');
+    /// ```
+    #[unstable(feature = "atomic_cell", issue = "none")]
+    #[cfg(target_has_atomic = "ptr")]
+    #[inline]
+    pub fn swap(&self, value: T, order: Ordering) -> T {
+        Self::from_bits(self.bits.swap(Self::to_bits(value), order))
+    }
+
+    /// Stores `new` if the current value is `current`.
+    ///
+    /// The return value is a result indicating whether the new value was written and containing
+    /// the previous value. On success this value is guaranteed to be equal to `current`.
+    ///
+    /// Values are compared by their bytes, not with [`PartialEq`]. This makes a difference for
+    /// types like `f32`, where `0.0 == -0.0` but their bytes differ, or `NaN`s whose bytes are
+    /// the same despite `NaN != NaN`.
+    ///
+    /// See [`AtomicPtr::compare_exchange`] for the meaning of `success` and `failure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_cell)]
+    /// use std::sync::Atomic;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// // SAFETY: `char` has no uninitialized bytes.
+    /// let atomic = unsafe { Atomic::new('a') };
+    /// assert_eq!(atomic.compare_exchange('a', 'b', Ordering::AcqRel, Ordering::Acquire), Ok('a'));
+    /// assert_eq!(atomic.compare_exchange('a', 'c', Ordering::AcqRel, Ordering::Acquire), Err('b'));
+    /// ```
+    #[unstable(feature = "atomic_cell", issue = "none")]
+    #[cfg(target_has_atomic = "ptr")]
+    #[inline]
+    pub fn compare_exchange(
+        &self,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T> {
+        self.bits
+            .compare_exchange(Self::to_bits(current), Self::to_bits(new), success, failure)
+            .map(Self::from_bits)
+            .map_err(Self::from_bits)
+    }
+
+    /// Stores `new` if the current value is `current`.
+    ///
+    /// Unlike [`Atomic::compare_exchange`], this function is allowed to spuriously fail even
+    /// when the comparison succeeds, which can result in more efficient code on some platforms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_cell)]
+    /// use std::sync::Atomic;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// // SAFETY: `u8` has no uninitialized bytes.
+    /// let atomic = unsafe { Atomic::new(4u8) };
+    /// let mut old = atomic.load(Ordering::Relaxed);
+    /// loop {
+    ///     match atomic.compare_exchange_weak(old, old * 2, Ordering::SeqCst, Ordering::Relaxed) {
+    ///         Ok(_) => break,
+    ///         Err(x) => old = x,
+    ///     }
+    /// }
+    /// assert_eq!(atomic.load(Ordering::Relaxed), 8);
+    /// ```
+    #[unstable(feature = "atomic_cell", issue = "none")]
+    #[cfg(target_has_atomic = "ptr")]
+    #[inline]
+    pub fn compare_exchange_weak(
+        &self,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T> {
+        self.bits
+            .compare_exchange_weak(Self::to_bits(current), Self::to_bits(new), success, failure)
+            .map(Self::from_bits)
+            .map_err(Self::from_bits)
+    }
+
+    #[inline]
+    const fn to_bits(value: T) -> *mut () {
+        let mut bits = Bits { bits: ptr::null_mut() };
+        bits.value = value;
+        // SAFETY: the bytes `value` doesn't cover are still zero, and the contract of `new` says
+        // the ones it does cover are initialized.
+        unsafe { bits.bits }
+    }
+
+    #[inline]
+    const fn from_bits(bits: *mut ()) -> T {
+        // SAFETY: `bits` came from `to_bits`, so it starts with the bytes of a valid `T`.
+        unsafe { Bits { bits }.value }
+    }
+}
+
+#[unstable(feature = "atomic_cell", issue = "none")]
+impl<T: Copy + fmt::Debug> fmt::Debug for Atomic<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
@@ -3,6 +3,11 @@
 #![stable(feature = "rust1", since = "1.0.0")]
 
 pub mod atomic;
+#[cfg(target_has_atomic_load_store = "ptr")]
+mod atomic_cell;
 mod exclusive;
+#[cfg(target_has_atomic_load_store = "ptr")]
+#[unstable(feature = "atomic_cell", issue = "none")]
+pub use atomic_cell::Atomic;
 #[unstable(feature = "exclusive_wrapper", issue = "98407")]
 pub use exclusive::Exclusive;
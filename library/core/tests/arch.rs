@@ -0,0 +1,88 @@
+use core::arch::{
+    clear_cpu_feature_cache, cpu_features, detect_cpu_feature, set_cpu_feature_hook,
+    take_cpu_feature_hook,
+};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+// The hook and its cache are global, so they are only used by this test.
+#[test]
+fn cpu_feature_hook() {
+    static CALLS: AtomicU32 = AtomicU32::new(0);
+
+    fn even(feature: u32) -> bool {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        feature % 2 == 0
+    }
+    fn none(_: u32) -> bool {
+        false
+    }
+
+    assert!(!detect_cpu_feature(0));
+    assert!(set_cpu_feature_hook(even).is_none());
+    assert!(detect_cpu_feature(0));
+    assert!(!detect_cpu_feature(1));
+    assert!(detect_cpu_feature(0));
+    assert!(!detect_cpu_feature(1));
+    assert_eq!(CALLS.load(Ordering::Relaxed), 2);
+
+    // Features that do not fit in the cache still work.
+    assert!(detect_cpu_feature(1000));
+    assert!(detect_cpu_feature(1000));
+    assert_eq!(CALLS.load(Ordering::Relaxed), 4);
+
+    clear_cpu_feature_cache();
+    assert!(detect_cpu_feature(0));
+    assert_eq!(CALLS.load(Ordering::Relaxed), 5);
+
+    let previous = set_cpu_feature_hook(none).unwrap();
+    assert!(previous(2));
+    assert!(!detect_cpu_feature(0));
+
+    let taken = take_cpu_feature_hook().unwrap();
+    assert!(!taken(2));
+    assert!(take_cpu_feature_hook().is_none());
+    assert!(!detect_cpu_feature(0));
+    assert_eq!(cpu_features().to_string(), "");
+
+    set_cpu_feature_hook(even);
+    let features = cpu_features();
+    assert!(features.contains(0));
+    assert!(!features.contains(1));
+    assert!(features.contains(126));
+    assert!(!features.contains(1000));
+    assert!(features.to_string().starts_with("0, 2, 4, "));
+    assert_eq!(features, cpu_features());
+    take_cpu_feature_hook();
+}
+
+#[test]
+#[cfg(target_arch = "x86_64")]
+fn statically_enabled_cpu_feature() {
+    // SSE2 is in the x86_64 baseline, so it is detected whatever the hook says.
+    assert!(core::arch::is_cpu_feature_detected!("sse2", u32::MAX));
+}
+
+#[test]
+fn arch_select() {
+    static SELECTIONS: AtomicU32 = AtomicU32::new(0);
+
+    fn selecting(selected: bool) -> bool {
+        SELECTIONS.fetch_add(1, Ordering::Relaxed);
+        selected
+    }
+    fn double(x: u32) -> u32 {
+        x * 2
+    }
+
+    core::arch::arch_select! {
+        fn scale(x: u32) -> u32 {
+            selecting(false) => double,
+            selecting(true) => |x| x * 3,
+            _ => |x| x,
+        }
+    }
+
+    assert_eq!(scale(2), 6);
+    assert_eq!(scale(3), 9);
+    assert_eq!(SELECTIONS.load(Ordering::Relaxed), 2);
+}
@@ -306,6 +306,76 @@ fn atomic_compare_exchange() {
     ATOMIC.compare_exchange_weak(0, 1, SeqCst, SeqCst).ok();
 }
 
+#[test]
+fn atomic_cell() {
+    use core::num::NonZeroU32;
+    use core::sync::Atomic;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum State {
+        Idle,
+        Running,
+        Done,
+    }
+
+    // SAFETY: fieldless enums have no uninitialized bytes.
+    let state = unsafe { Atomic::new(State::Idle) };
+    assert_eq!(state.load(SeqCst), State::Idle);
+    assert_eq!(
+        state.compare_exchange(State::Idle, State::Running, SeqCst, SeqCst),
+        Ok(State::Idle)
+    );
+    assert_eq!(
+        state.compare_exchange(State::Idle, State::Done, SeqCst, SeqCst),
+        Err(State::Running)
+    );
+    assert_eq!(state.swap(State::Done, SeqCst), State::Running);
+    assert_eq!(format!("{state:?}"), "Done");
+    assert_eq!(state.into_inner(), State::Done);
+
+    // SAFETY: `Option<NonZeroU32>` has no uninitialized bytes.
+    let id = unsafe { Atomic::new(NonZeroU32::new(7)) };
+    id.store(None, SeqCst);
+    assert_eq!(id.load(SeqCst), None);
+    id.store(NonZeroU32::new(u32::MAX), SeqCst);
+    assert_eq!(id.load(SeqCst), NonZeroU32::new(u32::MAX));
+}
+
+#[test]
+fn atomic_cell_static_hook() {
+    use core::sync::Atomic;
+
+    fn one() -> u32 {
+        1
+    }
+    fn two() -> u32 {
+        2
+    }
+
+    // SAFETY: `Option`s of function pointers have no uninitialized bytes.
+    static HOOK: Atomic<Option<fn() -> u32>> = unsafe { Atomic::new(None) };
+
+    assert!(HOOK.load(SeqCst).is_none());
+    HOOK.store(Some(one), SeqCst);
+    assert_eq!(HOOK.load(SeqCst).unwrap()(), 1);
+    assert_eq!(HOOK.swap(Some(two), SeqCst).unwrap()(), 1);
+    assert_eq!(HOOK.load(SeqCst).unwrap()(), 2);
+}
+
+#[test]
+fn atomic_cell_reference() {
+    use core::sync::Atomic;
+
+    static ONE: u32 = 1;
+    let two = 2;
+
+    // SAFETY: references have no uninitialized bytes.
+    let atomic = unsafe { Atomic::new(&ONE) };
+    assert_eq!(*atomic.load(SeqCst), 1);
+    assert_eq!(*atomic.swap(&two, SeqCst), 1);
+    assert_eq!(*atomic.into_inner(), 2);
+}
+
 /* FIXME(#110395)
 #[test]
 fn atomic_const_from() {
@@ -322,3 +322,20 @@ fn test_write_short_works() {
     h2.write(&[0xFFu8, 0x01u8]);
     assert_eq!(h1.finish(), h2.finish());
 }
+
+#[test]
+fn test_write_str_matches_write() {
+    let s = "abcdefghijklmnop";
+    for prefix in 0..8 {
+        for len in 0..=s.len() {
+            let mut h1 = SipHasher13::new_with_keys(1, 2);
+            h1.write(&s.as_bytes()[..prefix]);
+            h1.write_str(&s[..len]);
+            let mut h2 = SipHasher13::new_with_keys(1, 2);
+            h2.write(&s.as_bytes()[..prefix]);
+            h2.write(&s.as_bytes()[..len]);
+            h2.write_u8(0xFF);
+            assert_eq!(h1.finish(), h2.finish(), "prefix {prefix}, len {len}");
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use core::iter::*;
+
+fn collect_chunks<I, P>(iter: ChunkBy<I, P>) -> Vec<Vec<I::Item>>
+where
+    I: Iterator + Clone,
+    P: FnMut(&I::Item, &I::Item) -> bool,
+{
+    iter.map(Iterator::collect).collect()
+}
+
+#[test]
+fn test_iterator_chunk_by() {
+    let xs = [1, 1, 2, 3, 3, 3, 1];
+    assert_eq!(
+        collect_chunks(xs.iter().copied().chunk_by(|a, b| a == b)),
+        [&[1, 1][..], &[2], &[3, 3, 3], &[1]]
+    );
+    assert_eq!(
+        collect_chunks(xs.iter().copied().chunk_by(|a, b| a <= b)),
+        [&[1, 1, 2, 3, 3, 3][..], &[1]]
+    );
+    assert_eq!(collect_chunks(xs.iter().copied().chunk_by(|_, _| false)).len(), xs.len());
+    assert_eq!(collect_chunks(xs.iter().copied().chunk_by(|_, _| true)), [xs]);
+}
+
+#[test]
+fn test_iterator_chunk_by_empty() {
+    let mut iter = [0u8; 0].iter().chunk_by(|a, b| a == b);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_iterator_chunk_by_chunk_len() {
+    let mut iter = "aaabcc".chars().chunk_by(|a, b| a == b);
+
+    let mut chunk = iter.next().unwrap();
+    assert_eq!(chunk.len(), 3);
+    assert_eq!(chunk.next(), Some('a'));
+    assert_eq!(chunk.size_hint(), (2, Some(2)));
+    assert_eq!(chunk.by_ref().count(), 2);
+    assert_eq!(chunk.next(), None);
+
+    assert_eq!(iter.next().unwrap().collect::<String>(), "b");
+    assert_eq!(iter.next().unwrap().collect::<String>(), "cc");
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_iterator_chunk_by_size_hint() {
+    let mut iter = [1, 2, 2].iter().chunk_by(|a, b| a == b);
+    assert_eq!(iter.size_hint(), (1, Some(3)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (1, Some(2)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_iterator_chunk_by_pred_calls() {
+    // The predicate is called once per pair of neighbors, and chunks that are
+    // dropped without being consumed don't affect the ones after them.
+    let mut calls = 0;
+    let mut iter = (0..10).chunk_by(|a, b| {
+        calls += 1;
+        a / 3 == b / 3
+    });
+    let _ = iter.next();
+    assert_eq!(iter.next().unwrap().collect::<Vec<_>>(), [3, 4, 5]);
+    assert_eq!(iter.by_ref().count(), 2);
+    drop(iter);
+    assert_eq!(calls, 9);
+}
@@ -1,6 +1,7 @@
 mod array_chunks;
 mod by_ref_sized;
 mod chain;
+mod chunk_by;
 mod cloned;
 mod copied;
 mod cycle;
@@ -4,8 +4,10 @@
 #![feature(array_windows)]
 #![feature(ascii_char)]
 #![feature(ascii_char_variants)]
+#![feature(atomic_cell)]
 #![feature(bigint_helper_methods)]
 #![feature(cell_update)]
+#![feature(clone_to_uninit)]
 #![feature(const_align_offset)]
 #![feature(const_assume)]
 #![feature(const_align_of_val_raw)]
@@ -91,6 +93,7 @@
 #![feature(pointer_is_aligned)]
 #![feature(portable_simd)]
 #![feature(ptr_metadata)]
+#![feature(ptr_tagging)]
 #![feature(lazy_cell)]
 #![feature(unsized_tuple_coercion)]
 #![feature(const_option)]
@@ -116,13 +119,19 @@
 #![feature(is_ascii_octdigit)]
 #![feature(get_many_mut)]
 #![feature(offset_of)]
+#![feature(offset_of_enum)]
 #![feature(iter_map_windows)]
+#![feature(iter_chunk_by)]
+#![feature(arch_select)]
+#![feature(cpu_feature_hook)]
+#![feature(cpu_features_snapshot)]
 #![allow(internal_features)]
 #![deny(unsafe_op_in_unsafe_fn)]
 #![deny(fuzzy_provenance_casts)]
 
 mod alloc;
 mod any;
+mod arch;
 mod array;
 mod ascii;
 mod asserting;
@@ -566,6 +566,67 @@ struct Foo {
     assert_eq!(ptr::addr_of!(base).addr() + offset_of!(Foo, z.1), ptr::addr_of!(base.z.1).addr());
 }
 
+#[test]
+fn offset_of_enum() {
+    #[repr(u8)]
+    enum Foo {
+        A(u8, u16),
+        B { x: u8, y: Bar } = 5,
+        C,
+    }
+
+    #[repr(C)]
+    struct Bar(u8, u32);
+
+    assert_eq!(offset_of!(Foo, A.0), 1);
+    assert_eq!(offset_of!(Foo, A.1), 2);
+    assert_eq!(offset_of!(Foo, B.x), 1);
+    assert_eq!(offset_of!(Foo, B.y), 4);
+    assert_eq!(offset_of!(Foo, B.y.1), 8);
+
+    // The offsets of fields in different variants overlap.
+    let base = Foo::B { x: 1, y: Bar(2, 3) };
+    assert_eq!(unsafe { ptr::addr_of!(base).cast::<u8>().read() }, 5);
+    if let Foo::B { ref x, ref y } = base {
+        assert_eq!(ptr::addr_of!(base).addr() + offset_of!(Foo, B.x), ptr::addr_of!(*x).addr());
+        assert_eq!(ptr::addr_of!(base).addr() + offset_of!(Foo, B.y.1), ptr::addr_of!(y.1).addr());
+    }
+
+    let _ = (Foo::A(0, 0), Foo::C);
+}
+
+#[test]
+fn offset_of_nested_generic() {
+    #[repr(C)]
+    struct Outer<T> {
+        x: u8,
+        inner: Inner<T>,
+    }
+
+    #[repr(C)]
+    struct Inner<T> {
+        y: u16,
+        value: T,
+    }
+
+    fn offs_of_value<T>() -> usize {
+        offset_of!(Outer<T>, inner.value)
+    }
+
+    assert_eq!(offset_of!(Outer<u32>, inner.y), 4);
+    assert_eq!(offs_of_value::<u32>(), 8);
+    assert_eq!(offs_of_value::<u8>(), 4);
+    assert_eq!(offset_of!(Outer<Inner<u64>>, inner.value.value), 24);
+    assert_eq!(
+        offset_of!(Outer<(u8, Option<u16>)>, inner.value.1),
+        offset_of!(Outer<(u8, Option<u16>)>, inner.value) + offset_of!((u8, Option<u16>), 1)
+    );
+    assert_eq!(
+        offset_of!(Option<Outer<u8>>, Some.0.inner.value),
+        offset_of!(Option<Outer<u8>>, Some.0) + 4
+    );
+}
+
 #[test]
 fn const_maybe_uninit_zeroed() {
     // Sanity check for `MaybeUninit::zeroed` in a realistic const situation (plugin array term)
@@ -845,6 +845,10 @@ fn dyn_metadata() {
     assert_eq!(meta.align_of(), 32);
     assert_eq!(meta.align_of(), std::mem::align_of::<Something>());
     assert_eq!(meta.layout(), std::alloc::Layout::new::<Something>());
+    assert_eq!(ptr::layout_of_dyn(trait_object), std::alloc::Layout::new::<Something>());
+
+    let dangling = ptr::from_raw_parts::<dyn Debug>(ptr::null(), meta);
+    assert_eq!(ptr::layout_of_dyn(dangling), std::alloc::Layout::new::<Something>());
 
     assert!(format!("{meta:?}").starts_with("DynMetadata(0x"));
 }
@@ -1063,6 +1067,45 @@ pub fn set_tag(&mut self, data: usize) {
     }
 }
 
+#[test]
+fn raw_pointer_tag_lower_bits() {
+    let raw_pointer = Box::into_raw(Box::new(10u32));
+    assert_eq!(raw_pointer.untag(), (raw_pointer, 0));
+
+    let tagged = raw_pointer.tag_lower_bits(1);
+    assert_eq!(tagged.untag(), (raw_pointer, 1));
+
+    let tagged = tagged.tag_lower_bits(3);
+    let (untagged, tag) = tagged.untag();
+    assert_eq!(tag, 3);
+    assert_eq!(unsafe { *untagged }, 10);
+
+    let (untagged, tag) = (tagged as *const u32).untag();
+    assert_eq!(tag, 3);
+    assert_eq!(unsafe { *untagged }, 10);
+
+    unsafe { drop(Box::from_raw(untagged as *mut u32)) };
+}
+
+#[test]
+#[should_panic = "tag does not fit in the alignment bits of the pointer"]
+fn raw_pointer_tag_lower_bits_too_large() {
+    let value = 10u32;
+    let _ = (&value as *const u32).tag_lower_bits(4);
+}
+
+#[test]
+fn map_addr_checked() {
+    let value = [1u8, 2];
+    let first = value.as_ptr();
+    let second = first.map_addr_checked(|addr| addr.checked_add(1)).unwrap();
+    assert_eq!(unsafe { *second }, 2);
+    assert_eq!(first.map_addr_checked(|_| None), None);
+
+    let last = ptr::invalid_mut::<u8>(usize::MAX);
+    assert_eq!(last.map_addr_checked(|addr| addr.checked_add(1)), None);
+}
+
 #[test]
 fn swap_copy_untyped() {
     // We call `{swap,copy}{,_nonoverlapping}` at `bool` type on data that is not a valid bool.
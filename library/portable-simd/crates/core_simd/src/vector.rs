@@ -34,6 +34,9 @@
 /// assert_eq!(v * w, prod.into());
 /// ```
 ///
+/// Vectors of up to 8 elements convert the same way to and from tuples, such as `(T, T, T, T)`
+/// for `Simd<T, 4>`.
+///
 ///
 /// `Simd` with integer elements treats operators as wrapping, as if `T` was [`Wrapping<T>`].
 /// Thus, `Simd` does not implement `wrapping_add`, because that is the default behavior.
@@ -260,6 +263,9 @@ impl<const N: usize> Swizzle<N> for Splat {
 
     /// Converts a slice to a SIMD vector containing `slice[..N]`.
     ///
+    /// The slice only needs to be aligned for `T`, not for the vector, so this can load from any
+    /// position in a buffer.
+    ///
     /// # Panics
     ///
     /// Panics if the slice's length is less than the vector's `Simd::N`.
@@ -854,6 +860,54 @@ fn try_from(slice: &mut [T]) -> Result<Self, core::array::TryFromSliceError> {
     }
 }
 
+// vector/tuple conversion
+macro_rules! impl_tuple_conversions {
+    { $($lanes:literal => ($($elem:ident),+);)+ } => {
+        $(
+            impl<T> From<($(impl_tuple_conversions!(@ty $elem),)+)> for Simd<T, $lanes>
+            where
+                T: SimdElement,
+            {
+                #[inline]
+                fn from(($($elem,)+): ($(impl_tuple_conversions!(@ty $elem),)+)) -> Self {
+                    Self::from_array([$($elem),+])
+                }
+            }
+
+            impl<T> From<Simd<T, $lanes>> for ($(impl_tuple_conversions!(@ty $elem),)+)
+            where
+                T: SimdElement,
+            {
+                #[inline]
+                fn from(vector: Simd<T, $lanes>) -> Self {
+                    let [$($elem),+] = vector.to_array();
+                    ($($elem,)+)
+                }
+            }
+        )+
+    };
+    { @ty $elem:ident } => { T };
+}
+
+impl_tuple_conversions! {
+    1 => (a);
+    2 => (a, b);
+    4 => (a, b, c, d);
+    8 => (a, b, c, d, e, f, g, h);
+}
+
+#[cfg(feature = "all_lane_counts")]
+impl_tuple_conversions! {
+    3 => (a, b, c);
+    5 => (a, b, c, d, e);
+    6 => (a, b, c, d, e, f);
+    7 => (a, b, c, d, e, f, g);
+    9 => (a, b, c, d, e, f, g, h, i);
+    10 => (a, b, c, d, e, f, g, h, i, j);
+    11 => (a, b, c, d, e, f, g, h, i, j, k);
+    12 => (a, b, c, d, e, f, g, h, i, j, k, l);
+}
+
 mod sealed {
     pub trait Sealed {}
 }
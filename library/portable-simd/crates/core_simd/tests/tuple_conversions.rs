@@ -0,0 +1,42 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{f32x2, i32x4, u8x8, Simd};
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn from_tuple() {
+    assert_eq!(Simd::from((7u16,)), Simd::from_array([7]));
+    assert_eq!(f32x2::from((1.0, 2.0)), f32x2::from_array([1.0, 2.0]));
+    assert_eq!(i32x4::from((1, 2, 3, 4)), i32x4::from_array([1, 2, 3, 4]));
+    assert_eq!(
+        u8x8::from((0, 1, 2, 3, 4, 5, 6, 7)),
+        u8x8::from_array([0, 1, 2, 3, 4, 5, 6, 7])
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn to_tuple() {
+    let (x, y, z, w) = i32x4::from_array([1, 2, 3, 4]).into();
+    assert_eq!((x, y, z, w), (1, 2, 3, 4));
+
+    let tuple: (u8, u8, u8, u8, u8, u8, u8, u8) = u8x8::splat(9).into();
+    assert_eq!(tuple, (9, 9, 9, 9, 9, 9, 9, 9));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn from_slice_unaligned() {
+    // Every offset into the buffer is a valid starting point, regardless of the vector's alignment.
+    let buf = [0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    for offset in 0..4 {
+        let v = u8x8::from_slice(&buf[offset..]);
+        assert_eq!(v.to_array(), core::array::from_fn(|i| (offset + i) as u8));
+    }
+}
@@ -101,3 +101,19 @@ fn get_remove_insert(b: &mut Bencher) {
         k += 1;
     })
 }
+
+#[bench]
+fn find_existing_short_str(b: &mut Bencher) {
+    let keys: Vec<String> = (1..1001).map(|i| format!("k{i}")).collect();
+    let mut m = HashMap::new();
+
+    for key in &keys {
+        m.insert(key.as_str(), ());
+    }
+
+    b.iter(|| {
+        for key in &keys {
+            m.contains_key(key.as_str());
+        }
+    });
+}
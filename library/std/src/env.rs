@@ -544,6 +544,53 @@ fn description(&self) -> &str {
     }
 }
 
+/// Searches for the executable that [`Command::new`] would run for `program`,
+/// returning its path.
+///
+/// If `program` is a bare file name, the directories in the `PATH`
+/// environment variable are searched in order, following the same
+/// platform-specific rules as spawning a process. Otherwise `program` is only
+/// checked to exist. The entries of `PATH` are never collected, so the only
+/// allocations are for the candidate paths.
+///
+/// Returns `None` if no executable is found, and always on platforms that
+/// can't spawn processes.
+///
+/// # Platform-specific behavior
+///
+/// On Unix, `PATH` defaults to `/bin:/usr/bin` if it isn't set, empty entries
+/// stand for the current directory, and only regular files with an execute
+/// permission bit set are considered.
+///
+/// On Windows, the directory of the current executable and the system
+/// directories are searched before `PATH`, and `.exe` is appended to `program`
+/// if it has no extension.
+///
+/// Note that this [may change in the future][changes].
+///
+/// [`Command::new`]: crate::process::Command::new
+/// [changes]: io#platform-specific-behavior
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(env_find_in_path)]
+/// use std::env;
+/// use std::process::Command;
+///
+/// match env::find_in_path("git") {
+///     Some(git) => {
+///         Command::new(git).arg("status").status()?;
+///     }
+///     None => eprintln!("git is not installed"),
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[unstable(feature = "env_find_in_path", issue = "none")]
+pub fn find_in_path<P: AsRef<OsStr>>(program: P) -> Option<PathBuf> {
+    sys::process::find_in_path(program.as_ref())
+}
+
 /// Returns the path of the current user's home directory if known.
 ///
 /// # Unix
@@ -878,6 +925,95 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// An iterator over the arguments of a process, yielding each argument's raw
+/// bytes as a slice borrowed for `'static`.
+///
+/// This struct is created by [`env::args_os_bytes()`]. See its documentation
+/// for more.
+///
+/// [`env::args_os_bytes()`]: args_os_bytes
+#[cfg(unix)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "args_os_bytes", issue = "none")]
+pub struct ArgsOsBytes {
+    inner: sys::args::ArgsBytes,
+}
+
+/// Returns the arguments that this program was started with, as the raw bytes
+/// the system passed in `argv`.
+///
+/// Unlike [`args_os`], this neither copies nor allocates: the arguments are
+/// borrowed straight from `argv`, which lives for the whole program. This lets
+/// argument parsers work without allocating, and hand out slices of the
+/// arguments that outlive the iterator.
+///
+/// The same caveats as for [`args_os`] apply to the first element. On iOS,
+/// tvOS and watchOS, where the standard library reads arguments through
+/// `NSProcessInfo` rather than `argv`, the arguments are copied once, on the
+/// first call, and borrowed from that copy.
+///
+/// # Aliasing
+///
+/// The slices borrow the memory of `argv` itself. Some programs rewrite that
+/// memory in place, e.g. to change the process title shown by `ps`, like
+/// `setproctitle` does. Doing so while any slice returned by this iterator is
+/// still in use is undefined behavior, since it modifies memory that is
+/// borrowed immutably. Such programs should use [`args_os`] instead, which
+/// returns copies of the arguments.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(args_os_bytes)]
+/// use std::env;
+///
+/// let flags: Vec<&'static [u8]> =
+///     env::args_os_bytes().skip(1).filter(|arg| arg.starts_with(b"--")).collect();
+/// println!("{flags:?}");
+/// ```
+#[cfg(unix)]
+#[unstable(feature = "args_os_bytes", issue = "none")]
+pub fn args_os_bytes() -> ArgsOsBytes {
+    ArgsOsBytes { inner: sys::args::args_bytes() }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "args_os_bytes", issue = "none")]
+impl Iterator for ArgsOsBytes {
+    type Item = &'static [u8];
+    fn next(&mut self) -> Option<&'static [u8]> {
+        self.inner.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "args_os_bytes", issue = "none")]
+impl ExactSizeIterator for ArgsOsBytes {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "args_os_bytes", issue = "none")]
+impl DoubleEndedIterator for ArgsOsBytes {
+    fn next_back(&mut self) -> Option<&'static [u8]> {
+        self.inner.next_back()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "args_os_bytes", issue = "none")]
+impl fmt::Debug for ArgsOsBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { inner } = self;
+        f.debug_struct("ArgsOsBytes").field("inner", inner).finish()
+    }
+}
+
 /// Constants associated with the current target
 #[stable(feature = "env", since = "1.0.0")]
 pub mod consts {
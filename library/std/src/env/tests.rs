@@ -13,6 +13,16 @@ fn test_self_exe_path() {
     assert!(path.is_absolute());
 }
 
+#[test]
+#[cfg_attr(any(target_os = "emscripten", target_env = "sgx"), ignore)]
+fn test_find_in_path() {
+    let exe = current_exe().unwrap();
+    assert_eq!(find_in_path(&exe), Some(exe));
+
+    assert_eq!(find_in_path(""), None);
+    assert_eq!(find_in_path("this-program-does-not-exist-anywhere"), None);
+}
+
 #[test]
 fn test() {
     assert!((!Path::new("test-path").is_absolute()));
@@ -105,6 +115,18 @@ fn args_os_debug() {
     );
 }
 
+#[test]
+#[cfg(unix)]
+fn args_os_bytes_matches_args_os() {
+    use crate::os::unix::ffi::OsStrExt;
+
+    let args: Vec<OsString> = args_os().collect();
+    let bytes: Vec<&'static [u8]> = args_os_bytes().collect();
+    assert_eq!(bytes, args.iter().map(|arg| arg.as_bytes()).collect::<Vec<_>>());
+    assert_eq!(args_os_bytes().len(), args.len());
+    assert_eq!(format!("ArgsOsBytes {{ inner: {args:?} }}"), format!("{:?}", args_os_bytes()));
+}
+
 #[test]
 fn vars_debug() {
     assert_eq!(
@@ -991,6 +991,15 @@ fn source(&self) -> Option<&(dyn error::Error + 'static)> {
             ErrorData::Custom(c) => c.error.source(),
         }
     }
+
+    // Every variant provides its `ErrorKind`, but only `Custom` wraps an error the request can be
+    // forwarded to.
+    fn provide<'a>(&'a self, request: &mut error::Request<'a>) {
+        request.provide_value(self.kind());
+        if let ErrorData::Custom(c) = self.repr.data() {
+            c.error.provide(request);
+        }
+    }
 }
 
 fn _assert_error_is_sync_send() {
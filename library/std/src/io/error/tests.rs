@@ -59,6 +59,36 @@ impl error::Error for TestError {}
     extracted.downcast::<TestError>().unwrap();
 }
 
+#[test]
+fn test_provide() {
+    #[derive(Debug)]
+    struct TestError(u16);
+
+    impl fmt::Display for TestError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("asdf")
+        }
+    }
+
+    impl error::Error for TestError {
+        fn provide<'a>(&'a self, request: &mut error::Request<'a>) {
+            request.provide_ref::<u16>(&self.0).provide_value(ErrorKind::NotFound);
+        }
+    }
+
+    // The custom error is asked for anything `io::Error` doesn't provide itself.
+    let err = Error::new(ErrorKind::Other, TestError(404));
+    assert_eq!(error::request_ref::<u16>(&err), Some(&404));
+    assert_eq!(error::request_value::<ErrorKind>(&err), Some(ErrorKind::Other));
+
+    let dyn_err: &dyn error::Error = &err;
+    assert_eq!(error::request_ref::<u16>(dyn_err), Some(&404));
+
+    let err = Error::from(ErrorKind::TimedOut);
+    assert_eq!(error::request_ref::<u16>(&err), None);
+    assert_eq!(error::request_value::<ErrorKind>(&err), Some(ErrorKind::TimedOut));
+}
+
 #[test]
 fn test_const() {
     const E: Error = const_io_error!(ErrorKind::NotFound, "hello");
@@ -1421,6 +1421,7 @@ pub fn advance(&mut self, n: usize) {
     /// IoSlice::advance_slices(&mut bufs, 10);
     /// assert_eq!(bufs[0].deref(), [2; 14].as_ref());
     /// assert_eq!(bufs[1].deref(), [3; 8].as_ref());
+    /// ```
     #[unstable(feature = "io_slice_advance", issue = "62726")]
     #[inline]
     pub fn advance_slices(bufs: &mut &mut [IoSlice<'a>], n: usize) {
@@ -1699,12 +1700,12 @@ fn write_all(&mut self, mut buf: &[u8]) -> Result<()> {
     /// a slice of [`IoSlice`]s, not an immutable one. That's because we need to
     /// modify the slice to keep track of the bytes already written.
     ///
-    /// Once this function returns, the contents of `bufs` are unspecified, as
-    /// this depends on how many calls to [`write_vectored`] were necessary. It is
-    /// best to understand this function as taking ownership of `bufs` and to
-    /// not use `bufs` afterwards. The underlying buffers, to which the
-    /// [`IoSlice`]s point (but not the [`IoSlice`]s themselves), are unchanged and
-    /// can be reused.
+    /// Once this function returns, the [`IoSlice`]s in `bufs` have been
+    /// advanced past all the bytes that were written, and the ones that were
+    /// written completely are empty. If an error is returned, `bufs` therefore
+    /// holds exactly the data that is still left to write, so the write can be
+    /// resumed without duplicating or losing any data. The underlying buffers,
+    /// to which the [`IoSlice`]s point, are unchanged and can be reused.
     ///
     /// [`write_vectored`]: Write::write_vectored
     ///
@@ -1724,30 +1725,52 @@ fn write_all(&mut self, mut buf: &[u8]) -> Result<()> {
     /// ];
     ///
     /// writer.write_all_vectored(bufs)?;
-    /// // Note: the contents of `bufs` is now undefined, see the Notes section.
+    /// // All of `bufs` was written, so every `IoSlice` in it is now empty.
+    /// assert!(bufs.iter().all(|buf| buf.is_empty()));
     ///
     /// assert_eq!(writer, &[1, 2, 3, 4, 5, 6]);
     /// # Ok(()) }
     /// ```
     #[unstable(feature = "write_all_vectored", issue = "70436")]
-    fn write_all_vectored(&mut self, mut bufs: &mut [IoSlice<'_>]) -> Result<()> {
-        // Guarantee that bufs is empty if it contains no data,
-        // to avoid calling write_vectored if there is no data to be written.
-        IoSlice::advance_slices(&mut bufs, 0);
-        while !bufs.is_empty() {
-            match self.write_vectored(bufs) {
+    fn write_all_vectored(&mut self, bufs: &mut [IoSlice<'_>]) -> Result<()> {
+        // The buffers are advanced in place instead of being sliced off with
+        // `IoSlice::advance_slices`, so that the caller can see how much was
+        // written if this returns early.
+        let mut first = 0;
+        loop {
+            // Skip the buffers that are empty or have been written completely.
+            // This also avoids calling write_vectored if there is no data to be
+            // written.
+            while bufs.get(first).is_some_and(|buf| buf.is_empty()) {
+                first += 1;
+            }
+            let remaining = &mut bufs[first..];
+            if remaining.is_empty() {
+                return Ok(());
+            }
+            match self.write_vectored(remaining) {
                 Ok(0) => {
                     return Err(error::const_io_error!(
                         ErrorKind::WriteZero,
                         "failed to write whole buffer",
                     ));
                 }
-                Ok(n) => IoSlice::advance_slices(&mut bufs, n),
+                Ok(n) => {
+                    let mut left = n;
+                    for buf in remaining.iter_mut() {
+                        let advance = cmp::min(buf.len(), left);
+                        buf.advance(advance);
+                        left -= advance;
+                        if left == 0 {
+                            break;
+                        }
+                    }
+                    assert!(left == 0, "advancing io slices beyond their length");
+                }
                 Err(ref e) if e.is_interrupted() => {}
                 Err(e) => return Err(e),
             }
         }
-        Ok(())
     }
 
     /// Writes a formatted string into this writer, returning any error
@@ -614,6 +614,24 @@ fn test_write_all_vectored() {
     }
 }
 
+#[test]
+fn test_write_all_vectored_partial_progress() {
+    let mut out = [0; 4];
+    let mut writer = &mut out[..];
+    let mut bufs = [IoSlice::new(&[1, 1]), IoSlice::new(&[2, 2, 2]), IoSlice::new(&[3])];
+    let err = writer.write_all_vectored(&mut bufs).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    assert_eq!(out, [1, 1, 2, 2]);
+
+    // The buffers hold exactly the data that wasn't written, so the write can be resumed.
+    let remaining: Vec<&[u8]> = bufs.iter().map(|buf| buf.deref()).collect();
+    assert_eq!(remaining, [&[][..], &[2], &[3]]);
+    let mut rest = Vec::new();
+    rest.write_all_vectored(&mut bufs).unwrap();
+    assert_eq!(rest, [2, 3]);
+    assert!(bufs.iter().all(|buf| buf.is_empty()));
+}
+
 // Issue 94981
 #[test]
 #[should_panic = "number of read bytes exceeds limit"]
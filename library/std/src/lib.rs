@@ -308,9 +308,12 @@
 //
 // Library features (core):
 // tidy-alphabetical-start
+#![feature(alloc_hook_internals)]
 #![feature(char_internals)]
 #![feature(core_intrinsics)]
 #![feature(core_io_borrowed_buf)]
+#![feature(cpu_feature_hook)]
+#![feature(cpu_features_snapshot)]
 #![feature(duration_constants)]
 #![feature(error_generic_member_access)]
 #![feature(error_in_core)]
@@ -628,6 +631,12 @@ pub mod arch {
         is_arm_feature_detected, is_mips64_feature_detected, is_mips_feature_detected,
         is_powerpc64_feature_detected, is_powerpc_feature_detected, is_riscv_feature_detected,
     };
+
+    // These shadow the items of `core::arch` with the same names, to register the hook of `std`.
+    #[unstable(feature = "cpu_features_snapshot", issue = "none")]
+    pub use crate::sys_common::cpu_features::cpu_features;
+    #[unstable(feature = "cpu_feature_hook", issue = "none")]
+    pub use crate::sys_common::cpu_features::{detect_cpu_feature, is_cpu_feature_detected};
 }
 
 // This was stabilized in the crate root so we have to keep it there.
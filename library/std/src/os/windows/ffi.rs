@@ -87,6 +87,31 @@ pub trait OsStringExt: Sealed {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     fn from_wide(wide: &[u16]) -> Self;
+
+    /// Creates an `OsString` from a potentially ill-formed UTF-16 slice of
+    /// 16-bit code units, replacing unpaired surrogates with
+    /// [U+FFFD REPLACEMENT CHARACTER][U+FFFD].
+    ///
+    /// Unlike [`OsStringExt::from_wide`], the result is always valid Unicode,
+    /// so converting it with [`OsString::into_string`] never fails.
+    ///
+    /// [U+FFFD]: crate::char::REPLACEMENT_CHARACTER
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_wide_conversions)]
+    /// use std::ffi::OsString;
+    /// use std::os::windows::prelude::*;
+    ///
+    /// // "Uni", followed by an unpaired surrogate.
+    /// let source = [0x0055, 0x006E, 0x0069, 0xD800];
+    ///
+    /// let string = OsString::from_wide_lossy(&source[..]);
+    /// assert_eq!(string, "Uni\u{FFFD}");
+    /// ```
+    #[unstable(feature = "os_wide_conversions", issue = "none")]
+    fn from_wide_lossy(wide: &[u16]) -> Self;
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -94,6 +119,10 @@ impl OsStringExt for OsString {
     fn from_wide(wide: &[u16]) -> OsString {
         FromInner::from_inner(Buf { inner: Wtf8Buf::from_wide(wide) })
     }
+
+    fn from_wide_lossy(wide: &[u16]) -> OsString {
+        String::from_utf16_lossy(wide).into()
+    }
 }
 
 /// Windows-specific extensions to [`OsStr`].
@@ -125,6 +154,30 @@ pub trait OsStrExt: Sealed {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     fn encode_wide(&self) -> EncodeWide<'_>;
+
+    /// Re-encodes an `OsStr` as a wide character sequence like
+    /// [`OsStrExt::encode_wide`], appending the code units to `buf`.
+    ///
+    /// This lets a single buffer be reused across many conversions, for
+    /// example to add a null terminator before passing it to a system call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_wide_conversions)]
+    /// use std::ffi::OsStr;
+    /// use std::os::windows::prelude::*;
+    ///
+    /// let mut buf = Vec::new();
+    /// for name in ["a", "bc"] {
+    ///     buf.clear();
+    ///     OsStr::new(name).encode_wide_into(&mut buf);
+    ///     buf.push(0);
+    ///     assert_eq!(buf.len(), name.len() + 1);
+    /// }
+    /// ```
+    #[unstable(feature = "os_wide_conversions", issue = "none")]
+    fn encode_wide_into(&self, buf: &mut Vec<u16>);
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -133,4 +186,9 @@ impl OsStrExt for OsStr {
     fn encode_wide(&self) -> EncodeWide<'_> {
         self.as_inner().inner.encode_wide()
     }
+
+    #[inline]
+    fn encode_wide_into(&self, buf: &mut Vec<u16>) {
+        buf.extend(self.encode_wide());
+    }
 }
@@ -16,8 +16,6 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 #![allow(unused_macros)]
 
-use crate::ffi::CString;
-
 // Re-export some of our utilities which are expected by other crates.
 pub use crate::panicking::{begin_panic, panic_count};
 pub use core::panicking::{panic_display, panic_fmt};
@@ -25,7 +23,6 @@
 use crate::sync::Once;
 use crate::sys;
 use crate::sys_common::thread_info;
-use crate::thread::Thread;
 
 // Prints to the "panic output", depending on the platform this may be:
 // - the standard error output
@@ -97,12 +94,13 @@ unsafe fn init(argc: isize, argv: *const *const u8, sigpipe: u8) {
         sys::init(argc, argv, sigpipe);
 
         let main_guard = sys::thread::guard::init();
-        // Next, set up the current Thread with the guard information we just
-        // created. Note that this isn't necessary in general for new threads,
+        // Next, record the guard information we just created for the current
+        // thread. Note that this isn't necessary in general for new threads,
         // but we just do this to name the main thread and to give it correct
-        // info about the stack bounds.
-        let thread = Thread::new(Some(rtunwrap!(Ok, CString::new("main"))));
-        thread_info::set(main_guard, thread);
+        // info about the stack bounds. Its `Thread` handle is only created
+        // when it is first needed, so that the runtime starts up without
+        // allocating.
+        thread_info::set_main_thread(main_guard);
     }
 }
 
@@ -9,9 +9,10 @@
 /// A type indicating whether a timed wait on a condition variable returned
 /// due to a time out or not.
 ///
-/// It is returned by the [`wait_timeout`] method.
+/// It is returned by the [`wait_timeout`] and [`wait_deadline`] methods.
 ///
 /// [`wait_timeout`]: Condvar::wait_timeout
+/// [`wait_deadline`]: Condvar::wait_deadline
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[stable(feature = "wait_timeout", since = "1.5.0")]
 pub struct WaitTimeoutResult(bool);
@@ -388,6 +389,79 @@ pub fn wait_timeout<'a, T>(
         if poisoned { Err(PoisonError::new((guard, result))) } else { Ok((guard, result)) }
     }
 
+    /// Waits on this condition variable for a notification, timing out once
+    /// `deadline` is reached.
+    ///
+    /// The semantics of this function are equivalent to [`wait_timeout`],
+    /// except that the time limit is a point in time rather than a duration.
+    /// This makes it easier to wait in a loop without the total time spent
+    /// drifting: the deadline stays fixed across spurious wakeups, so there is
+    /// no remaining duration to recompute after each one.
+    ///
+    /// This function is susceptible to spurious wakeups, like [`wait`]. The
+    /// returned [`WaitTimeoutResult`] value only indicates a timeout if the
+    /// deadline has actually passed. A wakeup that happens before then, for
+    /// any reason, is reported as not timed out, so the predicate associated
+    /// with the condition variable should always be checked when this
+    /// function returns. A deadline that has already passed still releases
+    /// and re-acquires the lock, and then reports a timeout.
+    ///
+    /// Like [`wait`], the lock specified will be re-acquired when this function
+    /// returns, regardless of whether the deadline passed or not.
+    ///
+    /// [`wait`]: Self::wait
+    /// [`wait_timeout`]: Self::wait_timeout
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(deadline_api)]
+    /// use std::sync::{Arc, Mutex, Condvar};
+    /// use std::thread;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let pair = Arc::new((Mutex::new(false), Condvar::new()));
+    /// let pair2 = Arc::clone(&pair);
+    ///
+    /// thread::spawn(move || {
+    ///     let (lock, cvar) = &*pair2;
+    ///     let mut started = lock.lock().unwrap();
+    ///     *started = true;
+    ///     // We notify the condvar that the value has changed.
+    ///     cvar.notify_one();
+    /// });
+    ///
+    /// // wait for the thread to start up, for at most a second in total
+    /// let deadline = Instant::now() + Duration::from_secs(1);
+    /// let (lock, cvar) = &*pair;
+    /// let mut started = lock.lock().unwrap();
+    /// while !*started {
+    ///     let result = cvar.wait_deadline(started, deadline).unwrap();
+    ///     started = result.0;
+    ///     if result.1.timed_out() {
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    #[unstable(feature = "deadline_api", issue = "46316")]
+    pub fn wait_deadline<'a, T>(
+        &self,
+        guard: MutexGuard<'a, T>,
+        deadline: Instant,
+    ) -> LockResult<(MutexGuard<'a, T>, WaitTimeoutResult)> {
+        let (poisoned, result) = unsafe {
+            let lock = mutex::guard_lock(&guard);
+            let dur = deadline.saturating_duration_since(Instant::now());
+            // The platform may return early from a long wait without having
+            // been notified, which only counts as a timeout if the deadline
+            // has been reached.
+            let success = self.inner.wait_timeout(lock, dur);
+            let timed_out = !success && Instant::now() >= deadline;
+            (mutex::guard_poison(&guard).get(), WaitTimeoutResult(timed_out))
+        };
+        if poisoned { Err(PoisonError::new((guard, result))) } else { Ok((guard, result)) }
+    }
+
     /// Waits on this condition variable for a notification, timing out after a
     /// specified duration.
     ///
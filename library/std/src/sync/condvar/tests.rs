@@ -2,7 +2,7 @@
 use crate::sync::mpsc::channel;
 use crate::sync::{Arc, Condvar, Mutex};
 use crate::thread;
-use crate::time::Duration;
+use crate::time::{Duration, Instant};
 
 #[test]
 fn smoke() {
@@ -152,6 +152,37 @@ fn wait_timeout_while_wake() {
     assert!(*g2);
 }
 
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn wait_deadline_wait() {
+    let m = Arc::new(Mutex::new(()));
+    let c = Arc::new(Condvar::new());
+
+    let deadline = Instant::now() + Duration::from_millis(1);
+    let mut g = m.lock().unwrap();
+    loop {
+        let (g2, wait) = c.wait_deadline(g, deadline).unwrap();
+        g = g2;
+        // spurious wakeups are only reported as timeouts once the deadline
+        // has passed, so wait again on the same deadline if not timed out
+        if wait.timed_out() {
+            assert!(Instant::now() >= deadline);
+            break;
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn wait_deadline_passed() {
+    let m = Arc::new(Mutex::new(()));
+    let c = Arc::new(Condvar::new());
+
+    let g = m.lock().unwrap();
+    let (_g, wait) = c.wait_deadline(g, Instant::now()).unwrap();
+    assert!(wait.timed_out());
+}
+
 #[test]
 #[cfg_attr(target_os = "emscripten", ignore)]
 fn wait_timeout_wake() {
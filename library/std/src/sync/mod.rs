@@ -158,6 +158,9 @@
 pub use alloc_crate::sync::{Arc, Weak};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::sync::atomic;
+#[cfg(target_has_atomic_load_store = "ptr")]
+#[unstable(feature = "atomic_cell", issue = "none")]
+pub use core::sync::Atomic;
 #[unstable(feature = "exclusive_wrapper", issue = "98407")]
 pub use core::sync::Exclusive;
 
@@ -174,6 +177,8 @@
 pub use self::poison::{LockResult, PoisonError, TryLockError, TryLockResult};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[unstable(feature = "rwlock_upgradable", issue = "none")]
+pub use self::rwlock::RwLockUpgradableReadGuard;
 
 #[unstable(feature = "lazy_cell", issue = "109736")]
 pub use self::lazy_lock::LazyLock;
@@ -325,6 +325,101 @@ pub fn try_lock(&self) -> TryLockResult<MutexGuard<'_, T>> {
         }
     }
 
+    /// Attempts to acquire this lock, blocking the current thread for at most
+    /// `timeout` while it is held elsewhere.
+    ///
+    /// If the lock could not be acquired before the timeout elapsed, then
+    /// [`Err`] is returned. Otherwise, an RAII guard is returned. The lock will
+    /// be unlocked when the guard is dropped.
+    ///
+    /// The thread is put to sleep while waiting rather than spinning. Wakeups
+    /// that don't lead to acquiring the lock are handled internally, so this
+    /// only returns [`WouldBlock`] once the timeout has actually elapsed. As
+    /// with other timed waits, the time spent blocked may exceed `timeout` due
+    /// to scheduling, and it is measured with a monotonic clock.
+    ///
+    /// This function is only available on platforms where the mutex is built
+    /// on a primitive that supports timed waits, which currently are the
+    /// futex-based ones: Linux, Android, FreeBSD, OpenBSD, DragonFly BSD,
+    /// Hermit, and Emscripten and WASI with atomics enabled.
+    ///
+    /// # Errors
+    ///
+    /// If another user of this mutex panicked while holding the mutex, then
+    /// this call will return the [`Poisoned`] error if the mutex would
+    /// otherwise be acquired.
+    ///
+    /// If the mutex could not be acquired before the timeout elapsed, then
+    /// this call will return the [`WouldBlock`] error.
+    ///
+    /// [`Poisoned`]: TryLockError::Poisoned
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(
+        any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "freebsd",
+            target_os = "openbsd",
+            target_os = "dragonfly",
+            target_os = "hermit",
+            all(target_os = "emscripten", target_feature = "atomics"),
+            all(target_os = "wasi", target_feature = "atomics"),
+        ),
+        doc = "```"
+    )]
+    #[cfg_attr(
+        not(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "freebsd",
+            target_os = "openbsd",
+            target_os = "dragonfly",
+            target_os = "hermit",
+            all(target_os = "emscripten", target_feature = "atomics"),
+            all(target_os = "wasi", target_feature = "atomics"),
+        )),
+        doc = "```ignore"
+    )]
+    /// #![feature(mutex_try_lock_for)]
+    /// use std::sync::{Mutex, TryLockError};
+    /// use std::time::Duration;
+    ///
+    /// let mutex = Mutex::new(0);
+    ///
+    /// let guard = mutex.lock().unwrap();
+    /// assert!(matches!(
+    ///     mutex.try_lock_for(Duration::from_millis(10)),
+    ///     Err(TryLockError::WouldBlock)
+    /// ));
+    /// drop(guard);
+    ///
+    /// *mutex.try_lock_for(Duration::from_millis(10)).unwrap() = 10;
+    /// assert_eq!(*mutex.lock().unwrap(), 10);
+    /// ```
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "dragonfly",
+        target_os = "hermit",
+        all(target_os = "emscripten", target_feature = "atomics"),
+        all(target_os = "wasi", target_feature = "atomics"),
+    ))]
+    #[unstable(feature = "mutex_try_lock_for", issue = "none")]
+    pub fn try_lock_for(&self, timeout: crate::time::Duration) -> TryLockResult<MutexGuard<'_, T>> {
+        unsafe {
+            if self.inner.try_lock_for(timeout) {
+                Ok(MutexGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
     /// Immediately drops the guard, and consequently unlocks the mutex.
     ///
     /// This function is equivalent to calling [`drop`] on the guard but is more self-documenting.
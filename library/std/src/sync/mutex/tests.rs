@@ -57,6 +57,36 @@ fn try_lock() {
     *m.try_lock().unwrap() = ();
 }
 
+#[test]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "dragonfly",
+    target_os = "hermit",
+    all(target_os = "emscripten", target_feature = "atomics"),
+    all(target_os = "wasi", target_feature = "atomics"),
+))]
+fn try_lock_for() {
+    use crate::sync::TryLockError;
+    use crate::time::{Duration, Instant};
+
+    let m = Arc::new(Mutex::new(0));
+    let g = m.lock().unwrap();
+
+    let start = Instant::now();
+    assert!(matches!(m.try_lock_for(Duration::from_millis(10)), Err(TryLockError::WouldBlock)));
+    assert!(start.elapsed() >= Duration::from_millis(10));
+
+    let m2 = m.clone();
+    let t = thread::spawn(move || *m2.try_lock_for(Duration::from_secs(60)).unwrap() += 1);
+    thread::sleep(Duration::from_millis(1));
+    drop(g);
+    t.join().unwrap();
+    assert_eq!(*m.lock().unwrap(), 1);
+}
+
 #[test]
 fn test_into_inner() {
     let m = Mutex::new(NonCopy(10));
@@ -3,9 +3,10 @@
 
 use crate::cell::UnsafeCell;
 use crate::fmt;
+use crate::mem::ManuallyDrop;
 use crate::ops::{Deref, DerefMut};
 use crate::ptr::NonNull;
-use crate::sync::{poison, LockResult, TryLockError, TryLockResult};
+use crate::sync::{poison, LockResult, PoisonError, TryLockError, TryLockResult};
 use crate::sys::locks as sys;
 
 /// A reader-writer lock
@@ -78,6 +79,8 @@
 #[stable(feature = "rust1", since = "1.0.0")]
 #[cfg_attr(not(test), rustc_diagnostic_item = "RwLock")]
 pub struct RwLock<T: ?Sized> {
+    // Platforms whose lock primitive can't upgrade a read lock in place implement
+    // upgradable read locks as write locks, and upgrading them as a no-op.
     inner: sys::RwLock,
     poison: poison::Flag,
     data: UnsafeCell<T>,
@@ -144,6 +147,35 @@ impl<T: ?Sized> !Send for RwLockWriteGuard<'_, T> {}
 #[stable(feature = "rwlock_guard_sync", since = "1.23.0")]
 unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
 
+/// RAII structure used to release the upgradable read access of a lock when
+/// dropped.
+///
+/// An upgradable read lock gives shared read access, like a [`RwLockReadGuard`],
+/// and can be turned into exclusive write access with [`upgrade`] without
+/// letting any writer in between.
+///
+/// This structure is created by the [`read_upgradable`] and
+/// [`try_read_upgradable`] methods on [`RwLock`].
+///
+/// [`upgrade`]: RwLockUpgradableReadGuard::upgrade
+/// [`read_upgradable`]: RwLock::read_upgradable
+/// [`try_read_upgradable`]: RwLock::try_read_upgradable
+#[must_use = "if unused the RwLock will immediately unlock"]
+#[must_not_suspend = "holding a RwLockUpgradableReadGuard across suspend \
+                      points can cause deadlocks, delays, \
+                      and cause Futures to not implement `Send`"]
+#[unstable(feature = "rwlock_upgradable", issue = "none")]
+#[clippy::has_significant_drop]
+pub struct RwLockUpgradableReadGuard<'a, T: ?Sized + 'a> {
+    lock: &'a RwLock<T>,
+}
+
+#[unstable(feature = "rwlock_upgradable", issue = "none")]
+impl<T: ?Sized> !Send for RwLockUpgradableReadGuard<'_, T> {}
+
+#[unstable(feature = "rwlock_upgradable", issue = "none")]
+unsafe impl<T: ?Sized + Sync> Sync for RwLockUpgradableReadGuard<'_, T> {}
+
 impl<T> RwLock<T> {
     /// Creates a new instance of an `RwLock<T>` which is unlocked.
     ///
@@ -260,6 +292,113 @@ pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, T>> {
         }
     }
 
+    /// Locks this `RwLock` with upgradable read access, blocking the current
+    /// thread until it can be acquired.
+    ///
+    /// An upgradable read lock can be held alongside any number of plain read
+    /// locks, but not alongside a write lock or another upgradable read lock.
+    /// This means the data can't change while the guard is held, and the guard
+    /// can later be turned into a write lock with
+    /// [`RwLockUpgradableReadGuard::upgrade`] without letting any writer in
+    /// between. That makes it suitable for "check, then maybe modify" patterns
+    /// that would otherwise have to take the write lock up front, or drop the
+    /// read lock and check again after taking the write lock.
+    ///
+    /// On platforms whose lock primitive can't upgrade a read lock in place, an
+    /// upgradable read lock is exclusive, like a write lock. It will still
+    /// behave correctly, but won't let plain readers in concurrently.
+    ///
+    /// Returns an RAII guard which will release this thread's upgradable access
+    /// once it is dropped.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the `RwLock` is poisoned. An
+    /// `RwLock` is poisoned whenever a writer panics while holding an exclusive
+    /// lock. The failure will occur immediately after the lock has been
+    /// acquired.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when called if the lock is already held by the current thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable)]
+    ///
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(Vec::new());
+    ///
+    /// let guard = lock.read_upgradable().unwrap();
+    /// if guard.is_empty() {
+    ///     let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
+    ///     guard.push(1);
+    /// }
+    /// assert_eq!(*lock.read().unwrap(), [1]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "rwlock_upgradable", issue = "none")]
+    pub fn read_upgradable(&self) -> LockResult<RwLockUpgradableReadGuard<'_, T>> {
+        unsafe {
+            self.inner.read_upgradable();
+            RwLockUpgradableReadGuard::new(self)
+        }
+    }
+
+    /// Attempts to acquire this `RwLock` with upgradable read access.
+    ///
+    /// If the access could not be granted at this time, then `Err` is returned.
+    /// Otherwise, an RAII guard is returned which will release the upgradable
+    /// access when it is dropped.
+    ///
+    /// This function does not block.
+    ///
+    /// See [`read_upgradable`] for how upgradable read locks interact with
+    /// other locks.
+    ///
+    /// [`read_upgradable`]: Self::read_upgradable
+    ///
+    /// # Errors
+    ///
+    /// This function will return the [`Poisoned`] error if the `RwLock` is
+    /// poisoned. An `RwLock` is poisoned whenever a writer panics while holding
+    /// an exclusive lock. `Poisoned` will only be returned if the lock would
+    /// have otherwise been acquired.
+    ///
+    /// This function will return the [`WouldBlock`] error if the `RwLock` could
+    /// not be acquired because it was already locked exclusively, or another
+    /// upgradable read lock is held.
+    ///
+    /// [`Poisoned`]: TryLockError::Poisoned
+    /// [`WouldBlock`]: TryLockError::WouldBlock
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable)]
+    ///
+    /// use std::sync::RwLock;
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let guard = lock.try_read_upgradable().unwrap();
+    /// assert_eq!(*guard, 1);
+    /// assert!(lock.try_read_upgradable().is_err());
+    /// ```
+    #[inline]
+    #[unstable(feature = "rwlock_upgradable", issue = "none")]
+    pub fn try_read_upgradable(&self) -> TryLockResult<RwLockUpgradableReadGuard<'_, T>> {
+        unsafe {
+            if self.inner.try_read_upgradable() {
+                Ok(RwLockUpgradableReadGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
     /// Locks this `RwLock` with exclusive write access, blocking the current
     /// thread until it can be acquired.
     ///
@@ -522,6 +661,111 @@ unsafe fn new(lock: &'rwlock RwLock<T>) -> LockResult<RwLockReadGuard<'rwlock, T
     }
 }
 
+impl<'rwlock, T: ?Sized> RwLockUpgradableReadGuard<'rwlock, T> {
+    /// Create a new instance of `RwLockUpgradableReadGuard<T>` from a `RwLock<T>`.
+    // SAFETY: if and only if `lock.inner.read_upgradable()` (or `lock.inner.try_read_upgradable()`)
+    // has been successfully called from the same thread before instantiating this object.
+    unsafe fn new(lock: &'rwlock RwLock<T>) -> LockResult<RwLockUpgradableReadGuard<'rwlock, T>> {
+        poison::map_result(lock.poison.borrow(), |()| RwLockUpgradableReadGuard { lock })
+    }
+
+    /// Turns the upgradable read lock into a write lock, blocking the current
+    /// thread until all other readers have released the lock.
+    ///
+    /// No writer can acquire the lock in between, so the data seen through the
+    /// upgradable read lock is still current once this returns. New readers
+    /// are held off while the upgrade waits, so it can't be starved by them.
+    ///
+    /// The lock can't have been poisoned since the upgradable read lock was
+    /// acquired, and that already reported any earlier poisoning, so this
+    /// doesn't report it again.
+    ///
+    /// This is an associated function that needs to be used as
+    /// `RwLockUpgradableReadGuard::upgrade(...)`. A method would interfere with
+    /// methods of the same name on the contents of the `RwLock` used through
+    /// `Deref`.
+    ///
+    /// # Deadlocks
+    ///
+    /// This waits for every other reader, so it will never return if the
+    /// current thread also holds a plain read lock on the same `RwLock`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable)]
+    ///
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let guard = lock.read_upgradable().unwrap();
+    /// let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
+    /// *guard += 1;
+    /// drop(guard);
+    /// assert_eq!(*lock.read().unwrap(), 2);
+    /// ```
+    #[unstable(feature = "rwlock_upgradable", issue = "none")]
+    pub fn upgrade(orig: Self) -> RwLockWriteGuard<'rwlock, T> {
+        let orig = ManuallyDrop::new(orig);
+        // SAFETY: the conditions of `RwLockUpgradableReadGuard::new` were satisfied when
+        // `orig` was created, and after upgrading the lock the conditions of
+        // `RwLockWriteGuard::new` are satisfied too. `orig` won't be dropped, so the
+        // upgradable lock isn't released.
+        unsafe {
+            orig.lock.inner.upgrade();
+            RwLockWriteGuard::new(orig.lock).unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+
+    /// Attempts to turn the upgradable read lock into a write lock.
+    ///
+    /// This succeeds if no other readers hold the lock. Otherwise the
+    /// upgradable read lock is returned unchanged.
+    ///
+    /// This function does not block.
+    ///
+    /// This is an associated function that needs to be used as
+    /// `RwLockUpgradableReadGuard::try_upgrade(...)`. A method would interfere
+    /// with methods of the same name on the contents of the `RwLock` used
+    /// through `Deref`.
+    ///
+    /// As with [`upgrade`], poisoning isn't reported again.
+    ///
+    /// [`upgrade`]: Self::upgrade
+    ///
+    /// # Errors
+    ///
+    /// If other readers hold the lock, `orig` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable)]
+    ///
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let guard = lock.read_upgradable().unwrap();
+    /// let mut guard = RwLockUpgradableReadGuard::try_upgrade(guard).unwrap();
+    /// *guard += 1;
+    /// ```
+    #[unstable(feature = "rwlock_upgradable", issue = "none")]
+    pub fn try_upgrade(orig: Self) -> Result<RwLockWriteGuard<'rwlock, T>, Self> {
+        // SAFETY: the conditions of `RwLockUpgradableReadGuard::new` were satisfied when
+        // `orig` was created.
+        if unsafe { orig.lock.inner.try_upgrade() } {
+            let orig = ManuallyDrop::new(orig);
+            // SAFETY: the lock is now write locked by the current thread, and `orig` won't be
+            // dropped, so it isn't released.
+            Ok(unsafe { RwLockWriteGuard::new(orig.lock) }.unwrap_or_else(PoisonError::into_inner))
+        } else {
+            Err(orig)
+        }
+    }
+}
+
 impl<'rwlock, T: ?Sized> RwLockWriteGuard<'rwlock, T> {
     /// Create a new instance of `RwLockWriteGuard<T>` from a `RwLock<T>`.
     // SAFETY: if and only if `lock.inner.write()` (or `lock.inner.try_write()`) has been
@@ -545,6 +789,20 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable", issue = "none")]
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLockUpgradableReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[unstable(feature = "rwlock_upgradable", issue = "none")]
+impl<T: ?Sized + fmt::Display> fmt::Display for RwLockUpgradableReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
 #[stable(feature = "std_debug", since = "1.16.0")]
 impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLockWriteGuard<'_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -569,6 +827,16 @@ fn deref(&self) -> &T {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable", issue = "none")]
+impl<T: ?Sized> Deref for RwLockUpgradableReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: the conditions of `RwLockUpgradableReadGuard::new` were satisfied when created.
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
     type Target = T;
@@ -597,6 +865,16 @@ fn drop(&mut self) {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable", issue = "none")]
+impl<T: ?Sized> Drop for RwLockUpgradableReadGuard<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: the conditions of `RwLockUpgradableReadGuard::new` were satisfied when created.
+        unsafe {
+            self.lock.inner.upgradable_read_unlock();
+        }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
     fn drop(&mut self) {
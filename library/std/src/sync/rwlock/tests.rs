@@ -1,6 +1,6 @@
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sync::mpsc::channel;
-use crate::sync::{Arc, RwLock, RwLockReadGuard, TryLockError};
+use crate::sync::{Arc, RwLock, RwLockReadGuard, RwLockUpgradableReadGuard, TryLockError};
 use crate::thread;
 use rand::Rng;
 
@@ -257,3 +257,75 @@ fn do_stuff<'a>(_: RwLockReadGuard<'_, &'a i32>, _: &'a i32) {}
     }
     drop(lock);
 }
+
+#[test]
+fn test_upgradable_excludes_writers() {
+    let lock = RwLock::new(0);
+    let guard = lock.read_upgradable().unwrap();
+    assert!(matches!(lock.try_write(), Err(TryLockError::WouldBlock)));
+    assert!(matches!(lock.try_read_upgradable(), Err(TryLockError::WouldBlock)));
+    drop(guard);
+    drop(lock.try_write().unwrap());
+    drop(lock.try_read_upgradable().unwrap());
+}
+
+#[test]
+fn test_upgrade() {
+    let lock = RwLock::new(0);
+    let guard = lock.read_upgradable().unwrap();
+    let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
+    *guard += 1;
+    assert!(matches!(lock.try_read(), Err(TryLockError::WouldBlock)));
+    drop(guard);
+
+    let guard = lock.try_read_upgradable().unwrap();
+    let mut guard = RwLockUpgradableReadGuard::try_upgrade(guard).unwrap();
+    *guard += 1;
+    drop(guard);
+    assert_eq!(*lock.read().unwrap(), 2);
+}
+
+#[test]
+fn test_upgrade_is_atomic() {
+    const N: usize = 10;
+    const M: usize = if cfg!(miri) { 10 } else { 100 };
+
+    let lock = Arc::new(RwLock::new(0));
+    let threads: Vec<_> = (0..N)
+        .map(|_| {
+            let lock = lock.clone();
+            thread::spawn(move || {
+                for _ in 0..M {
+                    let guard = lock.read_upgradable().unwrap();
+                    let seen = *guard;
+                    // Plain readers may or may not get in, depending on the platform.
+                    drop(lock.try_read());
+                    let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
+                    assert_eq!(*guard, seen);
+                    *guard += 1;
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(*lock.read().unwrap(), N * M);
+}
+
+#[test]
+fn test_upgradable_poison() {
+    let lock = Arc::new(RwLock::new(1));
+    let lock2 = lock.clone();
+    let _ = thread::spawn(move || {
+        let _lock = lock2.write().unwrap();
+        panic!("test panic in inner thread to poison RwLock");
+    })
+    .join();
+
+    let guard = lock.read_upgradable().unwrap_err().into_inner();
+    let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
+    *guard = 2;
+    drop(guard);
+    assert!(lock.is_poisoned());
+}
@@ -14,6 +14,7 @@
 pub mod small_c_string;
 #[allow(unused_imports)]
 pub mod thread_local;
+pub mod ticks;
 
 #[cfg(test)]
 mod tests;
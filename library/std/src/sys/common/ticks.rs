@@ -0,0 +1,89 @@
+//! Raw hardware counters backing `std::time::Ticks`.
+//!
+//! Only counters that are readable from userspace and guaranteed to advance at a constant rate
+//! (independent of frequency scaling and sleep states) are used. Everything else reports that no
+//! counter is available, and `Ticks` falls back to `Instant`.
+
+cfg_if::cfg_if! {
+    if #[cfg(all(target_arch = "x86_64", not(target_env = "sgx"), not(miri)))] {
+        use crate::sync::atomic::{AtomicU8, Ordering::Relaxed};
+
+        const UNKNOWN: u8 = 0;
+        const INVARIANT: u8 = 1;
+        const UNAVAILABLE: u8 = 2;
+
+        static TSC_STATE: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+        /// Returns whether the time stamp counter is invariant, i.e. ticks at a constant rate in
+        /// all ACPI P-, C- and T-states (CPUID.80000007H:EDX[8]).
+        fn has_invariant_tsc() -> bool {
+            match TSC_STATE.load(Relaxed) {
+                INVARIANT => return true,
+                UNAVAILABLE => return false,
+                _ => {}
+            }
+
+            // SAFETY: `cpuid` is always available on x86_64.
+            let invariant = unsafe {
+                use core::arch::x86_64::__cpuid;
+
+                __cpuid(0x8000_0000).eax >= 0x8000_0007
+                    && __cpuid(0x8000_0007).edx & (1 << 8) != 0
+            };
+            // Racing threads compute the same answer, so a plain store is fine.
+            TSC_STATE.store(if invariant { INVARIANT } else { UNAVAILABLE }, Relaxed);
+            invariant
+        }
+
+        pub fn read() -> Option<u64> {
+            if has_invariant_tsc() {
+                // SAFETY: `rdtsc` is always available on x86_64.
+                Some(unsafe { core::arch::x86_64::_rdtsc() })
+            } else {
+                None
+            }
+        }
+
+        /// The TSC frequency is not architecturally exposed, so it must be calibrated.
+        pub fn frequency() -> Option<u64> {
+            None
+        }
+    } else if #[cfg(all(target_arch = "aarch64", not(miri)))] {
+        pub fn read() -> Option<u64> {
+            let ticks: u64;
+            // SAFETY: the generic timer's virtual count is readable at EL0 on every OS we support.
+            // The `isb` prevents the read from being speculated ahead of earlier instructions.
+            unsafe {
+                core::arch::asm!(
+                    "isb",
+                    "mrs {}, cntvct_el0",
+                    out(reg) ticks,
+                    options(nomem, nostack, preserves_flags),
+                );
+            }
+            Some(ticks)
+        }
+
+        pub fn frequency() -> Option<u64> {
+            let freq: u64;
+            // SAFETY: `cntfrq_el0` is readable at EL0 whenever `cntvct_el0` is.
+            unsafe {
+                core::arch::asm!(
+                    "mrs {}, cntfrq_el0",
+                    out(reg) freq,
+                    options(nomem, nostack, preserves_flags),
+                );
+            }
+            // Some firmware leaves the register unprogrammed; calibrate in that case.
+            (freq != 0).then_some(freq)
+        }
+    } else {
+        pub fn read() -> Option<u64> {
+            None
+        }
+
+        pub fn frequency() -> Option<u64> {
+            None
+        }
+    }
+}
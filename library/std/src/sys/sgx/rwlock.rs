@@ -184,6 +184,30 @@ unsafe fn unlock(&self) {
             unsafe { self.__read_unlock(rguard, wguard) };
         }
     }
+
+    #[inline]
+    pub fn read_upgradable(&self) {
+        self.write()
+    }
+
+    #[inline]
+    pub fn try_read_upgradable(&self) -> bool {
+        self.try_write()
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        // SAFETY: upgradable read locks are write locks here.
+        unsafe { self.write_unlock() }
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {}
+
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        true
+    }
 }
 
 // The following functions are needed by libunwind. These symbols are named
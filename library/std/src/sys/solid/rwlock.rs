@@ -81,6 +81,30 @@ pub unsafe fn write_unlock(&self) {
         let rwl = self.raw();
         expect_success_aborting(unsafe { abi::rwl_unl_rwl(rwl) }, &"rwl_unl_rwl");
     }
+
+    #[inline]
+    pub fn read_upgradable(&self) {
+        self.write()
+    }
+
+    #[inline]
+    pub fn try_read_upgradable(&self) -> bool {
+        self.try_write()
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        // SAFETY: upgradable read locks are write locks here.
+        unsafe { self.write_unlock() }
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {}
+
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        true
+    }
 }
 
 impl Drop for RwLock {
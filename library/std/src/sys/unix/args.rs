@@ -5,8 +5,11 @@
 
 #![allow(dead_code)] // runtime init functions not used during testing
 
-use crate::ffi::OsString;
+use crate::ffi::{CStr, OsStr, OsString};
 use crate::fmt;
+use crate::ops::Range;
+use crate::os::unix::ffi::OsStrExt;
+use crate::sync::OnceLock;
 use crate::vec;
 
 /// One-time global initialization.
@@ -19,6 +22,11 @@ pub fn args() -> Args {
     imp::args()
 }
 
+/// Returns the command line arguments, borrowed from the system-provided argv
+pub fn args_bytes() -> ArgsBytes {
+    imp::args_bytes()
+}
+
 pub struct Args {
     iter: vec::IntoIter<OsString>,
 }
@@ -54,6 +62,86 @@ fn next_back(&mut self) -> Option<OsString> {
     }
 }
 
+pub struct ArgsBytes {
+    argv: Argv,
+    range: Range<usize>,
+}
+
+/// Where `ArgsBytes` borrows the arguments from.
+enum Argv {
+    /// The system-provided argv.
+    System(*const *const libc::c_char),
+    /// A copy of the arguments, for the targets where argv isn't saved.
+    Owned(&'static [OsString]),
+}
+
+impl ArgsBytes {
+    /// Creates an iterator over the first `argc` arguments in `argv`, stopping early at the first
+    /// null pointer like `Args` does.
+    ///
+    /// # Safety
+    ///
+    /// `argv` must be null or point to `argc` pointers, or to fewer followed by a null pointer,
+    /// and the strings they point to must not be modified or freed while they are borrowed.
+    unsafe fn new(argc: isize, argv: *const *const libc::c_char) -> ArgsBytes {
+        let mut len = 0;
+        if !argv.is_null() {
+            while (len as isize) < argc && !(*argv.add(len)).is_null() {
+                len += 1;
+            }
+        }
+        ArgsBytes { argv: Argv::System(argv), range: 0..len }
+    }
+
+    /// Creates an iterator over a copy of the arguments returned by `args`, made on the first
+    /// call and kept for the whole program.
+    fn owned() -> ArgsBytes {
+        static ARGS: OnceLock<Vec<OsString>> = OnceLock::new();
+        let copy = ARGS.get_or_init(|| args().collect());
+        ArgsBytes { argv: Argv::Owned(copy), range: 0..copy.len() }
+    }
+
+    fn get(&self, i: usize) -> &'static [u8] {
+        match self.argv {
+            // SAFETY: `i` is in the range checked in `new`, and the caller of `args_os_bytes`
+            // guarantees that the argument isn't modified while borrowed.
+            Argv::System(argv) => unsafe { CStr::from_ptr(*argv.add(i)).to_bytes() },
+            Argv::Owned(args) => args[i].as_bytes(),
+        }
+    }
+}
+
+impl !Send for ArgsBytes {}
+impl !Sync for ArgsBytes {}
+
+impl fmt::Debug for ArgsBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.range.clone().map(|i| OsStr::from_bytes(self.get(i)))).finish()
+    }
+}
+
+impl Iterator for ArgsBytes {
+    type Item = &'static [u8];
+    fn next(&mut self) -> Option<&'static [u8]> {
+        self.range.next().map(|i| self.get(i))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ArgsBytes {
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+}
+
+impl DoubleEndedIterator for ArgsBytes {
+    fn next_back(&mut self) -> Option<&'static [u8]> {
+        self.range.next_back().map(|i| self.get(i))
+    }
+}
+
 #[cfg(any(
     target_os = "linux",
     target_os = "android",
@@ -75,7 +163,7 @@ fn next_back(&mut self) -> Option<OsString> {
     target_os = "hurd",
 ))]
 mod imp {
-    use super::Args;
+    use super::{Args, ArgsBytes};
     use crate::ffi::{CStr, OsString};
     use crate::os::unix::prelude::*;
     use crate::ptr;
@@ -132,6 +220,17 @@ pub fn args() -> Args {
         Args { iter: clone().into_iter() }
     }
 
+    pub fn args_bytes() -> ArgsBytes {
+        // SAFETY: as in `clone`, ARGC and ARGV hold the system-provided argc/argv, which are never
+        // freed. The strings may be rewritten in place, e.g. to set the process title, but
+        // `args_os_bytes` requires this not to happen while they are borrowed.
+        unsafe {
+            let argv = ARGV.load(Ordering::Relaxed);
+            let argc = if argv.is_null() { 0 } else { ARGC.load(Ordering::Relaxed) };
+            ArgsBytes::new(argc, argv as *const *const libc::c_char)
+        }
+    }
+
     fn clone() -> Vec<OsString> {
         unsafe {
             // Load ARGC and ARGV, which hold the unmodified system-provided
@@ -172,19 +271,21 @@ fn clone() -> Vec<OsString> {
 
 #[cfg(any(target_os = "macos", target_os = "ios", target_os = "tvos", target_os = "watchos"))]
 mod imp {
-    use super::Args;
+    use super::{Args, ArgsBytes};
     use crate::ffi::CStr;
 
+    #[cfg(target_os = "macos")]
+    extern "C" {
+        // These functions are in crt_externs.h.
+        fn _NSGetArgc() -> *mut libc::c_int;
+        fn _NSGetArgv() -> *mut *mut *mut libc::c_char;
+    }
+
     pub unsafe fn init(_argc: isize, _argv: *const *const u8) {}
 
     #[cfg(target_os = "macos")]
     pub fn args() -> Args {
         use crate::os::unix::prelude::*;
-        extern "C" {
-            // These functions are in crt_externs.h.
-            fn _NSGetArgc() -> *mut libc::c_int;
-            fn _NSGetArgv() -> *mut *mut *mut libc::c_char;
-        }
 
         let vec = unsafe {
             let (argc, argv) =
@@ -199,6 +300,15 @@ pub fn args() -> Args {
         Args { iter: vec.into_iter() }
     }
 
+    #[cfg(target_os = "macos")]
+    pub fn args_bytes() -> ArgsBytes {
+        // SAFETY: the system-provided argv lives for the whole program, and `args_os_bytes`
+        // requires its strings not to be modified while they are borrowed.
+        unsafe {
+            ArgsBytes::new(*_NSGetArgc() as isize, *_NSGetArgv() as *const *const libc::c_char)
+        }
+    }
+
     // As _NSGetArgc and _NSGetArgv aren't mentioned in iOS docs
     // and use underscores in their names - they're most probably
     // are considered private and therefore should be avoided
@@ -265,11 +375,17 @@ pub fn args() -> Args {
 
         Args { iter: res.into_iter() }
     }
+
+    // The strings returned by `NSProcessInfo` are autoreleased, so there is nothing to borrow.
+    #[cfg(any(target_os = "ios", target_os = "tvos", target_os = "watchos"))]
+    pub fn args_bytes() -> ArgsBytes {
+        ArgsBytes::owned()
+    }
 }
 
 #[cfg(any(target_os = "espidf", target_os = "vita"))]
 mod imp {
-    use super::Args;
+    use super::{Args, ArgsBytes};
 
     #[inline(always)]
     pub unsafe fn init(_argc: isize, _argv: *const *const u8) {}
@@ -277,4 +393,8 @@ pub unsafe fn init(_argc: isize, _argv: *const *const u8) {}
     pub fn args() -> Args {
         Args { iter: Vec::new().into_iter() }
     }
+
+    pub fn args_bytes() -> ArgsBytes {
+        ArgsBytes::owned()
+    }
 }
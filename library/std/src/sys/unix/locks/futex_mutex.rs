@@ -3,6 +3,9 @@
     Ordering::{Acquire, Relaxed, Release},
 };
 use crate::sys::futex::{futex_wait, futex_wake};
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+use crate::time::Duration;
+use crate::time::Instant;
 
 pub struct Mutex {
     /// 0: unlocked
@@ -25,12 +28,23 @@ pub fn try_lock(&self) -> bool {
     #[inline]
     pub fn lock(&self) {
         if self.futex.compare_exchange(0, 1, Acquire, Relaxed).is_err() {
-            self.lock_contended();
+            self.lock_contended(None);
         }
     }
 
+    /// Returns `false` if the lock could not be acquired within `timeout`.
+    // There is no clock to measure the timeout with on plain wasm.
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    #[inline]
+    pub fn try_lock_for(&self, timeout: Duration) -> bool {
+        // A deadline too far away to represent is as good as none at all.
+        self.futex.compare_exchange(0, 1, Acquire, Relaxed).is_ok()
+            || self.lock_contended(Instant::now().checked_add(timeout))
+    }
+
+    /// Returns `false` if `deadline` passed before the lock was acquired.
     #[cold]
-    fn lock_contended(&self) {
+    fn lock_contended(&self, deadline: Option<Instant>) -> bool {
         // Spin first to speed things up if the lock is released quickly.
         let mut state = self.spin();
 
@@ -38,7 +52,7 @@ fn lock_contended(&self) {
         // without marking it as contended.
         if state == 0 {
             match self.futex.compare_exchange(0, 1, Acquire, Relaxed) {
-                Ok(_) => return, // Locked!
+                Ok(_) => return true, // Locked!
                 Err(s) => state = s,
             }
         }
@@ -49,11 +63,21 @@ fn lock_contended(&self) {
             // to be friendlier for the caches.
             if state != 2 && self.futex.swap(2, Acquire) == 0 {
                 // We changed it from 0 to 2, so we just successfully locked it.
-                return;
+                return true;
             }
 
+            // Leaving the state at 2 when giving up is fine: at worst, the
+            // next unlock makes an unnecessary wake call.
+            let timeout = match deadline {
+                None => None,
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(timeout) if !timeout.is_zero() => Some(timeout),
+                    _ => return false,
+                },
+            };
+
             // Wait for the futex to change state, assuming it is still 2.
-            futex_wait(&self.futex, 2, None);
+            futex_wait(&self.futex, 2, timeout);
 
             // Spin again after waking up.
             state = self.spin();
@@ -5,13 +5,15 @@
 use crate::sys::futex::{futex_wait, futex_wake, futex_wake_all};
 
 pub struct RwLock {
-    // The state consists of a 30-bit reader counter, a 'readers waiting' flag, and a 'writers waiting' flag.
-    // Bits 0..30:
+    // The state consists of a 29-bit reader counter, an 'upgradable' flag, a 'readers waiting' flag,
+    // and a 'writers waiting' flag.
+    // Bits 0..29:
     //   0: Unlocked
-    //   1..=0x3FFF_FFFE: Locked by N readers
-    //   0x3FFF_FFFF: Write locked
+    //   1..=0x1FFF_FFFE: Locked by N readers
+    //   0x1FFF_FFFF: Write locked
+    // Bit 29: One of the readers holds an upgradable read lock.
     // Bit 30: Readers are waiting on this futex.
-    // Bit 31: Writers are waiting on the writer_notify futex.
+    // Bit 31: Writers, or an upgrading reader, are waiting on the writer_notify futex.
     state: AtomicU32,
     // The 'condition variable' to notify writers through.
     // Incremented on every signal.
@@ -19,9 +21,10 @@ pub struct RwLock {
 }
 
 const READ_LOCKED: u32 = 1;
-const MASK: u32 = (1 << 30) - 1;
+const MASK: u32 = (1 << 29) - 1;
 const WRITE_LOCKED: u32 = MASK;
 const MAX_READERS: u32 = MASK - 1;
+const UPGRADABLE: u32 = 1 << 29;
 const READERS_WAITING: u32 = 1 << 30;
 const WRITERS_WAITING: u32 = 1 << 31;
 
@@ -56,6 +59,16 @@ fn is_read_lockable(state: u32) -> bool {
     state & MASK < MAX_READERS && !has_readers_waiting(state) && !has_writers_waiting(state)
 }
 
+#[inline]
+fn is_upgradable_read_locked(state: u32) -> bool {
+    state & UPGRADABLE != 0
+}
+
+#[inline]
+fn is_upgradable_read_lockable(state: u32) -> bool {
+    is_read_lockable(state) && !is_upgradable_read_locked(state)
+}
+
 #[inline]
 fn has_reached_max_readers(state: u32) -> bool {
     state & MASK == MAX_READERS
@@ -92,12 +105,26 @@ pub unsafe fn read_unlock(&self) {
         let state = self.state.fetch_sub(READ_LOCKED, Release) - READ_LOCKED;
 
         // It's impossible for a reader to be waiting on a read-locked RwLock,
-        // except if there is also a writer waiting.
-        debug_assert!(!has_readers_waiting(state) || has_writers_waiting(state));
-
-        // Wake up a writer if we were the last reader and there's a writer waiting.
-        if is_unlocked(state) && has_writers_waiting(state) {
-            self.wake_writer_or_readers(state);
+        // except if there is also a writer waiting, or an upgradable read lock is held.
+        debug_assert!(
+            !has_readers_waiting(state)
+                || has_writers_waiting(state)
+                || is_upgradable_read_locked(state)
+                || is_unlocked(state)
+        );
+
+        if is_unlocked(state) {
+            // Wake up a writer if we were the last reader and there's a writer waiting,
+            // or readers blocked on an upgradable read lock that has since been released.
+            if has_writers_waiting(state) || has_readers_waiting(state) {
+                self.wake_writer_or_readers(state);
+            }
+        } else if state & MASK == READ_LOCKED
+            && is_upgradable_read_locked(state)
+            && has_writers_waiting(state)
+        {
+            // The only reader left holds the upgradable lock, which might be waiting to upgrade.
+            self.wake_upgrader();
         }
     }
 
@@ -141,6 +168,169 @@ fn read_contended(&self) {
         }
     }
 
+    #[inline]
+    pub fn try_read_upgradable(&self) -> bool {
+        self.state
+            .fetch_update(Acquire, Relaxed, |s| {
+                is_upgradable_read_lockable(s).then(|| s + READ_LOCKED + UPGRADABLE)
+            })
+            .is_ok()
+    }
+
+    #[inline]
+    pub fn read_upgradable(&self) {
+        let state = self.state.load(Relaxed);
+        if !is_upgradable_read_lockable(state)
+            || self
+                .state
+                .compare_exchange_weak(state, state + READ_LOCKED + UPGRADABLE, Acquire, Relaxed)
+                .is_err()
+        {
+            self.read_upgradable_contended();
+        }
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        let mut state =
+            self.state.fetch_sub(READ_LOCKED + UPGRADABLE, Release) - (READ_LOCKED + UPGRADABLE);
+
+        if is_unlocked(state) {
+            if has_writers_waiting(state) || has_readers_waiting(state) {
+                self.wake_writer_or_readers(state);
+            }
+            return;
+        }
+
+        // Other readers still hold the lock. Any readers waiting are waiting for the upgradable
+        // lock we just released (and plain readers queued up behind them), so wake them up,
+        // unless a writer is waiting. In that case the last reader wakes everyone up instead.
+        while has_readers_waiting(state) && !has_writers_waiting(state) && !is_unlocked(state) {
+            match self.state.compare_exchange(state, state - READERS_WAITING, Relaxed, Relaxed) {
+                Ok(_) => {
+                    futex_wake_all(&self.state);
+                    return;
+                }
+                Err(s) => state = s,
+            }
+        }
+    }
+
+    #[cold]
+    fn read_upgradable_contended(&self) {
+        let mut state = self.spin_read_upgradable();
+
+        loop {
+            // If we can lock it, lock it.
+            if is_upgradable_read_lockable(state) {
+                match self.state.compare_exchange_weak(
+                    state,
+                    state + READ_LOCKED + UPGRADABLE,
+                    Acquire,
+                    Relaxed,
+                ) {
+                    Ok(_) => return, // Locked!
+                    Err(s) => {
+                        state = s;
+                        continue;
+                    }
+                }
+            }
+
+            // Check for overflow.
+            if has_reached_max_readers(state) {
+                panic!("too many active read locks on RwLock");
+            }
+
+            // Make sure the readers waiting bit is set before we go to sleep.
+            if !has_readers_waiting(state) {
+                if let Err(s) =
+                    self.state.compare_exchange(state, state | READERS_WAITING, Relaxed, Relaxed)
+                {
+                    state = s;
+                    continue;
+                }
+            }
+
+            // Wait for the state to change.
+            futex_wait(&self.state, state | READERS_WAITING, None);
+
+            // Spin again after waking up.
+            state = self.spin_read_upgradable();
+        }
+    }
+
+    /// Turns the upgradable read lock held by the current thread into a write lock, once all
+    /// other readers are gone.
+    #[inline]
+    pub unsafe fn upgrade(&self) {
+        if !self.try_upgrade() {
+            self.upgrade_contended();
+        }
+    }
+
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        self.state
+            .fetch_update(Acquire, Relaxed, |s| {
+                (s & MASK == READ_LOCKED).then(|| s - READ_LOCKED - UPGRADABLE + WRITE_LOCKED)
+            })
+            .is_ok()
+    }
+
+    #[cold]
+    fn upgrade_contended(&self) {
+        let mut state = self.state.load(Relaxed);
+
+        loop {
+            debug_assert!(is_upgradable_read_locked(state));
+
+            // If we're the only reader left, take the write lock. The writers waiting bit is left
+            // on, since there might be writers waiting besides us.
+            if state & MASK == READ_LOCKED {
+                match self.state.compare_exchange_weak(
+                    state,
+                    state - READ_LOCKED - UPGRADABLE + WRITE_LOCKED,
+                    Acquire,
+                    Relaxed,
+                ) {
+                    Ok(_) => return, // Upgraded!
+                    Err(s) => {
+                        state = s;
+                        continue;
+                    }
+                }
+            }
+
+            // Set the writers waiting bit. This also stops new readers from coming in, so the
+            // upgrade can't be starved by them.
+            if !has_writers_waiting(state) {
+                if let Err(s) =
+                    self.state.compare_exchange(state, state | WRITERS_WAITING, Relaxed, Relaxed)
+                {
+                    state = s;
+                    continue;
+                }
+            }
+
+            // Examine the notification counter before we check if `state` has changed,
+            // to make sure we don't miss any notifications.
+            let seq = self.writer_notify.load(Acquire);
+
+            // Don't go to sleep if the other readers are gone already. The writers waiting bit
+            // can't be cleared while we hold a read lock.
+            state = self.state.load(Relaxed);
+            if state & MASK == READ_LOCKED {
+                continue;
+            }
+
+            // Wait for the last other reader to wake us.
+            futex_wait(&self.writer_notify, seq, None);
+
+            state = self.state.load(Relaxed);
+        }
+    }
+
     #[inline]
     pub fn try_write(&self) -> bool {
         self.state
@@ -290,6 +480,16 @@ fn wake_writer(&self) -> bool {
         // well in case both readers and writers were waiting.
     }
 
+    /// Wakes up the holder of the upgradable read lock if it is waiting to upgrade.
+    ///
+    /// It waits on the same futex as writers and there is no telling them apart, so everyone is
+    /// woken up. Writers will find the lock still locked and go back to sleep.
+    #[cold]
+    fn wake_upgrader(&self) {
+        self.writer_notify.fetch_add(1, Release);
+        futex_wake_all(&self.writer_notify);
+    }
+
     /// Spin for a while, but stop directly at the given condition.
     #[inline]
     fn spin_until(&self, f: impl Fn(u32) -> bool) -> u32 {
@@ -310,6 +510,16 @@ fn spin_write(&self) -> u32 {
         self.spin_until(|state| is_unlocked(state) || has_writers_waiting(state))
     }
 
+    #[inline]
+    fn spin_read_upgradable(&self) -> u32 {
+        // Stop spinning when it's lockable, or when there's waiting threads.
+        self.spin_until(|state| {
+            is_upgradable_read_lockable(state)
+                || has_readers_waiting(state)
+                || has_writers_waiting(state)
+        })
+    }
+
     #[inline]
     fn spin_read(&self) -> u32 {
         // Stop spinning when it's unlocked or read locked, or when there's waiting threads.
@@ -192,4 +192,29 @@ pub unsafe fn write_unlock(&self) {
         *lock.write_locked.get() = false;
         lock.raw_unlock();
     }
+
+    #[inline]
+    pub fn read_upgradable(&self) {
+        self.write()
+    }
+
+    #[inline]
+    pub fn try_read_upgradable(&self) -> bool {
+        // SAFETY: like `write`, `try_write` only accesses `write_locked` once
+        // `pthread_rwlock_trywrlock` succeeded, so it is sound to call at any time.
+        unsafe { self.try_write() }
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        self.write_unlock()
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {}
+
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        true
+    }
 }
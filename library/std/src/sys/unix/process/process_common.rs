@@ -4,10 +4,12 @@
 use crate::os::unix::prelude::*;
 
 use crate::collections::BTreeMap;
+use crate::env;
 use crate::ffi::{CStr, CString, OsStr, OsString};
 use crate::fmt;
+use crate::fs;
 use crate::io;
-use crate::path::Path;
+use crate::path::{Path, PathBuf};
 use crate::ptr;
 use crate::sys::fd::FileDesc;
 use crate::sys::fs::File;
@@ -177,6 +179,26 @@ fn new(program: &OsStr) -> Self {
     }
 }
 
+/// Finds the file `execvp` would run for `program`, searching `PATH` only if `program` is a bare
+/// file name.
+pub fn find_in_path(program: &OsStr) -> Option<PathBuf> {
+    if program.is_empty() {
+        return None;
+    }
+    let is_executable = |path: &Path| {
+        fs::metadata(path).is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+    };
+    if ProgramKind::new(program) != ProgramKind::PathLookup {
+        let path = PathBuf::from(program);
+        return is_executable(&path).then_some(path);
+    }
+    // Like `execvp`, fall back to a default search path if `PATH` isn't set. Empty entries stand
+    // for the current directory, which `join` gives us for free.
+    let paths = env::var_os("PATH");
+    let paths = paths.as_deref().unwrap_or(OsStr::new("/bin:/usr/bin"));
+    env::split_paths(paths).map(|dir| dir.join(program)).find(|path| is_executable(path))
+}
+
 impl Command {
     #[cfg(not(target_os = "linux"))]
     pub fn new(program: &OsStr) -> Command {
@@ -62,4 +62,28 @@ pub unsafe fn read_unlock(&self) {
     pub unsafe fn write_unlock(&self) {
         assert_eq!(self.mode.replace(0), -1);
     }
+
+    #[inline]
+    pub fn read_upgradable(&self) {
+        self.write()
+    }
+
+    #[inline]
+    pub fn try_read_upgradable(&self) -> bool {
+        self.try_write()
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        // SAFETY: upgradable read locks are write locks here.
+        unsafe { self.write_unlock() }
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {}
+
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        true
+    }
 }
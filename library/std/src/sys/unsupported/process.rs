@@ -3,7 +3,7 @@
 use crate::io;
 use crate::marker::PhantomData;
 use crate::num::NonZeroI32;
-use crate::path::Path;
+use crate::path::{Path, PathBuf};
 use crate::sys::fs::File;
 use crate::sys::pipe::AnonPipe;
 use crate::sys::unsupported;
@@ -11,6 +11,10 @@
 
 pub use crate::ffi::OsString as EnvKey;
 
+pub fn find_in_path(_program: &OsStr) -> Option<PathBuf> {
+    None
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Command
 ////////////////////////////////////////////////////////////////////////////////
@@ -37,4 +37,27 @@ pub unsafe fn read_unlock(&self) {
     pub unsafe fn write_unlock(&self) {
         c::ReleaseSRWLockExclusive(self.inner.get())
     }
+
+    #[inline]
+    pub fn read_upgradable(&self) {
+        self.write()
+    }
+
+    #[inline]
+    pub fn try_read_upgradable(&self) -> bool {
+        self.try_write()
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        self.write_unlock()
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {}
+
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        true
+    }
 }
@@ -477,16 +477,47 @@ fn resolve_exe<'a>(
     Err(io::const_io_error!(io::ErrorKind::NotFound, "program not found"))
 }
 
+// Finds the file `resolve_exe` would pick for `exe_path` when spawning with the parent's `PATH`,
+// checking that it exists even if `exe_path` isn't searched for.
+pub fn find_in_path(exe_path: &OsStr) -> Option<PathBuf> {
+    if exe_path.is_empty() || path::has_trailing_slash(exe_path) {
+        return None;
+    }
+    let exists = |path: PathBuf| program_exists(&path).map(|_| path);
+    if !path::is_file_name(exe_path) {
+        let path = PathBuf::from(exe_path);
+        let has_exe_suffix =
+            path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case(EXE_EXTENSION));
+        if has_exe_suffix {
+            return exists(path);
+        }
+        return exists(path::append_suffix(path.clone(), EXE_SUFFIX.as_ref()))
+            .or_else(|| exists(path));
+    }
+    let has_extension = exe_path.as_encoded_bytes().contains(&b'.');
+    search_paths(
+        || env::var_os("PATH"),
+        None,
+        |mut path| {
+            path.push(exe_path);
+            if !has_extension {
+                path.set_extension(EXE_EXTENSION);
+            }
+            exists(path)
+        },
+    )
+}
+
 // Calls `f` for every path that should be used to find an executable.
 // Returns once `f` returns the path to an executable or all paths have been searched.
-fn search_paths<Paths, Exists>(
+fn search_paths<Paths, Exists, T>(
     parent_paths: Paths,
     child_paths: Option<&OsStr>,
     mut exists: Exists,
-) -> Option<Vec<u16>>
+) -> Option<T>
 where
     Paths: FnOnce() -> Option<OsString>,
-    Exists: FnMut(PathBuf) -> Option<Vec<u16>>,
+    Exists: FnMut(PathBuf) -> Option<T>,
 {
     // 1. Child paths
     // This is for consistency with Rust's historic behaviour.
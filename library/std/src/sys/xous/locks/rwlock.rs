@@ -69,4 +69,28 @@ pub unsafe fn read_unlock(&self) {
     pub unsafe fn write_unlock(&self) {
         assert_eq!(self.mode.compare_exchange(-1, 0, SeqCst, SeqCst), Ok(-1));
     }
+
+    #[inline]
+    pub fn read_upgradable(&self) {
+        unsafe { self.write() }
+    }
+
+    #[inline]
+    pub fn try_read_upgradable(&self) -> bool {
+        unsafe { self.try_write() }
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        // SAFETY: upgradable read locks are write locks here.
+        unsafe { self.write_unlock() }
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {}
+
+    #[inline]
+    pub unsafe fn try_upgrade(&self) -> bool {
+        true
+    }
 }
@@ -0,0 +1,56 @@
+//! The CPU feature detection hook of `core::arch`, backed by `std_detect`.
+//!
+//! The hook is registered lazily, by the first detection done through `std::arch`, so that the
+//! runtime does no work for it before `main`.
+
+use crate::arch::CpuFeatures;
+use crate::sync::Once;
+
+/// Returns whether the CPU the program runs on supports the feature with the given index.
+///
+/// This is [`core::arch::detect_cpu_feature`], except that the hook of the standard library is
+/// registered first, unless the program registered its own.
+#[inline]
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+pub fn detect_cpu_feature(feature: u32) -> bool {
+    register_hook();
+    core::arch::detect_cpu_feature(feature)
+}
+
+/// Returns the set of CPU features detected at runtime.
+///
+/// This is [`core::arch::cpu_features`], except that the hook of the standard library is
+/// registered first, unless the program registered its own.
+#[unstable(feature = "cpu_features_snapshot", issue = "none")]
+pub fn cpu_features() -> CpuFeatures {
+    register_hook();
+    core::arch::cpu_features()
+}
+
+/// Returns whether the CPU the program runs on supports a feature, without querying the hook if the
+/// feature is enabled at compile time.
+///
+/// This is [`core::arch::is_cpu_feature_detected!`], except that the hook of the standard library
+/// is registered first, unless the program registered its own.
+#[unstable(feature = "cpu_feature_hook", issue = "none")]
+#[allow_internal_unstable(cpu_feature_hook)]
+#[rustc_macro_transparency = "semitransparent"]
+pub macro is_cpu_feature_detected($name:literal, $index:expr $(,)?) {
+    $crate::cfg!(target_feature = $name) || $crate::arch::detect_cpu_feature($index)
+}
+
+#[inline]
+fn register_hook() {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        #[cfg(target_has_atomic = "ptr")]
+        core::arch::set_default_cpu_feature_hook(detect);
+    });
+}
+
+#[cfg(target_has_atomic = "ptr")]
+fn detect(feature: u32) -> bool {
+    // `core::arch` identifies features by their index in the list of `std_detect`, which caches
+    // the results itself.
+    std_detect::detect::features().nth(feature as usize).is_some_and(|(_, detected)| detected)
+}
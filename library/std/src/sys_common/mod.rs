@@ -21,6 +21,7 @@
 mod tests;
 
 pub mod backtrace;
+pub mod cpu_features;
 pub mod fs;
 pub mod io;
 pub mod lazy_box;
@@ -1,6 +1,6 @@
 #![allow(dead_code)] // stack_guard isn't used right now on all platforms
 
-use crate::cell::OnceCell;
+use crate::cell::{Cell, OnceCell};
 use crate::sys::thread::guard::Guard;
 use crate::thread::Thread;
 
@@ -14,16 +14,39 @@ struct ThreadInfo {
        stack_guard: OnceCell::new(),
        thread: OnceCell::new()
    } };
+
+   // Set for the main thread by the runtime, along with the guard of its
+   // stack, and moved into `THREAD_INFO` when that is initialized. Unlike
+   // `THREAD_INFO`, this has no destructor to register, which may allocate.
+   // On targets without `#[thread_local]` support, its first use still
+   // allocates, so the runtime does allocate before `main` there.
+   static MAIN_THREAD: Cell<Option<Option<Guard>>> = const { Cell::new(None) };
+
+   // Whether the `Thread` handle in `THREAD_INFO` was created, which can be
+   // checked without registering the destructor of `THREAD_INFO`.
+   static HAS_THREAD: Cell<bool> = const { Cell::new(false) };
 }
 
 impl ThreadInfo {
+    #[allow(unreachable_code, unreachable_patterns)] // some platforms don't use stack_guard
     fn with<R, F>(f: F) -> Option<R>
     where
         F: FnOnce(&Thread, &OnceCell<Guard>) -> R,
     {
         THREAD_INFO
             .try_with(move |thread_info| {
-                let thread = thread_info.thread.get_or_init(|| Thread::new(None));
+                let thread = thread_info.thread.get_or_init(|| {
+                    HAS_THREAD.set(true);
+                    match MAIN_THREAD.take() {
+                        Some(stack_guard) => {
+                            if let Some(guard) = stack_guard {
+                                thread_info.stack_guard.set(guard).unwrap();
+                            }
+                            Thread::new_main()
+                        }
+                        None => Thread::new(None),
+                    }
+                });
                 f(thread, &thread_info.stack_guard)
             })
             .ok()
@@ -35,7 +58,26 @@ pub fn current_thread() -> Option<Thread> {
 }
 
 pub fn stack_guard() -> Option<Guard> {
-    ThreadInfo::with(|_, guard| guard.get().cloned()).flatten()
+    // This is called by the stack overflow handler, so unlike `current_thread`
+    // it doesn't create the `Thread` handle of the main thread.
+    let main_thread = MAIN_THREAD.take();
+    MAIN_THREAD.set(main_thread.clone());
+    match main_thread {
+        Some(stack_guard) => stack_guard,
+        None => THREAD_INFO
+            .try_with(|thread_info| thread_info.stack_guard.get().cloned())
+            .ok()
+            .flatten(),
+    }
+}
+
+/// Mark the current thread as the main thread, without allocating, panicking
+/// if its thread info has already been initialized
+pub fn set_main_thread(stack_guard: Option<Guard>) {
+    // Code running before the runtime, e.g. from `.init_array`, may have
+    // created an unnamed handle for this thread already.
+    rtassert!(!HAS_THREAD.get());
+    MAIN_THREAD.set(Some(stack_guard));
 }
 
 /// Set new thread info, panicking if it has already been initialized
@@ -1232,9 +1232,16 @@ pub fn as_u64(&self) -> NonZeroU64 {
 // Thread
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The name of a thread, which for the main thread isn't allocated
+enum ThreadName {
+    Main,
+    Other(CString), // Guaranteed to be UTF-8
+    Unnamed,
+}
+
 /// The internal representation of a `Thread` handle
 struct Inner {
-    name: Option<CString>, // Guaranteed to be UTF-8
+    name: ThreadName,
     id: ThreadId,
     parker: Parker,
 }
@@ -1273,6 +1280,15 @@ impl Thread {
     // Used only internally to construct a thread object without spawning
     // Panics if the name contains nuls.
     pub(crate) fn new(name: Option<CString>) -> Thread {
+        Self::new_inner(name.map_or(ThreadName::Unnamed, ThreadName::Other))
+    }
+
+    // Used only internally to construct the thread object of the main thread
+    pub(crate) fn new_main() -> Thread {
+        Self::new_inner(ThreadName::Main)
+    }
+
+    fn new_inner(name: ThreadName) -> Thread {
         // We have to use `unsafe` here to construct the `Parker` in-place,
         // which is required for the UNIX implementation.
         //
@@ -1390,7 +1406,12 @@ pub fn name(&self) -> Option<&str> {
     }
 
     fn cname(&self) -> Option<&CStr> {
-        self.inner.name.as_deref()
+        match &self.inner.name {
+            // SAFETY: the byte string is nul-terminated, without interior nuls.
+            ThreadName::Main => Some(unsafe { CStr::from_bytes_with_nul_unchecked(b"main\0") }),
+            ThreadName::Other(name) => Some(name),
+            ThreadName::Unnamed => None,
+        }
     }
 }
 
@@ -37,6 +37,8 @@
 use crate::error::Error;
 use crate::fmt;
 use crate::ops::{Add, AddAssign, Sub, SubAssign};
+use crate::sync::OnceLock;
+use crate::sys::common::ticks;
 use crate::sys::time;
 use crate::sys_common::{FromInner, IntoInner};
 
@@ -467,6 +469,130 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// A cheap, monotonically increasing tick count.
+///
+/// `Ticks` is meant for high-frequency timestamping, e.g. in profilers, tracing or lock-free
+/// code that needs to order events, where even the overhead of [`Instant::now`] can be
+/// significant. Reading it does not involve a system call on platforms with a suitable hardware
+/// counter.
+///
+/// The unit of a `Ticks` value is unspecified: use [`Ticks::frequency`] or
+/// [`Ticks::duration_since`] to relate it to wall time. Values are only comparable within one
+/// process.
+///
+/// # Underlying counters
+///
+/// | Platform | Counter                                                          |
+/// |----------|------------------------------------------------------------------|
+/// | x86_64   | `rdtsc`, only if the CPU advertises an invariant TSC             |
+/// | AArch64  | `cntvct_el0`, the generic timer's virtual count                  |
+/// | Other    | Nanoseconds since a process-wide [`Instant`]                     |
+///
+/// When no hardware counter is available (including on x86_64 CPUs without an invariant TSC),
+/// `Ticks` falls back to [`Instant`] and has the same cost and monotonicity guarantees.
+///
+/// Hardware counters are not synchronized by the operating system, so on buggy hardware or
+/// hypervisors readings taken on different cores may be slightly out of order. Like
+/// [`Instant::duration_since`], the methods on this type saturate to zero rather than panic.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(time_ticks)]
+/// use std::time::Ticks;
+///
+/// let start = Ticks::now();
+/// let end = Ticks::now();
+/// assert!(end >= start);
+/// println!("took {} ticks ({:?})", end.ticks_since(start), end.duration_since(start));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[unstable(feature = "time_ticks", issue = "none")]
+pub struct Ticks(u64);
+
+impl Ticks {
+    /// Returns the current tick count.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "time_ticks", issue = "none")]
+    pub fn now() -> Ticks {
+        match ticks::read() {
+            Some(ticks) => Ticks(ticks),
+            None => Ticks(ticks_fallback_anchor().elapsed().as_nanos() as u64),
+        }
+    }
+
+    /// Returns the number of ticks per second.
+    ///
+    /// If the hardware counter's frequency is not architecturally exposed (as is the case for
+    /// the x86 TSC), it is calibrated against [`Instant`] on the first call, which busy-waits
+    /// for a few milliseconds. The result is cached for the rest of the process.
+    #[must_use]
+    #[unstable(feature = "time_ticks", issue = "none")]
+    pub fn frequency() -> u64 {
+        static FREQUENCY: OnceLock<u64> = OnceLock::new();
+
+        *FREQUENCY.get_or_init(|| {
+            if ticks::read().is_none() {
+                return NANOS_PER_SEC;
+            }
+            if let Some(frequency) = ticks::frequency() {
+                return frequency;
+            }
+
+            let start_instant = Instant::now();
+            let start = Ticks::now();
+            while start_instant.elapsed() < TICKS_CALIBRATION_PERIOD {
+                crate::hint::spin_loop();
+            }
+            let end = Ticks::now();
+            let nanos = start_instant.elapsed().as_nanos();
+
+            let frequency = end.ticks_since(start) as u128 * NANOS_PER_SEC as u128 / nanos;
+            (frequency as u64).max(1)
+        })
+    }
+
+    /// Returns the number of ticks elapsed from `earlier` to this tick count, or zero if
+    /// `earlier` is later than `self`.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "time_ticks", issue = "none")]
+    pub fn ticks_since(&self, earlier: Ticks) -> u64 {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    /// Returns the amount of time elapsed from `earlier` to this tick count, or zero duration
+    /// if `earlier` is later than `self`.
+    ///
+    /// This calls [`Ticks::frequency`], so the first call may need to calibrate the counter.
+    #[must_use]
+    #[unstable(feature = "time_ticks", issue = "none")]
+    pub fn duration_since(&self, earlier: Ticks) -> Duration {
+        let ticks = self.ticks_since(earlier);
+        let frequency = Ticks::frequency();
+        let secs = ticks / frequency;
+        let nanos = (ticks % frequency) as u128 * NANOS_PER_SEC as u128 / frequency as u128;
+        Duration::new(secs, nanos as u32)
+    }
+
+    /// Returns the amount of time elapsed since this tick count.
+    #[must_use]
+    #[unstable(feature = "time_ticks", issue = "none")]
+    pub fn elapsed(&self) -> Duration {
+        Ticks::now().duration_since(*self)
+    }
+}
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+const TICKS_CALIBRATION_PERIOD: Duration = Duration::from_millis(5);
+
+/// The origin of the [`Instant`]-based fallback for [`Ticks`].
+fn ticks_fallback_anchor() -> Instant {
+    static ANCHOR: OnceLock<Instant> = OnceLock::new();
+    *ANCHOR.get_or_init(Instant::now)
+}
+
 impl SystemTime {
     /// An anchor in time which can be used to create new `SystemTime` instances or
     /// learn about where in time a `SystemTime` lies.
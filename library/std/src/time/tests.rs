@@ -1,4 +1,4 @@
-use super::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use super::{Duration, Instant, SystemTime, Ticks, UNIX_EPOCH};
 use core::fmt::Debug;
 #[cfg(not(target_arch = "wasm32"))]
 use test::{black_box, Bencher};
@@ -53,6 +53,29 @@ fn instant_elapsed() {
     let _ = a.elapsed();
 }
 
+#[test]
+fn ticks_monotonic() {
+    let a = Ticks::now();
+    let b = Ticks::now();
+    assert!(b >= a);
+    assert_eq!(a.ticks_since(b), 0);
+    assert_eq!(a.duration_since(b), Duration::ZERO);
+}
+
+#[test]
+fn ticks_duration() {
+    let start = Ticks::now();
+    let instant = Instant::now();
+    while instant.elapsed() < Duration::from_millis(10) {}
+    let end = Ticks::now();
+
+    assert!(Ticks::frequency() > 0);
+    // The counter may be calibrated against `Instant`, so only check for gross errors.
+    let elapsed = end.duration_since(start);
+    assert!(elapsed >= Duration::from_millis(5), "{elapsed:?}");
+    assert!(elapsed < Duration::from_secs(5), "{elapsed:?}");
+}
+
 #[test]
 fn instant_math() {
     let a = Instant::now();
@@ -1,5 +1,5 @@
 use crate::msrvs::Msrv;
-use crate::types::{DisallowedPath, MacroMatcher, MatchLintBehaviour, Rename};
+use crate::types::{AsyncRuntime, DisallowedPath, MacroMatcher, MatchLintBehaviour, Rename};
 use crate::ClippyConfiguration;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_session::Session;
@@ -37,6 +37,13 @@
 ];
 const DEFAULT_DISALLOWED_NAMES: &[&str] = &["foo", "baz", "quux"];
 const DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS: &[&str] = &["i", "j", "x", "y", "z", "w", "n"];
+const DEFAULT_ALLOWED_TRAITS_WITH_RENAMED_PARAMS: &[&str] =
+    &["core::convert::From", "core::convert::TryFrom", "core::str::FromStr"];
+const DEFAULT_UNBOUNDED_CHANNEL_CONSTRUCTORS: &[&str] = &[
+    "std::sync::mpsc::channel",
+    "tokio::sync::mpsc::unbounded_channel",
+    "crossbeam_channel::unbounded",
+];
 
 /// Conf with parse errors
 #[derive(Default)]
@@ -249,7 +256,7 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     ///
     /// Suppress lints whenever the suggested change would cause breakage for other crates.
     (avoid_breaking_exported_api: bool = true),
-    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD, MANUAL_HASH_ONE, ITER_KV_MAP.
+    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD, MANUAL_HASH_ONE, ITER_KV_MAP, MANUAL_TRY_INTO_ARRAY, MANUAL_ARRAY_MAP, MANUAL_DIV_CEIL, MANUAL_ABS_DIFF, REDUNDANT_SORT_BY, MANUAL_ILOG2, MANUAL_OPTION_ZIP, MANUAL_ONCE_CELL, TUPLE_ARRAY_TRANSMUTE, MANUAL_C_STR_LITERAL, MANUAL_INSPECT, MANUAL_IS_VARIANT_AND.
     ///
     /// The minimum rust version that the project supports. Defaults to the `rust-version` field in `Cargo.toml`
     #[default_text = ""]
@@ -547,6 +554,93 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     ///
     /// Whether to also run the listed lints on private items.
     (check_private_items: bool = false),
+    /// Lint: TUPLE_ARRAY_CONVERSIONS.
+    ///
+    /// The minimum number of elements a tuple or array must have for its conversion to be linted
+    (tuple_array_conversions_min_size: u64 = 1),
+    /// Lint: TUPLE_ARRAY_CONVERSIONS.
+    ///
+    /// Whether to skip conversions of bindings with distinct names, such as `[x, y]` or
+    /// `(width, height)`. Bindings that only differ in a numeric suffix, like `[a0, a1]`, are still linted.
+    (ignore_heterogeneous_names: bool = false),
+    /// Lint: GLOBAL_ALLOCATOR_IN_LIBRARY.
+    ///
+    /// Library crates that are allowed to define a `#[global_allocator]`, such as crates whose
+    /// purpose is to provide one
+    (global_allocator_allowed_crates: FxHashSet<String> = FxHashSet::default()),
+    /// Lint: ALLOCATION_IN_HANDLER.
+    ///
+    /// Additional functions that must not allocate, such as interrupt handlers, given by name or by
+    /// their path from the crate root, like `irq::timer_tick`
+    (no_alloc_fns: FxHashSet<String> = FxHashSet::default()),
+    /// Lint: BLOCKING_OP_IN_ASYNC.
+    ///
+    /// Additional functions to treat as blocking, given by path, optionally with a reason that is
+    /// shown in the warning, such as the async alternative to use
+    (blocking_functions: Vec<DisallowedPath> = Vec::new()),
+    /// Lint: UNBOUNDED_CHANNELS.
+    ///
+    /// Paths to functions that create unbounded channels. The value `".."` can be used as part of the
+    /// list to indicate that the configured values should be appended to the default list.
+    (unbounded_channel_constructors: Vec<String> =
+        DEFAULT_UNBOUNDED_CHANNEL_CONSTRUCTORS.iter().map(ToString::to_string).collect()),
+    /// Lint: TUPLE_STRUCT_ARRAY_CONVERSIONS.
+    ///
+    /// Tuple structs that `tuple_struct_array_conversions` should not lint, given as paths to the types
+    /// or to the crates or modules containing them, e.g. `["nalgebra", "my_crate::geometry::Point"]`.
+    (tuple_struct_conversion_allowed_types: Vec<String> = Vec::new()),
+    /// Lint: MUTEX_ATOMIC, MUTEX_INTEGER.
+    ///
+    /// Whether to only lint `static`s, which are the most likely to be simple flags and counters
+    (mutex_atomic_statics_only: bool = false),
+    /// Lint: SLEEP_IN_ASYNC.
+    ///
+    /// The async runtime whose timer to suggest, either `Tokio` or `AsyncStd`.
+    (async_runtime: AsyncRuntime = AsyncRuntime::Tokio),
+    /// Lint: MULTIPLE_CRATE_VERSIONS.
+    ///
+    /// A list of crate names to allow duplicates of
+    (allowed_duplicate_crates: FxHashSet<String> = FxHashSet::default()),
+    /// Lint: MULTIPLE_CRATE_VERSIONS.
+    ///
+    /// The depth of the dependency tree to look for duplicated crates in, where direct dependencies
+    /// are at depth 1. By default the whole tree is searched
+    #[default_text = ""]
+    (duplicate_crates_max_depth: Option<u64> = None),
+    /// Lint: RENAMED_FUNCTION_PARAMS.
+    ///
+    /// List of trait paths to ignore when checking renamed function parameters.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// allow-renamed-params-for = [ "std::convert::From" ]
+    /// ```
+    ///
+    /// #### Noteworthy
+    ///
+    /// - By default, the following traits are ignored: `From`, `TryFrom`, `FromStr`
+    /// - `".."` can be used as part of the list to indicate that the configured values should be appended to the
+    /// default configuration of Clippy. By default, any configuration will replace the default value.
+    (allow_renamed_params_for: Vec<String> =
+        DEFAULT_ALLOWED_TRAITS_WITH_RENAMED_PARAMS.iter().map(ToString::to_string).collect()),
+    /// Lint: SINGLE_CALL_FN.
+    ///
+    /// Functions to ignore, given as patterns matched against their path, like `ffi::bindings_*`, where
+    /// `*` matches any sequence of characters. Useful for generated code, e.g. `*_bindgen*`.
+    (single_call_fn_ignore_patterns: Vec<String> = Vec::new()),
+    /// Lint: SINGLE_CALL_FN.
+    ///
+    /// Whether to ignore functions marked with `#[inline]`.
+    (single_call_fn_ignore_inline: bool = false),
+    /// Lint: SINGLE_CALL_FN.
+    ///
+    /// Whether to ignore methods of traits, including their implementations.
+    (single_call_fn_ignore_trait_methods: bool = false),
+    /// Lint: SINGLE_CALL_FN.
+    ///
+    /// Whether to also count calls from tests, which are ignored by default.
+    (single_call_fn_count_test_calls: bool = false),
 }
 
 /// Search for the configuration file.
@@ -607,6 +701,14 @@ fn deserialize(file: &SourceFile) -> TryConf {
         Ok(mut conf) => {
             extend_vec_if_indicator_present(&mut conf.conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
             extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
+            extend_vec_if_indicator_present(
+                &mut conf.conf.unbounded_channel_constructors,
+                DEFAULT_UNBOUNDED_CHANNEL_CONSTRUCTORS,
+            );
+            extend_vec_if_indicator_present(
+                &mut conf.conf.allow_renamed_params_for,
+                DEFAULT_ALLOWED_TRAITS_WITH_RENAMED_PARAMS,
+            );
             // TODO: THIS SHOULD BE TESTED, this comment will be gone soon
             if conf.conf.allowed_idents_below_min_chars.contains(&"..".to_owned()) {
                 conf.conf
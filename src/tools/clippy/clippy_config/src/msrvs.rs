@@ -16,20 +16,25 @@ macro_rules! msrv_aliases {
 
 // names may refer to stabilized feature flags or library items
 msrv_aliases! {
+    1,77,0 { C_STR_LITERALS }
+    1,76,0 { OPTION_RESULT_INSPECT }
+    1,73,0 { MANUAL_DIV_CEIL }
     1,71,0 { TUPLE_ARRAY_CONVERSIONS, BUILD_HASHER_HASH_ONE }
-    1,70,0 { OPTION_IS_SOME_AND, BINARY_HEAP_RETAIN }
+    1,70,0 { OPTION_IS_SOME_AND, BINARY_HEAP_RETAIN, ONCE_LOCK }
     1,68,0 { PATH_MAIN_SEPARATOR_STR }
+    1,67,0 { ILOG2 }
     1,65,0 { LET_ELSE, POINTER_CAST_CONSTNESS }
-    1,62,0 { BOOL_THEN_SOME, DEFAULT_ENUM_ATTRIBUTE }
+    1,62,0 { BOOL_THEN_SOME, DEFAULT_ENUM_ATTRIBUTE, TOTAL_CMP }
+    1,60,0 { ABS_DIFF }
     1,58,0 { FORMAT_ARGS_CAPTURE, PATTERN_TRAIT_CHAR_ARRAY }
-    1,55,0 { SEEK_REWIND }
+    1,55,0 { SEEK_REWIND, ARRAY_MAP }
     1,54,0 { INTO_KEYS }
     1,53,0 { OR_PATTERNS, MANUAL_BITS, BTREE_MAP_RETAIN, BTREE_SET_RETAIN, ARRAY_INTO_ITERATOR }
     1,52,0 { STR_SPLIT_ONCE, REM_EUCLID_CONST }
     1,51,0 { BORROW_AS_PTR, SEEK_FROM_CURRENT, UNSIGNED_ABS }
     1,50,0 { BOOL_THEN, CLAMP }
     1,47,0 { TAU, IS_ASCII_DIGIT_CONST, ARRAY_IMPL_ANY_LEN }
-    1,46,0 { CONST_IF_MATCH }
+    1,46,0 { CONST_IF_MATCH, OPTION_ZIP }
     1,45,0 { STR_STRIP_PREFIX }
     1,43,0 { LOG2_10, LOG10_2 }
     1,42,0 { MATCHES_MACRO, SLICE_PATTERNS, PTR_SLICE_RAW_PARTS }
@@ -52,9 +57,13 @@ macro_rules! msrv_aliases {
 }
 
 /// Tracks the current MSRV from `clippy.toml`, `Cargo.toml` or set via `#[clippy::msrv]`
+///
+/// The attribute can be placed on any item, including modules and `impl` blocks, and applies to
+/// everything inside of it. Each item with the attribute pushes an entry when it's entered and pops
+/// it when it's left, so that an invalid attribute keeps the outer MSRV for its scope.
 #[derive(Debug, Clone)]
 pub struct Msrv {
-    stack: Vec<RustcVersion>,
+    stack: Vec<Option<RustcVersion>>,
 }
 
 impl<'de> Deserialize<'de> for Msrv {
@@ -64,7 +73,7 @@ fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     {
         let v = String::deserialize(deserializer)?;
         RustcVersion::parse(&v)
-            .map(|v| Msrv { stack: vec![v] })
+            .map(|v| Msrv { stack: vec![Some(v)] })
             .map_err(|_| serde::de::Error::custom("not a valid Rust version"))
     }
 }
@@ -80,7 +89,7 @@ pub fn read_cargo(&mut self, sess: &Session) {
             .and_then(|v| RustcVersion::parse(&v).ok());
 
         match (self.current(), cargo_msrv) {
-            (None, Some(cargo_msrv)) => self.stack = vec![cargo_msrv],
+            (None, Some(cargo_msrv)) => self.stack = vec![Some(cargo_msrv)],
             (Some(clippy_msrv), Some(cargo_msrv)) => {
                 if clippy_msrv != cargo_msrv {
                     sess.warn(format!(
@@ -93,16 +102,22 @@ pub fn read_cargo(&mut self, sess: &Session) {
     }
 
     pub fn current(&self) -> Option<RustcVersion> {
-        self.stack.last().copied()
+        self.stack.last().copied().flatten()
     }
 
     pub fn meets(&self, required: RustcVersion) -> bool {
         self.current().map_or(true, |version| version.meets(required))
     }
 
-    fn parse_attr(sess: &Session, attrs: &[Attribute]) -> Option<RustcVersion> {
+    fn msrv_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
         let sym_msrv = Symbol::intern("msrv");
-        let mut msrv_attrs = attrs.iter().filter(|attr| attr.path_matches(&[sym::clippy, sym_msrv]));
+        attrs
+            .iter()
+            .filter(move |attr| attr.path_matches(&[sym::clippy, sym_msrv]))
+    }
+
+    fn parse_attr(sess: &Session, attrs: &[Attribute]) -> Option<RustcVersion> {
+        let mut msrv_attrs = Self::msrv_attrs(attrs);
 
         if let Some(msrv_attr) = msrv_attrs.next() {
             if let Some(duplicate) = msrv_attrs.last() {
@@ -126,13 +141,15 @@ fn parse_attr(sess: &Session, attrs: &[Attribute]) -> Option<RustcVersion> {
     }
 
     pub fn enter_lint_attrs(&mut self, sess: &Session, attrs: &[Attribute]) {
-        if let Some(version) = Self::parse_attr(sess, attrs) {
+        if Self::msrv_attrs(attrs).next().is_some() {
+            let version = Self::parse_attr(sess, attrs).or_else(|| self.current());
             self.stack.push(version);
         }
     }
 
-    pub fn exit_lint_attrs(&mut self, sess: &Session, attrs: &[Attribute]) {
-        if Self::parse_attr(sess, attrs).is_some() {
+    pub fn exit_lint_attrs(&mut self, _sess: &Session, attrs: &[Attribute]) {
+        // The attribute was parsed, and any error reported, on the way in.
+        if Self::msrv_attrs(attrs).next().is_some() {
             self.stack.pop();
         }
     }
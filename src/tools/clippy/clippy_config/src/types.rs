@@ -39,6 +39,12 @@ pub enum MatchLintBehaviour {
     Never,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum AsyncRuntime {
+    Tokio,
+    AsyncStd,
+}
+
 #[derive(Debug)]
 pub struct MacroMatcher {
     pub name: String,
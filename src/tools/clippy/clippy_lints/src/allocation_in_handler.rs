@@ -0,0 +1,215 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::macros::macro_backtrace;
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{path_def_id, peel_blocks};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{Expr, ExprKind, Item, ItemKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, GenericArgsRef, Instance, TypeckResults};
+use rustc_session::impl_lint_pass;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls that may allocate in a `#[panic_handler]`, an `#[alloc_error_handler]`, or
+    /// a function listed in the `no-alloc-fns` configuration, such as an interrupt handler.
+    ///
+    /// Calls into the `alloc` crate are considered allocating, like `format!`, `String::from` or
+    /// `Vec::push`, except for `const fn`s and methods that borrow `self` and return a value
+    /// without a destructor, like `Vec::len`. Calls to functions of the current crate are
+    /// followed, so a helper that allocates is caught too.
+    ///
+    /// ### Why is this bad?
+    /// These functions can run while the allocator is unusable: after it failed, while it holds a
+    /// lock that the interrupted code took, or on a target where allocating from an interrupt is
+    /// not allowed at all. Allocating there can recurse, deadlock or corrupt the heap.
+    ///
+    /// ### Known problems
+    /// Allocations behind generic code from other crates, such as `collect` into a `Vec`, or
+    /// behind trait objects and function pointers, are not detected.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[panic_handler]
+    /// fn panic(info: &PanicInfo) -> ! {
+    ///     let message = format!("{info}");
+    ///     serial::write(message.as_bytes());
+    ///     loop {}
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #[panic_handler]
+    /// fn panic(info: &PanicInfo) -> ! {
+    ///     // `serial::Port` implements `core::fmt::Write` and writes straight to the device
+    ///     let _ = write!(serial::Port, "{info}");
+    ///     loop {}
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub ALLOCATION_IN_HANDLER,
+    restriction,
+    "allocating in a function that must not allocate, like a `#[panic_handler]`"
+}
+
+pub struct AllocationInHandler {
+    no_alloc_fns: FxHashSet<String>,
+}
+
+impl AllocationInHandler {
+    pub fn new(no_alloc_fns: FxHashSet<String>) -> Self {
+        Self { no_alloc_fns }
+    }
+
+    /// Describes why `item` must not allocate, if it mustn't.
+    fn handler_kind(&self, cx: &LateContext<'_>, item: &Item<'_>) -> Option<(LocalDefId, &'static str)> {
+        let ItemKind::Fn(_, _, body) = item.kind else {
+            return None;
+        };
+        let def_id = item.owner_id.def_id;
+        if cx
+            .tcx
+            .hir()
+            .attrs(item.hir_id())
+            .iter()
+            .any(|attr| attr.has_name(sym::panic_handler))
+        {
+            Some((def_id, "a `#[panic_handler]`"))
+        } else if self.no_alloc_fns.contains(item.ident.as_str())
+            || self.no_alloc_fns.contains(&cx.tcx.def_path_str(def_id))
+        {
+            Some((def_id, "a function listed in `no-alloc-fns`"))
+        } else if macro_backtrace(item.span).any(|macro_call| {
+            cx.tcx.item_name(macro_call.def_id) == sym::alloc_error_handler
+                && cx.tcx.crate_name(macro_call.def_id.krate) == sym::core
+        })
+            // The attribute expands to a shim that calls the handler, which is all we can see of it.
+            && let ExprKind::Call(handler, _) = peel_blocks(cx.tcx.hir().body(body).value).kind
+            && let Some(handler) = path_def_id(cx, handler).and_then(DefId::as_local)
+        {
+            Some((handler, "an `#[alloc_error_handler]`"))
+        } else {
+            None
+        }
+    }
+}
+
+impl_lint_pass!(AllocationInHandler => [ALLOCATION_IN_HANDLER]);
+
+impl LateLintPass<'_> for AllocationInHandler {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        let hir = cx.tcx.hir();
+        for id in hir.items() {
+            if let Some((handler, kind)) = self.handler_kind(cx, hir.item(id)) {
+                check_handler(cx, handler, kind);
+            }
+        }
+    }
+}
+
+fn check_handler(cx: &LateContext<'_>, handler: LocalDefId, kind: &str) {
+    let hir = cx.tcx.hir();
+    let typeck = cx.tcx.typeck(handler);
+    let mut visited = FxHashSet::default();
+    visited.insert(handler);
+    let mut linted = FxHashSet::default();
+    for_each_expr_with_closures(cx, hir.body(hir.body_owned_by(handler)).value, |e| {
+        if let Some(allocation) = allocation(cx, typeck, handler, e, &mut visited) {
+            // Report macros like `format!` once, at their call site.
+            let span = e.span.source_callsite();
+            if linted.insert(span) {
+                span_lint_hir_and_then(
+                    cx,
+                    ALLOCATION_IN_HANDLER,
+                    e.hir_id,
+                    span,
+                    &format!("allocation in {kind}"),
+                    |diag| {
+                        if allocation != span {
+                            diag.span_note(allocation, "the called function allocates here");
+                        }
+                    },
+                );
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+}
+
+/// Returns where the call `e` allocates: `e` itself, or a call inside the function it calls.
+fn allocation<'tcx>(
+    cx: &LateContext<'tcx>,
+    typeck: &TypeckResults<'tcx>,
+    owner: LocalDefId,
+    e: &Expr<'_>,
+    visited: &mut FxHashSet<LocalDefId>,
+) -> Option<Span> {
+    let (def_id, args) = callee(cx, typeck, owner, e)?;
+    if allocates(cx, def_id, args) {
+        Some(e.span.source_callsite())
+    } else {
+        first_allocation(cx, def_id.as_local()?, visited)
+    }
+}
+
+/// Returns the first allocation in a function of the current crate, looking through the functions
+/// it calls in turn.
+fn first_allocation(cx: &LateContext<'_>, fn_id: LocalDefId, visited: &mut FxHashSet<LocalDefId>) -> Option<Span> {
+    if !visited.insert(fn_id) {
+        return None;
+    }
+    let hir = cx.tcx.hir();
+    let body = hir.maybe_body_owned_by(fn_id)?;
+    let typeck = cx.tcx.typeck(fn_id);
+    for_each_expr_with_closures(cx, hir.body(body).value, |e| {
+        match allocation(cx, typeck, fn_id, e, visited) {
+            Some(span) => ControlFlow::Break(span),
+            None => ControlFlow::Continue(()),
+        }
+    })
+}
+
+/// The function called by `e`, resolved to the implementation of a trait method where possible.
+fn callee<'tcx>(
+    cx: &LateContext<'tcx>,
+    typeck: &TypeckResults<'tcx>,
+    owner: LocalDefId,
+    e: &Expr<'_>,
+) -> Option<(DefId, GenericArgsRef<'tcx>)> {
+    let (def_id, args) = match e.kind {
+        ExprKind::Call(callee, _) => match *typeck.expr_ty(callee).kind() {
+            ty::FnDef(def_id, args) => (def_id, args),
+            _ => return None,
+        },
+        ExprKind::MethodCall(..) => (typeck.type_dependent_def_id(e.hir_id)?, typeck.node_args(e.hir_id)),
+        _ => return None,
+    };
+    let args = cx.tcx.erase_regions(args);
+    match Instance::resolve(cx.tcx, cx.tcx.param_env(owner), def_id, args) {
+        Ok(Some(instance)) => Some((instance.def_id(), instance.args)),
+        _ => Some((def_id, args)),
+    }
+}
+
+/// Whether `def_id` is a function from `alloc` that may allocate.
+fn allocates<'tcx>(cx: &LateContext<'tcx>, def_id: DefId, args: GenericArgsRef<'tcx>) -> bool {
+    if cx.tcx.crate_name(def_id.krate) != sym::alloc || cx.tcx.is_const_fn(def_id) {
+        return false;
+    }
+    let sig = cx
+        .tcx
+        .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).instantiate(cx.tcx, args));
+    // Something like `Vec::len`, as opposed to `String::clone` or `ToString::to_string`.
+    let is_getter = cx
+        .tcx
+        .opt_associated_item(def_id)
+        .map_or(false, |item| item.fn_has_self_parameter)
+        && matches!(
+            sig.inputs().first().map(|ty| ty.kind()),
+            Some(ty::Ref(_, _, Mutability::Not))
+        )
+        && !sig.output().needs_drop(cx.tcx, cx.param_env);
+    !is_getter
+}
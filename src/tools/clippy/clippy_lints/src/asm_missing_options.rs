@@ -0,0 +1,135 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_opt;
+use rustc_ast::ast::{Expr, ExprKind, InlineAsm, InlineAsmOperand, InlineAsmOptions, InlineAsmTemplatePiece};
+use rustc_errors::Applicability;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::BytePos;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `asm!` blocks which don't appear to access memory, but aren't marked with
+    /// `options(nomem)`, or `options(nostack)` when they don't use the stack either.
+    ///
+    /// ### Why is this bad?
+    /// Without these options the compiler has to assume that the block reads and writes any
+    /// memory it can reach, and may push to the stack, which keeps it from optimizing the
+    /// surrounding code.
+    ///
+    /// ### Known problems
+    /// Whether an instruction accesses memory is guessed from its operands and mnemonic, which
+    /// can miss memory accesses in instructions this lint doesn't know about.
+    ///
+    /// Blocks with an empty template are ignored, as `asm!("")` is commonly used as a
+    /// compiler barrier, which relies on the compiler assuming memory is accessed.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # #[cfg(target_arch = "x86_64")]
+    /// # unsafe { let mut x = 1u64;
+    /// # use std::arch::asm;
+    /// asm!("add {0}, {0}", inout(reg) x);
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # #[cfg(target_arch = "x86_64")]
+    /// # unsafe { let mut x = 1u64;
+    /// # use std::arch::asm;
+    /// asm!("add {0}, {0}", inout(reg) x, options(nomem, nostack));
+    /// # }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub ASM_MISSING_OPTIONS,
+    pedantic,
+    "`asm!` blocks which don't access memory without `options(nomem, nostack)`"
+}
+
+declare_lint_pass!(AsmMissingOptions => [ASM_MISSING_OPTIONS]);
+
+/// Mnemonics, or their prefixes, of instructions which access memory or the stack without
+/// taking a memory operand, such as calls, stack and string instructions, and system calls, on
+/// x86, Arm, AArch64 and RISC-V. Fences and barriers, interrupt masking, waiting for interrupts
+/// and writes to model specific registers are included as well, as they're only useful when
+/// memory accesses around them aren't reordered.
+const IMPLICIT_MEMORY_ACCESS: &[&str] = &[
+    "bl", "call", "cli", "cmps", "dmb", "dsb", "ecall", "enter", "fence", "fxrstor", "fxsave", "ins", "int", "iret",
+    "isb", "ldm", "leave", "lfence", "lods", "mfence", "movs", "outs", "pop", "push", "ret", "scas", "sfence", "sti",
+    "stm", "stos", "svc", "syscall", "sysenter", "wfi", "wrmsr", "xlat", "xrstor", "xsave",
+];
+
+impl EarlyLintPass for AsmMissingOptions {
+    fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
+        if let ExprKind::InlineAsm(ref inline_asm) = expr.kind
+            && !expr.span.from_expansion()
+            && !inline_asm.options.intersects(InlineAsmOptions::NOMEM | InlineAsmOptions::READONLY)
+            && inline_asm.clobber_abis.is_empty()
+            && !inline_asm
+                .operands
+                .iter()
+                .any(|(op, _)| matches!(op, InlineAsmOperand::Sym { .. }))
+            && !accesses_memory(inline_asm)
+            && let Some(snippet) = snippet_opt(cx, expr.span)
+            // The closing delimiter of the macro call.
+            && let Some(args) = snippet.get(..snippet.len() - 1)
+        {
+            let options = if inline_asm.options.contains(InlineAsmOptions::NOSTACK) {
+                "options(nomem)"
+            } else {
+                "options(nomem, nostack)"
+            };
+            let args = args.trim_end();
+            let sugg_span = expr.span.with_lo(expr.span.lo() + BytePos::from_usize(args.len()));
+            let sugg = if args.ends_with(',') {
+                format!(" {options}")
+            } else {
+                format!(", {options}")
+            };
+            span_lint_and_then(
+                cx,
+                ASM_MISSING_OPTIONS,
+                expr.span,
+                "this `asm!` block doesn't appear to access memory",
+                |diag| {
+                    diag.span_suggestion_verbose(
+                        sugg_span.shrink_to_lo(),
+                        format!("if it doesn't, use `{options}`"),
+                        sugg,
+                        Applicability::MaybeIncorrect,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Whether any instruction in the template of `inline_asm` takes a memory operand, or is one
+/// that accesses memory anyway. Templates without instructions also count, see the lint docs.
+fn accesses_memory(inline_asm: &InlineAsm) -> bool {
+    let template: String = inline_asm
+        .template
+        .iter()
+        .map(|piece| match piece {
+            InlineAsmTemplatePiece::String(s) => s.as_str(),
+            InlineAsmTemplatePiece::Placeholder { .. } => "{}",
+        })
+        .collect();
+    let mut instructions = template
+        .split(['\n', ';'])
+        .map(|line| match line.trim().split_once(':') {
+            // Skip labels.
+            Some((label, rest)) if !label.contains(|c: char| c.is_whitespace() || c == '%') => rest.trim(),
+            _ => line.trim(),
+        })
+        .filter(|line| !line.is_empty())
+        .peekable();
+    instructions.peek().is_none()
+        || instructions.any(|instruction| {
+            let mnemonic = instruction.split_whitespace().next().unwrap_or_default().to_lowercase();
+            // Memory operands: `[rax]`, `(%rax)` and `%fs:0` on x86, `[x0]` on Arm, `0(a0)` on
+            // RISC-V. Directives, like `.byte`, can encode anything.
+            instruction.contains(['[', '(', ':'])
+                || mnemonic.starts_with('.')
+                || IMPLICIT_MEMORY_ACCESS.iter().any(|prefix| mnemonic.starts_with(prefix))
+        })
+}
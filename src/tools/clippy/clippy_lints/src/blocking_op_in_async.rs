@@ -0,0 +1,255 @@
+use clippy_config::types::{AsyncRuntime, DisallowedPath};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::{def_path_def_ids, fn_def_id, is_lint_allowed, match_def_path, paths};
+use rustc_errors::Applicability;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, DefIdMap};
+use rustc_hir::{CoroutineKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to blocking functions directly inside an `async fn` or `async` block:
+    /// `std::thread::sleep`, the functions of `std::fs` and the methods of its types that access the
+    /// file system, and the functions listed in
+    /// the `blocking-functions` configuration. `std::thread::sleep` is left to `sleep_in_async`
+    /// unless that lint is allowed.
+    ///
+    /// ### Why is this bad?
+    /// An async runtime runs many tasks on a few threads, and only switches between them at
+    /// `.await` points. A blocking call holds up its thread, and every task waiting for it, until
+    /// it returns.
+    ///
+    /// ### Known problems
+    /// Only direct calls are checked, not blocking calls made by the functions that are called.
+    /// Closures inside the `async` block are skipped, since they may well be passed to something
+    /// like `spawn_blocking`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// async fn retry() {
+    ///     std::thread::sleep(Duration::from_secs(1));
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// # use std::time::Duration;
+    /// async fn retry() {
+    ///     tokio::time::sleep(Duration::from_secs(1)).await;
+    /// }
+    /// ```
+    ///
+    /// ### Configuration
+    /// Blocking functions of other crates can be added in `clippy.toml`, with an optional reason
+    /// that is shown along with the warning:
+    /// ```toml
+    /// blocking-functions = [
+    ///     "std::net::TcpStream::connect",
+    ///     { path = "reqwest::blocking::get", reason = "use `reqwest::get` instead" },
+    /// ]
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub BLOCKING_OP_IN_ASYNC,
+    pedantic,
+    "calling a blocking function inside an `async fn` or `async` block"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `std::thread::sleep` directly inside an `async fn` or `async` block.
+    ///
+    /// ### Why is this bad?
+    /// `std::thread::sleep` blocks the thread the task runs on, so no other task gets to run on it
+    /// until it returns. The async runtime's timer suspends only the task.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// async fn retry() {
+    ///     std::thread::sleep(Duration::from_secs(1));
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// # use std::time::Duration;
+    /// async fn retry() {
+    ///     tokio::time::sleep(Duration::from_secs(1)).await;
+    /// }
+    /// ```
+    ///
+    /// ### Configuration
+    /// The suggested timer is the one of the `async-runtime` set in `clippy.toml`, `Tokio` by
+    /// default. The suggestion can only be applied automatically if the crate already depends on
+    /// that runtime.
+    #[clippy::version = "1.76.0"]
+    pub SLEEP_IN_ASYNC,
+    suspicious,
+    "calling `std::thread::sleep` inside an `async fn` or `async` block"
+}
+
+pub struct BlockingOpInAsync {
+    conf_blocking: Vec<DisallowedPath>,
+    blocking: DefIdMap<usize>,
+    async_runtime: AsyncRuntime,
+    /// Whether the crate depends on `async_runtime` directly, with its timer enabled.
+    runtime_available: bool,
+}
+
+impl BlockingOpInAsync {
+    pub fn new(conf_blocking: Vec<DisallowedPath>, async_runtime: AsyncRuntime) -> Self {
+        Self {
+            conf_blocking,
+            blocking: DefIdMap::default(),
+            async_runtime,
+            runtime_available: false,
+        }
+    }
+}
+
+impl_lint_pass!(BlockingOpInAsync => [BLOCKING_OP_IN_ASYNC, SLEEP_IN_ASYNC]);
+
+impl<'tcx> LateLintPass<'tcx> for BlockingOpInAsync {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for (index, conf) in self.conf_blocking.iter().enumerate() {
+            let segs: Vec<_> = conf.path().split("::").collect();
+            for id in def_path_def_ids(cx, &segs) {
+                self.blocking.insert(id, index);
+            }
+        }
+
+        let sleep = sleep_path(self.async_runtime);
+        self.runtime_available = cx.tcx.crates(()).iter().any(|&cnum| {
+            cx.tcx.crate_name(cnum).as_str() == sleep[0]
+                && cx.tcx.extern_crate(cnum.as_def_id()).is_some_and(|e| e.is_direct())
+        }) && def_path_def_ids(cx, sleep).next().is_some();
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(def_id) = fn_def_id(cx, expr)
+            && let Some(body) = cx.enclosing_body
+            && let Some(CoroutineKind::Async(_)) = cx.tcx.hir().body(body).coroutine_kind
+        {
+            if match_def_path(cx, def_id, &paths::THREAD_SLEEP) && !is_lint_allowed(cx, SLEEP_IN_ASYNC, expr.hir_id) {
+                self.check_sleep(cx, expr);
+                return;
+            }
+            let (note, help) = if let Some(&index) = self.blocking.get(&def_id) {
+                (self.conf_blocking[index].reason(), None)
+            } else if match_def_path(cx, def_id, &paths::THREAD_SLEEP) {
+                (
+                    None,
+                    Some("use the async runtime's timer instead, such as `tokio::time::sleep`"),
+                )
+            } else if is_blocking_std_fs(cx, def_id) {
+                (
+                    None,
+                    Some(
+                        "use the async runtime's file system API instead, such as `tokio::fs`, \
+                        or run the call on a thread pool for blocking work",
+                    ),
+                )
+            } else {
+                return;
+            };
+            span_lint_and_then(
+                cx,
+                BLOCKING_OP_IN_ASYNC,
+                expr.span,
+                &format!("blocking call to `{}` in an async context", cx.tcx.def_path_str(def_id)),
+                |diag| {
+                    if let Some(note) = note {
+                        diag.note(note);
+                    }
+                    if let Some(help) = help {
+                        diag.help(help);
+                    }
+                },
+            );
+        }
+    }
+}
+
+impl BlockingOpInAsync {
+    fn check_sleep(&self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        let ExprKind::Call(_, [duration]) = expr.kind else {
+            return;
+        };
+        let path = sleep_path(self.async_runtime).join("::");
+        span_lint_and_then(
+            cx,
+            SLEEP_IN_ASYNC,
+            expr.span,
+            "blocking sleep in an async context",
+            |diag| {
+                let mut app = if self.runtime_available {
+                    Applicability::MachineApplicable
+                } else {
+                    Applicability::MaybeIncorrect
+                };
+                let (duration, _) = snippet_with_context(cx, duration.span, expr.span.ctxt(), "..", &mut app);
+                diag.span_suggestion(
+                    expr.span,
+                    "use the async runtime's timer",
+                    format!("{path}({duration}).await"),
+                    app,
+                );
+                if !self.runtime_available {
+                    diag.note(format!("`{path}` is not available in this crate"));
+                }
+            },
+        );
+    }
+}
+
+fn sleep_path(runtime: AsyncRuntime) -> &'static [&'static str] {
+    match runtime {
+        AsyncRuntime::Tokio => &["tokio", "time", "sleep"],
+        AsyncRuntime::AsyncStd => &["async_std", "task", "sleep"],
+    }
+}
+
+/// The methods of the types of `std::fs` that access the file system, as opposed to builders and
+/// accessors like `OpenOptions::new` or `Permissions::readonly`.
+const BLOCKING_FS_METHODS: &[(&str, &str)] = &[
+    ("DirBuilder", "create"),
+    ("DirEntry", "file_type"),
+    ("DirEntry", "metadata"),
+    ("File", "create"),
+    ("File", "create_new"),
+    ("File", "metadata"),
+    ("File", "open"),
+    ("File", "set_len"),
+    ("File", "set_modified"),
+    ("File", "set_permissions"),
+    ("File", "set_times"),
+    ("File", "sync_all"),
+    ("File", "sync_data"),
+    ("File", "try_clone"),
+    ("OpenOptions", "open"),
+];
+
+/// Whether `def_id` is a function of `std::fs`, all of which access the file system, or one of
+/// `BLOCKING_FS_METHODS`.
+fn is_blocking_std_fs(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    if !matches!(&*cx.get_def_path(def_id), [krate, module, ..] if *krate == sym::std && module.as_str() == "fs") {
+        return false;
+    }
+    let parent = cx.tcx.parent(def_id);
+    match cx.tcx.def_kind(parent) {
+        DefKind::Mod => true,
+        DefKind::Impl { of_trait: false } => {
+            let Some(adt) = cx.tcx.type_of(parent).instantiate_identity().ty_adt_def() else {
+                return false;
+            };
+            let (ty_name, method_name) = (cx.tcx.item_name(adt.did()), cx.tcx.item_name(def_id));
+            BLOCKING_FS_METHODS
+                .iter()
+                .any(|&(ty, method)| ty_name.as_str() == ty && method_name.as_str() == method)
+        },
+        _ => false,
+    }
+}
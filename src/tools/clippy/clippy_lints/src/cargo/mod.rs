@@ -6,6 +6,7 @@
 use cargo_metadata::MetadataCommand;
 use clippy_utils::diagnostics::span_lint;
 use clippy_utils::is_lint_allowed;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_hir::hir_id::CRATE_HIR_ID;
 use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_session::impl_lint_pass;
@@ -128,6 +129,9 @@
     /// ### Known problems
     /// Because this can be caused purely by the dependencies
     /// themselves, it's not always possible to fix this issue.
+    /// Such crates can be listed in the `allowed-duplicate-crates`
+    /// configuration, and `duplicate-crates-max-depth` limits the
+    /// lint to the top of the dependency tree.
     ///
     /// ### Example
     /// ```toml
@@ -164,6 +168,8 @@
 
 pub struct Cargo {
     pub ignore_publish: bool,
+    pub allowed_duplicate_crates: FxHashSet<String>,
+    pub duplicate_crates_max_depth: Option<u64>,
 }
 
 impl_lint_pass!(Cargo => [
@@ -208,7 +214,12 @@ fn check_crate(&mut self, cx: &LateContext<'_>) {
         {
             match MetadataCommand::new().exec() {
                 Ok(metadata) => {
-                    multiple_crate_versions::check(cx, &metadata);
+                    multiple_crate_versions::check(
+                        cx,
+                        &metadata,
+                        &self.allowed_duplicate_crates,
+                        self.duplicate_crates_max_depth,
+                    );
                 },
                 Err(e) => {
                     for lint in WITH_DEPS_LINTS {
@@ -1,15 +1,24 @@
 //! lint on multiple versions of a crate being used
 
-use cargo_metadata::{DependencyKind, Metadata, Node, Package, PackageId};
-use clippy_utils::diagnostics::span_lint;
+use std::collections::hash_map::Entry;
+use std::collections::VecDeque;
+
+use cargo_metadata::{DependencyKind, Metadata, Package, PackageId};
+use clippy_utils::diagnostics::span_lint_and_then;
 use itertools::Itertools;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_lint::LateContext;
 use rustc_span::DUMMY_SP;
 
 use super::MULTIPLE_CRATE_VERSIONS;
 
-pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    metadata: &Metadata,
+    allowed_duplicate_crates: &FxHashSet<String>,
+    max_depth: Option<u64>,
+) {
     let local_name = cx.tcx.crate_name(LOCAL_CRATE);
     let mut packages = metadata.packages.clone();
     packages.sort_by(|a, b| a.name.cmp(&b.name));
@@ -23,42 +32,120 @@ pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
             }
         })
     {
-        for (name, group) in &packages.iter().group_by(|p| p.name.clone()) {
-            let group: Vec<&Package> = group.collect();
+        // Only normal dependencies end up in the build, dev and build dependencies don't count.
+        let deps: FxHashMap<&PackageId, Vec<&PackageId>> = resolve
+            .nodes
+            .iter()
+            .map(|node| {
+                let deps = node
+                    .deps
+                    .iter()
+                    .filter(|dep| {
+                        dep.dep_kinds
+                            .iter()
+                            .any(|info| matches!(info.kind, DependencyKind::Normal))
+                    })
+                    .map(|dep| &dep.pkg)
+                    .collect();
+                (&node.id, deps)
+            })
+            .collect();
+        let packages_by_id: FxHashMap<&PackageId, &Package> = packages.iter().map(|p| (&p.id, p)).collect();
 
-            if group.len() <= 1 {
+        for (name, group) in &packages.iter().group_by(|p| p.name.clone()) {
+            if allowed_duplicate_crates.contains(&name) {
                 continue;
             }
 
-            if group.iter().all(|p| is_normal_dep(&resolve.nodes, local_id, &p.id)) {
-                let mut versions: Vec<_> = group.into_iter().map(|p| &p.version).collect();
-                versions.sort();
-                let versions = versions.iter().join(", ");
+            let mut versions: Vec<(&Package, Vec<Vec<&PackageId>>)> = group
+                .filter_map(|p| {
+                    let paths = dependency_paths(&deps, local_id, &p.id);
+                    // The first path is the shortest, and its length includes the local crate.
+                    let depth = paths.first()?.len() as u64 - 1;
+                    max_depth.map_or(true, |max| depth <= max).then_some((p, paths))
+                })
+                .collect();
 
-                span_lint(
-                    cx,
-                    MULTIPLE_CRATE_VERSIONS,
-                    DUMMY_SP,
-                    &format!("multiple versions for dependency `{name}`: {versions}"),
-                );
+            if versions.len() <= 1 {
+                continue;
             }
+
+            versions.sort_by(|(a, _), (b, _)| a.version.cmp(&b.version));
+            span_lint_and_then(
+                cx,
+                MULTIPLE_CRATE_VERSIONS,
+                DUMMY_SP,
+                &format!(
+                    "multiple versions for dependency `{name}`: {}",
+                    versions.iter().map(|(p, _)| &p.version).join(", ")
+                ),
+                |diag| {
+                    for (p, paths) in &versions {
+                        for path in paths {
+                            let path = path
+                                .iter()
+                                .map(|id| {
+                                    let package = packages_by_id[id];
+                                    if *id == local_id {
+                                        package.name.clone()
+                                    } else {
+                                        format!("{} {}", package.name, package.version)
+                                    }
+                                })
+                                .join(" -> ");
+                            diag.note(format!("`{} {}` is pulled in by {path}", p.name, p.version));
+                        }
+                    }
+                },
+            );
         }
     }
 }
 
-fn is_normal_dep(nodes: &[Node], local_id: &PackageId, dep_id: &PackageId) -> bool {
-    fn depends_on(node: &Node, dep_id: &PackageId) -> bool {
-        node.deps.iter().any(|dep| {
-            dep.pkg == *dep_id
-                && dep
-                    .dep_kinds
-                    .iter()
-                    .any(|info| matches!(info.kind, DependencyKind::Normal))
+/// The shortest path from `local_id` to `dep_id` through each direct dependency of the local crate
+/// that leads to it, shortest first.
+fn dependency_paths<'a>(
+    deps: &FxHashMap<&'a PackageId, Vec<&'a PackageId>>,
+    local_id: &'a PackageId,
+    dep_id: &PackageId,
+) -> Vec<Vec<&'a PackageId>> {
+    let mut paths: Vec<_> = deps
+        .get(local_id)
+        .into_iter()
+        .flatten()
+        .filter_map(|&direct| {
+            let mut path = shortest_path(deps, direct, dep_id)?;
+            path.insert(0, local_id);
+            Some(path)
         })
-    }
+        .collect();
+    paths.sort_by_key(Vec::len);
+    paths
+}
 
-    nodes
-        .iter()
-        .filter(|node| depends_on(node, dep_id))
-        .any(|node| node.id == *local_id || is_normal_dep(nodes, local_id, &node.id))
+fn shortest_path<'a>(
+    deps: &FxHashMap<&'a PackageId, Vec<&'a PackageId>>,
+    from: &'a PackageId,
+    to: &PackageId,
+) -> Option<Vec<&'a PackageId>> {
+    let mut parents: FxHashMap<&PackageId, Option<&PackageId>> = FxHashMap::default();
+    parents.insert(from, None);
+    let mut queue = VecDeque::from([from]);
+    while let Some(id) = queue.pop_front() {
+        if id == to {
+            let mut path = vec![id];
+            while let Some(&Some(parent)) = parents.get(path[path.len() - 1]) {
+                path.push(parent);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for &dep in deps.get(id).into_iter().flatten() {
+            if let Entry::Vacant(entry) = parents.entry(dep) {
+                entry.insert(Some(id));
+                queue.push_back(dep);
+            }
+        }
+    }
+    None
 }
@@ -16,6 +16,8 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Detects manual `std::default::Default` implementations that are identical to a derived implementation.
+    /// For enums, this includes implementations returning a unit variant, which can be marked as
+    /// `#[default]` instead.
     ///
     /// ### Why is this bad?
     /// It is less concise.
@@ -130,17 +132,17 @@ fn check_struct<'tcx>(
 
     if should_emit {
         let struct_span = cx.tcx.def_span(adt_def.did());
+        let indent_struct = indent_of(cx, struct_span).unwrap_or(0);
         span_lint_and_then(cx, DERIVABLE_IMPLS, item.span, "this `impl` can be derived", |diag| {
-            diag.span_suggestion_hidden(
-                item.span,
-                "remove the manual implementation...",
-                String::new(),
-                Applicability::MachineApplicable,
-            );
-            diag.span_suggestion(
-                struct_span.shrink_to_lo(),
-                "...and instead derive it",
-                "#[derive(Default)]\n".to_string(),
+            diag.multipart_suggestion(
+                "replace the manual implementation with a derive attribute",
+                vec![
+                    (item.span, String::new()),
+                    (
+                        struct_span.shrink_to_lo(),
+                        format!("#[derive(Default)]\n{indent}", indent = " ".repeat(indent_struct)),
+                    ),
+                ],
                 Applicability::MachineApplicable,
             );
         });
@@ -160,22 +162,19 @@ fn check_enum<'tcx>(cx: &LateContext<'tcx>, item: &'tcx Item<'_>, func_expr: &Ex
         let variant_span = cx.tcx.def_span(variant_def.def_id);
         let indent_variant = indent_of(cx, variant_span).unwrap_or(0);
         span_lint_and_then(cx, DERIVABLE_IMPLS, item.span, "this `impl` can be derived", |diag| {
-            diag.span_suggestion_hidden(
-                item.span,
-                "remove the manual implementation...",
-                String::new(),
-                Applicability::MachineApplicable,
-            );
-            diag.span_suggestion(
-                enum_span.shrink_to_lo(),
-                "...and instead derive it...",
-                format!("#[derive(Default)]\n{indent}", indent = " ".repeat(indent_enum),),
-                Applicability::MachineApplicable,
-            );
-            diag.span_suggestion(
-                variant_span.shrink_to_lo(),
-                "...and mark the default variant",
-                format!("#[default]\n{indent}", indent = " ".repeat(indent_variant),),
+            diag.multipart_suggestion(
+                "replace the manual implementation with a derive attribute and mark the default variant",
+                vec![
+                    (item.span, String::new()),
+                    (
+                        enum_span.shrink_to_lo(),
+                        format!("#[derive(Default)]\n{indent}", indent = " ".repeat(indent_enum)),
+                    ),
+                    (
+                        variant_span.shrink_to_lo(),
+                        format!("#[default]\n{indent}", indent = " ".repeat(indent_variant)),
+                    ),
+                ],
                 Applicability::MachineApplicable,
             );
         });
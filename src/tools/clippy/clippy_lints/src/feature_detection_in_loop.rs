@@ -0,0 +1,99 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::first_node_macro_backtrace;
+use clippy_utils::{get_parent_expr, is_trait_method};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for runtime CPU feature detection, such as `is_x86_feature_detected!` or
+    /// `is_aarch64_feature_detected!`, inside a loop body or a closure passed to an iterator
+    /// adapter.
+    ///
+    /// ### Why is this bad?
+    /// Each check goes through the runtime feature detection machinery rather than being a
+    /// constant, which adds work to every iteration of a hot loop even though the answer can't
+    /// change while the program runs.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # #[cfg(target_arch = "x86_64")]
+    /// # fn f(data: &mut [u32]) {
+    /// for x in data.iter_mut() {
+    ///     if is_x86_feature_detected!("avx2") {
+    ///         *x += 1;
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # #[cfg(target_arch = "x86_64")]
+    /// # fn f(data: &mut [u32]) {
+    /// let has_avx2 = is_x86_feature_detected!("avx2");
+    /// for x in data.iter_mut() {
+    ///     if has_avx2 {
+    ///         *x += 1;
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub FEATURE_DETECTION_IN_LOOP,
+    perf,
+    "runtime CPU feature detection inside a loop"
+}
+declare_lint_pass!(FeatureDetectionInLoop => [FEATURE_DETECTION_IN_LOOP]);
+
+impl<'tcx> LateLintPass<'tcx> for FeatureDetectionInLoop {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(macro_call) =
+            first_node_macro_backtrace(cx, expr).find(|macro_call| is_feature_detection_macro(cx, macro_call.def_id))
+            && runs_per_iteration(cx, expr)
+        {
+            span_lint_and_help(
+                cx,
+                FEATURE_DETECTION_IN_LOOP,
+                macro_call.span,
+                "this CPU feature check runs on every iteration",
+                None,
+                "check once before the loop and store the result in a `let` binding",
+            );
+        }
+    }
+}
+
+/// `is_x86_feature_detected!`, `is_aarch64_feature_detected!` and the like, which are all defined
+/// in `std_detect` and reexported from `std::arch`.
+fn is_feature_detection_macro(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    let name = cx.tcx.item_name(def_id);
+    cx.tcx.crate_name(def_id.krate).as_str() == "std_detect"
+        && name.as_str().starts_with("is_")
+        && name.as_str().ends_with("_feature_detected")
+}
+
+/// Whether `expr` is inside a loop, or a closure passed to an iterator method, within the
+/// enclosing function. Other closures aren't assumed to run repeatedly.
+fn runs_per_iteration(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::Loop(..) => return true,
+                ExprKind::Closure(_) => {
+                    return get_parent_expr(cx, parent).map_or(false, |call| {
+                        matches!(call.kind, ExprKind::MethodCall(_, _, args, _)
+                            if args.iter().any(|arg| arg.hir_id == parent.hir_id))
+                            && is_trait_method(cx, call, sym::Iterator)
+                    });
+                },
+                _ => {},
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) | Node::AnonConst(_) => return false,
+            _ => {},
+        }
+    }
+    false
+}
@@ -2,11 +2,14 @@
 mod misnamed_getters;
 mod must_use;
 mod not_unsafe_ptr_arg_deref;
+mod renamed_function_params;
 mod result;
 mod too_many_arguments;
 mod too_many_lines;
 
+use clippy_utils::def_path_def_ids;
 use rustc_hir as hir;
+use rustc_hir::def_id::DefIdSet;
 use rustc_hir::intravisit;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::impl_lint_pass;
@@ -359,13 +362,55 @@
     "`impl Trait` is used in the function's parameters"
 }
 
-#[derive(Copy, Clone)]
+declare_clippy_lint! {
+    /// ### What it does
+    /// Lints when the name of a function parameter in a trait impl differs from the name
+    /// of the same parameter in the trait declaration.
+    ///
+    /// ### Why is this bad?
+    /// Using the same names makes it easier to find all the implementations of a method with
+    /// `grep`, and IDEs show the names from the trait declaration in inlay hints, which are
+    /// then different from the ones used in the implementation.
+    ///
+    /// Parameters whose name starts with `_` are ignored, as are the traits listed in the
+    /// `allow-renamed-params-for` configuration.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// struct A(u32);
+    ///
+    /// impl PartialEq for A {
+    ///     fn eq(&self, b: &Self) -> bool {
+    ///         self.0 == b.0
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// struct A(u32);
+    ///
+    /// impl PartialEq for A {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.0 == other.0
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub RENAMED_FUNCTION_PARAMS,
+    restriction,
+    "renamed function parameters in trait implementations"
+}
+
+#[derive(Clone)]
 #[allow(clippy::struct_field_names)]
 pub struct Functions {
     too_many_arguments_threshold: u64,
     too_many_lines_threshold: u64,
     large_error_threshold: u64,
     avoid_breaking_exported_api: bool,
+    allow_renamed_params_for: Vec<String>,
+    /// The traits in `allow_renamed_params_for`, resolved in `check_crate`.
+    trait_ids: DefIdSet,
 }
 
 impl Functions {
@@ -374,12 +419,15 @@ pub fn new(
         too_many_lines_threshold: u64,
         large_error_threshold: u64,
         avoid_breaking_exported_api: bool,
+        allow_renamed_params_for: Vec<String>,
     ) -> Self {
         Self {
             too_many_arguments_threshold,
             too_many_lines_threshold,
             large_error_threshold,
             avoid_breaking_exported_api,
+            allow_renamed_params_for,
+            trait_ids: DefIdSet::default(),
         }
     }
 }
@@ -395,9 +443,17 @@ pub fn new(
     RESULT_LARGE_ERR,
     MISNAMED_GETTERS,
     IMPL_TRAIT_IN_PARAMS,
+    RENAMED_FUNCTION_PARAMS,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Functions {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in &self.allow_renamed_params_for {
+            let path = path.split("::").collect::<Vec<_>>();
+            self.trait_ids.extend(def_path_def_ids(cx, &path));
+        }
+    }
+
     fn check_fn(
         &mut self,
         cx: &LateContext<'tcx>,
@@ -424,6 +480,7 @@ fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<
         must_use::check_impl_item(cx, item);
         result::check_impl_item(cx, item, self.large_error_threshold);
         impl_trait_in_params::check_impl_item(cx, item);
+        renamed_function_params::check_impl_item(cx, item, &self.trait_ids);
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'_>) {
@@ -0,0 +1,54 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_errors::{Applicability, MultiSpan};
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{ImplItem, ImplItemKind};
+use rustc_lint::LateContext;
+use rustc_span::symbol::Ident;
+
+use super::RENAMED_FUNCTION_PARAMS;
+
+pub(super) fn check_impl_item(cx: &LateContext<'_>, item: &ImplItem<'_>, ignored_traits: &DefIdSet) {
+    if let ImplItemKind::Fn(_, body_id) = item.kind
+        && !item.span.from_expansion()
+        && let Some(trait_item_id) = cx.tcx.associated_item(item.owner_id).trait_item_def_id
+        && let Some(trait_id) = cx.tcx.trait_of_item(trait_item_id)
+        && !ignored_traits.contains(&trait_id)
+    {
+        let renames: Vec<(Ident, Ident)> = cx
+            .tcx
+            .hir()
+            .body_param_names(body_id)
+            .zip(cx.tcx.fn_arg_names(trait_item_id).iter().copied())
+            .filter(|&(name, trait_name)| is_renamed(name, trait_name))
+            .collect();
+        if renames.is_empty() {
+            return;
+        }
+
+        let plural = if renames.len() == 1 { "" } else { "s" };
+        span_lint_and_then(
+            cx,
+            RENAMED_FUNCTION_PARAMS,
+            MultiSpan::from_spans(renames.iter().map(|(name, _)| name.span).collect()),
+            &format!("renamed function parameter{plural} of trait impl"),
+            |diag| {
+                diag.multipart_suggestion(
+                    format!("consider using the name{plural} from the trait"),
+                    renames
+                        .iter()
+                        .map(|(name, trait_name)| (name.span, trait_name.to_string()))
+                        .collect(),
+                    // The uses of the parameters in the body aren't renamed.
+                    Applicability::Unspecified,
+                );
+            },
+        );
+    }
+}
+
+/// Whether the impl's parameter `name` is a meaningful rename of the trait's `trait_name`.
+/// Patterns other than single bindings, and names starting with `_`, don't count.
+fn is_renamed(name: Ident, trait_name: Ident) -> bool {
+    let is_meaningful = |ident: Ident| !ident.as_str().is_empty() && !ident.as_str().starts_with('_');
+    is_meaningful(name) && is_meaningful(trait_name) && name.name != trait_name.name
+}
@@ -0,0 +1,93 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::macro_backtrace;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_hir::{Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::config::CrateType;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+use rustc_span::symbol::kw;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `#[global_allocator]` definitions in crates compiled as a Rust library
+    /// (`lib`, `rlib` or `dylib`).
+    ///
+    /// Crates listed in the `global-allocator-allowed-crates` configuration, such as crates whose
+    /// whole purpose is to provide an allocator, are not linted.
+    ///
+    /// ### Why is this bad?
+    /// There can only be one global allocator in a program, so it is the final binary's choice to
+    /// make. A library that defines one silently replaces the allocator of every program that
+    /// depends on it, and fails to link next to any other crate that does the same.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// // in a library crate
+    /// #[global_allocator]
+    /// static GLOBAL: MyAllocator = MyAllocator;
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// // in the library, export the allocator
+    /// pub struct MyAllocator;
+    ///
+    /// // in the binary crate
+    /// #[global_allocator]
+    /// static GLOBAL: my_library::MyAllocator = my_library::MyAllocator;
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub GLOBAL_ALLOCATOR_IN_LIBRARY,
+    restriction,
+    "defining a `#[global_allocator]` in a library crate"
+}
+
+pub struct GlobalAllocatorInLibrary {
+    allowed_crates: FxHashSet<String>,
+}
+
+impl GlobalAllocatorInLibrary {
+    pub fn new(allowed_crates: &FxHashSet<String>) -> Self {
+        Self {
+            // Crate names are always normalized to use underscores.
+            allowed_crates: allowed_crates.iter().map(|name| name.replace('-', "_")).collect(),
+        }
+    }
+}
+
+impl_lint_pass!(GlobalAllocatorInLibrary => [GLOBAL_ALLOCATOR_IN_LIBRARY]);
+
+impl LateLintPass<'_> for GlobalAllocatorInLibrary {
+    fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        // The attribute itself is gone after expansion, but it leaves behind a `const _` holding the
+        // allocator shims, whose expansion points back at the `#[global_allocator]` attribute.
+        if let ItemKind::Const(..) = item.kind
+            && item.ident.name == kw::Underscore
+            && let Some(macro_call) = macro_backtrace(item.span).find(|macro_call| {
+                cx.tcx.item_name(macro_call.def_id) == sym::global_allocator
+                    && cx.tcx.crate_name(macro_call.def_id.krate) == sym::core
+            })
+            && is_rust_library(cx)
+            && !self.allowed_crates.contains(cx.tcx.crate_name(LOCAL_CRATE).as_str())
+        {
+            span_lint_and_help(
+                cx,
+                GLOBAL_ALLOCATOR_IN_LIBRARY,
+                macro_call.span,
+                "`#[global_allocator]` defined in a library crate",
+                None,
+                "leave the choice of allocator to the binary, or add this crate to `global-allocator-allowed-crates`",
+            );
+        }
+    }
+}
+
+/// Whether the crate is built to be linked into other Rust crates. Crates that are also built as a
+/// `staticlib` or `cdylib` still count, as their `rlib` can be depended upon all the same.
+fn is_rust_library(cx: &LateContext<'_>) -> bool {
+    cx.tcx
+        .crate_types()
+        .iter()
+        .any(|t| matches!(t, CrateType::Rlib | CrateType::Dylib))
+}
@@ -1,15 +1,21 @@
 //! lint when there is a large size difference between variants on an enum
 
-use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::get_parent_expr;
+use clippy_utils::source::{snippet_opt, snippet_with_applicability};
 use clippy_utils::ty::{approx_ty_size, is_copy, AdtVariantInfo};
 use rustc_errors::Applicability;
-use rustc_hir::{Item, ItemKind};
+use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
+use rustc_hir::def_id::DefIdMap;
+use rustc_hir::intravisit::{walk_expr, walk_pat, Visitor};
+use rustc_hir::pat_util::EnumerateAndAdjustIterator;
+use rustc_hir::{BodyId, Expr, ExprKind, HirId, HirIdMap, Item, ItemKind, Mutability, Node, Pat, PatKind, QPath, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::nested_filter::OnlyBodies;
 use rustc_middle::lint::in_external_macro;
-use rustc_middle::ty::{Adt, Ty};
+use rustc_middle::ty::{Adt, BindingMode, Ty, TypeckResults, VariantDef};
 use rustc_session::impl_lint_pass;
-use rustc_span::Span;
+use rustc_span::{Span, Symbol};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -53,15 +59,26 @@
     ///     B(Box<[i32; 8000]>),
     /// }
     /// ```
+    ///
+    /// The suggestion also boxes the fields where the variant is constructed, and dereferences
+    /// the bindings of the fields where it is matched on, throughout the crate. It can only be
+    /// applied automatically if none of these are in macros or constants, and the enum isn't
+    /// exported, since uses of it in other crates can't be fixed.
     #[clippy::version = "pre 1.29.0"]
     pub LARGE_ENUM_VARIANT,
     perf,
     "large size difference between variants on an enum"
 }
 
-#[derive(Copy, Clone)]
+const HELP: &str = "consider boxing the large fields to reduce the total size of the enum";
+
 pub struct LargeEnumVariant {
     maximum_size_difference_allowed: u64,
+    /// The enums to lint. They are linted once the whole crate has been checked, so that their
+    /// construction and pattern sites can be fixed along with their definition.
+    enums: Vec<LargeEnum>,
+    /// The indices of the fields to box, by variant, with the index of the enum in `enums`.
+    boxed_fields: DefIdMap<(usize, Vec<usize>)>,
 }
 
 impl LargeEnumVariant {
@@ -69,10 +86,25 @@ impl LargeEnumVariant {
     pub fn new(maximum_size_difference_allowed: u64) -> Self {
         Self {
             maximum_size_difference_allowed,
+            enums: Vec::new(),
+            boxed_fields: DefIdMap::default(),
         }
     }
 }
 
+struct LargeEnum {
+    hir_id: HirId,
+    span: Span,
+    labels: Vec<(Span, String)>,
+    /// The span of the largest variant.
+    largest_span: Span,
+    /// The name of the enum, if it is `Copy` and boxing a variant is not an option.
+    copy_ident: Option<Span>,
+    /// Boxing the types of the fields, then wrapping and unwrapping them at every site.
+    sugg: Vec<(Span, String)>,
+    applicability: Applicability,
+}
+
 impl_lint_pass!(LargeEnumVariant => [LARGE_ENUM_VARIANT]);
 
 impl<'tcx> LateLintPass<'tcx> for LargeEnumVariant {
@@ -92,78 +124,312 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &Item<'tcx>) {
 
             let mut difference = variants_size[0].size - variants_size[1].size;
             if difference > self.maximum_size_difference_allowed {
-                let help_text = "consider boxing the large fields to reduce the total size of the enum";
-                span_lint_and_then(
-                    cx,
-                    LARGE_ENUM_VARIANT,
-                    item.span,
-                    "large size difference between variants",
-                    |diag| {
-                        diag.span_label(
-                            item.span,
-                            format!("the entire enum is at least {} bytes", approx_ty_size(cx, ty)),
-                        );
-                        diag.span_label(
-                            def.variants[variants_size[0].ind].span,
-                            format!("the largest variant contains at least {} bytes", variants_size[0].size),
-                        );
-                        diag.span_label(
-                            def.variants[variants_size[1].ind].span,
-                            if variants_size[1].fields_size.is_empty() {
-                                "the second-largest variant carries no data at all".to_owned()
-                            } else {
-                                format!(
-                                    "the second-largest variant contains at least {} bytes",
-                                    variants_size[1].size
-                                )
-                            },
-                        );
-
-                        let fields = def.variants[variants_size[0].ind].data.fields();
-                        let mut applicability = Applicability::MaybeIncorrect;
-                        if is_copy(cx, ty) || maybe_copy(cx, ty) {
-                            diag.span_note(
-                                item.ident.span,
-                                "boxing a variant would require the type no longer be `Copy`",
-                            );
+                let largest = &def.variants[variants_size[0].ind];
+                let labels = vec![
+                    (
+                        item.span,
+                        format!("the entire enum is at least {} bytes", approx_ty_size(cx, ty)),
+                    ),
+                    (
+                        largest.span,
+                        format!("the largest variant contains at least {} bytes", variants_size[0].size),
+                    ),
+                    (
+                        def.variants[variants_size[1].ind].span,
+                        if variants_size[1].fields_size.is_empty() {
+                            "the second-largest variant carries no data at all".to_owned()
                         } else {
-                            let sugg: Vec<(Span, String)> = variants_size[0]
-                                .fields_size
-                                .iter()
-                                .rev()
-                                .map_while(|&(ind, size)| {
-                                    if difference > self.maximum_size_difference_allowed {
-                                        difference = difference.saturating_sub(size);
-                                        Some((
-                                            fields[ind].ty.span,
-                                            format!(
-                                                "Box<{}>",
-                                                snippet_with_applicability(
-                                                    cx,
-                                                    fields[ind].ty.span,
-                                                    "..",
-                                                    &mut applicability
-                                                )
-                                                .into_owned()
-                                            ),
-                                        ))
-                                    } else {
-                                        None
-                                    }
-                                })
-                                .collect();
-
-                            if !sugg.is_empty() {
-                                diag.multipart_suggestion(help_text, sugg, Applicability::MaybeIncorrect);
-                                return;
-                            }
+                            format!(
+                                "the second-largest variant contains at least {} bytes",
+                                variants_size[1].size
+                            )
+                        },
+                    ),
+                ];
+
+                let fields = largest.data.fields();
+                let mut applicability = if cx.effective_visibilities.is_exported(item.owner_id.def_id) {
+                    Applicability::MaybeIncorrect
+                } else {
+                    Applicability::MachineApplicable
+                };
+                let is_copy = is_copy(cx, ty) || maybe_copy(cx, ty);
+                let mut boxed = Vec::new();
+                let mut sugg = Vec::new();
+                if !is_copy {
+                    for &(ind, size) in variants_size[0].fields_size.iter().rev() {
+                        if difference <= self.maximum_size_difference_allowed {
+                            break;
                         }
-                        diag.span_help(def.variants[variants_size[0].ind].span, help_text);
-                    },
-                );
+                        difference = difference.saturating_sub(size);
+                        boxed.push(ind);
+                        sugg.push((
+                            fields[ind].ty.span,
+                            format!(
+                                "Box<{}>",
+                                snippet_with_applicability(cx, fields[ind].ty.span, "..", &mut applicability)
+                            ),
+                        ));
+                    }
+                }
+
+                if !boxed.is_empty() {
+                    self.boxed_fields
+                        .insert(largest.def_id.to_def_id(), (self.enums.len(), boxed));
+                }
+                self.enums.push(LargeEnum {
+                    hir_id: item.hir_id(),
+                    span: item.span,
+                    labels,
+                    largest_span: largest.span,
+                    copy_ident: is_copy.then_some(item.ident.span),
+                    sugg,
+                    applicability,
+                });
             }
         }
     }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        if !self.boxed_fields.is_empty() {
+            cx.tcx.hir().visit_all_item_likes_in_crate(&mut SiteVisitor {
+                cx,
+                typeck: None,
+                boxed_fields: &self.boxed_fields,
+                enums: &mut self.enums,
+                bindings: HirIdMap::default(),
+            });
+        }
+
+        for large_enum in self.enums.drain(..) {
+            span_lint_hir_and_then(
+                cx,
+                LARGE_ENUM_VARIANT,
+                large_enum.hir_id,
+                large_enum.span,
+                "large size difference between variants",
+                |diag| {
+                    for (span, label) in large_enum.labels {
+                        diag.span_label(span, label);
+                    }
+                    if let Some(ident) = large_enum.copy_ident {
+                        diag.span_note(ident, "boxing a variant would require the type no longer be `Copy`");
+                    } else if !large_enum.sugg.is_empty() {
+                        diag.multipart_suggestion(HELP, large_enum.sugg, large_enum.applicability);
+                        return;
+                    }
+                    diag.span_help(large_enum.largest_span, HELP);
+                },
+            );
+        }
+    }
+}
+
+fn is_boxed(variant: &VariantDef, boxed: &[usize], name: Symbol) -> bool {
+    variant
+        .fields
+        .iter()
+        .position(|field| field.name == name)
+        .is_some_and(|i| boxed.contains(&i))
+}
+
+/// Finds where the variants with boxed fields are constructed and matched on, and adds the
+/// changes needed there to the suggestion.
+struct SiteVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck: Option<&'tcx TypeckResults<'tcx>>,
+    boxed_fields: &'a DefIdMap<(usize, Vec<usize>)>,
+    enums: &'a mut [LargeEnum],
+    /// The bindings of boxed fields in patterns, with the index of their enum.
+    bindings: HirIdMap<(usize, BindingMode)>,
+}
+
+impl<'a, 'tcx> SiteVisitor<'a, 'tcx> {
+    /// The variant `qpath` constructs or matches, and the boxed fields in it, if any.
+    fn boxed_variant(
+        &self,
+        typeck: &TypeckResults<'tcx>,
+        qpath: &QPath<'_>,
+        id: HirId,
+    ) -> Option<(&'tcx VariantDef, usize, &'a [usize])> {
+        let res = typeck.qpath_res(qpath, id);
+        let variant_id = match res {
+            Res::Def(DefKind::Variant, id) => id,
+            Res::Def(DefKind::Ctor(CtorOf::Variant, _), id) => self.cx.tcx.parent(id),
+            _ => return None,
+        };
+        let (index, fields) = self.boxed_fields.get(&variant_id)?;
+        let variant = self.cx.tcx.adt_def(self.cx.tcx.parent(variant_id)).variant_of_res(res);
+        Some((variant, *index, fields))
+    }
+
+    /// Marks the suggestion for the enum at `index` as one that can't be applied automatically.
+    fn unfixable(&mut self, index: usize) {
+        self.enums[index].applicability = Applicability::MaybeIncorrect;
+    }
+
+    /// Wraps the field value at `span` in `Box::new`, with `prefix` before it.
+    fn wrap(&mut self, index: usize, site: &Expr<'_>, span: Span, prefix: &str) {
+        if span.from_expansion() || self.cx.tcx.hir().is_inside_const_context(site.hir_id) {
+            self.unfixable(index);
+        } else {
+            let sugg = &mut self.enums[index].sugg;
+            sugg.push((span.shrink_to_lo(), format!("{prefix}Box::new(")));
+            sugg.push((span.shrink_to_hi(), ")".to_owned()));
+        }
+    }
+
+    fn check_expr(&mut self, typeck: &'tcx TypeckResults<'tcx>, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::Call(callee, args)
+                if let ExprKind::Path(ref qpath) = callee.kind
+                    && let Some((_, index, boxed)) = self.boxed_variant(typeck, qpath, callee.hir_id) =>
+            {
+                for &field in boxed {
+                    if let Some(arg) = args.get(field) {
+                        self.wrap(index, expr, arg.span, "");
+                    }
+                }
+            },
+            ExprKind::Struct(qpath, fields, _)
+                if let Some((variant, index, boxed)) = self.boxed_variant(typeck, qpath, expr.hir_id) =>
+            {
+                for field in fields {
+                    if is_boxed(variant, boxed, field.ident.name) {
+                        if field.is_shorthand {
+                            self.wrap(index, expr, field.span, &format!("{}: ", field.ident));
+                        } else {
+                            self.wrap(index, expr, field.expr.span, "");
+                        }
+                    }
+                }
+            },
+            ExprKind::Path(ref qpath) => match typeck.qpath_res(qpath, expr.hir_id) {
+                Res::Local(id) if let Some(&(index, mode)) = self.bindings.get(&id) => self.unwrap(index, mode, expr),
+                // A constructor passed as a function, like `.map(Enum::Variant)`.
+                Res::Def(DefKind::Ctor(CtorOf::Variant, CtorKind::Fn), _)
+                    if let Some((_, index, _)) = self.boxed_variant(typeck, qpath, expr.hir_id)
+                        && !matches!(
+                            get_parent_expr(self.cx, expr),
+                            Some(Expr { kind: ExprKind::Call(callee, _), .. }) if callee.hir_id == expr.hir_id
+                        ) =>
+                {
+                    self.unfixable(index);
+                },
+                _ => {},
+            },
+            _ => {},
+        }
+    }
+
+    /// Dereferences a use of a binding of a boxed field, so that it keeps its type.
+    fn unwrap(&mut self, index: usize, mode: BindingMode, expr: &Expr<'_>) {
+        let Some(name) = snippet_opt(self.cx, expr.span).filter(|_| !expr.span.from_expansion()) else {
+            self.unfixable(index);
+            return;
+        };
+        let parent = get_parent_expr(self.cx, expr);
+        let (span, sugg) = match mode {
+            BindingMode::BindByValue(_) => (expr.span, format!("*{name}")),
+            // `*x` of a `&T` is `**x` of a `&Box<T>`.
+            BindingMode::BindByReference(_)
+                if let Some(parent) = parent
+                    && let ExprKind::Unary(UnOp::Deref, _) = parent.kind
+                    && !parent.span.from_expansion() =>
+            {
+                (parent.span, format!("**{name}"))
+            },
+            BindingMode::BindByReference(Mutability::Not) => (expr.span, format!("&**{name}")),
+            BindingMode::BindByReference(Mutability::Mut) => (expr.span, format!("&mut **{name}")),
+        };
+        let needs_paren = span == expr.span
+            && parent.is_some_and(|parent| match parent.kind {
+                ExprKind::MethodCall(_, e, ..)
+                | ExprKind::Field(e, _)
+                | ExprKind::Index(e, ..)
+                | ExprKind::Call(e, _) => e.hir_id == expr.hir_id,
+                _ => false,
+            });
+        let sugg = if needs_paren { format!("({sugg})") } else { sugg };
+        self.enums[index].sugg.push((span, sugg));
+    }
+
+    fn check_pat(&mut self, typeck: &'tcx TypeckResults<'tcx>, pat: &'tcx Pat<'tcx>) {
+        let (index, boxed_pats): (_, Vec<_>) = match pat.kind {
+            PatKind::TupleStruct(ref qpath, pats, dotdot)
+                if let Some((variant, index, boxed)) = self.boxed_variant(typeck, qpath, pat.hir_id) =>
+            {
+                let pats = pats
+                    .iter()
+                    .enumerate_and_adjust(variant.fields.len(), dotdot)
+                    .filter(|(i, _)| boxed.contains(i))
+                    .map(|(_, pat)| pat)
+                    .collect();
+                (index, pats)
+            },
+            PatKind::Struct(ref qpath, fields, _)
+                if let Some((variant, index, boxed)) = self.boxed_variant(typeck, qpath, pat.hir_id) =>
+            {
+                let pats = fields
+                    .iter()
+                    .filter(|field| is_boxed(variant, boxed, field.ident.name))
+                    .map(|field| field.pat)
+                    .collect();
+                (index, pats)
+            },
+            _ => return,
+        };
+        // The binding would have a different type in each alternative.
+        let in_or_pat = matches!(
+            self.cx.tcx.hir().get_parent(pat.hir_id),
+            Node::Pat(Pat {
+                kind: PatKind::Or(_),
+                ..
+            })
+        );
+        for field_pat in boxed_pats {
+            match field_pat.kind {
+                PatKind::Wild => {},
+                PatKind::Binding(_, id, _, None)
+                    if !in_or_pat
+                        && !field_pat.span.from_expansion()
+                        && let Some(&mode) = typeck.pat_binding_modes().get(id) =>
+                {
+                    self.bindings.insert(id, (index, mode));
+                },
+                // Patterns can't look into a `Box`.
+                _ => self.unfixable(index),
+            }
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for SiteVisitor<'_, 'tcx> {
+    type NestedFilter = OnlyBodies;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+
+    fn visit_nested_body(&mut self, id: BodyId) {
+        let typeck = self.typeck.replace(self.cx.tcx.typeck_body(id));
+        self.visit_body(self.cx.tcx.hir().body(id));
+        self.typeck = typeck;
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let Some(typeck) = self.typeck {
+            self.check_expr(typeck, expr);
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_pat(&mut self, pat: &'tcx Pat<'tcx>) {
+        if let Some(typeck) = self.typeck {
+            self.check_pat(typeck, pat);
+        }
+        walk_pat(self, pat);
+    }
 }
 
 fn maybe_copy<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
@@ -63,17 +63,20 @@
 
 // begin lints modules, do not remove this comment, it’s used in `update_lints`
 mod absolute_paths;
+mod allocation_in_handler;
 mod allow_attributes;
 mod almost_complete_range;
 mod approx_const;
 mod arc_with_non_send_sync;
 mod as_conversions;
+mod asm_missing_options;
 mod asm_syntax;
 mod assertions_on_constants;
 mod assertions_on_result_states;
 mod async_yields_async;
 mod attrs;
 mod await_holding_invalid;
+mod blocking_op_in_async;
 mod blocks_in_if_conditions;
 mod bool_assert_comparison;
 mod bool_to_int_with_if;
@@ -127,6 +130,7 @@
 mod explicit_write;
 mod extra_unused_type_parameters;
 mod fallible_impl_from;
+mod feature_detection_in_loop;
 mod float_literal;
 mod floating_point_arithmetic;
 mod format;
@@ -140,6 +144,7 @@
 mod from_str_radix_10;
 mod functions;
 mod future_not_send;
+mod global_allocator_in_library;
 mod if_let_mutex;
 mod if_not_else;
 mod if_then_some_else_none;
@@ -183,22 +188,33 @@
 mod loops;
 mod macro_use;
 mod main_recursion;
+mod manual_abs_diff;
+mod manual_array_map;
 mod manual_assert;
 mod manual_async_fn;
 mod manual_bits;
+mod manual_checked_sub;
 mod manual_clamp;
+mod manual_div_ceil;
 mod manual_float_methods;
 mod manual_hash_one;
+mod manual_ilog2;
 mod manual_is_ascii_check;
+mod manual_is_power_of_two;
 mod manual_let_else;
 mod manual_main_separator_str;
 mod manual_non_exhaustive;
+mod manual_noop_waker;
+mod manual_once_cell;
+mod manual_option_zip;
 mod manual_range_patterns;
 mod manual_rem_euclid;
 mod manual_retain;
+mod manual_rotate;
 mod manual_slice_size_calculation;
 mod manual_string_new;
 mod manual_strip;
+mod manual_try_into_array;
 mod map_unit_fn;
 mod match_result_ok;
 mod matches;
@@ -265,6 +281,7 @@
 mod pass_by_ref_or_value;
 mod pattern_type_mismatch;
 mod permissions_set_readonly_false;
+mod pointer_in_nomem_asm;
 mod precedence;
 mod ptr;
 mod ptr_offset_with_cast;
@@ -322,7 +339,9 @@
 mod trait_bounds;
 mod transmute;
 mod tuple_array_conversions;
+mod tuple_struct_array_conversions;
 mod types;
+mod unbounded_channels;
 mod undocumented_unsafe_blocks;
 mod unicode;
 mod uninit_vec;
@@ -354,10 +373,12 @@
 mod wildcard_imports;
 mod write;
 mod zero_div_zero;
+mod zero_repeat_side_effects;
 mod zero_sized_map_values;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
 use clippy_config::{get_configuration_metadata, Conf};
+pub use clippy_utils::qualify_min_const_fn::DryRuns;
 
 /// Register all pre expansion lints
 ///
@@ -496,7 +517,7 @@ fn register_categories(store: &mut rustc_lint::LintStore) {
 ///
 /// Used in `./src/driver.rs`.
 #[expect(clippy::too_many_lines)]
-pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
+pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf, dry_runs: &DryRuns) {
     let Conf {
         ref absolute_paths_allowed_crates,
         absolute_paths_max_segments,
@@ -508,16 +529,20 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         allow_one_hash_in_raw_strings,
         allow_print_in_tests,
         allow_private_module_inception,
+        ref allow_renamed_params_for,
         allow_unwrap_in_tests,
         ref allowed_dotfiles,
+        ref allowed_duplicate_crates,
         ref allowed_idents_below_min_chars,
         ref allowed_scripts,
         ref arithmetic_side_effects_allowed_binary,
         ref arithmetic_side_effects_allowed_unary,
         ref arithmetic_side_effects_allowed,
         array_size_threshold,
+        async_runtime,
         avoid_breaking_exported_api,
         ref await_holding_invalid_types,
+        ref blocking_functions,
         cargo_ignore_publish,
         cognitive_complexity_threshold,
         ref disallowed_macros,
@@ -525,6 +550,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         ref disallowed_names,
         ref disallowed_types,
         ref doc_valid_idents,
+        duplicate_crates_max_depth,
         enable_raw_pointer_heuristic_for_send,
         enforce_iter_loop_reborrow,
         ref enforced_import_renames,
@@ -532,6 +558,8 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         enum_variant_size_threshold,
         excessive_nesting_threshold,
         future_size_threshold,
+        ref global_allocator_allowed_crates,
+        ignore_heterogeneous_names,
         ref ignore_interior_mutability,
         large_error_threshold,
         literal_representation_threshold,
@@ -544,9 +572,15 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         min_ident_chars_threshold,
         missing_docs_in_crate_items,
         ref msrv,
+        mutex_atomic_statics_only,
+        ref no_alloc_fns,
         pass_by_value_size_limit,
         semicolon_inside_block_ignore_singleline,
         semicolon_outside_block_ignore_multiline,
+        single_call_fn_count_test_calls,
+        single_call_fn_ignore_inline,
+        ref single_call_fn_ignore_patterns,
+        single_call_fn_ignore_trait_methods,
         single_char_binding_names_threshold,
         stack_size_threshold,
         ref standard_macro_braces,
@@ -556,7 +590,10 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         too_many_arguments_threshold,
         too_many_lines_threshold,
         trivial_copy_size_limit,
+        tuple_array_conversions_min_size,
+        ref tuple_struct_conversion_allowed_types,
         type_complexity_threshold,
+        ref unbounded_channel_constructors,
         unnecessary_box_size,
         unreadable_literal_lint_fractions,
         upper_case_acronyms_aggressive,
@@ -655,7 +692,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(attrs::Attributes));
     store.register_late_pass(|_| Box::new(blocks_in_if_conditions::BlocksInIfConditions));
     store.register_late_pass(|_| Box::new(unicode::Unicode));
-    store.register_late_pass(|_| Box::new(uninit_vec::UninitVec));
+    store.register_late_pass(|_| Box::<uninit_vec::UninitVec>::default());
     store.register_late_pass(|_| Box::new(unit_return_expecting_ord::UnitReturnExpectingOrd));
     store.register_late_pass(|_| Box::new(strings::StringAdd));
     store.register_late_pass(|_| Box::new(implicit_return::ImplicitReturn));
@@ -685,7 +722,10 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| Box::new(ranges::Ranges::new(msrv())));
     store.register_late_pass(move |_| Box::new(from_over_into::FromOverInto::new(msrv())));
     store.register_late_pass(move |_| Box::new(use_self::UseSelf::new(msrv())));
-    store.register_late_pass(move |_| Box::new(missing_const_for_fn::MissingConstForFn::new(msrv())));
+    let dry_runs = dry_runs.clone();
+    store.register_late_pass(move |_| {
+        Box::new(missing_const_for_fn::MissingConstForFn::new(msrv(), dry_runs.clone()))
+    });
     store.register_late_pass(move |_| Box::new(needless_question_mark::NeedlessQuestionMark));
     store.register_late_pass(move |_| Box::new(casts::Casts::new(msrv())));
     store.register_early_pass(move || Box::new(unnested_or_patterns::UnnestedOrPatterns::new(msrv())));
@@ -705,7 +745,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(entry::HashMapPass));
     store.register_late_pass(|_| Box::new(minmax::MinMaxPass));
     store.register_late_pass(|_| Box::new(zero_div_zero::ZeroDiv));
-    store.register_late_pass(|_| Box::new(mutex_atomic::Mutex));
+    store.register_late_pass(move |_| Box::new(mutex_atomic::Mutex::new(mutex_atomic_statics_only)));
     store.register_late_pass(|_| Box::new(needless_update::NeedlessUpdate));
     store.register_late_pass(|_| Box::new(needless_borrowed_ref::NeedlessBorrowedRef));
     store.register_late_pass(|_| Box::new(borrow_deref_ref::BorrowDerefRef));
@@ -745,6 +785,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
             too_many_lines_threshold,
             large_error_threshold,
             avoid_breaking_exported_api,
+            allow_renamed_params_for.clone(),
         ))
     });
     store.register_late_pass(move |_| Box::new(doc::Documentation::new(doc_valid_idents, check_private_items)));
@@ -759,7 +800,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(unused_io_amount::UnusedIoAmount));
     store.register_late_pass(move |_| Box::new(large_enum_variant::LargeEnumVariant::new(enum_variant_size_threshold)));
     store.register_late_pass(|_| Box::new(explicit_write::ExplicitWrite));
-    store.register_late_pass(|_| Box::new(needless_pass_by_value::NeedlessPassByValue));
+    store.register_late_pass(|_| Box::<needless_pass_by_value::NeedlessPassByValue>::default());
     store.register_late_pass(move |tcx| {
         Box::new(pass_by_ref_or_value::PassByRefOrValue::new(
             trivial_copy_size_limit,
@@ -936,6 +977,8 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| {
         Box::new(cargo::Cargo {
             ignore_publish: cargo_ignore_publish,
+            allowed_duplicate_crates: allowed_duplicate_crates.clone(),
+            duplicate_crates_max_depth,
         })
     });
     store.register_early_pass(|| Box::new(crate_in_macro_def::CrateInMacroDef));
@@ -1034,6 +1077,10 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| {
         Box::new(single_call_fn::SingleCallFn {
             avoid_breaking_exported_api,
+            ignore_patterns: single_call_fn_ignore_patterns.clone(),
+            ignore_inline: single_call_fn_ignore_inline,
+            ignore_trait_methods: single_call_fn_ignore_trait_methods,
+            count_test_calls: single_call_fn_count_test_calls,
             def_id_to_usage: rustc_data_structures::fx::FxHashMap::default(),
         })
     });
@@ -1044,7 +1091,13 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     });
     store.register_late_pass(|_| Box::new(manual_range_patterns::ManualRangePatterns));
     store.register_early_pass(|| Box::new(visibility::Visibility));
-    store.register_late_pass(move |_| Box::new(tuple_array_conversions::TupleArrayConversions { msrv: msrv() }));
+    store.register_late_pass(move |_| {
+        Box::new(tuple_array_conversions::TupleArrayConversions::new(
+            msrv(),
+            tuple_array_conversions_min_size,
+            ignore_heterogeneous_names,
+        ))
+    });
     store.register_late_pass(|_| Box::new(manual_float_methods::ManualFloatMethods));
     store.register_late_pass(|_| Box::new(four_forward_slashes::FourForwardSlashes));
     store.register_late_pass(|_| Box::new(error_impl_error::ErrorImplError));
@@ -1069,6 +1122,36 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(iter_without_into_iter::IterWithoutIntoIter));
     store.register_late_pass(|_| Box::new(iter_over_hash_type::IterOverHashType));
     store.register_late_pass(|_| Box::new(impl_hash_with_borrow_str_and_bytes::ImplHashWithBorrowStrBytes));
+    store.register_late_pass(move |_| Box::new(manual_try_into_array::ManualTryIntoArray::new(msrv())));
+    store.register_late_pass(move |_| Box::new(manual_array_map::ManualArrayMap::new(msrv())));
+    store.register_late_pass(move |_| Box::new(manual_div_ceil::ManualDivCeil::new(msrv())));
+    store.register_late_pass(|_| Box::new(manual_is_power_of_two::ManualIsPowerOfTwo));
+    store.register_late_pass(move |_| Box::new(manual_abs_diff::ManualAbsDiff::new(msrv())));
+    store.register_late_pass(|_| Box::new(manual_rotate::ManualRotate));
+    store.register_late_pass(move |_| Box::new(manual_ilog2::ManualIlog2::new(msrv())));
+    store.register_late_pass(|_| Box::new(manual_checked_sub::ManualCheckedSub));
+    store.register_late_pass(|_| Box::new(feature_detection_in_loop::FeatureDetectionInLoop));
+    store.register_late_pass(move |_| {
+        Box::new(global_allocator_in_library::GlobalAllocatorInLibrary::new(global_allocator_allowed_crates))
+    });
+    store.register_late_pass(move |_| Box::new(allocation_in_handler::AllocationInHandler::new(no_alloc_fns.clone())));
+    store.register_late_pass(move |_| {
+        Box::new(blocking_op_in_async::BlockingOpInAsync::new(blocking_functions.clone(), async_runtime))
+    });
+    store.register_late_pass(move |_| {
+        Box::new(unbounded_channels::UnboundedChannels::new(unbounded_channel_constructors.clone()))
+    });
+    store.register_late_pass(move |_| {
+        Box::new(tuple_struct_array_conversions::TupleStructArrayConversions::new(
+            tuple_struct_conversion_allowed_types.clone(),
+        ))
+    });
+    store.register_late_pass(move |_| Box::new(manual_option_zip::ManualOptionZip::new(msrv())));
+    store.register_late_pass(move |_| Box::new(manual_once_cell::ManualOnceCell::new(msrv())));
+    store.register_late_pass(|_| Box::new(manual_noop_waker::ManualNoopWaker));
+    store.register_late_pass(|_| Box::new(zero_repeat_side_effects::ZeroRepeatSideEffects));
+    store.register_early_pass(|| Box::new(asm_missing_options::AsmMissingOptions));
+    store.register_late_pass(|_| Box::new(pointer_in_nomem_asm::PointerInNomemAsm));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
@@ -1125,6 +1208,16 @@ pub fn register_renamed(ls: &mut rustc_lint::LintStore) {
     }
 }
 
+/// Runs the checks that need MIR from before `mir_promoted` steals it, recording their outcome in
+/// `dry_runs` for the lint passes registered with it.
+///
+/// Used in `./src/driver.rs`, which calls this after expansion, before the MIR is built for analysis.
+pub fn check_before_promotion(tcx: rustc_middle::ty::TyCtxt<'_>, dry_runs: &DryRuns) {
+    for def_id in tcx.hir().body_owners() {
+        missing_const_for_fn::check_before_promotion(tcx, def_id, dry_runs);
+    }
+}
+
 // only exists to let the dogfood integration test works.
 // Don't run clippy as an executable directly
 #[allow(dead_code)]
@@ -0,0 +1,79 @@
+use super::DYN_DISPATCH_IN_LOOP;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_hir::{Block, BorrowKind, Expr, ExprKind, HirId, Mutability};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, loop_expr: &Expr<'_>, body: &'tcx Block<'tcx>) {
+    // Only innermost loops are hot enough to be worth it.
+    let has_inner_loop = for_each_expr(body, |e| match e.kind {
+        ExprKind::Loop(..) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some();
+    if has_inner_loop {
+        return;
+    }
+
+    for_each_expr(body, |e| {
+        if !e.span.from_expansion()
+            && let Some(callee) = dyn_callee(cx, e)
+            && let Some(local) = path_to_local(callee)
+            && !cx.tcx.hir().parent_id_iter(local).any(|id| id == loop_expr.hir_id)
+            && !is_reassigned(local, body)
+        {
+            span_lint_and_help(
+                cx,
+                DYN_DISPATCH_IN_LOOP,
+                e.span,
+                "dynamically dispatched call in a loop",
+                None,
+                &format!(
+                    "`{}` is the same on every iteration; consider making the function generic over its type, \
+                     so that the call can be resolved at compile time",
+                    snippet(cx, callee.span, "..")
+                ),
+            );
+        }
+        ControlFlow::<()>::Continue(())
+    });
+}
+
+/// The receiver of a trait method called on a `dyn Trait`, or the callee of a call to a `dyn Fn`,
+/// behind a reference or a `Box`.
+fn dyn_callee<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match expr.kind {
+        ExprKind::MethodCall(_, recv, ..) => {
+            let method = cx.typeck_results().type_dependent_def_id(expr.hir_id)?;
+            cx.tcx.trait_of_item(method)?;
+            // Only a `dyn Trait` as `Self` makes the call virtual, and not e.g. `Clone` on a `&dyn Trait`.
+            let self_ty = cx.typeck_results().node_args(expr.hir_id).type_at(0);
+            matches!(self_ty.kind(), ty::Dynamic(..)).then_some(recv)
+        },
+        ExprKind::Call(callee, _) => is_dyn(cx.typeck_results().expr_ty(callee)).then_some(callee),
+        _ => None,
+    }
+}
+
+fn is_dyn(ty: Ty<'_>) -> bool {
+    let ty = ty.peel_refs();
+    let ty = if ty.is_box() { ty.boxed_ty() } else { ty };
+    matches!(ty.kind(), ty::Dynamic(..))
+}
+
+/// Whether `local` is assigned to, or borrowed mutably so it could be, in `body`.
+pub(super) fn is_reassigned<'tcx>(local: HirId, body: &'tcx Block<'tcx>) -> bool {
+    for_each_expr(body, |e| match e.kind {
+        ExprKind::Assign(lhs, ..) | ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, lhs)
+            if path_to_local_id(lhs, local) =>
+        {
+            ControlFlow::Break(())
+        },
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
@@ -1,3 +1,4 @@
+mod dyn_dispatch_in_loop;
 mod empty_loop;
 mod explicit_counter_loop;
 mod explicit_into_iter_loop;
@@ -12,6 +13,7 @@
 mod mut_range_bound;
 mod needless_range_loop;
 mod never_loop;
+mod reader_in_loop;
 mod same_item_push;
 mod single_element_loop;
 mod unused_enumerate_index;
@@ -635,6 +637,92 @@
     "checking for emptiness of a `Vec` in the loop condition and popping an element in the body"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls through a `&dyn Trait`, `Box<dyn Trait>` or `&dyn Fn(..)` inside an innermost
+    /// loop, where the callee is a local that is the same on every iteration.
+    ///
+    /// ### Why is this bad?
+    /// Every such call loads the function pointer from the vtable and calls it indirectly, which
+    /// also keeps it from being inlined into the loop. A generic function is compiled for each
+    /// type it is used with, so the call in the loop can be resolved statically.
+    ///
+    /// ### Known problems
+    /// Monomorphizing a function for each type it is called with makes for more code to compile.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn total(values: &[f64], weight: &dyn Fn(f64) -> f64) -> f64 {
+    ///     let mut total = 0.0;
+    ///     for &value in values {
+    ///         total += weight(value);
+    ///     }
+    ///     total
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn total(values: &[f64], weight: impl Fn(f64) -> f64) -> f64 {
+    ///     let mut total = 0.0;
+    ///     for &value in values {
+    ///         total += weight(value);
+    ///     }
+    ///     total
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub DYN_DISPATCH_IN_LOOP,
+    pedantic,
+    "dynamically dispatched calls to the same callee on every iteration of a loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `BufReader::new(File::open(path)?)`, and `read_to_string` called directly on
+    /// `File::open(path)?`, inside a loop, where `path` is the same on every iteration.
+    ///
+    /// ### Why is this bad?
+    /// Every iteration opens the file again with a system call, allocates a new buffer for the
+    /// reader or the `String`, and drops it again at the end. The file and the buffer can be kept
+    /// across iterations instead, by seeking back to the start of the file and clearing the `String`.
+    ///
+    /// ### Known problems
+    /// Seeking reads the same file again. If the path is replaced by another file during the loop,
+    /// for example by renaming a new file over it, the loop keeps reading the old file.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use std::io::{BufRead, BufReader};
+    /// # fn f() -> std::io::Result<()> {
+    /// for pattern in ["error", "warning"] {
+    ///     let reader = BufReader::new(File::open("app.log")?);
+    ///     let count = reader.lines().filter(|line| line.as_ref().is_ok_and(|l| l.contains(pattern))).count();
+    ///     println!("{pattern}: {count}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use std::io::{BufRead, BufReader, Seek, SeekFrom};
+    /// # fn f() -> std::io::Result<()> {
+    /// let mut reader = BufReader::new(File::open("app.log")?);
+    /// for pattern in ["error", "warning"] {
+    ///     reader.seek(SeekFrom::Start(0))?;
+    ///     let count = (&mut reader).lines().filter(|line| line.as_ref().is_ok_and(|l| l.contains(pattern))).count();
+    ///     println!("{pattern}: {count}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub READER_IN_LOOP,
+    perf,
+    "opening and reading the same file on every iteration of a loop"
+}
+
 pub struct Loops {
     msrv: Msrv,
     enforce_iter_loop_reborrow: bool,
@@ -669,6 +757,8 @@ pub fn new(msrv: Msrv, enforce_iter_loop_reborrow: bool) -> Self {
     MANUAL_FIND,
     MANUAL_WHILE_LET_SOME,
     UNUSED_ENUMERATE_INDEX,
+    DYN_DISPATCH_IN_LOOP,
+    READER_IN_LOOP,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Loops {
@@ -691,6 +781,8 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             self.check_for_loop(cx, pat, arg, body, expr, span);
             if let ExprKind::Block(block, _) = body.kind {
                 never_loop::check(cx, block, loop_id, span, for_loop.as_ref());
+                dyn_dispatch_in_loop::check(cx, expr, block);
+                reader_in_loop::check(cx, expr, block);
             }
         }
 
@@ -702,6 +794,8 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         // check for never_loop
         if let ExprKind::Loop(block, ..) = expr.kind {
             never_loop::check(cx, block, expr.hir_id, expr.span, None);
+            dyn_dispatch_in_loop::check(cx, expr, block);
+            reader_in_loop::check(cx, expr, block);
         }
 
         // check for `loop { if let {} else break }` that could be `while let`
@@ -0,0 +1,94 @@
+use super::dyn_dispatch_in_loop::is_reassigned;
+use super::READER_IN_LOOP;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{fn_def_id, is_trait_method, match_def_path, path_res, paths};
+use core::ops::ControlFlow;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Block, Expr, ExprKind, MatchSource};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, loop_expr: &Expr<'_>, body: &'tcx Block<'tcx>) {
+    for_each_expr(body, |e| {
+        // Inner loops are checked on their own.
+        if matches!(e.kind, ExprKind::Loop(..)) {
+            return ControlFlow::<()>::Continue(Descend::No);
+        }
+        if e.span.from_expansion() {
+            return ControlFlow::Continue(Descend::Yes);
+        }
+        let (arg, help) = match e.kind {
+            ExprKind::Call(_, [arg])
+                if fn_def_id(cx, e).is_some_and(|id| match_def_path(cx, id, &paths::BUF_READER_NEW)) =>
+            {
+                (
+                    arg,
+                    "consider creating the reader before the loop, and calling `seek(SeekFrom::Start(0))` on it \
+                     to read the file again",
+                )
+            },
+            ExprKind::MethodCall(name, recv, [_], _)
+                if name.ident.as_str() == "read_to_string" && is_trait_method(cx, e, sym::IoRead) =>
+            {
+                (
+                    recv,
+                    "consider opening the file and creating the `String` before the loop, then calling `clear` \
+                     on the `String` and `seek(SeekFrom::Start(0))` on the file to read it again",
+                )
+            },
+            _ => return ControlFlow::Continue(Descend::Yes),
+        };
+        let open = peel_try_or_unwrap(arg);
+        if let ExprKind::Call(_, [path]) = open.kind
+            && fn_def_id(cx, open).is_some_and(|id| match_def_path(cx, id, &paths::FILE_OPEN))
+            && is_loop_invariant(cx, loop_expr, body, path)
+        {
+            span_lint_and_help(
+                cx,
+                READER_IN_LOOP,
+                e.span,
+                "reopening the same file on every iteration of a loop",
+                None,
+                help,
+            );
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+}
+
+/// Looks through `?`, `unwrap()` and `expect(..)`.
+fn peel_try_or_unwrap<'tcx>(mut expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    loop {
+        expr = match expr.kind {
+            ExprKind::Match(scrutinee, _, MatchSource::TryDesugar(_)) => match scrutinee.kind {
+                ExprKind::Call(_, [inner]) => inner,
+                _ => return expr,
+            },
+            ExprKind::MethodCall(name, recv, ..) if matches!(name.ident.as_str(), "unwrap" | "expect") => recv,
+            _ => return expr,
+        };
+    }
+}
+
+/// Whether `expr` is a literal, a constant, or a local that keeps its value for the whole loop,
+/// optionally borrowed.
+fn is_loop_invariant<'tcx>(
+    cx: &LateContext<'tcx>,
+    loop_expr: &Expr<'_>,
+    body: &'tcx Block<'tcx>,
+    expr: &Expr<'_>,
+) -> bool {
+    match expr.kind {
+        ExprKind::Lit(_) => true,
+        ExprKind::AddrOf(_, _, inner) => is_loop_invariant(cx, loop_expr, body, inner),
+        ExprKind::Path(_) => match path_res(cx, expr) {
+            Res::Local(local) => {
+                !cx.tcx.hir().parent_id_iter(local).any(|id| id == loop_expr.hir_id) && !is_reassigned(local, body)
+            },
+            Res::Def(DefKind::Const | DefKind::AssocConst | DefKind::Static(_), _) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,191 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{eq_expr_value, higher, is_else_clause, is_trait_method, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for expressions like `if a > b { a - b } else { b - a }` or `a.max(b) - a.min(b)`
+    /// on integers, which are manual reimplementations of `a.abs_diff(b)`.
+    ///
+    /// ### Why is this bad?
+    /// It's simpler and more readable. For signed integers, `abs_diff` also can't overflow,
+    /// since it returns the unsigned type of the same size.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let (a, b) = (3u32, 5u32);
+    /// let diff = if a > b { a - b } else { b - a };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let (a, b) = (3u32, 5u32);
+    /// let diff = a.abs_diff(b);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_ABS_DIFF,
+    complexity,
+    "manually reimplementing `abs_diff`"
+}
+
+pub struct ManualAbsDiff {
+    msrv: Msrv,
+}
+
+impl ManualAbsDiff {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualAbsDiff => [MANUAL_ABS_DIFF]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualAbsDiff {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !expr.span.from_expansion()
+            && !in_external_macro(cx.sess(), expr.span)
+            && let ty = cx.typeck_results().expr_ty(expr)
+            && ty.is_integral()
+            && let Some((a, b)) = if_operands(cx, expr).or_else(|| max_min_operands(cx, expr))
+            && self.msrv.meets(msrvs::ABS_DIFF)
+        {
+            span_lint_and_then(
+                cx,
+                MANUAL_ABS_DIFF,
+                expr.span,
+                "manually reimplementing `abs_diff`",
+                |diag| {
+                    // For signed integers, `abs_diff` returns a different type.
+                    let mut app = if ty.is_signed() {
+                        Applicability::MaybeIncorrect
+                    } else {
+                        Applicability::MachineApplicable
+                    };
+                    let ctxt = expr.span.ctxt();
+                    let a = Sugg::hir_with_context(cx, a, ctxt, "..", &mut app).maybe_par();
+                    let (b, _) = snippet_with_context(cx, b.span, ctxt, "..", &mut app);
+                    let mut sugg = format!("{a}.abs_diff({b})");
+                    if is_else_clause(cx.tcx, expr) {
+                        sugg = format!("{{ {sugg} }}");
+                    }
+                    diag.span_suggestion(expr.span, "use `abs_diff` instead", sugg, app);
+                    if ty.is_signed() {
+                        diag.note(format!(
+                            "`abs_diff` returns `{}`, which can hold differences that overflow `{ty}`",
+                            unsigned_of(cx, ty),
+                        ));
+                    }
+                },
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Matches `if a > b { a - b } else { b - a }`, and the same with any of `>=`, `<` and `<=` with
+/// the branches swapped accordingly, returning `a` and `b` in the order the condition uses them.
+fn if_operands<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    let higher::If {
+        cond,
+        then,
+        r#else: Some(r#else),
+    } = higher::If::hir(expr)?
+    else {
+        return None;
+    };
+    let ExprKind::Binary(op, a, b) = cond.kind else {
+        return None;
+    };
+    let (greater, lesser) = match op.node {
+        BinOpKind::Gt | BinOpKind::Ge => (a, b),
+        BinOpKind::Lt | BinOpKind::Le => (b, a),
+        _ => return None,
+    };
+    if is_sub_of(cx, peel_blocks(then), greater, lesser) && is_sub_of(cx, peel_blocks(r#else), lesser, greater) {
+        Some((a, b))
+    } else {
+        None
+    }
+}
+
+/// `lhs - rhs`
+fn is_sub_of(cx: &LateContext<'_>, expr: &Expr<'_>, lhs: &Expr<'_>, rhs: &Expr<'_>) -> bool {
+    if let ExprKind::Binary(op, l, r) = expr.kind {
+        op.node == BinOpKind::Sub && eq_expr_value(cx, l, lhs) && eq_expr_value(cx, r, rhs)
+    } else {
+        false
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum MinMax {
+    Min,
+    Max,
+}
+
+/// Matches `a.max(b) - a.min(b)` and `max(a, b) - min(a, b)`, with the arguments of either in any
+/// order, returning `a` and `b` in the order `max` takes them.
+fn max_min_operands<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::Binary(op, max, min) = expr.kind
+        && op.node == BinOpKind::Sub
+        && let Some((MinMax::Max, a, b)) = min_max(cx, max)
+        && let Some((MinMax::Min, c, d)) = min_max(cx, min)
+        && (eq_expr_value(cx, a, c) && eq_expr_value(cx, b, d) || eq_expr_value(cx, a, d) && eq_expr_value(cx, b, c))
+    {
+        Some((a, b))
+    } else {
+        None
+    }
+}
+
+fn min_max<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(MinMax, &'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    match expr.kind {
+        ExprKind::Call(path, [a, b]) => {
+            if let ExprKind::Path(ref qpath) = path.kind {
+                cx.typeck_results()
+                    .qpath_res(qpath, path.hir_id)
+                    .opt_def_id()
+                    .and_then(|def_id| match cx.tcx.get_diagnostic_name(def_id) {
+                        Some(sym::cmp_min) => Some((MinMax::Min, a, b)),
+                        Some(sym::cmp_max) => Some((MinMax::Max, a, b)),
+                        _ => None,
+                    })
+            } else {
+                None
+            }
+        },
+        ExprKind::MethodCall(path, receiver, [arg], _) if is_trait_method(cx, expr, sym::Ord) => {
+            if path.ident.name == sym!(max) {
+                Some((MinMax::Max, receiver, arg))
+            } else if path.ident.name == sym!(min) {
+                Some((MinMax::Min, receiver, arg))
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+fn unsigned_of<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Ty<'tcx> {
+    match ty.kind() {
+        ty::Int(int) => Ty::new_uint(cx.tcx, int.to_unsigned()),
+        _ => ty,
+    }
+}
@@ -0,0 +1,162 @@
+use crate::tuple_array_conversions::{bindings_receiver, emit_suggestion, index_projection};
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{fn_def_id, get_parent_node, is_from_proc_macro, path_to_local, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::impl_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for arrays built by calling the same function on each element of another array, in
+    /// order, e.g. `[f(a[0]), f(a[1]), f(a[2])]`, or `[f(x), f(y), f(z)]` where `[x, y, z]` was
+    /// destructured from an array.
+    ///
+    /// ### Why is this bad?
+    /// `array::map` says the same thing without spelling out each element, and can't get their
+    /// order or the length of the array wrong.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn double(x: u32) -> u32 {
+    ///     x * 2
+    /// }
+    ///
+    /// let a = [1, 2, 3];
+    /// let b = [double(a[0]), double(a[1]), double(a[2])];
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn double(x: u32) -> u32 {
+    /// #     x * 2
+    /// # }
+    /// let a = [1, 2, 3];
+    /// let b = a.map(double);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_ARRAY_MAP,
+    complexity,
+    "calling a function on each element of an array by hand instead of using `array::map`"
+}
+impl_lint_pass!(ManualArrayMap => [MANUAL_ARRAY_MAP]);
+
+pub struct ManualArrayMap {
+    msrv: Msrv,
+}
+
+impl ManualArrayMap {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualArrayMap {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Array(elements) = expr.kind
+            && !elements.is_empty()
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(calls) = elements
+                .iter()
+                .map(|e| single_arg_call(cx, e))
+                .collect::<Option<Vec<_>>>()
+            && let [(callee, _), rest @ ..] = &calls[..]
+            && rest.iter().all(|(c, _)| SpanlessEq::new(cx).eq_expr(c, callee))
+            && self.msrv.meets(msrvs::ARRAY_MAP)
+            && !is_from_proc_macro(cx, expr)
+        {
+            let args = calls.iter().map(|&(_, arg)| arg).collect::<Vec<_>>();
+            let mut app = Applicability::MachineApplicable;
+            let Some((recv, edits)) = projection_receiver(cx, expr, &args, &mut app)
+                .map(|recv| (recv, vec![]))
+                .or_else(|| destructured_receiver(cx, &args, &mut app))
+            else {
+                return;
+            };
+
+            span_lint_and_then(
+                cx,
+                MANUAL_ARRAY_MAP,
+                expr.span,
+                "manual implementation of `array::map`",
+                |diag| {
+                    let (callee, _) = snippet_with_context(cx, callee.span, expr.span.ctxt(), "..", &mut app);
+                    let mut edits = edits;
+                    edits.push((expr.span, format!("{}.map({callee})", recv.maybe_par())));
+                    emit_suggestion(diag, "use `map` instead", edits, app);
+                },
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// `f(arg)` where `f` names a function, returning `f` and `arg`. Coercing `arg` wouldn't happen
+/// inside `map`, so that isn't allowed either.
+fn single_arg_call<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::Call(callee, [arg]) = expr.kind
+        && let ExprKind::Path(_) = callee.kind
+        && fn_def_id(cx, expr).is_some()
+        && !expr.span.from_expansion()
+        && cx.typeck_results().expr_adjustments(arg).is_empty()
+    {
+        Some((callee, arg))
+    } else {
+        None
+    }
+}
+
+/// `[f(a[0]), f(a[1])]` where `a` is an array of exactly that many elements, returning `a`.
+fn projection_receiver<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    args: &[&'tcx Expr<'tcx>],
+    app: &mut Applicability,
+) -> Option<Sugg<'tcx>> {
+    let recv = index_projection(args.first().copied()?, 0)?;
+    let local = path_to_local(recv)?;
+    if args
+        .iter()
+        .enumerate()
+        .all(|(i, &arg)| index_projection(arg, i).and_then(path_to_local) == Some(local))
+        && is_array_of_len(cx, cx.typeck_results().expr_ty(recv), args.len())
+    {
+        Some(Sugg::hir_with_context(cx, recv, expr.span.ctxt(), "..", app))
+    } else {
+        None
+    }
+}
+
+/// `[f(a), f(b)]` where `a` and `b` were bound by the pattern `[a, b]` on an array, which can then
+/// bind the whole array instead.
+fn destructured_receiver<'tcx>(
+    cx: &LateContext<'tcx>,
+    args: &[&'tcx Expr<'tcx>],
+    app: &mut Applicability,
+) -> Option<(Sugg<'tcx>, Vec<(Span, String)>)> {
+    let Some(Node::Pat(pat)) = get_parent_node(cx.tcx, path_to_local(args.first()?)?) else {
+        return None;
+    };
+    if !is_array_of_len(cx, cx.typeck_results().pat_ty(pat), args.len()) {
+        return None;
+    }
+    bindings_receiver(cx, args, "arr", app)
+}
+
+fn is_array_of_len<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, len: usize) -> bool {
+    if let ty::Array(_, array_len) = ty.kind() {
+        array_len.try_eval_target_usize(cx.tcx, cx.param_env) == Some(len as u64)
+    } else {
+        false
+    }
+}
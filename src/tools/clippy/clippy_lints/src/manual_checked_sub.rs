@@ -0,0 +1,168 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{
+    eq_expr_value, higher, is_else_clause, is_integer_literal, is_res_lang_ctor, path_res, peel_blocks,
+};
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionNone, OptionSome};
+use rustc_hir::{BinOpKind, Expr, ExprKind, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for expressions like `if a >= b { Some(a - b) } else { None }` and
+    /// `if a >= b { a - b } else { 0 }` on unsigned integers, written with `if` or with a `match`
+    /// on the condition, which are manual reimplementations of `a.checked_sub(b)` and
+    /// `a.saturating_sub(b)`.
+    ///
+    /// ### Why is this bad?
+    /// The methods are shorter and say what is being computed, and there's no comparison to get
+    /// the wrong way around.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let (a, b) = (5u32, 3u32);
+    /// let diff = if a >= b { Some(a - b) } else { None };
+    /// let rest = if a > b { a - b } else { 0 };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let (a, b) = (5u32, 3u32);
+    /// let diff = a.checked_sub(b);
+    /// let rest = a.saturating_sub(b);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_CHECKED_SUB,
+    complexity,
+    "manually reimplementing `checked_sub` or `saturating_sub`"
+}
+declare_lint_pass!(ManualCheckedSub => [MANUAL_CHECKED_SUB]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualCheckedSub {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !expr.span.from_expansion()
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some((cond, then, r#else)) = if_or_match_bool(expr)
+            && let ExprKind::Binary(op, a, b) = cond.kind
+            && let Some((then_bound, else_bound)) = branch_bounds(op.node, a, b)
+            && let Some((method, lhs, rhs)) = sub_or_fallback(cx, peel_blocks(then), peel_blocks(r#else), then_bound)
+                .or_else(|| sub_or_fallback(cx, peel_blocks(r#else), peel_blocks(then), else_bound))
+        {
+            let mut app = Applicability::MachineApplicable;
+            let ctxt = expr.span.ctxt();
+            let lhs = Sugg::hir_with_context(cx, lhs, ctxt, "..", &mut app).maybe_par();
+            let (rhs, _) = snippet_with_context(cx, rhs.span, ctxt, "..", &mut app);
+            let mut sugg = format!("{lhs}.{method}({rhs})");
+            if is_else_clause(cx.tcx, expr) {
+                sugg = format!("{{ {sugg} }}");
+            }
+            span_lint_and_sugg(
+                cx,
+                MANUAL_CHECKED_SUB,
+                expr.span,
+                &format!("manually reimplementing `{method}`"),
+                &format!("use `{method}` instead"),
+                sugg,
+                app,
+            );
+        }
+    }
+}
+
+/// Matches `if cond { then } else { else }` and `match cond { true => then, false => else }`,
+/// with the arms in either order and the last one possibly a wildcard.
+fn if_or_match_bool<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let Some(higher::If {
+        cond,
+        then,
+        r#else: Some(r#else),
+    }) = higher::If::hir(expr)
+    {
+        return Some((cond, then, r#else));
+    }
+    if let ExprKind::Match(cond, [first, second], _) = expr.kind
+        && first.guard.is_none()
+        && second.guard.is_none()
+        && let Some(first_value) = bool_pat(first.pat)
+        && (matches!(second.pat.kind, PatKind::Wild) || bool_pat(second.pat) == Some(!first_value))
+    {
+        return Some(if first_value {
+            (cond, first.body, second.body)
+        } else {
+            (cond, second.body, first.body)
+        });
+    }
+    None
+}
+
+fn bool_pat(pat: &Pat<'_>) -> Option<bool> {
+    if let PatKind::Lit(lit) = pat.kind
+        && let ExprKind::Lit(lit) = lit.kind
+        && let LitKind::Bool(value) = lit.node
+    {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Relation known to hold between two operands: `greater > lesser`, or `greater >= lesser`.
+#[derive(Clone, Copy)]
+struct Bound<'tcx> {
+    greater: &'tcx Expr<'tcx>,
+    lesser: &'tcx Expr<'tcx>,
+    strict: bool,
+}
+
+/// Returns the relations that hold in the `then` and `else` branches of a comparison.
+fn branch_bounds<'tcx>(op: BinOpKind, a: &'tcx Expr<'tcx>, b: &'tcx Expr<'tcx>) -> Option<(Bound<'tcx>, Bound<'tcx>)> {
+    let bound = |greater, lesser, strict| Bound {
+        greater,
+        lesser,
+        strict,
+    };
+    match op {
+        BinOpKind::Gt => Some((bound(a, b, true), bound(b, a, false))),
+        BinOpKind::Ge => Some((bound(a, b, false), bound(b, a, true))),
+        BinOpKind::Lt => Some((bound(b, a, true), bound(a, b, false))),
+        BinOpKind::Le => Some((bound(b, a, false), bound(a, b, true))),
+        _ => None,
+    }
+}
+
+/// Matches a branch computing `greater - lesser` under `bound`, with the other branch `0` or
+/// `None`, returning the method to use and its operands. `checked_sub` also returns `Some(0)` when
+/// the operands are equal, so it needs the subtraction to happen for them too.
+fn sub_or_fallback<'tcx>(
+    cx: &LateContext<'tcx>,
+    sub_branch: &'tcx Expr<'tcx>,
+    other_branch: &Expr<'_>,
+    bound: Bound<'tcx>,
+) -> Option<(&'static str, &'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    let (method, sub) = if let ExprKind::Call(path, [sub]) = sub_branch.kind
+        && is_res_lang_ctor(cx, path_res(cx, path), OptionSome)
+        && is_res_lang_ctor(cx, path_res(cx, other_branch), OptionNone)
+        && !bound.strict
+    {
+        ("checked_sub", sub)
+    } else if is_integer_literal(other_branch, 0) {
+        ("saturating_sub", sub_branch)
+    } else {
+        return None;
+    };
+    if let ExprKind::Binary(op, lhs, rhs) = sub.kind
+        && op.node == BinOpKind::Sub
+        && let ty::Uint(_) = cx.typeck_results().expr_ty(sub).kind()
+        && eq_expr_value(cx, lhs, bound.greater)
+        && eq_expr_value(cx, rhs, bound.lesser)
+    {
+        Some((method, lhs, rhs))
+    } else {
+        None
+    }
+}
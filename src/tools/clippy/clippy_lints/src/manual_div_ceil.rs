@@ -0,0 +1,214 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::consts::{constant_full_int, FullInt};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{eq_expr_value, is_integer_const, path_to_local};
+use rustc_ast::{LitIntType, LitKind};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::SyntaxContext;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for an expression like `(a + b - 1) / b` or `a / b + (a % b != 0) as u32` on unsigned
+    /// integers, which are common manual reimplementations of `a.div_ceil(b)`.
+    ///
+    /// ### Why is this bad?
+    /// It's simpler and more readable. `div_ceil` also can't overflow, while `a + b - 1` can for
+    /// large values of `a`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let len: u32 = 24;
+    /// let pages = (len + 4096 - 1) / 4096;
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let len: u32 = 24;
+    /// let pages = len.div_ceil(4096);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_DIV_CEIL,
+    complexity,
+    "manually reimplementing `div_ceil`"
+}
+
+pub struct ManualDivCeil {
+    msrv: Msrv,
+}
+
+impl ManualDivCeil {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualDivCeil => [MANUAL_DIV_CEIL]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualDivCeil {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Binary(op, lhs, rhs) = expr.kind
+            && !expr.span.from_expansion()
+            && !in_external_macro(cx.sess(), expr.span)
+            && let ty = cx.typeck_results().expr_ty(expr)
+            && let ty::Uint(_) = ty.kind()
+            && let ctxt = expr.span.ctxt()
+            && let Some((dividend, divisor, may_overflow)) = match op.node {
+                // (a + b - 1) / b
+                BinOpKind::Div => rounded_up_dividend(cx, ctxt, lhs, rhs).map(|a| (a, rhs, true)),
+                // a / b + (a % b != 0) as _
+                BinOpKind::Add => quotient_plus_remainder_check(cx, ctxt, lhs, rhs)
+                    .or_else(|| quotient_plus_remainder_check(cx, ctxt, rhs, lhs))
+                    .map(|(a, b)| (a, b, false)),
+                _ => None,
+            }
+            && let ty::Uint(_) = cx.typeck_results().expr_ty(divisor).kind()
+            && self.msrv.meets(msrvs::MANUAL_DIV_CEIL)
+        {
+            span_lint_and_then(
+                cx,
+                MANUAL_DIV_CEIL,
+                expr.span,
+                "manually reimplementing `div_ceil`",
+                |diag| {
+                    let mut app = Applicability::MachineApplicable;
+                    let (divisor_sugg, _) = snippet_with_context(cx, divisor.span, ctxt, "..", &mut app);
+                    let sugg = if is_unsuffixed_int_lit(dividend) {
+                        let (lit, _) = snippet_with_context(cx, dividend.span, ctxt, "..", &mut app);
+                        format!("{lit}_{ty}.div_ceil({divisor_sugg})")
+                    } else if is_unannotated_int_local(cx, dividend) {
+                        // A method call on a local still inferred from an integer literal is ambiguous.
+                        let (local, _) = snippet_with_context(cx, dividend.span, ctxt, "..", &mut app);
+                        format!("{ty}::div_ceil({local}, {divisor_sugg})")
+                    } else {
+                        let dividend_sugg = Sugg::hir_with_context(cx, dividend, ctxt, "..", &mut app).maybe_par();
+                        format!("{dividend_sugg}.div_ceil({divisor_sugg})")
+                    };
+                    diag.span_suggestion(expr.span, "use `div_ceil` instead", sugg, app);
+
+                    if may_overflow && !(is_constant(cx, dividend) && is_constant(cx, divisor)) {
+                        diag.note(
+                            "the addition here can overflow for large dividends, which `div_ceil` avoids, \
+                             so results that used to wrap or panic become correct",
+                        );
+                    }
+                },
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Matches the numerator of `(a + b - 1) / b`, `(a + (b - 1)) / b` and, for a constant `b`,
+/// `(a + c) / b` where `c == b - 1`, returning `a`.
+fn rounded_up_dividend<'tcx>(
+    cx: &LateContext<'_>,
+    ctxt: SyntaxContext,
+    numerator: &'tcx Expr<'tcx>,
+    divisor: &Expr<'_>,
+) -> Option<&'tcx Expr<'tcx>> {
+    let ExprKind::Binary(op, lhs, rhs) = numerator.kind else {
+        return None;
+    };
+    if numerator.span.ctxt() != ctxt {
+        return None;
+    }
+    match op.node {
+        BinOpKind::Sub if is_integer_const(cx, rhs, 1) => {
+            if let ExprKind::Binary(add, x, y) = lhs.kind
+                && add.node == BinOpKind::Add
+                && lhs.span.ctxt() == ctxt
+            {
+                if eq_expr_value(cx, y, divisor) {
+                    Some(x)
+                } else if eq_expr_value(cx, x, divisor) {
+                    Some(y)
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        },
+        BinOpKind::Add => {
+            if is_divisor_minus_one(cx, ctxt, rhs, divisor) {
+                Some(lhs)
+            } else if is_divisor_minus_one(cx, ctxt, lhs, divisor) {
+                Some(rhs)
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+/// `b - 1`, or a constant one less than a constant `b`.
+fn is_divisor_minus_one(cx: &LateContext<'_>, ctxt: SyntaxContext, expr: &Expr<'_>, divisor: &Expr<'_>) -> bool {
+    if let ExprKind::Binary(op, lhs, rhs) = expr.kind
+        && op.node == BinOpKind::Sub
+        && expr.span.ctxt() == ctxt
+        && is_integer_const(cx, rhs, 1)
+    {
+        eq_expr_value(cx, lhs, divisor)
+    } else if let Some(FullInt::U(c)) = constant_full_int(cx, cx.typeck_results(), expr)
+        && let Some(FullInt::U(b)) = constant_full_int(cx, cx.typeck_results(), divisor)
+    {
+        b > 1 && c == b - 1
+    } else {
+        false
+    }
+}
+
+/// Matches `a / b` and `(a % b != 0) as _`, returning `a` and `b`.
+fn quotient_plus_remainder_check<'tcx>(
+    cx: &LateContext<'_>,
+    ctxt: SyntaxContext,
+    quotient: &'tcx Expr<'tcx>,
+    check: &Expr<'_>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::Binary(div, a, b) = quotient.kind
+        && div.node == BinOpKind::Div
+        && let ExprKind::Cast(cond, _) = check.kind
+        && let ExprKind::Binary(ne, rem, zero) = cond.kind
+        && ne.node == BinOpKind::Ne
+        && is_integer_const(cx, zero, 0)
+        && let ExprKind::Binary(rem_op, rem_a, rem_b) = rem.kind
+        && rem_op.node == BinOpKind::Rem
+        && [quotient, check, cond, rem].iter().all(|e| e.span.ctxt() == ctxt)
+        && eq_expr_value(cx, a, rem_a)
+        && eq_expr_value(cx, b, rem_b)
+    {
+        Some((a, b))
+    } else {
+        None
+    }
+}
+
+fn is_constant(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    constant_full_int(cx, cx.typeck_results(), expr).is_some()
+}
+
+fn is_unsuffixed_int_lit(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Int(_, LitIntType::Unsuffixed)))
+}
+
+/// `x` where `let x = 1;` has neither a type annotation nor a suffix on the literal.
+fn is_unannotated_int_local(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let Some(hir_id) = path_to_local(expr)
+        && let Some(Node::Local(local)) = cx.tcx.hir().find_parent(hir_id)
+        && local.ty.is_none()
+        && let Some(init) = local.init
+    {
+        is_unsuffixed_int_lit(init)
+    } else {
+        false
+    }
+}
@@ -0,0 +1,180 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{eq_expr_value, higher};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for expressions like `31 - x.leading_zeros()` or `u32::BITS - 1 - x.leading_zeros()`
+    /// on unsigned integers, and for `x.trailing_zeros()` where `x` is known to be a power of two,
+    /// which are manual reimplementations of `x.ilog2()`.
+    ///
+    /// ### Why is this bad?
+    /// `ilog2` says what is being computed, and doesn't depend on getting the bit width right.
+    ///
+    /// ### Known problems
+    /// For `x == 0`, `ilog2` panics, while `31 - x.leading_zeros()` overflows, which wraps around
+    /// in release builds.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let x: u32 = 100;
+    /// let log = 31 - x.leading_zeros();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let x: u32 = 100;
+    /// let log = x.ilog2();
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_ILOG2,
+    complexity,
+    "manually reimplementing `ilog2`"
+}
+
+pub struct ManualIlog2 {
+    msrv: Msrv,
+}
+
+impl ManualIlog2 {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualIlog2 => [MANUAL_ILOG2]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualIlog2 {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !expr.span.from_expansion()
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some((x, mut app)) = leading_zeros_log(cx, expr)
+                .map(|x| {
+                    // Powers of two are never zero, other values have to be known not to be.
+                    let app = if matches!(constant(cx, cx.typeck_results(), x), Some(Constant::Int(n)) if n != 0) {
+                        Applicability::MachineApplicable
+                    } else {
+                        Applicability::MaybeIncorrect
+                    };
+                    (x, app)
+                })
+                .or_else(|| trailing_zeros_log(cx, expr).map(|x| (x, Applicability::MachineApplicable)))
+            && self.msrv.meets(msrvs::ILOG2)
+        {
+            let x = Sugg::hir_with_context(cx, x, expr.span.ctxt(), "..", &mut app).maybe_par();
+            span_lint_and_sugg(
+                cx,
+                MANUAL_ILOG2,
+                expr.span,
+                "manually reimplementing `ilog2`",
+                "try",
+                format!("{x}.ilog2()"),
+                app,
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Matches `(BITS - 1) - x.leading_zeros()` and `BITS - x.leading_zeros() - 1`, returning `x`.
+fn leading_zeros_log<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let ExprKind::Binary(op, lhs, rhs) = expr.kind else {
+        return None;
+    };
+    if op.node != BinOpKind::Sub {
+        return None;
+    }
+    if let Some((x, bits)) = leading_zeros(cx, rhs)
+        && int_value(cx, lhs) == Some(bits - 1)
+    {
+        return Some(x);
+    }
+    if let ExprKind::Binary(inner_op, bits_expr, lz) = lhs.kind
+        && inner_op.node == BinOpKind::Sub
+        && let Some((x, bits)) = leading_zeros(cx, lz)
+        && int_value(cx, bits_expr) == Some(bits)
+        && int_value(cx, rhs) == Some(1)
+    {
+        return Some(x);
+    }
+    None
+}
+
+/// Matches `x.leading_zeros()` on an unsigned integer, returning `x` and its bit width.
+fn leading_zeros<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, u128)> {
+    if let ExprKind::MethodCall(path, x, [], _) = expr.kind
+        && path.ident.as_str() == "leading_zeros"
+        && let ty::Uint(uint_ty) = cx.typeck_results().expr_ty(x).kind()
+    {
+        let bits = uint_ty
+            .bit_width()
+            .unwrap_or_else(|| cx.tcx.data_layout.pointer_size.bits());
+        Some((x, u128::from(bits)))
+    } else {
+        None
+    }
+}
+
+/// Matches `x.trailing_zeros()` on an unsigned integer that is either a constant power of two, or
+/// checked with `x.is_power_of_two()` in the condition of an enclosing `if`, returning `x`.
+fn trailing_zeros_log<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::MethodCall(path, x, [], _) = expr.kind
+        && path.ident.as_str() == "trailing_zeros"
+        && let ty::Uint(_) = cx.typeck_results().expr_ty(x).kind()
+        && (matches!(constant(cx, cx.typeck_results(), x), Some(Constant::Int(n)) if n.is_power_of_two())
+            || is_checked_power_of_two(cx, expr, x))
+    {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+fn is_checked_power_of_two<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, x: &Expr<'_>) -> bool {
+    cx.tcx.hir().parent_iter(expr.hir_id).any(|(_, node)| {
+        if let Node::Expr(parent) = node
+            && let Some(higher::If { cond, then, .. }) = higher::If::hir(parent)
+            && then.span.contains(expr.span)
+        {
+            checks_power_of_two(cx, cond, x)
+        } else {
+            false
+        }
+    })
+}
+
+/// Whether `cond` is `x.is_power_of_two()`, possibly as one of several conditions joined by `&&`.
+fn checks_power_of_two(cx: &LateContext<'_>, cond: &Expr<'_>, x: &Expr<'_>) -> bool {
+    match cond.kind {
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::And => {
+            checks_power_of_two(cx, lhs, x) || checks_power_of_two(cx, rhs, x)
+        },
+        ExprKind::MethodCall(path, receiver, [], _) => {
+            path.ident.as_str() == "is_power_of_two" && eq_expr_value(cx, receiver, x)
+        },
+        _ => false,
+    }
+}
+
+/// Evaluates an integer constant, looking through casts such as `usize::BITS as u32`.
+fn int_value(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<u128> {
+    match expr.kind {
+        ExprKind::Cast(inner, _) => int_value(cx, inner),
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::Sub => {
+            int_value(cx, lhs)?.checked_sub(int_value(cx, rhs)?)
+        },
+        _ => match constant(cx, cx.typeck_results(), expr) {
+            Some(Constant::Int(n)) => Some(n),
+            _ => None,
+        },
+    }
+}
@@ -0,0 +1,187 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{eq_expr_value, get_parent_expr, is_integer_literal};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for expressions like `x != 0 && x & (x - 1) == 0` or `x.count_ones() == 1` on
+    /// unsigned integers, which are manual reimplementations of `x.is_power_of_two()`.
+    ///
+    /// ### Why is this bad?
+    /// It's simpler and more readable. Without the `x != 0` check, the bit trick is also wrong
+    /// for zero: the subtraction overflows, panicking in debug builds and claiming that zero is a
+    /// power of two otherwise.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let x: u32 = 8;
+    /// let is_pow2 = x != 0 && x & (x - 1) == 0;
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let x: u32 = 8;
+    /// let is_pow2 = x.is_power_of_two();
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_IS_POWER_OF_TWO,
+    complexity,
+    "manually reimplementing `is_power_of_two`"
+}
+declare_lint_pass!(ManualIsPowerOfTwo => [MANUAL_IS_POWER_OF_TWO]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualIsPowerOfTwo {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if expr.span.from_expansion() || in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        let ExprKind::Binary(op, lhs, rhs) = expr.kind else {
+            return;
+        };
+        match op.node {
+            // x != 0 && x & (x - 1) == 0
+            BinOpKind::And => {
+                if let Some(x) = bit_trick_operand(cx, rhs)
+                    .filter(|x| is_nonzero_check(cx, lhs, x))
+                    .or_else(|| bit_trick_operand(cx, lhs).filter(|x| is_nonzero_check(cx, rhs, x)))
+                {
+                    emit(cx, expr, x, true);
+                }
+            },
+            BinOpKind::Eq => {
+                if let Some(x) = count_ones_operand(cx, expr) {
+                    emit(cx, expr, x, true);
+                } else if let Some(x) = bit_trick_operand(cx, expr)
+                    && !is_guarded_by_nonzero_check(cx, expr, x)
+                {
+                    emit(cx, expr, x, false);
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+fn emit(cx: &LateContext<'_>, expr: &Expr<'_>, x: &Expr<'_>, handles_zero: bool) {
+    span_lint_and_then(
+        cx,
+        MANUAL_IS_POWER_OF_TWO,
+        expr.span,
+        "manually reimplementing `is_power_of_two`",
+        |diag| {
+            let mut app = if handles_zero {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+            let x = Sugg::hir_with_context(cx, x, expr.span.ctxt(), "..", &mut app).maybe_par();
+            diag.span_suggestion(
+                expr.span,
+                "use `is_power_of_two` instead",
+                format!("{x}.is_power_of_two()"),
+                app,
+            );
+            if !handles_zero {
+                diag.note(
+                    "for zero, this expression overflows, and is `true` unless it panics, \
+                     while `is_power_of_two` returns `false`",
+                );
+            }
+        },
+    );
+}
+
+/// Matches `x & (x - 1) == 0` in either order of every operator, returning `x`.
+fn bit_trick_operand<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let ExprKind::Binary(eq, lhs, rhs) = expr.kind else {
+        return None;
+    };
+    let masked = if is_integer_literal(rhs, 0) {
+        lhs
+    } else if is_integer_literal(lhs, 0) {
+        rhs
+    } else {
+        return None;
+    };
+    if eq.node == BinOpKind::Eq
+        && let ExprKind::Binary(and, a, b) = masked.kind
+        && and.node == BinOpKind::BitAnd
+        && masked.span.ctxt() == expr.span.ctxt()
+        && let ty::Uint(_) = cx.typeck_results().expr_ty(masked).kind()
+    {
+        [(a, b), (b, a)]
+            .into_iter()
+            .find(|&(x, minus_one)| is_minus_one_of(cx, minus_one, x))
+            .map(|(x, _)| x)
+    } else {
+        None
+    }
+}
+
+/// `x - 1`
+fn is_minus_one_of(cx: &LateContext<'_>, expr: &Expr<'_>, x: &Expr<'_>) -> bool {
+    if let ExprKind::Binary(op, lhs, rhs) = expr.kind {
+        op.node == BinOpKind::Sub && is_integer_literal(rhs, 1) && eq_expr_value(cx, lhs, x)
+    } else {
+        false
+    }
+}
+
+/// `x != 0` or `x > 0`, in either order.
+fn is_nonzero_check(cx: &LateContext<'_>, expr: &Expr<'_>, x: &Expr<'_>) -> bool {
+    if let ExprKind::Binary(op, lhs, rhs) = expr.kind {
+        match op.node {
+            BinOpKind::Ne => {
+                is_integer_literal(rhs, 0) && eq_expr_value(cx, lhs, x)
+                    || is_integer_literal(lhs, 0) && eq_expr_value(cx, rhs, x)
+            },
+            BinOpKind::Gt => is_integer_literal(rhs, 0) && eq_expr_value(cx, lhs, x),
+            BinOpKind::Lt => is_integer_literal(lhs, 0) && eq_expr_value(cx, rhs, x),
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
+
+/// Whether `expr` is one side of an `&&` whose other side checks `x` for zero, in which case the
+/// whole `&&` is linted instead.
+fn is_guarded_by_nonzero_check(cx: &LateContext<'_>, expr: &Expr<'_>, x: &Expr<'_>) -> bool {
+    if let Some(parent) = get_parent_expr(cx, expr)
+        && let ExprKind::Binary(op, lhs, rhs) = parent.kind
+        && op.node == BinOpKind::And
+    {
+        let other = if lhs.hir_id == expr.hir_id { rhs } else { lhs };
+        is_nonzero_check(cx, other, x)
+    } else {
+        false
+    }
+}
+
+/// Matches `x.count_ones() == 1` in either order, returning `x`.
+fn count_ones_operand<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let ExprKind::Binary(_, lhs, rhs) = expr.kind else {
+        return None;
+    };
+    let call = if is_integer_literal(rhs, 1) {
+        lhs
+    } else if is_integer_literal(lhs, 1) {
+        rhs
+    } else {
+        return None;
+    };
+    if let ExprKind::MethodCall(path, recv, [], _) = call.kind
+        && path.ident.as_str() == "count_ones"
+        && call.span.ctxt() == expr.span.ctxt()
+        && let ty::Uint(_) = cx.typeck_results().expr_ty(recv).kind()
+    {
+        Some(recv)
+    } else {
+        None
+    }
+}
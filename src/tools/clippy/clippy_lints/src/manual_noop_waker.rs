@@ -0,0 +1,207 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::{get_enclosing_unsafe_block, match_def_path, paths, peel_blocks, std_or_core};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Body, BodyId, Closure, Expr, ExprKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TypeckResults;
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Waker`s built from a hand-written `RawWakerVTable` whose `wake`, `wake_by_ref`
+    /// and `drop` functions do nothing, and whose `clone` function returns the same kind of
+    /// `RawWaker` again.
+    ///
+    /// ### Why is this bad?
+    /// `Waker::noop()` returns such a waker without any `unsafe` code.
+    ///
+    /// ### Known problems
+    /// `Waker::noop()` is unstable, so the lint only runs in crates that enable the `noop_waker`
+    /// feature.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # #![feature(noop_waker)]
+    /// use std::task::{RawWaker, RawWakerVTable, Waker};
+    ///
+    /// const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| noop_raw_waker(), |_| {}, |_| {}, |_| {});
+    ///
+    /// fn noop_raw_waker() -> RawWaker {
+    ///     RawWaker::new(std::ptr::null(), &VTABLE)
+    /// }
+    ///
+    /// let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # #![feature(noop_waker)]
+    /// use std::task::Waker;
+    ///
+    /// let waker = Waker::noop();
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_NOOP_WAKER,
+    complexity,
+    "building a `Waker` that does nothing from a hand-written `RawWakerVTable`"
+}
+
+declare_lint_pass!(ManualNoopWaker => [MANUAL_NOOP_WAKER]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualNoopWaker {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(func, [raw]) = expr.kind
+            && !expr.span.from_expansion()
+            && let Some(func_id) = call_def_id(cx.typeck_results(), func)
+            && match_def_path(cx, func_id, &paths::WAKER_FROM_RAW)
+            && cx
+                .tcx
+                .features()
+                .declared_lib_features
+                .iter()
+                .any(|(feature, _)| feature.as_str() == "noop_waker")
+            && let Some(std_or_core) = std_or_core(cx)
+            && (NoopWakerVisitor {
+                cx,
+                visiting: FxHashSet::default(),
+            })
+            .is_noop_raw_waker(cx.typeck_results(), raw)
+        {
+            span_lint_and_sugg(
+                cx,
+                MANUAL_NOOP_WAKER,
+                get_enclosing_unsafe_block(cx, expr).unwrap_or(expr.span),
+                "building a waker that does nothing by hand",
+                "use",
+                format!("{std_or_core}::task::Waker::noop()"),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// Follows the functions and constants making up a `RawWaker` through their bodies, each with
+/// its own typeck results.
+struct NoopWakerVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    /// Functions and vtables already being checked. A `clone` function usually leads back to the
+    /// waker it was called on, which is assumed to be a no-op until shown otherwise.
+    visiting: FxHashSet<DefId>,
+}
+
+impl<'tcx> NoopWakerVisitor<'_, 'tcx> {
+    /// Whether `expr` is `RawWaker::new(data, vtable)` with a no-op `vtable`, or a call to a
+    /// function without arguments that returns one.
+    fn is_noop_raw_waker(&mut self, typeck: &TypeckResults<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+        let ExprKind::Call(func, args) = peel_blocks(expr).kind else {
+            return false;
+        };
+        let Some(func_id) = call_def_id(typeck, func) else {
+            return false;
+        };
+        match args {
+            [data, vtable] if match_def_path(self.cx, func_id, &paths::RAW_WAKER_NEW) => {
+                is_pure_data(self.cx, typeck, data) && self.is_noop_vtable(typeck, vtable)
+            },
+            [] if let Some(local_id) = func_id.as_local()
+                && self.cx.tcx.def_kind(func_id) == DefKind::Fn =>
+            {
+                if !self.visiting.insert(func_id) {
+                    return true;
+                }
+                let body = self.cx.tcx.hir().body_owned_by(local_id);
+                self.is_noop_raw_waker(self.cx.tcx.typeck_body(body), self.cx.tcx.hir().body(body).value)
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether `expr` is a reference to a `RawWakerVTable` whose `wake`, `wake_by_ref` and `drop`
+    /// functions do nothing, and whose `clone` function returns a no-op `RawWaker`.
+    fn is_noop_vtable(&mut self, typeck: &TypeckResults<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+        let ExprKind::AddrOf(_, _, vtable) = expr.kind else {
+            return false;
+        };
+        if let ExprKind::Path(ref qpath) = vtable.kind {
+            let Res::Def(DefKind::Const | DefKind::Static(_), item_id) = typeck.qpath_res(qpath, vtable.hir_id) else {
+                return false;
+            };
+            let Some(local_id) = item_id.as_local() else {
+                return false;
+            };
+            if !self.visiting.insert(item_id) {
+                return true;
+            }
+            let body = self.cx.tcx.hir().body_owned_by(local_id);
+            return self.is_noop_vtable_new(self.cx.tcx.typeck_body(body), self.cx.tcx.hir().body(body).value);
+        }
+        self.is_noop_vtable_new(typeck, vtable)
+    }
+
+    /// Whether `expr` is `RawWakerVTable::new(clone, wake, wake_by_ref, drop)` with no-op functions.
+    fn is_noop_vtable_new(&mut self, typeck: &TypeckResults<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+        if let ExprKind::Call(func, [clone, wake, wake_by_ref, drop]) = peel_blocks(expr).kind
+            && let Some(func_id) = call_def_id(typeck, func)
+            && match_def_path(self.cx, func_id, &paths::RAW_WAKER_VTABLE_NEW)
+            && [wake, wake_by_ref, drop]
+                .into_iter()
+                .all(|f| self.fn_body(typeck, f).is_some_and(is_empty_body))
+            && let Some(clone_body) = self.fn_body(typeck, clone)
+        {
+            self.is_noop_raw_waker(self.cx.tcx.typeck_body(clone_body.id()), clone_body.value)
+        } else {
+            false
+        }
+    }
+
+    /// The body of a closure, or of a function of this crate given by its path.
+    fn fn_body(&self, typeck: &TypeckResults<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Body<'tcx>> {
+        let body: BodyId = match expr.kind {
+            ExprKind::Closure(&Closure { body, .. }) => body,
+            ExprKind::Path(ref qpath) => match typeck.qpath_res(qpath, expr.hir_id) {
+                Res::Def(DefKind::Fn, def_id) => self.cx.tcx.hir().body_owned_by(def_id.as_local()?),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(self.cx.tcx.hir().body(body))
+    }
+}
+
+fn call_def_id(typeck: &TypeckResults<'_>, func: &Expr<'_>) -> Option<DefId> {
+    match func.kind {
+        ExprKind::Path(ref qpath) => typeck.qpath_res(qpath, func.hir_id).opt_def_id(),
+        _ => None,
+    }
+}
+
+/// Whether `body` is `{}` or `()`, looking through `unsafe` blocks.
+fn is_empty_body(body: &Body<'_>) -> bool {
+    let mut expr = body.value;
+    while let ExprKind::Block(block, _) = expr.kind
+        && block.stmts.is_empty()
+    {
+        match block.expr {
+            Some(inner) => expr = inner,
+            None => return true,
+        }
+    }
+    matches!(expr.kind, ExprKind::Tup([]))
+}
+
+/// Whether the data pointer of a `RawWaker` can be dropped without losing anything, such as a
+/// null pointer or a reference to a constant.
+fn is_pure_data(cx: &LateContext<'_>, typeck: &TypeckResults<'_>, expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Lit(_) | ExprKind::Path(_) => true,
+        ExprKind::Cast(inner, _) | ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Neg, inner) => {
+            is_pure_data(cx, typeck, inner)
+        },
+        ExprKind::Call(func, []) => call_def_id(typeck, func)
+            .is_some_and(|id| matches!(cx.tcx.get_diagnostic_name(id), Some(sym::ptr_null | sym::ptr_null_mut))),
+        _ => false,
+    }
+}
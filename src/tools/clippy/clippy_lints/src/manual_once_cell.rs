@@ -0,0 +1,374 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::source::{indent_of, reindent_multiline, snippet_opt, snippet_with_applicability};
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item, match_type};
+use clippy_utils::{can_move_expr_to_closure, higher, is_res_lang_ctor, path_res, paths};
+use rustc_ast::LitKind;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::LangItem::{OptionNone, OptionSome};
+use rustc_hir::{Closure, Expr, ExprKind, GenericArg, Item, ItemKind, Mutability, QPath, StmtKind, Ty, TyKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a `static mut` of type `Option<T>` that a function fills in on first use, guarded
+    /// by a `static` `Once` or `AtomicBool`, and then hands out a reference to.
+    ///
+    /// ### Why is this bad?
+    /// `std::sync::OnceLock` does the same without `unsafe`, and is sound when the function is
+    /// called from several threads at once, which the `AtomicBool` version is not. If the value
+    /// doesn't depend on the function's arguments, `LazyLock` can even do away with the function.
+    ///
+    /// ### Known problems
+    /// `static`s that are exported from the crate are not linted, as changing their type would
+    /// break their users.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::Once;
+    /// # struct Config;
+    /// # impl Config { fn load() -> Self { Config } }
+    /// static mut CONFIG: Option<Config> = None;
+    /// static CONFIG_INIT: Once = Once::new();
+    ///
+    /// fn config() -> &'static Config {
+    ///     unsafe {
+    ///         CONFIG_INIT.call_once(|| {
+    ///             CONFIG = Some(Config::load());
+    ///         });
+    ///         CONFIG.as_ref().unwrap()
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::OnceLock;
+    /// # struct Config;
+    /// # impl Config { fn load() -> Self { Config } }
+    /// static CONFIG: OnceLock<Config> = OnceLock::new();
+    ///
+    /// fn config() -> &'static Config {
+    ///     CONFIG.get_or_init(Config::load)
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_ONCE_CELL,
+    suspicious,
+    "lazily initializing a `static mut` by hand instead of using `OnceLock`"
+}
+
+/// A function that initializes the `static mut` `data` on first use, guarded by `flag`.
+struct LazyInit {
+    data: LocalDefId,
+    flag: LocalDefId,
+    /// How often the function mentions `flag`.
+    flag_uses: usize,
+    /// The function's body, and what to replace it with.
+    body: (Span, String),
+    /// Whether the function takes no arguments, so that `LazyLock` would work as well.
+    no_args: bool,
+    app: Applicability,
+}
+
+pub struct ManualOnceCell {
+    msrv: Msrv,
+    /// How often each `static` of the crate is mentioned.
+    static_uses: FxHashMap<LocalDefId, usize>,
+    lazy_inits: Vec<LazyInit>,
+}
+
+impl ManualOnceCell {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self {
+            msrv,
+            static_uses: FxHashMap::default(),
+            lazy_inits: Vec::new(),
+        }
+    }
+}
+
+impl_lint_pass!(ManualOnceCell => [MANUAL_ONCE_CELL]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualOnceCell {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        let ItemKind::Fn(_, _, body_id) = item.kind else {
+            return;
+        };
+        if item.span.from_expansion() || !self.msrv.meets(msrvs::ONCE_LOCK) {
+            return;
+        }
+        let body = cx.tcx.hir().body(body_id);
+        if let Some((init_expr, result)) = split_body(body.value)
+            && let Some((flag, data, init, flag_uses)) =
+                call_once_init(cx, init_expr).or_else(|| atomic_bool_init(cx, init_expr))
+            && unwrapped_static(cx, result) == Some(data)
+        {
+            // An initializer that was not already in a closure may `return` or use `?`.
+            let mut app = if can_move_expr_to_closure(cx, init).is_some() {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+            let name = cx.tcx.item_name(data.to_def_id());
+            let indent = indent_of(cx, item.span).unwrap_or(0);
+            // `|| f()` is just `f`.
+            let init = if let ExprKind::Call(callee, []) = init.kind
+                && let ExprKind::Path(_) = callee.kind
+            {
+                snippet_with_applicability(cx, callee.span, "..", &mut app)
+            } else {
+                let init = reindent_multiline(
+                    snippet_with_applicability(cx, init.span, "..", &mut app),
+                    true,
+                    Some(indent + 4),
+                );
+                format!("|| {init}").into()
+            };
+            let sugg = format!(
+                "{{\n{pad}    {name}.get_or_init({init})\n{pad}}}",
+                pad = " ".repeat(indent)
+            );
+            self.lazy_inits.push(LazyInit {
+                data,
+                flag,
+                flag_uses,
+                body: (body.value.span, sugg),
+                no_args: body.params.is_empty(),
+                app,
+            });
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Path(ref qpath) = expr.kind
+            && let Res::Def(DefKind::Static(_), def_id) = cx.qpath_res(qpath, expr.hir_id)
+            && let Some(def_id) = def_id.as_local()
+        {
+            *self.static_uses.entry(def_id).or_default() += 1;
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for lazy_init in &self.lazy_inits {
+            // Both `static`s must be private to the function, which uses `data` twice: once to
+            // initialize it, once to return it.
+            if self.static_uses.get(&lazy_init.data) != Some(&2)
+                || self.static_uses.get(&lazy_init.flag) != Some(&lazy_init.flag_uses)
+            {
+                continue;
+            }
+            let data_item = cx.tcx.hir().expect_item(lazy_init.data);
+            let flag_item = cx.tcx.hir().expect_item(lazy_init.flag);
+            let ItemKind::Static(data_ty, ..) = data_item.kind else {
+                continue;
+            };
+            let Some(value_ty) = option_arg(data_ty) else {
+                continue;
+            };
+            if data_item.span.from_expansion()
+                || flag_item.span.from_expansion()
+                || cx.effective_visibilities.is_exported(lazy_init.data)
+                || !is_send_sync_option(cx, lazy_init.data)
+            {
+                continue;
+            }
+            // Remove the whole line of the flag, not just the item.
+            let flag_span = cx
+                .sess()
+                .source_map()
+                .span_extend_prev_while(flag_item.span, char::is_whitespace)
+                .unwrap_or(flag_item.span);
+
+            let mut app = lazy_init.app;
+            let vis = snippet_opt(cx, data_item.vis_span)
+                .filter(|vis| !vis.is_empty())
+                .map_or(String::new(), |vis| format!("{vis} "));
+            let value_ty = snippet_with_applicability(cx, value_ty.span, "..", &mut app);
+            let name = data_item.ident;
+            span_lint_hir_and_then(
+                cx,
+                MANUAL_ONCE_CELL,
+                data_item.hir_id(),
+                data_item.span,
+                "lazy initialization of a `static mut` implemented by hand",
+                |diag| {
+                    diag.multipart_suggestion(
+                        "use a `OnceLock` instead",
+                        vec![
+                            (
+                                data_item.span,
+                                format!(
+                                    "{vis}static {name}: std::sync::OnceLock<{value_ty}> = std::sync::OnceLock::new();"
+                                ),
+                            ),
+                            (flag_span, String::new()),
+                            lazy_init.body.clone(),
+                        ],
+                        app,
+                    );
+                    if lazy_init.no_args
+                        && cx
+                            .tcx
+                            .features()
+                            .declared_lib_features
+                            .iter()
+                            .any(|(feature, _)| feature.as_str() == "lazy_cell")
+                    {
+                        diag.help(format!(
+                            "or make `{name}` a `std::sync::LazyLock` and use it in place of the function"
+                        ));
+                    }
+                },
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// The single statement and the trailing expression of a function body, looking through an
+/// `unsafe` block.
+fn split_body<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    let ExprKind::Block(block, _) = expr.kind else {
+        return None;
+    };
+    match (block.stmts, block.expr) {
+        ([], Some(inner)) => split_body(inner),
+        ([stmt], Some(result)) => match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some((e, result)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `FLAG.call_once(|| DATA = Some(init))`, returning `FLAG`, `DATA`, `init` and how often `FLAG`
+/// is used.
+fn call_once_init<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(LocalDefId, LocalDefId, &'tcx Expr<'tcx>, usize)> {
+    if let ExprKind::MethodCall(path, flag, [closure], _) = expr.kind
+        && path.ident.as_str() == "call_once"
+        && let ExprKind::Closure(&Closure { body, .. }) = closure.kind
+        && let Some(flag) = local_static(cx, flag, Mutability::Not)
+        && match_type(cx, cx.tcx.type_of(flag).instantiate_identity(), &paths::STD_SYNC_ONCE)
+        && let Some((data, init)) = init_assignment(cx, cx.tcx.hir().body(body).value)
+    {
+        Some((flag, data, init, 1))
+    } else {
+        None
+    }
+}
+
+/// `if !FLAG.load(..) { DATA = Some(init); FLAG.store(true, ..); }`, returning `FLAG`, `DATA`,
+/// `init` and how often `FLAG` is used.
+fn atomic_bool_init<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(LocalDefId, LocalDefId, &'tcx Expr<'tcx>, usize)> {
+    if let Some(if_expr) = higher::If::hir(expr)
+        && if_expr.r#else.is_none()
+        && let ExprKind::Unary(UnOp::Not, load) = if_expr.cond.kind
+        && let ExprKind::MethodCall(load_path, flag, [_], _) = load.kind
+        && load_path.ident.as_str() == "load"
+        && let Some(flag) = local_static(cx, flag, Mutability::Not)
+        && is_type_diagnostic_item(cx, cx.tcx.type_of(flag).instantiate_identity(), sym::AtomicBool)
+        && let ExprKind::Block(then, _) = if_expr.then.kind
+        && let ([assign, store], None) = (then.stmts, then.expr)
+        && let StmtKind::Semi(assign) = assign.kind
+        && let StmtKind::Semi(store) = store.kind
+        && let ExprKind::MethodCall(store_path, store_flag, [value, _], _) = store.kind
+        && store_path.ident.as_str() == "store"
+        && local_static(cx, store_flag, Mutability::Not) == Some(flag)
+        && let ExprKind::Lit(lit) = value.kind
+        && let LitKind::Bool(true) = lit.node
+        && let Some((data, init)) = init_assignment(cx, assign)
+    {
+        Some((flag, data, init, 2))
+    } else {
+        None
+    }
+}
+
+/// `DATA = Some(init)`, possibly as the only statement of a block, where `DATA` is a `static mut`
+/// starting out as `None`.
+fn init_assignment<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(LocalDefId, &'tcx Expr<'tcx>)> {
+    let expr = match expr.kind {
+        ExprKind::Block(block, _) => match (block.stmts, block.expr) {
+            ([stmt], None) => match stmt.kind {
+                StmtKind::Semi(e) => e,
+                _ => return None,
+            },
+            _ => return None,
+        },
+        _ => expr,
+    };
+    if let ExprKind::Assign(lhs, rhs, _) = expr.kind
+        && let ExprKind::Call(ctor, [init]) = rhs.kind
+        && is_res_lang_ctor(cx, path_res(cx, ctor), OptionSome)
+        && let Some(data) = local_static(cx, lhs, Mutability::Mut)
+        && let ItemKind::Static(_, _, body) = cx.tcx.hir().expect_item(data).kind
+        && is_res_lang_ctor(cx, path_res(cx, cx.tcx.hir().body(body).value), OptionNone)
+    {
+        Some((data, init))
+    } else {
+        None
+    }
+}
+
+/// `DATA.as_ref().unwrap()`, or with `expect` or `unwrap_unchecked`, returning the `static mut`
+/// `DATA`.
+fn unwrapped_static(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<LocalDefId> {
+    if let ExprKind::MethodCall(unwrap, recv, _, _) = expr.kind
+        && matches!(unwrap.ident.as_str(), "unwrap" | "expect" | "unwrap_unchecked")
+        && let ExprKind::MethodCall(as_ref, data, [], _) = recv.kind
+        && as_ref.ident.as_str() == "as_ref"
+    {
+        local_static(cx, data, Mutability::Mut)
+    } else {
+        None
+    }
+}
+
+/// If `expr` is a path to a `static` of this crate with the given mutability, returns it.
+fn local_static(cx: &LateContext<'_>, expr: &Expr<'_>, mutbl: Mutability) -> Option<LocalDefId> {
+    match path_res(cx, expr) {
+        Res::Def(DefKind::Static(m), def_id) if m == mutbl => def_id.as_local(),
+        _ => None,
+    }
+}
+
+/// Whether the `static mut` `data` is an `Option<T>` with `T: Send + Sync`, which a `static`
+/// `OnceLock<T>` needs.
+fn is_send_sync_option(cx: &LateContext<'_>, data: LocalDefId) -> bool {
+    if let ty::Adt(_, args) = cx.tcx.type_of(data).instantiate_identity().kind()
+        && let Some(send) = cx.tcx.get_diagnostic_item(sym::Send)
+        && let Some(sync) = cx.tcx.lang_items().sync_trait()
+    {
+        let value_ty = args.type_at(0);
+        implements_trait(cx, value_ty, send, &[]) && implements_trait(cx, value_ty, sync, &[])
+    } else {
+        false
+    }
+}
+
+/// `T` in `Option<T>`.
+fn option_arg<'tcx>(ty: &'tcx Ty<'tcx>) -> Option<&'tcx Ty<'tcx>> {
+    if let TyKind::Path(QPath::Resolved(None, path)) = ty.kind
+        && let [.., last] = path.segments
+        && let Some(args) = last.args
+        && let [GenericArg::Type(ty)] = args.args
+    {
+        Some(ty)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,215 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::higher::IfLetOrMatch;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{in_constant, is_res_lang_ctor, path_res, path_to_local_id, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionNone, OptionSome};
+use rustc_hir::{BindingAnnotation, Closure, Expr, ExprKind, HirId, MatchSource, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for code that combines two `Option`s into an `Option` of a tuple by hand, which is
+    /// what `Option::zip` does: a `match` or `if let` on `(a, b)`, nested `if let`s or `match`es,
+    /// or `a.and_then(|x| b.map(|y| (x, y)))`.
+    ///
+    /// ### Why is this bad?
+    /// `a.zip(b)` is shorter, and says what it does.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let (a, b) = (Some(1), Some("one"));
+    /// let pair = match (a, b) {
+    ///     (Some(x), Some(y)) => Some((x, y)),
+    ///     _ => None,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let (a, b) = (Some(1), Some("one"));
+    /// let pair = a.zip(b);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_OPTION_ZIP,
+    complexity,
+    "manually reimplementing `Option::zip`"
+}
+
+pub struct ManualOptionZip {
+    msrv: Msrv,
+}
+
+impl ManualOptionZip {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualOptionZip => [MANUAL_OPTION_ZIP]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualOptionZip {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !expr.span.from_expansion()
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some((a, b)) = tuple_split(cx, expr)
+                .or_else(|| nested_split(cx, expr))
+                .or_else(|| and_then_map(cx, expr))
+            && !in_constant(cx, expr.hir_id)
+            && self.msrv.meets(msrvs::OPTION_ZIP)
+        {
+            let mut app = Applicability::MachineApplicable;
+            let a = Sugg::hir_with_context(cx, a, expr.span.ctxt(), "..", &mut app).maybe_par();
+            let (b, _) = snippet_with_context(cx, b.span, expr.span.ctxt(), "..", &mut app);
+            span_lint_and_sugg(
+                cx,
+                MANUAL_OPTION_ZIP,
+                expr.span,
+                "manual implementation of `Option::zip`",
+                "try",
+                format!("{a}.zip({b})"),
+                app,
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// `match (a, b) { (Some(x), Some(y)) => Some((x, y)), _ => None }`, or the same with `if let`,
+/// returning `a` and `b`.
+fn tuple_split<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    let (scrutinee, pat, then) = option_split(cx, expr)?;
+    if let ExprKind::Tup([a, b]) = scrutinee.kind
+        && let PatKind::Tuple([pat_a, pat_b], None) = pat.kind
+        && let Some(x) = some_binding(cx, pat_a, a)
+        && let Some(y) = some_binding(cx, pat_b, b)
+        && is_some_pair(cx, then, x, y)
+    {
+        Some((a, b))
+    } else {
+        None
+    }
+}
+
+/// `if let Some(x) = a { if let Some(y) = b { Some((x, y)) } else { None } } else { None }`, or the
+/// same with `match`es, returning `a` and `b`.
+fn nested_split<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    let (a, pat_a, then) = option_split(cx, expr)?;
+    let x = some_binding(cx, pat_a, a)?;
+    let (b, pat_b, inner_then) = option_split(cx, peel_blocks(then))?;
+    let y = some_binding(cx, pat_b, b)?;
+    (is_some_pair(cx, inner_then, x, y) && can_eval_early(cx, b, x)).then_some((a, b))
+}
+
+/// `a.and_then(|x| b.map(|y| (x, y)))`, returning `a` and `b`.
+fn and_then_map<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::MethodCall(and_then, a, [outer], _) = expr.kind
+        && and_then.ident.as_str() == "and_then"
+        && let Some(x) = closure_param(cx, outer)
+        && let ExprKind::MethodCall(map, b, [inner], _) = peel_blocks(closure_body(cx, outer)).kind
+        && map.ident.as_str() == "map"
+        && let Some(y) = closure_param(cx, inner)
+        && let ExprKind::Tup([first, second]) = peel_blocks(closure_body(cx, inner)).kind
+        && path_to_local_id(first, x)
+        && path_to_local_id(second, y)
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(a), sym::Option)
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(b), sym::Option)
+        && can_eval_early(cx, b, x)
+    {
+        Some((a, b))
+    } else {
+        None
+    }
+}
+
+/// Splits `if let pat = scrutinee { then } else { else }`, or a `match` with an arm for `pat` and a
+/// wildcard or `None` arm, into `(scrutinee, pat, then)`, provided that `else` is `None`.
+fn option_split<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Pat<'tcx>, &'tcx Expr<'tcx>)> {
+    let (scrutinee, pat, then, els) = match IfLetOrMatch::parse(cx, expr)? {
+        IfLetOrMatch::IfLet(scrutinee, pat, then, Some(els)) => (scrutinee, pat, then, els),
+        IfLetOrMatch::Match(scrutinee, [first, second], MatchSource::Normal)
+            if first.guard.is_none() && second.guard.is_none() =>
+        {
+            if is_fallback_pat(cx, second.pat) {
+                (scrutinee, first.pat, first.body, second.body)
+            } else if is_fallback_pat(cx, first.pat) {
+                (scrutinee, second.pat, second.body, first.body)
+            } else {
+                return None;
+            }
+        },
+        _ => return None,
+    };
+    is_res_lang_ctor(cx, path_res(cx, peel_blocks(els)), OptionNone).then_some((scrutinee, pat, then))
+}
+
+/// `_` or `None`
+fn is_fallback_pat(cx: &LateContext<'_>, pat: &Pat<'_>) -> bool {
+    match pat.kind {
+        PatKind::Wild => true,
+        PatKind::Path(ref qpath) => is_res_lang_ctor(cx, cx.qpath_res(qpath, pat.hir_id), OptionNone),
+        _ => false,
+    }
+}
+
+/// If `pat` is `Some(x)` matched against `scrutinee`, an `Option` taken by value, returns `x`.
+fn some_binding(cx: &LateContext<'_>, pat: &Pat<'_>, scrutinee: &Expr<'_>) -> Option<HirId> {
+    if let PatKind::TupleStruct(ref qpath, [inner], None) = pat.kind
+        && is_res_lang_ctor(cx, cx.qpath_res(qpath, pat.hir_id), OptionSome)
+        && let PatKind::Binding(BindingAnnotation::NONE, id, _, None) = inner.kind
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(scrutinee), sym::Option)
+    {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+/// `Some((x, y))`
+fn is_some_pair(cx: &LateContext<'_>, expr: &Expr<'_>, x: HirId, y: HirId) -> bool {
+    if let ExprKind::Call(ctor, [arg]) = peel_blocks(expr).kind
+        && is_res_lang_ctor(cx, path_res(cx, ctor), OptionSome)
+        && let ExprKind::Tup([first, second]) = arg.kind
+    {
+        path_to_local_id(first, x) && path_to_local_id(second, y)
+    } else {
+        false
+    }
+}
+
+/// Whether `b`, which was only evaluated once `a` turned out to be `Some(x)`, can be evaluated
+/// up front as the argument to `zip`.
+fn can_eval_early<'tcx>(cx: &LateContext<'tcx>, b: &'tcx Expr<'tcx>, x: HirId) -> bool {
+    !is_local_used(cx, b, x) && switch_to_eager_eval(cx, b)
+}
+
+/// The binding of a closure's only parameter, like `x` in `|x| ..`.
+fn closure_param(cx: &LateContext<'_>, closure: &Expr<'_>) -> Option<HirId> {
+    if let ExprKind::Closure(&Closure { body, .. }) = closure.kind
+        && let [param] = cx.tcx.hir().body(body).params
+        && let PatKind::Binding(BindingAnnotation::NONE, id, _, None) = param.pat.kind
+    {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+fn closure_body<'tcx>(cx: &LateContext<'tcx>, closure: &Expr<'_>) -> &'tcx Expr<'tcx> {
+    let ExprKind::Closure(&Closure { body, .. }) = closure.kind else {
+        unreachable!("`closure_param` checked that this is a closure");
+    };
+    cx.tcx.hir().body(body).value
+}
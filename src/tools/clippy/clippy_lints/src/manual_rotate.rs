@@ -0,0 +1,145 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{eq_expr_value, is_integer_const};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for expressions like `(x << n) | (x >> (32 - n))` on unsigned integers, including
+    /// the masked form `(x << (n & 31)) | (x >> (n.wrapping_neg() & 31))`, which are manual
+    /// reimplementations of `x.rotate_left(n)` or `x.rotate_right(n)`.
+    ///
+    /// ### Why is this bad?
+    /// The methods are clearer, and compile to a single rotate instruction where the target has
+    /// one. Unlike the unmasked form, they also don't overflow when `n` is zero.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let x: u32 = 0x1234_5678;
+    /// let rotated = (x << 8) | (x >> 24);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let x: u32 = 0x1234_5678;
+    /// let rotated = x.rotate_left(8);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_ROTATE,
+    complexity,
+    "manually reimplementing `rotate_left` or `rotate_right`"
+}
+declare_lint_pass!(ManualRotate => [MANUAL_ROTATE]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualRotate {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Binary(op, lhs, rhs) = expr.kind
+            && op.node == BinOpKind::BitOr
+            && !expr.span.from_expansion()
+            && !in_external_macro(cx.sess(), expr.span)
+            && let ty::Uint(uint_ty) = cx.typeck_results().expr_ty(expr).kind()
+            && let Some((shl_x, shl_amount)) = shift(lhs, BinOpKind::Shl).or_else(|| shift(rhs, BinOpKind::Shl))
+            && let Some((shr_x, shr_amount)) = shift(lhs, BinOpKind::Shr).or_else(|| shift(rhs, BinOpKind::Shr))
+            && eq_expr_value(cx, shl_x, shr_x)
+        {
+            let bits = uint_ty
+                .bit_width()
+                .unwrap_or_else(|| cx.tcx.data_layout.pointer_size.bits());
+            let bits = u128::from(bits);
+            let (method, amount) = if let Some(amount) = rotate_amount(cx, bits, shl_amount, shr_amount) {
+                ("rotate_left", amount)
+            } else if let Some(amount) = rotate_amount(cx, bits, shr_amount, shl_amount) {
+                ("rotate_right", amount)
+            } else {
+                return;
+            };
+
+            let mut app = Applicability::MachineApplicable;
+            let ctxt = expr.span.ctxt();
+            let x = Sugg::hir_with_context(cx, shl_x, ctxt, "..", &mut app).maybe_par();
+            let amount = match amount {
+                Amount::Const(n) => n.to_string(),
+                Amount::Expr(n) => {
+                    let sugg = Sugg::hir_with_context(cx, n, ctxt, "..", &mut app);
+                    // The methods take a `u32`. Truncating a wider amount keeps its low bits, which
+                    // are the only ones a rotation looks at.
+                    if matches!(cx.typeck_results().expr_ty(n).kind(), ty::Uint(ty::UintTy::U32)) {
+                        sugg.to_string()
+                    } else {
+                        sugg.as_ty("u32").to_string()
+                    }
+                },
+            };
+            span_lint_and_sugg(
+                cx,
+                MANUAL_ROTATE,
+                expr.span,
+                &format!("manually reimplementing `{method}`"),
+                &format!("use `{method}` instead"),
+                format!("{x}.{method}({amount})"),
+                app,
+            );
+        }
+    }
+}
+
+enum Amount<'tcx> {
+    Const(u128),
+    Expr(&'tcx Expr<'tcx>),
+}
+
+/// Matches `x << n` or `x >> n`, depending on `op`, returning `x` and `n`.
+fn shift<'tcx>(expr: &'tcx Expr<'tcx>, op: BinOpKind) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::Binary(shift, x, n) = expr.kind
+        && shift.node == op
+    {
+        Some((x, n))
+    } else {
+        None
+    }
+}
+
+/// Returns the amount of a rotation in the direction `n` shifts in, if `m` shifts by the rest of
+/// the `bits`: either both are constants adding up to `bits`, or `m` is `bits - n`,
+/// `(bits - n) & (bits - 1)` or `n.wrapping_neg() & (bits - 1)`, where `n` may also be masked.
+fn rotate_amount<'tcx>(cx: &LateContext<'tcx>, bits: u128, n: &'tcx Expr<'tcx>, m: &Expr<'_>) -> Option<Amount<'tcx>> {
+    if let Some(Constant::Int(n)) = constant(cx, cx.typeck_results(), n)
+        && let Some(Constant::Int(m)) = constant(cx, cx.typeck_results(), m)
+    {
+        return (n > 0 && m > 0 && n.checked_add(m) == Some(bits)).then_some(Amount::Const(n));
+    }
+
+    let n = peel_mask(cx, bits, n).0;
+    let (m, m_masked) = peel_mask(cx, bits, m);
+    let is_complement = match m.kind {
+        ExprKind::Binary(op, lhs, rhs) => {
+            op.node == BinOpKind::Sub
+                && is_integer_const(cx, lhs, bits)
+                && eq_expr_value(cx, peel_mask(cx, bits, rhs).0, n)
+        },
+        ExprKind::MethodCall(path, receiver, [], _) => {
+            m_masked && path.ident.as_str() == "wrapping_neg" && eq_expr_value(cx, peel_mask(cx, bits, receiver).0, n)
+        },
+        _ => false,
+    };
+    is_complement.then_some(Amount::Expr(n))
+}
+
+/// Peels `n & (bits - 1)`, in either order, returning `n` and whether it was masked.
+fn peel_mask<'tcx>(cx: &LateContext<'_>, bits: u128, expr: &'tcx Expr<'tcx>) -> (&'tcx Expr<'tcx>, bool) {
+    if let ExprKind::Binary(op, lhs, rhs) = expr.kind
+        && op.node == BinOpKind::BitAnd
+    {
+        if is_integer_const(cx, rhs, bits - 1) {
+            return (lhs, true);
+        } else if is_integer_const(cx, lhs, bits - 1) {
+            return (rhs, true);
+        }
+    }
+    (expr, false)
+}
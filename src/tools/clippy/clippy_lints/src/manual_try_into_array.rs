@@ -0,0 +1,141 @@
+use crate::tuple_array_conversions::{index_projection, is_conversion_target_known, ToType};
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::{higher, is_from_proc_macro, path_to_local};
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for arrays built by indexing a slice element by element, e.g. `[s[0], s[1], s[2]]`,
+    /// including ones guarded by a check like `if s.len() == 3`.
+    ///
+    /// ### Why is this bad?
+    /// Converting a subslice with `.try_into()` states the intent directly, and doesn't need
+    /// updating by hand whenever the length of the array changes.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn header(s: &[u8]) -> u32 {
+    ///     let magic: [u8; 4] = [s[0], s[1], s[2], s[3]];
+    ///     u32::from_le_bytes(magic)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn header(s: &[u8]) -> u32 {
+    ///     let magic: [u8; 4] = s[..4].try_into().unwrap();
+    ///     u32::from_le_bytes(magic)
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_TRY_INTO_ARRAY,
+    pedantic,
+    "building an array from a slice one element at a time"
+}
+impl_lint_pass!(ManualTryIntoArray => [MANUAL_TRY_INTO_ARRAY]);
+
+pub struct ManualTryIntoArray {
+    msrv: Msrv,
+}
+
+impl ManualTryIntoArray {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualTryIntoArray {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Array(elements) = expr.kind
+            && let [first, ..] = elements
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(recv) = index_projection(first, 0)
+            && let Some(local) = path_to_local(recv)
+            && elements
+                .iter()
+                .enumerate()
+                .all(|(i, e)| index_projection(e, i).and_then(path_to_local) == Some(local))
+            // Only slices, and only if no element was coerced on its way into the array.
+            && let ty::Array(elem_ty, _) = cx.typeck_results().expr_ty(expr).kind()
+            && let ty::Slice(slice_elem_ty) = cx.typeck_results().expr_ty(recv).peel_refs().kind()
+            && elem_ty == slice_elem_ty
+            && self.msrv.meets(msrvs::ARRAY_IMPL_ANY_LEN)
+            && !is_from_proc_macro(cx, expr)
+        {
+            let guard = find_len_guard(cx, expr, local, elements.len());
+            span_lint_and_then(
+                cx,
+                MANUAL_TRY_INTO_ARRAY,
+                expr.span,
+                "building an array from a slice element by element",
+                |diag| {
+                    let len = elements.len();
+                    let mut app = Applicability::MachineApplicable;
+                    let (recv, _) = snippet_with_context(cx, recv.span, expr.span.ctxt(), "..", &mut app);
+                    let sugg = if is_conversion_target_known(cx, expr, ToType::Array) {
+                        format!("{recv}[..{len}].try_into().unwrap()")
+                    } else {
+                        format!("<[_; {len}]>::try_from(&{recv}[..{len}]).unwrap()")
+                    };
+                    diag.span_suggestion(expr.span, "use `.try_into()` on a subslice", sugg, app);
+
+                    if let Some(guard) = guard {
+                        diag.span_help(
+                            guard.span,
+                            "alternatively, replace this length check with a slice pattern binding the elements",
+                        );
+                    }
+                },
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Finds the condition of an enclosing `if local.len() == len { .. }` that `expr` is in the `then`
+/// branch of.
+fn find_len_guard<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, local: HirId, len: usize) -> Option<&'tcx Expr<'tcx>> {
+    let mut child = expr.hir_id;
+    for (parent, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        if let Node::Expr(e) = node
+            && let Some(higher::If { cond, then, .. }) = higher::If::hir(e)
+            && then.hir_id == child
+            && let ExprKind::Binary(op, lhs, rhs) = cond.kind
+            && op.node == BinOpKind::Eq
+            && (is_len_of(lhs, local) && is_usize_lit(rhs, len) || is_usize_lit(lhs, len) && is_len_of(rhs, local))
+        {
+            return Some(cond);
+        }
+        child = parent;
+    }
+    None
+}
+
+/// `local.len()`
+fn is_len_of(expr: &Expr<'_>, local: HirId) -> bool {
+    if let ExprKind::MethodCall(path, recv, [], _) = expr.kind {
+        path.ident.name == sym::len && path_to_local(recv) == Some(local)
+    } else {
+        false
+    }
+}
+
+fn is_usize_lit(expr: &Expr<'_>, val: usize) -> bool {
+    if let ExprKind::Lit(lit) = expr.kind
+        && let LitKind::Int(lit_val, _) = lit.node
+    {
+        lit_val == val as u128
+    } else {
+        false
+    }
+}
@@ -0,0 +1,142 @@
+use super::{COLLECT_TO_CHECK_EMPTINESS, NEEDLESS_COLLECT};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::get_iterator_item_ty;
+use clippy_utils::{get_parent_expr, is_integer_literal, is_lint_allowed, path_to_local};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, ClosureKind};
+use rustc_span::sym;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, collect_expr: &'tcx Expr<'tcx>, iter_expr: &'tcx Expr<'tcx>) {
+    let Some(adt) = cx.typeck_results().expr_ty(collect_expr).ty_adt_def() else {
+        return;
+    };
+    match cx.tcx.get_diagnostic_name(adt.did()) {
+        Some(
+            sym::Vec
+            | sym::VecDeque
+            | sym::LinkedList
+            | sym::BinaryHeap
+            | sym::HashSet
+            | sym::HashMap
+            | sym::BTreeSet
+            | sym::BTreeMap,
+        ) => {
+            // `needless_collect` already suggests `next().is_none()` or `count()` for these.
+            if !is_lint_allowed(cx, NEEDLESS_COLLECT, collect_expr.hir_id) {
+                return;
+            }
+        },
+        // Collecting `&str`s or `String`s can give an empty `String` even if there were items.
+        Some(sym::String)
+            if get_iterator_item_ty(cx, cx.typeck_results().expr_ty(iter_expr))
+                .map_or(false, |item| item.is_char()) => {},
+        _ => return,
+    }
+    let Some((check_expr, is_empty)) = emptiness_check(cx, collect_expr) else {
+        return;
+    };
+    if check_expr.span.from_expansion() {
+        return;
+    }
+
+    let mut app = Applicability::MachineApplicable;
+    let iter = Sugg::hir_with_context(cx, iter_expr, check_expr.span.ctxt(), "..", &mut app).maybe_par();
+    let sugg = if visits_for_side_effects(cx, iter_expr) {
+        let sugg = format!("{iter}.count() {} 0", if is_empty { "==" } else { "!=" });
+        match get_parent_expr(cx, check_expr) {
+            Some(parent) if matches!(parent.kind, ExprKind::Unary(..)) => format!("({sugg})"),
+            _ => sugg,
+        }
+    } else {
+        // `next` takes the iterator by `&mut`, which a local binding may not allow. It also stops
+        // at the first item, so a closure with side effects that `visits_for_side_effects` can't
+        // see, like a `println!` in a `map`, no longer runs for the other items.
+        if path_to_local(iter_expr).is_some() || has_closure_arg(cx, iter_expr) {
+            app = Applicability::MaybeIncorrect;
+        }
+        format!("{iter}.next().{}()", if is_empty { "is_none" } else { "is_some" })
+    };
+    span_lint_and_sugg(
+        cx,
+        COLLECT_TO_CHECK_EMPTINESS,
+        check_expr.span,
+        "collecting an iterator only to check whether it is empty",
+        "check the iterator instead",
+        sugg,
+        app,
+    );
+}
+
+/// The expression checking whether the collection is empty, like `.is_empty()` or `.len() == 0`,
+/// and whether it is `true` for an empty collection.
+fn emptiness_check<'tcx>(cx: &LateContext<'tcx>, collect_expr: &Expr<'_>) -> Option<(&'tcx Expr<'tcx>, bool)> {
+    let parent = get_parent_expr(cx, collect_expr)?;
+    let ExprKind::MethodCall(name, _, [], _) = parent.kind else {
+        return None;
+    };
+    match name.ident.as_str() {
+        "is_empty" => Some((parent, true)),
+        "len" => {
+            let cmp = get_parent_expr(cx, parent)?;
+            let ExprKind::Binary(op, lhs, rhs) = cmp.kind else {
+                return None;
+            };
+            // Normalized to `len() op 0`.
+            let op = if lhs.hir_id == parent.hir_id && is_integer_literal(rhs, 0) {
+                op.node
+            } else if rhs.hir_id == parent.hir_id && is_integer_literal(lhs, 0) {
+                match op.node {
+                    BinOpKind::Lt => BinOpKind::Gt,
+                    BinOpKind::Ge => BinOpKind::Le,
+                    op => op,
+                }
+            } else {
+                return None;
+            };
+            match op {
+                BinOpKind::Eq | BinOpKind::Le => Some((cmp, true)),
+                BinOpKind::Ne | BinOpKind::Gt => Some((cmp, false)),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Whether the iterator is `inspect`ed, or one of its adapters is given a closure that mutates
+/// what it captures, so that collecting it did more than find out whether it is empty.
+fn visits_for_side_effects(cx: &LateContext<'_>, mut iter: &Expr<'_>) -> bool {
+    while let ExprKind::MethodCall(name, recv, args, _) = iter.kind {
+        if name.ident.as_str() == "inspect"
+            || args.iter().any(|arg| {
+                matches!(
+                    cx.typeck_results().expr_ty(arg).kind(),
+                    ty::Closure(_, args) if args.as_closure().kind() == ClosureKind::FnMut
+                )
+            })
+        {
+            return true;
+        }
+        iter = recv;
+    }
+    false
+}
+
+/// Whether one of the iterator's adapters is given a closure or a function.
+fn has_closure_arg(cx: &LateContext<'_>, mut iter: &Expr<'_>) -> bool {
+    while let ExprKind::MethodCall(_, recv, args, _) = iter.kind {
+        if args.iter().any(|arg| {
+            matches!(
+                cx.typeck_results().expr_ty(arg).kind(),
+                ty::Closure(..) | ty::FnDef(..) | ty::FnPtr(_)
+            )
+        }) {
+            return true;
+        }
+        iter = recv;
+    }
+    false
+}
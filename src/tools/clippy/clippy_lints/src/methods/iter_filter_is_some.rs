@@ -0,0 +1,71 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::{is_lint_allowed, is_trait_method, path_to_local_id, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span, Symbol};
+
+use super::{ITER_FILTER_IS_SOME, OPTION_FILTER_MAP};
+
+/// Checks `expr`, which is `_.filter(filter_arg).map(map_arg)`, with `filter_span` being the span
+/// of `filter`. Returns whether the lint was emitted.
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    filter_arg: &Expr<'_>,
+    filter_span: Span,
+    map_arg: &Expr<'_>,
+) -> bool {
+    if is_trait_method(cx, expr, sym::Iterator)
+        && !expr.span.from_expansion()
+        && let Some((filter_ty, filter_method)) = called_method(cx, filter_arg)
+        && let Some((map_ty, map_method)) = called_method(cx, map_arg)
+        && map_method == sym::unwrap
+        && filter_ty == map_ty
+    {
+        let sugg = match filter_method.as_str() {
+            // `option_filter_map` already covers these, and suggests the same thing.
+            "is_some" if filter_ty == sym::Option && is_lint_allowed(cx, OPTION_FILTER_MAP, expr.hir_id) => "flatten()",
+            "is_ok" if filter_ty == sym::Result => "filter_map(Result::ok)",
+            _ => return false,
+        };
+        span_lint_and_sugg(
+            cx,
+            ITER_FILTER_IS_SOME,
+            filter_span.with_hi(expr.span.hi()),
+            &format!("`filter` for `{filter_method}` followed by `unwrap`"),
+            "use",
+            sugg.to_owned(),
+            Applicability::MachineApplicable,
+        );
+        true
+    } else {
+        false
+    }
+}
+
+/// If `expr` is a path to a method of `Option` or `Result`, or a closure which only calls one on
+/// its parameter, returns the name of the type and of the method.
+fn called_method(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<(Symbol, Symbol)> {
+    let method_id = match expr.kind {
+        ExprKind::Path(ref qpath) => cx.qpath_res(qpath, expr.hir_id).opt_def_id()?,
+        ExprKind::Closure(&Closure { body, .. }) => {
+            let body = cx.tcx.hir().body(body);
+            let value = peel_blocks(body.value);
+            if let [param] = body.params
+                && let PatKind::Binding(_, param_id, _, None) = param.pat.kind
+                && let ExprKind::MethodCall(_, recv, [], _) = value.kind
+                && path_to_local_id(recv, param_id)
+            {
+                cx.typeck_results().type_dependent_def_id(value.hir_id)?
+            } else {
+                return None;
+            }
+        },
+        _ => return None,
+    };
+    let impl_id = cx.tcx.impl_of_method(method_id)?;
+    let adt = cx.tcx.type_of(impl_id).instantiate_identity().ty_adt_def()?;
+    let ty_name = cx.tcx.get_diagnostic_name(adt.did())?;
+    matches!(ty_name, sym::Option | sym::Result).then(|| (ty_name, cx.tcx.item_name(method_id)))
+}
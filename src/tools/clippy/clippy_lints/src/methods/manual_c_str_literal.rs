@@ -0,0 +1,77 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use rustc_ast::{LitKind, StrStyle};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, LangItem, Lit, QPath};
+use rustc_lint::LateContext;
+use rustc_middle::ty::Ty;
+use rustc_span::sym;
+
+use super::MANUAL_C_STR_LITERAL;
+
+/// Checks `expr`, which is `recv.unwrap()`, or `recv.expect(msg)` if `msg` is given.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, msg: Option<&Expr<'_>>, msrv: &Msrv) {
+    if let ExprKind::Call(func, [arg]) = recv.kind
+        && let ExprKind::Path(ref qpath) = func.kind
+        && let QPath::TypeRelative(_, name) = qpath
+        && let ExprKind::Lit(lit) = arg.kind
+        && !expr.span.from_expansion()
+        // `c""` literals are reserved syntax before the 2021 edition.
+        && expr.span.at_least_rust_2021()
+        && msrv.meets(msrvs::C_STR_LITERALS)
+        && let Some(fn_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+        && let Some(impl_id) = cx.tcx.impl_of_method(fn_id)
+        && let Some((ty_name, sugg)) = c_str_literal(
+            cx,
+            cx.tcx.type_of(impl_id).instantiate_identity(),
+            name.ident.as_str(),
+            lit,
+        )
+    {
+        // The message of `expect` would be dropped along with the call.
+        let app = if msg.map_or(true, |msg| matches!(msg.kind, ExprKind::Lit(_))) {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        span_lint_and_sugg(
+            cx,
+            MANUAL_C_STR_LITERAL,
+            expr.span,
+            &format!("creating a `{ty_name}` from a literal with `{ty_name}::{}`", name.ident),
+            "use a `c\"\"` literal instead",
+            sugg,
+            app,
+        );
+    }
+}
+
+/// If `method` called with `lit` always succeeds, returns the name of `self_ty` and the `c""`
+/// literal the call can be replaced with.
+fn c_str_literal(cx: &LateContext<'_>, self_ty: Ty<'_>, method: &str, lit: &Lit) -> Option<(&'static str, String)> {
+    let snippet = snippet_opt(cx, lit.span)?;
+    let is_cstring = is_type_diagnostic_item(cx, self_ty, sym::cstring_type);
+    match (method, &lit.node) {
+        // The trailing nul is implied by the `c""` literal, and must be the only one.
+        ("from_bytes_with_nul", LitKind::ByteStr(bytes, StrStyle::Cooked))
+            if is_type_lang_item(cx, self_ty, LangItem::CStr)
+                && let Some((&0, rest)) = bytes.split_last()
+                && !rest.contains(&0) =>
+        {
+            let contents = snippet.strip_prefix("b\"")?;
+            let contents = contents
+                .strip_suffix("\\0\"")
+                .or_else(|| contents.strip_suffix("\\x00\""))?;
+            Some(("CStr", format!("c\"{contents}\"")))
+        },
+        ("new", LitKind::Str(s, _)) if is_cstring && !s.as_str().contains('\0') => {
+            Some(("CString", format!("c{snippet}.to_owned()")))
+        },
+        ("new", LitKind::ByteStr(bytes, _)) if is_cstring && !bytes.contains(&0) => {
+            Some(("CString", format!("c{}.to_owned()", snippet.strip_prefix('b')?)))
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,125 @@
+use std::ops::ControlFlow;
+
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{is_format_macro, macro_backtrace};
+use clippy_utils::ty::{is_copy, is_type_diagnostic_item};
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{get_parent_expr, is_trait_method, path_to_local_id};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, BorrowKind, Closure, Expr, ExprKind, HirId, Mutability, PatKind, Stmt, TyKind};
+use rustc_lint::LateContext;
+use rustc_span::symbol::Ident;
+use rustc_span::{sym, Span};
+
+use super::MANUAL_INSPECT;
+
+/// Checks `expr`, which is `_.map(arg)` or `_.map_err(arg)`, with `name_span` being the span of
+/// the method name.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, arg: &Expr<'_>, name: &str, name_span: Span, msrv: &Msrv) {
+    if let ExprKind::Closure(&Closure { body, fn_decl, .. }) = arg.kind
+        && !expr.span.from_expansion()
+        && let body = cx.tcx.hir().body(body)
+        && let [param] = body.params
+        && let PatKind::Binding(BindingAnnotation::NONE, param_id, param_name, None) = param.pat.kind
+        && let ExprKind::Block(block, None) = body.value.kind
+        && let Some(ret) = block.expr
+        && path_to_local_id(ret, param_id)
+        && let Some(last_stmt) = block.stmts.last()
+        && !block.span.from_expansion()
+        && let Some(new_name) = inspect_method(cx, expr, name, msrv)
+        && let Some(mut edits) = param_edits(cx, block.stmts, param_id, param_name)
+    {
+        // Once the parameter is a reference, method calls on it may resolve to a method of the
+        // reference itself, like `Clone::clone` of `&T` or `IntoIterator::into_iter` of `&Vec<T>`.
+        let param_ty = cx.typeck_results().pat_ty(param.pat);
+        let app = if is_copy(cx, param_ty) && !param_ty.is_ref() {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        edits.push((name_span, new_name.to_owned()));
+        // The statements stay, only the returned value goes.
+        edits.push((last_stmt.span.shrink_to_hi().to(ret.span), String::new()));
+        if let [input] = fn_decl.inputs
+            && !matches!(input.kind, TyKind::Infer)
+        {
+            edits.push((input.span.shrink_to_lo(), "&".to_owned()));
+        }
+        span_lint_and_then(
+            cx,
+            MANUAL_INSPECT,
+            name_span,
+            &format!("using `{name}` over `{new_name}`"),
+            |diag| {
+                diag.multipart_suggestion(format!("use `{new_name}` instead"), edits, app);
+            },
+        );
+    }
+}
+
+/// The method that passes the value of `expr`'s receiver to a function by reference, like `map`
+/// passes it by value.
+fn inspect_method(cx: &LateContext<'_>, expr: &Expr<'_>, name: &str, msrv: &Msrv) -> Option<&'static str> {
+    if name == "map" && is_trait_method(cx, expr, sym::Iterator) {
+        return Some("inspect");
+    }
+    let ty = cx.typeck_results().expr_ty(expr);
+    if !msrv.meets(msrvs::OPTION_RESULT_INSPECT) {
+        return None;
+    }
+    match name {
+        "map" if is_type_diagnostic_item(cx, ty, sym::Option) || is_type_diagnostic_item(cx, ty, sym::Result) => {
+            Some("inspect")
+        },
+        "map_err" if is_type_diagnostic_item(cx, ty, sym::Result) => Some("inspect_err"),
+        _ => None,
+    }
+}
+
+/// The edits to `stmts` needed once the closure parameter `param_id`, named `param`, is a
+/// reference to the value instead of the value itself, or `None` if it's used in a way that can't
+/// be adapted, like by value.
+fn param_edits<'tcx>(
+    cx: &LateContext<'tcx>,
+    stmts: &'tcx [Stmt<'tcx>],
+    param_id: HirId,
+    param: Ident,
+) -> Option<Vec<(Span, String)>> {
+    let mut edits = Vec::new();
+    let res = for_each_expr_with_closures(cx, stmts, |e| {
+        if !path_to_local_id(e, param_id) {
+            // Returning from the closure would skip the value being returned at its end.
+            return if matches!(e.kind, ExprKind::Ret(_)) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            };
+        }
+        match get_parent_expr(cx, e) {
+            Some(parent) if parent.span.ctxt() != e.span.ctxt() => {
+                // Formatting macros take their arguments by reference, which works for references
+                // just as well.
+                match macro_backtrace(parent.span).next() {
+                    Some(macro_call) if is_format_macro(cx, macro_call.def_id) => ControlFlow::Continue(()),
+                    _ => ControlFlow::Break(()),
+                }
+            },
+            Some(parent) => match parent.kind {
+                ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) => {
+                    edits.push((parent.span, param.to_string()));
+                    ControlFlow::Continue(())
+                },
+                // Auto-deref takes care of these.
+                ExprKind::MethodCall(_, recv, ..) | ExprKind::Index(recv, ..) if recv.hir_id == e.hir_id => {
+                    ControlFlow::Continue(())
+                },
+                ExprKind::Field(..) => ControlFlow::Continue(()),
+                // A use by value, which would need a copy of the value, or a move out of it.
+                _ => ControlFlow::Break(()),
+            },
+            None => ControlFlow::Break(()),
+        }
+    });
+    res.is_none().then_some(edits)
+}
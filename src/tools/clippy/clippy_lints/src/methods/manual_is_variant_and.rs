@@ -0,0 +1,61 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::MANUAL_IS_VARIANT_AND;
+
+/// Checks `expr`, which is `recv.map(map_arg).unwrap_or(default)` or
+/// `recv.map_or(default, map_arg)`, where `method_span` is the span of `map` or `map_or`.
+/// Returns whether the lint was emitted.
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    recv: &Expr<'_>,
+    default: &Expr<'_>,
+    map_arg: &Expr<'_>,
+    method_span: Span,
+    msrv: &Msrv,
+) -> bool {
+    if let ExprKind::Lit(lit) = default.kind
+        && let LitKind::Bool(false) = lit.node
+        && !expr.span.from_expansion()
+        && cx.typeck_results().expr_ty(expr).is_bool()
+        && msrv.meets(msrvs::OPTION_IS_SOME_AND)
+    {
+        let recv_ty = cx.typeck_results().expr_ty(recv);
+        let (kind, method) = if is_type_diagnostic_item(cx, recv_ty, sym::Option) {
+            ("an `Option`", "is_some_and")
+        } else if is_type_diagnostic_item(cx, recv_ty, sym::Result) {
+            ("a `Result`", "is_ok_and")
+        } else {
+            return false;
+        };
+        let call = if let ExprKind::MethodCall(path, ..) = expr.kind
+            && path.ident.name == sym::unwrap_or
+        {
+            "map(<f>).unwrap_or(false)"
+        } else {
+            "map_or(false, <f>)"
+        };
+        let mut app = Applicability::MachineApplicable;
+        let map_arg = snippet_with_context(cx, map_arg.span, expr.span.ctxt(), "..", &mut app).0;
+        span_lint_and_sugg(
+            cx,
+            MANUAL_IS_VARIANT_AND,
+            method_span.with_hi(expr.span.hi()),
+            &format!("called `{call}` on {kind} value"),
+            "use",
+            format!("{method}({map_arg})"),
+            app,
+        );
+        true
+    } else {
+        false
+    }
+}
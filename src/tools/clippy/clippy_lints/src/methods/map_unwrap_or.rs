@@ -8,6 +8,7 @@
 use rustc_lint::LateContext;
 use rustc_span::symbol::sym;
 
+use super::unnecessary_result_map_or_else::ignores_error;
 use super::MAP_UNWRAP_OR;
 
 /// lint use of `map().unwrap_or_else()` for `Option`s and `Result`s
@@ -29,6 +30,11 @@ pub(super) fn check<'tcx>(
         return false;
     }
 
+    // `unnecessary_result_map_or_else` suggests this form when the error is ignored.
+    if is_result && ignores_error(cx, unwrap_arg) {
+        return false;
+    }
+
     if is_option || is_result {
         // Don't make a suggestion that may fail to compile due to mutably borrowing
         // the same variable twice.
@@ -14,6 +14,7 @@
 mod clone_on_ref_ptr;
 mod cloned_instead_of_copied;
 mod collapsible_str_replace;
+mod collect_to_check_emptiness;
 mod drain_collect;
 mod err_expect;
 mod expect_fun_call;
@@ -38,6 +39,7 @@
 mod is_digit_ascii_radix;
 mod iter_cloned_collect;
 mod iter_count;
+mod iter_filter_is_some;
 mod iter_kv_map;
 mod iter_next_slice;
 mod iter_nth;
@@ -50,6 +52,9 @@
 mod iter_with_drain;
 mod iterator_step_by_zero;
 mod join_absolute_paths;
+mod manual_c_str_literal;
+mod manual_inspect;
+mod manual_is_variant_and;
 mod manual_next_back;
 mod manual_ok_or;
 mod manual_saturating_arithmetic;
@@ -63,6 +68,7 @@
 mod map_unwrap_or;
 mod mut_mutex_lock;
 mod needless_collect;
+mod needless_drain;
 mod needless_option_as_deref;
 mod needless_option_take;
 mod no_effect_replace;
@@ -77,10 +83,12 @@
 mod or_then_unwrap;
 mod path_buf_push_overwrite;
 mod path_ends_with_ext;
+mod push_absolute_path;
 mod range_zip_with_len;
 mod read_line_without_trim;
 mod readonly_write_lock;
 mod redundant_as_str;
+mod redundant_sort_by;
 mod repeat_once;
 mod result_map_or_else_none;
 mod search_is_some;
@@ -109,6 +117,7 @@
 mod unnecessary_join;
 mod unnecessary_lazy_eval;
 mod unnecessary_literal_unwrap;
+mod unnecessary_result_map_or_else;
 mod unnecessary_sort_by;
 mod unnecessary_to_owned;
 mod unwrap_expect_used;
@@ -125,7 +134,9 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
 use clippy_utils::ty::{contains_ty_adt_constructor_opaque, implements_trait, is_copy, is_type_diagnostic_item};
-use clippy_utils::{contains_return, is_bool, is_trait_method, iter_input_pats, peel_blocks, return_ty};
+use clippy_utils::{
+    contains_return, is_bool, is_lint_allowed, is_trait_method, iter_input_pats, peel_blocks, return_ty,
+};
 pub use path_ends_with_ext::DEFAULT_ALLOWED_DOTFILES;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
@@ -3752,6 +3763,275 @@
     "using `Option.map_or(Err(_), Ok)`, which is more succinctly expressed as `Option.ok_or(_)`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for usage of `sort_by` or `sort_unstable_by` with a closure that compares
+    /// floats with `partial_cmp(..).unwrap()`, or that compares a field holding a reference,
+    /// which [`unnecessary_sort_by`] leaves alone.
+    ///
+    /// ### Why is this bad?
+    /// `f32::total_cmp` and `f64::total_cmp` are a total order, so they can't panic on `NaN`.
+    /// `sort_by_key` is clearer than comparing the same field of both elements by hand, and
+    /// copying a reference out of a field doesn't borrow from the element.
+    ///
+    /// ### Known problems
+    /// `total_cmp` orders `-0.0` before `0.0`, which `partial_cmp` considers equal.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let mut v = vec![2.0_f32, 1.0];
+    /// v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let mut v = vec![2.0_f32, 1.0];
+    /// v.sort_by(f32::total_cmp);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub REDUNDANT_SORT_BY,
+    complexity,
+    "using `sort_by` with a comparison that `total_cmp` or `sort_by_key` expresses directly"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `drain` calls that empty a `HashMap`, `HashSet` or `BinaryHeap` local variable
+    /// which isn't used again, or is only reassigned, and for `drain` calls that empty any of
+    /// these or a `Vec` or `VecDeque` field which is reassigned right after.
+    ///
+    /// ### Why is this bad?
+    /// `drain` keeps the collection alive so that it can be reused, and has to put it back into
+    /// a valid state when the iterator is dropped. When the collection is thrown away anyway,
+    /// `into_iter()` consumes it directly, or `std::mem::take(..).into_iter()` if it can't be
+    /// moved out of.
+    ///
+    /// Local `Vec`s and `VecDeque`s are left to `iter_with_drain`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # fn process(_: u32, _: u32) {}
+    /// let mut jobs = HashMap::from([(1, 2)]);
+    /// for (id, job) in jobs.drain() {
+    ///     process(id, job);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # fn process(_: u32, _: u32) {}
+    /// let jobs = HashMap::from([(1, 2)]);
+    /// for (id, job) in jobs {
+    ///     process(id, job);
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub NEEDLESS_DRAIN,
+    pedantic,
+    "draining a collection that is dropped or reassigned right after"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for iterators that are collected only to check whether the collection is empty,
+    /// like `iter.collect::<Vec<_>>().is_empty()` or `iter.collect::<String>().len() == 0`.
+    ///
+    /// ### Why is this bad?
+    /// Collecting allocates, and visits every item when the first one is enough to know the
+    /// answer. `iter.next().is_none()` does neither, and `iter.count() == 0` still visits every
+    /// item for the side effects of the iterator, but doesn't allocate.
+    ///
+    /// ### Known problems
+    /// The lint only sees side effects of `inspect` and of `FnMut` closures, and then suggests
+    /// `count()`. A closure that has other side effects, such as printing, runs only until the
+    /// first item with `next()`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let lines = ["", "a b"];
+    /// let no_words = lines.iter().flat_map(|l| l.split_whitespace()).collect::<Vec<_>>().is_empty();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let lines = ["", "a b"];
+    /// let no_words = lines.iter().flat_map(|l| l.split_whitespace()).next().is_none();
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub COLLECT_TO_CHECK_EMPTINESS,
+    perf,
+    "collecting an iterator only to check whether it is empty"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `PathBuf::push` with an absolute path: one that starts with a path
+    /// separator or a Windows drive prefix like `C:`. Besides literals, this covers constants,
+    /// locals initialized to one, and conversions like `Path::new("/etc")`.
+    ///
+    /// ### Why is this bad?
+    /// Pushing an absolute path replaces the whole `PathBuf` instead of extending it, so the base
+    /// path is silently discarded.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::path::PathBuf;
+    /// let mut config = PathBuf::from("/home/user");
+    /// config.push("/.config");
+    /// assert_eq!(config, PathBuf::from("/.config"));
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::path::PathBuf;
+    /// let mut config = PathBuf::from("/home/user");
+    /// config.push(".config");
+    /// assert_eq!(config, PathBuf::from("/home/user/.config"));
+    /// ```
+    /// If replacing the path is intended, say so:
+    /// ```no_run
+    /// # use std::path::PathBuf;
+    /// # let mut config = PathBuf::from("/home/user");
+    /// config = PathBuf::from("/.config");
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub PUSH_ABSOLUTE_PATH,
+    suspicious,
+    "pushing an absolute path onto a `PathBuf`, which replaces it"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `CStr::from_bytes_with_nul` and `CString::new` called with a literal and
+    /// unwrapped right away.
+    ///
+    /// ### Why is this bad?
+    /// A `c""` literal is checked at compile time, and doesn't need the trailing nul byte to be
+    /// written out.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::ffi::{CStr, CString};
+    /// let borrowed = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+    /// let owned = CString::new("foo").unwrap();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let borrowed = c"foo";
+    /// let owned = c"foo".to_owned();
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_C_STR_LITERAL,
+    complexity,
+    "creating a `CStr` or `CString` from a literal at runtime instead of using a `c\"\"` literal"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `map` and `map_err` calls whose closure only looks at the value before
+    /// returning it unchanged.
+    ///
+    /// ### Why is this bad?
+    /// `inspect` and `inspect_err` make it clear that the value is passed through as is.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let x = Some(1).map(|x| {
+    ///     println!("{x}");
+    ///     x
+    /// });
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let x = Some(1).inspect(|x| {
+    ///     println!("{x}");
+    /// });
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_INSPECT,
+    complexity,
+    "using `map` or `map_err` to inspect a value instead of `inspect` or `inspect_err`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `option.map(f).unwrap_or(false)`, `result.map(f).unwrap_or(false)` and the
+    /// `map_or(false, f)` spelling of both.
+    ///
+    /// ### Why is this bad?
+    /// `is_some_and` and `is_ok_and` say the same thing more directly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let option = Some(1);
+    /// let result: Result<i32, ()> = Ok(1);
+    /// let _ = option.map(|x| x > 0).unwrap_or(false);
+    /// let _ = result.map_or(false, |x| x > 0);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let option = Some(1);
+    /// let result: Result<i32, ()> = Ok(1);
+    /// let _ = option.is_some_and(|x| x > 0);
+    /// let _ = result.is_ok_and(|x| x > 0);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MANUAL_IS_VARIANT_AND,
+    pedantic,
+    "using `.map(f).unwrap_or(false)` or `.map_or(false, f)` instead of `is_some_and` or `is_ok_and`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for iterators of `Option`s or `Result`s filtered on `is_some` or `is_ok` and then
+    /// unwrapped with `map`.
+    ///
+    /// ### Why is this bad?
+    /// `flatten` and `filter_map(Result::ok)` do the same in one step, without an `unwrap` that
+    /// can never panic.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let v = vec![Ok::<i32, ()>(1), Err(())];
+    /// let _: Vec<i32> = v.into_iter().filter(Result::is_ok).map(Result::unwrap).collect();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let v = vec![Ok::<i32, ()>(1), Err(())];
+    /// let _: Vec<i32> = v.into_iter().filter_map(Result::ok).collect();
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub ITER_FILTER_IS_SOME,
+    pedantic,
+    "filtering an iterator on `is_some` or `is_ok` then unwrapping with `map`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Result::map_or_else` calls whose map function is the identity, like
+    /// `result.map_or_else(|e| handle(e), |v| v)`, or whose default ignores the error, like
+    /// `result.map_or_else(|_| default, f)`.
+    ///
+    /// ### Why is this bad?
+    /// The first is just `unwrap_or_else`, which is more concise. The second reads more naturally
+    /// as `map(f).unwrap_or_else(|_| default)`, in the order the values are used.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let x: Result<u32, ()> = Ok(0);
+    /// let y = x.map_or_else(|e| e, |v| v);
+    /// let z = x.map_or_else(|_| 0, |v| v + 1);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let x: Result<u32, ()> = Ok(0);
+    /// let y = x.unwrap_or_else(|e| e);
+    /// let z = x.map(|v| v + 1).unwrap_or_else(|_| 0);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub UNNECESSARY_RESULT_MAP_OR_ELSE,
+    style,
+    "making no use of the \"map closure\" when calling `Result::map_or_else`"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
@@ -3903,6 +4183,15 @@ pub fn new(
     UNNECESSARY_FALLIBLE_CONVERSIONS,
     JOIN_ABSOLUTE_PATHS,
     OPTION_MAP_OR_ERR_OK,
+    REDUNDANT_SORT_BY,
+    NEEDLESS_DRAIN,
+    COLLECT_TO_CHECK_EMPTINESS,
+    PUSH_ABSOLUTE_PATH,
+    MANUAL_C_STR_LITERAL,
+    MANUAL_INSPECT,
+    MANUAL_IS_VARIANT_AND,
+    ITER_FILTER_IS_SOME,
+    UNNECESSARY_RESULT_MAP_OR_ELSE,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -4153,6 +4442,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("cloned", []) => cloned_instead_of_copied::check(cx, expr, recv, span, &self.msrv),
                 ("collect", []) if is_trait_method(cx, expr, sym::Iterator) => {
                     needless_collect::check(cx, span, expr, recv, call_span);
+                    collect_to_check_emptiness::check(cx, expr, recv);
                     match method_call(recv) {
                         Some((name @ ("cloned" | "copied"), recv2, [], _, _)) => {
                             iter_cloned_collect::check(cx, name, expr, recv2);
@@ -4190,8 +4480,11 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         && args.len() <= 1
                     {
                         clear_with_drain::check(cx, expr, recv, span, args.first());
-                    } else if let [arg] = args {
-                        iter_with_drain::check(cx, expr, recv, span, arg);
+                    } else {
+                        needless_drain::check(cx, expr, recv, span, args);
+                        if let [arg] = args {
+                            iter_with_drain::check(cx, expr, recv, span, arg);
+                        }
                     }
                 },
                 ("ends_with", [arg]) => {
@@ -4216,6 +4509,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         ),
                     }
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+                    manual_c_str_literal::check(cx, expr, recv, args.first(), &self.msrv);
                 },
                 ("expect_err", [_]) => {
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
@@ -4325,6 +4619,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     mut_mutex_lock::check(cx, expr, recv, span);
                 },
                 (name @ ("map" | "map_err"), [m_arg]) => {
+                    manual_inspect::check(cx, expr, m_arg, name, span, &self.msrv);
                     if name == "map" {
                         map_clone::check(cx, expr, recv, m_arg, &self.msrv);
                         match method_call(recv) {
@@ -4349,7 +4644,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                             ("as_mut", []) => option_as_ref_deref::check(cx, expr, recv2, m_arg, true, &self.msrv),
                             ("as_ref", []) => option_as_ref_deref::check(cx, expr, recv2, m_arg, false, &self.msrv),
                             ("filter", [f_arg]) => {
-                                filter_map::check(cx, expr, recv2, f_arg, span2, recv, m_arg, span, false);
+                                if !iter_filter_is_some::check(cx, expr, f_arg, span2, m_arg) {
+                                    filter_map::check(cx, expr, recv2, f_arg, span2, recv, m_arg, span, false);
+                                }
                             },
                             ("find", [f_arg]) => {
                                 filter_map::check(cx, expr, recv2, f_arg, span2, recv, m_arg, span, true);
@@ -4360,12 +4657,15 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     map_identity::check(cx, expr, recv, m_arg, name, span);
                 },
                 ("map_or", [def, map]) => {
+                    manual_is_variant_and::check(cx, expr, recv, def, map, span, &self.msrv);
                     option_map_or_none::check(cx, expr, recv, def, map);
                     manual_ok_or::check(cx, expr, recv, def, map);
                     option_map_or_err_ok::check(cx, expr, recv, def, map);
                 },
                 ("map_or_else", [def, map]) => {
-                    result_map_or_else_none::check(cx, expr, recv, def, map);
+                    if !result_map_or_else_none::check(cx, expr, recv, def, map) {
+                        unnecessary_result_map_or_else::check(cx, expr, recv, def, map);
+                    }
                 },
                 ("next", []) => {
                     if let Some((name2, recv2, args2, _, _)) = method_call(recv) {
@@ -4412,7 +4712,12 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     }
                 },
                 ("push", [arg]) => {
-                    path_buf_push_overwrite::check(cx, expr, arg);
+                    // `path_buf_push_overwrite` is the older, narrower version of this lint.
+                    if is_lint_allowed(cx, PATH_BUF_PUSH_OVERWRITE, expr.hir_id) {
+                        push_absolute_path::check(cx, expr, recv, arg);
+                    } else {
+                        path_buf_push_overwrite::check(cx, expr, arg);
+                    }
                 },
                 ("read_to_end", [_]) => {
                     verbose_file_reads::check(cx, expr, recv, verbose_file_reads::READ_TO_END_MSG);
@@ -4468,9 +4773,11 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 },
                 ("sort_by", [arg]) => {
                     unnecessary_sort_by::check(cx, expr, recv, arg, false);
+                    redundant_sort_by::check(cx, expr, recv, arg, false, &self.msrv);
                 },
                 ("sort_unstable_by", [arg]) => {
                     unnecessary_sort_by::check(cx, expr, recv, arg, true);
+                    redundant_sort_by::check(cx, expr, recv, arg, true, &self.msrv);
                 },
                 ("splitn" | "rsplitn", [count_arg, pat_arg]) => {
                     if let Some(Constant::Int(count)) = constant(cx, cx.typeck_results(), count_arg) {
@@ -4532,6 +4839,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         _ => {},
                     }
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+                    manual_c_str_literal::check(cx, expr, recv, None, &self.msrv);
                     unwrap_expect_used::check(
                         cx,
                         expr,
@@ -4558,7 +4866,11 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                             manual_saturating_arithmetic::check(cx, expr, lhs, rhs, u_arg, &arith["checked_".len()..]);
                         },
                         Some(("map", m_recv, [m_arg], span, _)) => {
-                            option_map_unwrap_or::check(cx, expr, m_recv, m_arg, recv, u_arg, span, &self.msrv);
+                            if is_lint_allowed(cx, MANUAL_IS_VARIANT_AND, expr.hir_id)
+                                || !manual_is_variant_and::check(cx, expr, m_recv, u_arg, m_arg, span, &self.msrv)
+                            {
+                                option_map_unwrap_or::check(cx, expr, m_recv, m_arg, recv, u_arg, span, &self.msrv);
+                            }
                         },
                         Some(("then_some", t_recv, [t_arg], _, _)) => {
                             obfuscated_if_else::check(cx, expr, t_recv, t_arg, u_arg);
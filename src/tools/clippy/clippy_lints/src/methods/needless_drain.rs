@@ -0,0 +1,140 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::usage::local_used_after_expr;
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{
+    get_enclosing_loop_or_multi_call_closure, get_parent_expr, is_range_full, path_to_local, SpanlessEq,
+};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, HirId, Node, QPath, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span, Symbol};
+
+use super::NEEDLESS_DRAIN;
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, span: Span, args: &[Expr<'_>]) {
+    let Some(ty_name) = drained_collection(cx, recv, args) else {
+        return;
+    };
+    if expr.span.from_expansion()
+        // `drain_collect` already suggests `mem::take` for these.
+        || get_parent_expr(cx, expr).is_some_and(|parent| {
+            matches!(parent.kind, ExprKind::MethodCall(path, ..) if path.ident.as_str() == "collect")
+        })
+    {
+        return;
+    }
+
+    let msg = &format!("`{ty_name}` is drained right before it is dropped or reassigned");
+    if let Some(local) = path_to_local(recv) {
+        // `iter_with_drain` already covers local `Vec`s and `VecDeque`s.
+        if !matches!(ty_name, sym::Vec | sym::VecDeque) && is_dropped_or_reassigned_after(cx, local, expr) {
+            span_lint_and_sugg(
+                cx,
+                NEEDLESS_DRAIN,
+                span.with_hi(expr.span.hi()),
+                msg,
+                "consume it instead",
+                "into_iter()".to_string(),
+                Applicability::MachineApplicable,
+            );
+        }
+    } else if is_default(cx, recv) && is_reassigned_by_next_stmt(cx, recv, expr) {
+        let mut app = Applicability::MachineApplicable;
+        let (recv, _) = snippet_with_context(cx, recv.span, expr.span.ctxt(), "..", &mut app);
+        span_lint_and_sugg(
+            cx,
+            NEEDLESS_DRAIN,
+            expr.span,
+            msg,
+            "take it instead",
+            format!("std::mem::take(&mut {recv}).into_iter()"),
+            app,
+        );
+    }
+}
+
+/// If `recv.drain(args)` removes every element of a collection that `into_iter` consumes into
+/// the same items, returns the name of the collection.
+fn drained_collection(cx: &LateContext<'_>, recv: &Expr<'_>, args: &[Expr<'_>]) -> Option<Symbol> {
+    let adt = cx.typeck_results().expr_ty(recv).ty_adt_def()?;
+    let name = cx.tcx.get_diagnostic_name(adt.did())?;
+    let drains_everything = match (name, args) {
+        (sym::Vec | sym::VecDeque, [arg]) => {
+            let container_path = match recv.kind {
+                ExprKind::Path(QPath::Resolved(None, path)) => Some(path),
+                _ => None,
+            };
+            is_range_full(cx, arg, container_path)
+        },
+        (sym::HashMap | sym::HashSet | sym::BinaryHeap, []) => true,
+        _ => false,
+    };
+    drains_everything.then_some(name)
+}
+
+/// Whether `std::mem::take` can be used on `e`, which needs its type to implement `Default`.
+fn is_default(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    cx.tcx
+        .get_diagnostic_item(sym::Default)
+        .is_some_and(|default| implements_trait(cx, cx.typeck_results().expr_ty(e), default, &[]))
+}
+
+/// Whether the local owning the collection is never used again after `drain_expr`, or only to be
+/// assigned a new value, so that it may as well be moved out of.
+fn is_dropped_or_reassigned_after(cx: &LateContext<'_>, local: HirId, drain_expr: &Expr<'_>) -> bool {
+    // Moving out of a local captured by a closure would make the closure `FnOnce`.
+    if is_captured_by_closure(cx, local, drain_expr) {
+        return false;
+    }
+    if !local_used_after_expr(cx, local, drain_expr) {
+        return true;
+    }
+    get_enclosing_loop_or_multi_call_closure(cx, drain_expr).is_none()
+        && for_each_local_use_after_expr(cx, local, drain_expr.hir_id, |e| {
+            if let Some(parent) = get_parent_expr(cx, e)
+                && let ExprKind::Assign(lhs, ..) = parent.kind
+                && lhs.hir_id == e.hir_id
+            {
+                ControlFlow::Break(true)
+            } else {
+                ControlFlow::Break(false)
+            }
+        }) == ControlFlow::Break(true)
+}
+
+/// Whether `e` is inside a closure that `local` was declared outside of.
+fn is_captured_by_closure(cx: &LateContext<'_>, local: HirId, e: &Expr<'_>) -> bool {
+    let hir = cx.tcx.hir();
+    hir.parent_iter(e.hir_id)
+        .find(|(_, node)| matches!(node, Node::Expr(e) if matches!(e.kind, ExprKind::Closure(_))))
+        .map_or(false, |(closure, _)| !hir.parent_id_iter(local).any(|id| id == closure))
+}
+
+/// Whether the statement holding `drain_expr` is directly followed by one assigning to `place`.
+fn is_reassigned_by_next_stmt(cx: &LateContext<'_>, place: &Expr<'_>, drain_expr: &Expr<'_>) -> bool {
+    let hir = cx.tcx.hir();
+    let mut child = drain_expr.hir_id;
+    for (parent, node) in hir.parent_iter(drain_expr.hir_id) {
+        match node {
+            Node::Block(block) => {
+                let Some(i) = block.stmts.iter().position(|stmt| stmt.hir_id == child) else {
+                    return false;
+                };
+                let next = block.stmts.get(i + 1).map_or(block.expr, |stmt| match stmt.kind {
+                    StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                    _ => None,
+                });
+                let Some(next) = next else {
+                    return false;
+                };
+                return matches!(next.kind, ExprKind::Assign(lhs, ..) if SpanlessEq::new(cx).eq_expr(lhs, place));
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => return false,
+            _ => child = parent,
+        }
+    }
+    false
+}
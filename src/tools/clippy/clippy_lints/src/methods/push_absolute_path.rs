@@ -0,0 +1,113 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::expr_or_init;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, LangItem, QPath};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::PUSH_ABSOLUTE_PATH;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, arg: &'tcx Expr<'tcx>) {
+    if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
+        && is_type_diagnostic_item(cx, cx.tcx.type_of(impl_id).instantiate_identity(), sym::PathBuf)
+        && !expr.span.from_expansion()
+        && let Some(path) = absolute_path(cx, arg)
+    {
+        span_lint_and_then(
+            cx,
+            PUSH_ABSOLUTE_PATH,
+            expr.span,
+            "pushing an absolute path onto a `PathBuf` replaces it",
+            |diag| {
+                if let Some(lit) = path.lit
+                    && let Some(stripped) = strip_root(cx, lit, &path.root)
+                {
+                    diag.span_suggestion(
+                        lit,
+                        "if this is unintentional, remove the root",
+                        stripped,
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+                let mut app = Applicability::MaybeIncorrect;
+                let mut path_buf = Sugg::hir_with_context(cx, recv, expr.span.ctxt(), "..", &mut app);
+                if cx.typeck_results().expr_ty(recv).is_ref() {
+                    path_buf = path_buf.deref();
+                }
+                let arg = Sugg::hir_with_context(cx, arg, expr.span.ctxt(), "..", &mut app);
+                diag.span_suggestion(
+                    expr.span,
+                    "if this is intentional, replace the path instead",
+                    format!("{path_buf} = PathBuf::from({arg})"),
+                    app,
+                );
+            },
+        );
+    }
+}
+
+pub(super) struct AbsolutePath {
+    /// The leading separators, or the Windows drive prefix like `C:` with the separators after it.
+    pub root: String,
+    /// The string literal the path was written as, if any.
+    pub lit: Option<Span>,
+}
+
+/// Evaluates `expr` as a path, looking through locals, constants, `&` and conversions like
+/// `Path::new`, and returns its root if it is absolute.
+pub(super) fn absolute_path<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<AbsolutePath> {
+    let mut expr = expr_or_init(cx, expr);
+    loop {
+        match expr.kind {
+            ExprKind::AddrOf(_, _, inner) => expr = expr_or_init(cx, inner),
+            ExprKind::Call(func, [inner])
+                if let ExprKind::Path(QPath::TypeRelative(_, name)) = func.kind
+                    && matches!(name.ident.as_str(), "new" | "from")
+                    && is_path_like(cx, expr) =>
+            {
+                expr = expr_or_init(cx, inner);
+            },
+            _ => break,
+        }
+    }
+    let Some(Constant::Str(path)) = constant(cx, cx.typeck_results(), expr) else {
+        return None;
+    };
+    let rest = match path.as_bytes() {
+        [drive, b':', ..] if drive.is_ascii_alphabetic() => &path[2..],
+        [b'/' | b'\\', ..] => &path,
+        _ => return None,
+    };
+    let root_len = path.len() - rest.trim_start_matches(['/', '\\']).len();
+    Some(AbsolutePath {
+        root: path[..root_len].to_owned(),
+        lit: matches!(expr.kind, ExprKind::Lit(_)).then_some(expr.span),
+    })
+}
+
+fn is_path_like(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(expr).peel_refs();
+    is_type_diagnostic_item(cx, ty, sym::Path)
+        || is_type_diagnostic_item(cx, ty, sym::PathBuf)
+        || is_type_lang_item(cx, ty, LangItem::String)
+}
+
+/// The string literal at `lit` without `root` at its start, keeping raw strings raw.
+pub(super) fn strip_root(cx: &LateContext<'_>, lit: Span, root: &str) -> Option<String> {
+    let snippet = snippet_opt(cx, lit)?;
+    let start = snippet.find('"')? + 1;
+    // In a non-raw string, each `\` is written as `\\`.
+    let written_root = if snippet.starts_with('r') {
+        root.to_owned()
+    } else {
+        root.replace('\\', "\\\\")
+    };
+    snippet[start..]
+        .strip_prefix(&written_root)
+        .map(|rest| format!("{}{rest}", &snippet[..start]))
+}
@@ -0,0 +1,120 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_copy;
+use clippy_utils::{is_trait_method, path_to_local_id, peel_ref_operators};
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, HirId, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::unnecessary_sort_by::{expr_borrows, mirrored_exprs};
+use super::REDUNDANT_SORT_BY;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    arg: &'tcx Expr<'_>,
+    is_unstable: bool,
+    msrv: &Msrv,
+) {
+    if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
+        && cx.tcx.type_of(impl_id).instantiate_identity().is_slice()
+        && !expr.span.from_expansion()
+        && let ExprKind::Closure(&Closure { body, .. }) = arg.kind
+        && let closure_body = cx.tcx.hir().body(body)
+        && let [left_param, right_param] = closure_body.params
+        && let PatKind::Binding(_, left_id, left_ident, _) = left_param.pat.kind
+        && let PatKind::Binding(_, right_id, right_ident, _) = right_param.pat.kind
+    {
+        let value = closure_body.value;
+        let sort = if is_unstable { "sort_unstable" } else { "sort" };
+        // Both rewrites can change behavior or fail to infer lifetimes in edge cases.
+        let mut app = Applicability::MaybeIncorrect;
+
+        // `|a, b| a.key.cmp(&b.key)` with a reference in `key`, which `unnecessary_sort_by` skips.
+        if let ExprKind::MethodCall(path, left_expr, [right_expr], _) = value.kind
+            && path.ident.name == sym::cmp
+            && is_trait_method(cx, value, sym::Ord)
+            // Only fields can be copied out of the element without borrowing from it.
+            && is_field_of(left_expr, left_id, right_id)
+            && expr_borrows(cx, left_expr)
+            && is_copy(cx, cx.typeck_results().expr_ty(left_expr))
+        {
+            let (closure_arg, reverse) = if mirrored_exprs(left_expr, &left_ident, right_expr, &right_ident) {
+                (left_ident, false)
+            } else if mirrored_exprs(left_expr, &right_ident, right_expr, &left_ident) {
+                (right_ident, true)
+            } else {
+                return;
+            };
+            span_lint_and_then(
+                cx,
+                REDUNDANT_SORT_BY,
+                expr.span,
+                &format!("use `{sort}_by_key` here instead"),
+                |diag| {
+                    let vec = Sugg::hir_with_applicability(cx, recv, "..", &mut app).maybe_par();
+                    let key = snippet_with_applicability(cx, left_expr.span, "..", &mut app);
+                    let key = if reverse {
+                        format!("std::cmp::Reverse({key})")
+                    } else {
+                        key.to_string()
+                    };
+                    diag.span_suggestion(
+                        expr.span,
+                        "try",
+                        format!("{vec}.{sort}_by_key(|{closure_arg}| {key})"),
+                        app,
+                    );
+                },
+            );
+        // `|a, b| a.partial_cmp(b).unwrap()` on floats
+        } else if let ExprKind::MethodCall(unwrap, cmp, [], _) = value.kind
+            && unwrap.ident.name == sym::unwrap
+            && let ExprKind::MethodCall(path, left_expr, [right_expr], _) = cmp.kind
+            && path.ident.name == sym::partial_cmp
+            && is_trait_method(cx, cmp, sym::PartialOrd)
+            && let ty::Float(float_ty) = cx.typeck_results().expr_ty(left_expr).peel_refs().kind()
+            && msrv.meets(msrvs::TOTAL_CMP)
+        {
+            let float_ty = float_ty.name_str();
+            span_lint_and_then(
+                cx,
+                REDUNDANT_SORT_BY,
+                expr.span,
+                &format!("use `{float_ty}::total_cmp` here instead"),
+                |diag| {
+                    let vec = Sugg::hir_with_applicability(cx, recv, "..", &mut app).maybe_par();
+                    let sugg = if path_to_local_id(left_expr, left_id)
+                        && path_to_local_id(peel_ref_operators(cx, right_expr), right_id)
+                        && let ty::Ref(_, elem_ty, _) = cx.typeck_results().expr_ty(left_expr).kind()
+                        && elem_ty.is_floating_point()
+                    {
+                        format!("{vec}.{sort}_by({float_ty}::total_cmp)")
+                    } else {
+                        let left = Sugg::hir_with_applicability(cx, left_expr, "..", &mut app).maybe_par();
+                        let right = snippet_with_applicability(cx, right_expr.span, "..", &mut app);
+                        format!("{vec}.{sort}_by(|{left_ident}, {right_ident}| {left}.total_cmp({right}))")
+                    };
+                    diag.span_suggestion(expr.span, "try", sugg, app);
+                    diag.note("`total_cmp` orders `-0.0` before `0.0`, which `partial_cmp` considers equal");
+                },
+            );
+        }
+    }
+}
+
+/// `a.field`, possibly nested, where `a` is one of the closure parameters.
+fn is_field_of(mut expr: &Expr<'_>, left_id: HirId, right_id: HirId) -> bool {
+    let mut is_field = false;
+    while let ExprKind::Field(base, _) = expr.kind {
+        expr = base;
+        is_field = true;
+    }
+    is_field && (path_to_local_id(expr, left_id) || path_to_local_id(expr, right_id))
+}
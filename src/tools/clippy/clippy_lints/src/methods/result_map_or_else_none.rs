@@ -11,13 +11,15 @@
 use super::RESULT_MAP_OR_INTO_OPTION;
 
 /// lint use of `_.map_or_else(|_| None, Some)` for `Result`s
+///
+/// Returns true if the lint was emitted
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx hir::Expr<'_>,
     recv: &'tcx hir::Expr<'_>,
     def_arg: &'tcx hir::Expr<'_>,
     map_arg: &'tcx hir::Expr<'_>,
-) {
+) -> bool {
     // lint if the caller of `map_or_else()` is a `Result`
     if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Result)
         // We check that it is mapped as `Some`.
@@ -38,5 +40,7 @@ pub(super) fn check<'tcx>(
             format!("{self_snippet}.ok()"),
             Applicability::MachineApplicable,
         );
+        return true;
     }
+    false
 }
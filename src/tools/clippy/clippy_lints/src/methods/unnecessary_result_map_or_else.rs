@@ -0,0 +1,78 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::is_expr_untyped_identity_function;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::symbol::sym;
+
+use super::UNNECESSARY_RESULT_MAP_OR_ELSE;
+
+/// lint use of `_.map_or_else(f, |x| x)` and `_.map_or_else(|_| default, f)` for `Result`s
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    recv: &'tcx hir::Expr<'_>,
+    def_arg: &'tcx hir::Expr<'_>,
+    map_arg: &'tcx hir::Expr<'_>,
+) {
+    if !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Result) || expr.span.from_expansion() {
+        return;
+    }
+    let mut applicability = Applicability::MachineApplicable;
+    let self_snippet = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
+    let err_snippet = snippet_with_applicability(cx, def_arg.span, "..", &mut applicability);
+    if is_expr_untyped_identity_function(cx, map_arg) {
+        span_lint_and_sugg(
+            cx,
+            UNNECESSARY_RESULT_MAP_OR_ELSE,
+            expr.span,
+            "called `map_or_else` with an identity function on a `Result` value",
+            "try using `unwrap_or_else` instead",
+            format!("{self_snippet}.unwrap_or_else({err_snippet})"),
+            applicability,
+        );
+    } else if let hir::ExprKind::Closure(&hir::Closure { body, .. }) = def_arg.kind
+        && ignores_error(cx, def_arg)
+        && def_arg.span.eq_ctxt(map_arg.span)
+    {
+        let map_snippet = snippet_with_applicability(cx, map_arg.span, "..", &mut applicability);
+        // Don't go through `unwrap_or_else` when `unnecessary_lazy_evaluations` would then suggest
+        // `unwrap_or`.
+        let default = cx.tcx.hir().body(body).value;
+        let (help, sugg) = if switch_to_eager_eval(cx, default) {
+            let default_snippet = snippet_with_applicability(cx, default.span, "..", &mut applicability);
+            (
+                "try using `map` and `unwrap_or` instead",
+                format!("{self_snippet}.map({map_snippet}).unwrap_or({default_snippet})"),
+            )
+        } else {
+            (
+                "try using `map` and `unwrap_or_else` instead",
+                format!("{self_snippet}.map({map_snippet}).unwrap_or_else({err_snippet})"),
+            )
+        };
+        span_lint_and_sugg(
+            cx,
+            UNNECESSARY_RESULT_MAP_OR_ELSE,
+            expr.span,
+            "called `map_or_else` with a default that ignores the error on a `Result` value",
+            help,
+            sugg,
+            applicability,
+        );
+    }
+}
+
+/// Checks if `expr` is a closure that ignores its only argument, the error, with a `_` pattern.
+pub(super) fn ignores_error(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    if let hir::ExprKind::Closure(&hir::Closure { body, .. }) = expr.kind
+        && let [param] = cx.tcx.hir().body(body).params
+    {
+        matches!(param.pat.kind, hir::PatKind::Wild)
+    } else {
+        false
+    }
+}
@@ -1,14 +1,16 @@
 use clippy_config::msrvs::{self, Msrv};
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::qualify_min_const_fn::is_min_const_fn;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::qualify_min_const_fn::{is_min_const_fn, DryRuns};
 use clippy_utils::ty::has_drop;
 use clippy_utils::{fn_has_unsatisfiable_preds, is_entrypoint_fn, is_from_proc_macro, trait_ref_of_method};
+use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::def_id::CRATE_DEF_ID;
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::{Body, Constness, FnDecl, GenericParamKind};
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, Level};
 use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::TyCtxt;
 use rustc_session::impl_lint_pass;
 use rustc_span::def_id::LocalDefId;
 use rustc_span::Span;
@@ -21,9 +23,10 @@
     /// Not having the function const prevents callers of the function from being const as well.
     ///
     /// ### Known problems
-    /// Const functions are currently still being worked on, with some features only being available
-    /// on nightly. This lint does not consider all edge cases currently and the suggestions may be
-    /// incorrect if you are using this lint on stable.
+    /// The function is checked the same way the compiler would check it as a `const fn`.
+    ///
+    /// If the function would rely on unstable features that the crate enables, these are pointed
+    /// out in a note.
     ///
     /// Also, the lint only runs one pass over the code. Consider these two non-const functions:
     ///
@@ -75,12 +78,13 @@
 
 pub struct MissingConstForFn {
     msrv: Msrv,
+    dry_runs: DryRuns,
 }
 
 impl MissingConstForFn {
     #[must_use]
-    pub fn new(msrv: Msrv) -> Self {
-        Self { msrv }
+    pub fn new(msrv: Msrv, dry_runs: DryRuns) -> Self {
+        Self { msrv, dry_runs }
     }
 }
 
@@ -151,17 +155,46 @@ fn check_fn(
 
         let mir = cx.tcx.optimized_mir(def_id);
 
-        if let Err((span, err)) = is_min_const_fn(cx.tcx, mir, &self.msrv) {
-            if cx.tcx.is_const_fn_raw(def_id.to_def_id()) {
-                cx.tcx.sess.span_err(span, err);
-            }
-        } else {
-            span_lint(cx, MISSING_CONST_FOR_FN, span, "this could be a `const fn`");
+        if let Ok(features) = is_min_const_fn(cx.tcx, mir, &self.msrv, &self.dry_runs) {
+            span_lint_and_then(cx, MISSING_CONST_FOR_FN, span, "this could be a `const fn`", |diag| {
+                for feature in features {
+                    diag.note(format!("this relies on the unstable feature `{feature}`"));
+                }
+                let vis_span = match cx.tcx.hir().get_by_def_id(def_id) {
+                    hir::Node::Item(item) => item.vis_span,
+                    hir::Node::ImplItem(item) => item.vis_span,
+                    _ => return,
+                };
+                // `const` goes right after the visibility, before `unsafe` or `extern`.
+                let (sugg_span, sugg) = if vis_span.is_empty() {
+                    (vis_span, "const ")
+                } else {
+                    (vis_span.shrink_to_hi(), " const")
+                };
+                diag.span_suggestion_verbose(
+                    sugg_span,
+                    "make the function `const`",
+                    sugg,
+                    Applicability::MaybeIncorrect,
+                );
+            });
         }
     }
     extract_msrv_attr!(LateContext);
 }
 
+/// Const-checks the function `def_id` as if it was a `const fn`, unless the lint is allowed for it.
+/// This has to happen before `mir_promoted` steals the MIR the const checker looks at.
+pub fn check_before_promotion(tcx: TyCtxt<'_>, def_id: LocalDefId, dry_runs: &DryRuns) {
+    if tcx
+        .lint_level_at_node(MISSING_CONST_FOR_FN, tcx.local_def_id_to_hir_id(def_id))
+        .0
+        != Level::Allow
+    {
+        dry_runs.check_before_promotion(tcx, def_id);
+    }
+}
+
 /// Returns true if any of the method parameters is a type that implements `Drop`. The method
 /// can't be made const then, because `drop` can't be const-evaluated.
 fn method_accepts_droppable(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
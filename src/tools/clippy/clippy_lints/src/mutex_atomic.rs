@@ -3,16 +3,15 @@
 //! This lint is **allow** by default
 
 use clippy_utils::diagnostics::span_lint;
-use clippy_utils::ty::is_type_diagnostic_item;
-use rustc_hir::Expr;
+use rustc_hir::{BodyOwnerKind, Expr};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty::{self, Ty};
-use rustc_session::declare_lint_pass;
+use rustc_middle::ty::{self, IntTy, Ty, UintTy};
+use rustc_session::impl_lint_pass;
 use rustc_span::sym;
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `Mutex<X>` where an atomic will do.
+    /// Checks for usage of `Mutex<X>` or `RwLock<X>` where an atomic will do.
     ///
     /// ### Why is this bad?
     /// Using a mutex just to make access to a plain bool or
@@ -24,6 +23,8 @@
     /// verify correctness. An atomic does not behave the same as
     /// an equivalent mutex. See [this issue](https://github.com/rust-lang/rust-clippy/issues/4295)'s commentary for more details.
     ///
+    /// The `mutex-atomic-statics-only` configuration restricts the lint to `static`s.
+    ///
     /// ### Known problems
     /// This lint cannot detect if the mutex is actually used
     /// for waiting before a critical section.
@@ -49,13 +50,16 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `Mutex<X>` where `X` is an integral
+    /// Checks for usage of `Mutex<X>` or `RwLock<X>` where `X` is an integral
     /// type.
     ///
     /// ### Why is this bad?
     /// Using a mutex just to make access to a plain integer
     /// sequential is
-    /// shooting flies with cannons. `std::sync::atomic::AtomicUsize` is leaner and faster.
+    /// shooting flies with cannons. `std::sync::atomic::AtomicU32` and the other atomic integers
+    /// are leaner and faster.
+    ///
+    /// The `mutex-atomic-statics-only` configuration restricts the lint to `static`s.
     ///
     /// ### Known problems
     /// This lint cannot detect if the mutex is actually used
@@ -64,13 +68,13 @@
     /// ### Example
     /// ```no_run
     /// # use std::sync::Mutex;
-    /// let x = Mutex::new(0usize);
+    /// let x = Mutex::new(0u32);
     /// ```
     ///
     /// Use instead:
     /// ```no_run
-    /// # use std::sync::atomic::AtomicUsize;
-    /// let x = AtomicUsize::new(0usize);
+    /// # use std::sync::atomic::AtomicU32;
+    /// let x = AtomicU32::new(0u32);
     /// ```
     #[clippy::version = "pre 1.29.0"]
     pub MUTEX_INTEGER,
@@ -78,35 +82,69 @@
     "using a mutex for an integer type"
 }
 
-declare_lint_pass!(Mutex => [MUTEX_ATOMIC, MUTEX_INTEGER]);
+pub struct Mutex {
+    statics_only: bool,
+}
+
+impl Mutex {
+    pub fn new(statics_only: bool) -> Self {
+        Self { statics_only }
+    }
+}
+
+impl_lint_pass!(Mutex => [MUTEX_ATOMIC, MUTEX_INTEGER]);
 
 impl<'tcx> LateLintPass<'tcx> for Mutex {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         let ty = cx.typeck_results().expr_ty(expr);
-        if let ty::Adt(_, subst) = ty.kind() {
-            if is_type_diagnostic_item(cx, ty, sym::Mutex) {
-                let mutex_param = subst.type_at(0);
-                if let Some(atomic_name) = get_atomic_name(mutex_param) {
-                    let msg = format!(
-                        "consider using an `{atomic_name}` instead of a `Mutex` here; if you just want the locking \
-                         behavior and not the internal type, consider using `Mutex<()>`"
-                    );
-                    match *mutex_param.kind() {
-                        ty::Uint(t) if t != ty::UintTy::Usize => span_lint(cx, MUTEX_INTEGER, expr.span, &msg),
-                        ty::Int(t) if t != ty::IntTy::Isize => span_lint(cx, MUTEX_INTEGER, expr.span, &msg),
-                        _ => span_lint(cx, MUTEX_ATOMIC, expr.span, &msg),
-                    };
-                }
-            }
+        if let ty::Adt(adt, subst) = ty.kind()
+            && let Some(lock @ (sym::Mutex | sym::RwLock)) = cx.tcx.get_diagnostic_name(adt.did())
+            && let mutex_param = subst.type_at(0)
+            && let Some(atomic_name) = get_atomic_name(mutex_param)
+            && (!self.statics_only || in_static(cx, expr))
+        {
+            let msg = format!(
+                "consider using an `{atomic_name}` instead of a `{lock}` here; if you just want the locking \
+                 behavior and not the internal type, consider using `{lock}<()>`"
+            );
+            match *mutex_param.kind() {
+                ty::Uint(t) if t != UintTy::Usize => span_lint(cx, MUTEX_INTEGER, expr.span, &msg),
+                ty::Int(t) if t != IntTy::Isize => span_lint(cx, MUTEX_INTEGER, expr.span, &msg),
+                _ => span_lint(cx, MUTEX_ATOMIC, expr.span, &msg),
+            };
         }
     }
 }
 
+fn in_static(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let hir = cx.tcx.hir();
+    matches!(
+        hir.body_owner_kind(hir.enclosing_body_owner(expr.hir_id)),
+        BodyOwnerKind::Static(_)
+    )
+}
+
 fn get_atomic_name(ty: Ty<'_>) -> Option<&'static str> {
     match ty.kind() {
         ty::Bool => Some("AtomicBool"),
-        ty::Uint(_) => Some("AtomicUsize"),
-        ty::Int(_) => Some("AtomicIsize"),
+        ty::Uint(uint_ty) => match uint_ty {
+            UintTy::U8 => Some("AtomicU8"),
+            UintTy::U16 => Some("AtomicU16"),
+            UintTy::U32 => Some("AtomicU32"),
+            UintTy::U64 => Some("AtomicU64"),
+            UintTy::Usize => Some("AtomicUsize"),
+            // `AtomicU128` is unstable.
+            UintTy::U128 => None,
+        },
+        ty::Int(int_ty) => match int_ty {
+            IntTy::I8 => Some("AtomicI8"),
+            IntTy::I16 => Some("AtomicI16"),
+            IntTy::I32 => Some("AtomicI32"),
+            IntTy::I64 => Some("AtomicI64"),
+            IntTy::Isize => Some("AtomicIsize"),
+            // `AtomicI128` is unstable.
+            IntTy::I128 => None,
+        },
         ty::RawPtr(_) => Some("AtomicPtr"),
         _ => None,
     }
@@ -1,24 +1,30 @@
-use clippy_utils::diagnostics::{multispan_sugg, span_lint_and_then};
-use clippy_utils::is_self;
+use clippy_utils::diagnostics::{multispan_sugg_with_applicability, span_lint_and_then};
 use clippy_utils::ptr::get_spans;
 use clippy_utils::source::{snippet, snippet_opt};
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::{
     implements_trait, implements_trait_with_env_from_iter, is_copy, is_type_diagnostic_item, is_type_lang_item,
 };
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{get_parent_expr, is_self, path_to_local_id};
+use core::ops::ControlFlow;
 use rustc_ast::ast::Attribute;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::{Applicability, Diagnostic};
-use rustc_hir::intravisit::FnKind;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::intravisit::{walk_expr, FnKind, Visitor};
 use rustc_hir::{
-    BindingAnnotation, Body, FnDecl, GenericArg, HirId, HirIdSet, Impl, ItemKind, LangItem, Mutability, Node, PatKind,
-    QPath, TyKind,
+    BindingAnnotation, Body, BodyId, Expr, ExprKind, FnDecl, GenericArg, HirId, HirIdSet, Impl, ItemKind, LangItem,
+    Mutability, Node, PatKind, QPath, TyKind,
 };
 use rustc_hir_typeck::expr_use_visitor as euv;
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::nested_filter;
 use rustc_middle::mir::FakeReadCause;
-use rustc_middle::ty::{self, Ty, TypeVisitableExt};
-use rustc_session::declare_lint_pass;
-use rustc_span::def_id::LocalDefId;
+use rustc_middle::ty::{self, Ty, TypeVisitableExt, TypeckResults};
+use rustc_session::impl_lint_pass;
+use rustc_span::def_id::{DefId, LocalDefId};
 use rustc_span::symbol::kw;
 use rustc_span::{sym, Span};
 use rustc_target::spec::abi::Abi;
@@ -36,6 +42,10 @@
     /// sometimes avoid
     /// unnecessary allocations.
     ///
+    /// If the function can't be called from other crates, and is only ever called
+    /// directly, the suggestion also borrows the argument at each of its call sites
+    /// in the crate.
+    ///
     /// ### Known problems
     /// * This lint suggests taking an argument by reference,
     /// however sometimes it is better to let users decide the argument type
@@ -59,7 +69,13 @@
     "functions taking arguments by value, but not consuming them in its body"
 }
 
-declare_lint_pass!(NeedlessPassByValue => [NEEDLESS_PASS_BY_VALUE]);
+#[derive(Default)]
+pub struct NeedlessPassByValue {
+    /// The calls to the functions of this crate, collected the first time they are needed.
+    call_sites: Option<CallSites>,
+}
+
+impl_lint_pass!(NeedlessPassByValue => [NEEDLESS_PASS_BY_VALUE]);
 
 macro_rules! need {
     ($e: expr) => {
@@ -277,9 +293,46 @@ fn check_fn(
                         }
                     }
 
-                    let spans = vec![(input.span, format!("&{}", snippet(cx, input.span, "_")))];
+                    let mut spans = vec![(input.span, format!("&{}", snippet(cx, input.span, "_")))];
+                    let mut app = if only_used_through_autoderef(cx, body.id(), canonical_id) {
+                        Applicability::MachineApplicable
+                    } else {
+                        Applicability::MaybeIncorrect
+                    };
+
+                    let call_sites = match self.borrow_at_call_sites(cx, fn_def_id, idx, &mut app) {
+                        Some(call_sites) if !call_sites.is_empty() => call_sites,
+                        call_sites => {
+                            // If the callers can't be updated, changing the signature breaks them.
+                            let app = if call_sites.is_some() {
+                                app
+                            } else {
+                                Applicability::Unspecified
+                            };
+                            multispan_sugg_with_applicability(diag, "consider taking a reference instead", app, spans);
+                            return;
+                        },
+                    };
+
+                    // The suggestion is only shown for the file the function is in, but is applied to
+                    // all of them.
+                    let sm = cx.sess().source_map();
+                    let other_files: Vec<Span> = call_sites
+                        .iter()
+                        .map(|&(span, _)| span)
+                        .filter(|&span| sm.span_to_filename(span) != sm.span_to_filename(input.span))
+                        .collect();
+                    if !other_files.is_empty() {
+                        diag.span_note(other_files, "the argument is borrowed at these call sites too");
+                    }
 
-                    multispan_sugg(diag, "consider taking a reference instead", spans);
+                    spans.extend(call_sites);
+                    multispan_sugg_with_applicability(
+                        diag,
+                        "consider taking a reference instead, and borrowing the argument at each call site",
+                        app,
+                        spans,
+                    );
                 };
 
                 span_lint_and_then(
@@ -294,6 +347,149 @@ fn check_fn(
     }
 }
 
+impl NeedlessPassByValue {
+    /// Suggestions to borrow the `idx`th argument at each call of `fn_def_id`. Returns `None` if
+    /// the callers can't all be changed: the function may be called from other crates, used other
+    /// than by calling it, such as through a `fn` pointer, or called from a macro expansion.
+    fn borrow_at_call_sites(
+        &mut self,
+        cx: &LateContext<'_>,
+        fn_def_id: LocalDefId,
+        idx: usize,
+        app: &mut Applicability,
+    ) -> Option<Vec<(Span, String)>> {
+        if cx.effective_visibilities.is_exported(fn_def_id) {
+            return None;
+        }
+        let call_sites = self.call_sites.get_or_insert_with(|| CallSites::collect(cx));
+        if call_sites.used_as_value.contains(&fn_def_id) {
+            return None;
+        }
+        let Some(calls) = call_sites.calls.get(&fn_def_id) else {
+            return Some(Vec::new());
+        };
+
+        calls
+            .iter()
+            .map(|&hir_id| {
+                let call = cx.tcx.hir().expect_expr(hir_id);
+                let arg = match call.kind {
+                    ExprKind::Call(_, args) => args.get(idx)?,
+                    // The receiver is the first parameter, but isn't in `args`.
+                    ExprKind::MethodCall(_, _, args, _) => args.get(idx.checked_sub(1)?)?,
+                    _ => return None,
+                };
+                if call.span.from_expansion() || arg.span.ctxt() != call.span.ctxt() {
+                    return None;
+                }
+                let sugg = Sugg::hir_with_applicability(cx, arg, "_", app).addr();
+                Some((arg.span, sugg.to_string()))
+            })
+            .collect()
+    }
+}
+
+/// Whether the body would still compile with the parameter `id` taken by reference, as it is only
+/// used where it's auto-dereferenced: as a method receiver, or the base of a field access or an
+/// indexing expression.
+fn only_used_through_autoderef(cx: &LateContext<'_>, body: BodyId, id: HirId) -> bool {
+    for_each_expr_with_closures(cx, cx.tcx.hir().body(body).value, |e| {
+        if path_to_local_id(e, id) && !is_autoderef_use(cx, e) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_none()
+}
+
+fn is_autoderef_use(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    match get_parent_expr(cx, e).map(|parent| parent.kind) {
+        Some(ExprKind::MethodCall(_, receiver, ..)) => {
+            receiver.hir_id == e.hir_id && !cx.typeck_results().expr_adjustments(e).is_empty()
+        },
+        Some(ExprKind::Field(base, _) | ExprKind::Index(base, ..)) => base.hir_id == e.hir_id,
+        _ => false,
+    }
+}
+
+/// The call graph of the crate, as far as it's needed to update the callers of a function.
+#[derive(Default)]
+struct CallSites {
+    /// The `Call` and `MethodCall` expressions calling each function.
+    calls: FxHashMap<LocalDefId, Vec<HirId>>,
+    /// Functions that are used other than by calling them.
+    used_as_value: FxHashSet<LocalDefId>,
+}
+
+impl CallSites {
+    fn collect(cx: &LateContext<'_>) -> Self {
+        let mut visitor = CallSitesVisitor {
+            cx,
+            maybe_typeck_results: None,
+            call_sites: Self::default(),
+        };
+        cx.tcx.hir().visit_all_item_likes_in_crate(&mut visitor);
+        visitor.call_sites
+    }
+}
+
+struct CallSitesVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    maybe_typeck_results: Option<&'tcx TypeckResults<'tcx>>,
+    call_sites: CallSites,
+}
+
+impl<'tcx> Visitor<'tcx> for CallSitesVisitor<'_, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+
+    fn visit_nested_body(&mut self, body: BodyId) {
+        let old_typeck_results = self.maybe_typeck_results.replace(self.cx.tcx.typeck_body(body));
+        self.visit_body(self.cx.tcx.hir().body(body));
+        self.maybe_typeck_results = old_typeck_results;
+    }
+
+    fn visit_expr(&mut self, e: &'tcx Expr<'tcx>) {
+        if let Some(typeck) = self.maybe_typeck_results {
+            match e.kind {
+                ExprKind::Call(callee, args) if let ExprKind::Path(ref qpath) = callee.kind => {
+                    if let Some(def_id) = local_fn(typeck, qpath, callee.hir_id) {
+                        self.call_sites.calls.entry(def_id).or_default().push(e.hir_id);
+                    }
+                    // Skip the callee, which would otherwise count as a use of the function as a value.
+                    for arg in args {
+                        self.visit_expr(arg);
+                    }
+                    return;
+                },
+                ExprKind::MethodCall(..) => {
+                    if let Some(def_id) = typeck.type_dependent_def_id(e.hir_id).and_then(DefId::as_local) {
+                        self.call_sites.calls.entry(def_id).or_default().push(e.hir_id);
+                    }
+                },
+                ExprKind::Path(ref qpath) => {
+                    if let Some(def_id) = local_fn(typeck, qpath, e.hir_id) {
+                        self.call_sites.used_as_value.insert(def_id);
+                    }
+                },
+                _ => {},
+            }
+        }
+        walk_expr(self, e);
+    }
+}
+
+fn local_fn(typeck: &TypeckResults<'_>, qpath: &QPath<'_>, hir_id: HirId) -> Option<LocalDefId> {
+    match typeck.qpath_res(qpath, hir_id) {
+        Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) => def_id.as_local(),
+        _ => None,
+    }
+}
+
 /// Functions marked with these attributes must have the exact signature.
 pub(crate) fn requires_exact_signature(attrs: &[Attribute]) -> bool {
     attrs.iter().any(|attr| {
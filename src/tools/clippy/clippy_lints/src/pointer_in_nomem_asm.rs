@@ -0,0 +1,100 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_ast::InlineAsmOptions;
+use rustc_hir::{Expr, ExprKind, InlineAsmOperand};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for raw pointers passed to `asm!` blocks marked with `options(nomem)`, and mutable
+    /// raw pointers passed to ones marked with `options(readonly)`.
+    ///
+    /// ### Why is this bad?
+    /// Pointers are usually passed to `asm!` to access the memory they point to. With `nomem`
+    /// the compiler assumes that the block doesn't access memory at all, and with `readonly` that
+    /// it doesn't write to memory, so it may keep values in registers or reorder accesses around
+    /// the block. This is undefined behavior if the block does access memory through the pointer.
+    ///
+    /// ### Known problems
+    /// Blocks which only use the address, without accessing the memory behind it, are fine.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # #[cfg(target_arch = "x86_64")]
+    /// # unsafe { let mut x = 0u64; let p: *mut u64 = &mut x;
+    /// # use std::arch::asm;
+    /// asm!("inc qword ptr [{}]", in(reg) p, options(nomem, nostack));
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # #[cfg(target_arch = "x86_64")]
+    /// # unsafe { let mut x = 0u64; let p: *mut u64 = &mut x;
+    /// # use std::arch::asm;
+    /// asm!("inc qword ptr [{}]", in(reg) p, options(nostack));
+    /// # }
+    /// ```
+    /// Or pass the value itself:
+    /// ```rust,no_run
+    /// # #[cfg(target_arch = "x86_64")]
+    /// # unsafe { let mut x = 0u64;
+    /// # use std::arch::asm;
+    /// asm!("inc {}", inout(reg) x, options(nomem, nostack));
+    /// # }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub POINTER_IN_NOMEM_ASM,
+    suspicious,
+    "raw pointers passed to `asm!` blocks which claim not to access memory"
+}
+
+declare_lint_pass!(PointerInNomemAsm => [POINTER_IN_NOMEM_ASM]);
+
+impl<'tcx> LateLintPass<'tcx> for PointerInNomemAsm {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::InlineAsm(inline_asm) = expr.kind else {
+            return;
+        };
+        let option = if inline_asm.options.contains(InlineAsmOptions::NOMEM) {
+            "nomem"
+        } else if inline_asm.options.contains(InlineAsmOptions::READONLY) {
+            "readonly"
+        } else {
+            return;
+        };
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+
+        for (operand, span) in inline_asm.operands {
+            let input = match *operand {
+                InlineAsmOperand::In { expr, .. } | InlineAsmOperand::InOut { expr, .. } => expr,
+                InlineAsmOperand::SplitInOut { in_expr, .. } => in_expr,
+                _ => continue,
+            };
+            let ty = cx.typeck_results().expr_ty(input);
+            // Reading through a pointer is fine with `readonly`.
+            if !ty.is_unsafe_ptr() || (option == "readonly" && !ty.is_mutable_ptr()) {
+                continue;
+            }
+            span_lint_and_then(
+                cx,
+                POINTER_IN_NOMEM_ASM,
+                *span,
+                &format!("`{ty}` passed to an `asm!` block with `options({option})`"),
+                |diag| {
+                    if option == "nomem" {
+                        diag.note("the compiler assumes the block doesn't access memory");
+                    } else {
+                        diag.note("the compiler assumes the block doesn't write to memory");
+                    }
+                    diag.help(format!(
+                        "remove `{option}` if the block accesses memory through the pointer, \
+                        or pass the value itself instead"
+                    ));
+                },
+            );
+        }
+    }
+}
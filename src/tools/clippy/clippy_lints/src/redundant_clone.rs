@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::{span_lint_hir, span_lint_hir_and_then};
-use clippy_utils::mir::{visit_local_usage, LocalUsage, PossibleBorrowerMap};
+use clippy_utils::mir::{visit_place_usage, LocalUsage, PossibleBorrowerMap};
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::{has_drop, is_copy, is_type_diagnostic_item, is_type_lang_item, walk_ptrs_ty_depth};
 use clippy_utils::{fn_has_unsatisfiable_preds, match_def_path, paths};
@@ -32,7 +32,9 @@ macro_rules! unwrap_or_continue {
     /// allocations and deallocations generated by redundant `clone()`s.
     ///
     /// ### Known problems
-    /// False-negatives: analysis performed by this lint is conservative and limited.
+    /// False-negatives: analysis performed by this lint is conservative. In particular, a clone
+    /// whose source may still be borrowed at that point, or is only reachable through a reference,
+    /// is never linted.
     ///
     /// ### Example
     /// ```no_run
@@ -55,7 +57,7 @@ macro_rules! unwrap_or_continue {
     /// ```
     #[clippy::version = "1.32.0"]
     pub REDUNDANT_CLONE,
-    nursery,
+    perf,
     "`clone()` of an owned value that is going to be dropped immediately"
 }
 
@@ -88,11 +90,6 @@ fn check_fn(
                 continue;
             }
 
-            // Give up on loops
-            if terminator.successors().any(|s| s == bb) {
-                continue;
-            }
-
             let (fn_def_id, arg, arg_ty, clone_ret) =
                 unwrap_or_continue!(is_call_with_ref_arg(cx, mir, &terminator.kind));
 
@@ -123,12 +120,12 @@ fn check_fn(
                 statement_index: bbdata.statements.len(),
             };
 
-            // `Local` to be cloned, and a local of `clone` call's destination
-            let (local, ret_local) = if from_borrow {
+            // `Place` to be cloned, and a local of `clone` call's destination
+            let (place, ret_local) = if from_borrow {
                 // `res = clone(arg)` can be turned into `res = move arg;`
                 // if `arg` is the only borrow of `cloned` at this point.
 
-                if cannot_move_out || !possible_borrower.only_borrowers(&[arg], cloned, loc) {
+                if cannot_move_out || !possible_borrower.only_borrowers(&[arg], cloned.local, loc) {
                     continue;
                 }
 
@@ -146,7 +143,7 @@ fn check_fn(
                 // receiver of the `deref()` call
                 let (pred_arg, deref_clone_ret) = if let Some((pred_fn_def_id, pred_arg, pred_arg_ty, res)) =
                     is_call_with_ref_arg(cx, mir, &pred_terminator.kind)
-                    && res == cloned
+                    && cloned.as_local() == Some(res)
                     && cx.tcx.is_diagnostic_item(sym::deref_method, pred_fn_def_id)
                     && (is_type_diagnostic_item(cx, pred_arg_ty, sym::PathBuf)
                         || is_type_diagnostic_item(cx, pred_arg_ty, sym::OsString))
@@ -156,46 +153,48 @@ fn check_fn(
                     continue;
                 };
 
-                let (local, cannot_move_out) =
+                let (place, cannot_move_out) =
                     unwrap_or_continue!(find_stmt_assigns_to(cx, mir, pred_arg, true, ps[0]));
                 let loc = mir::Location {
                     block: bb,
                     statement_index: mir.basic_blocks[bb].statements.len(),
                 };
 
-                // This can be turned into `res = move local` if `arg` and `cloned` are not borrowed
+                // This can be turned into `res = move place` if `arg` and `cloned` are not borrowed
                 // at the last statement:
                 //
                 // ```
-                // pred_arg = &local;
+                // pred_arg = &place;
                 // cloned = deref(pred_arg);
                 // arg = &cloned;
                 // StorageDead(pred_arg);
                 // res = to_path_buf(cloned);
                 // ```
-                if cannot_move_out || !possible_borrower.only_borrowers(&[arg, cloned], local, loc) {
+                if cannot_move_out || !possible_borrower.only_borrowers(&[arg, cloned.local], place.local, loc) {
                     continue;
                 }
 
-                (local, deref_clone_ret)
+                (place, deref_clone_ret)
             };
 
-            let clone_usage = if local == ret_local {
+            let clone_usage = if place.as_local() == Some(ret_local) {
                 CloneUsage {
                     cloned_used: false,
-                    cloned_consume_or_mutate_loc: None,
+                    cloned_consume_or_mutate_locs: Vec::new(),
                     clone_consumed_or_mutated: true,
                 }
             } else {
-                let clone_usage = visit_clone_usage(local, ret_local, mir, bb);
+                let clone_usage = visit_clone_usage(cx, place, ret_local, mir, bb);
                 if clone_usage.cloned_used && clone_usage.clone_consumed_or_mutated {
                     // cloned value is used, and the clone is modified or moved
                     continue;
-                } else if let Some(loc) = clone_usage.cloned_consume_or_mutate_loc {
+                } else if clone_usage
+                    .cloned_consume_or_mutate_locs
+                    .iter()
+                    .any(|&loc| possible_borrower.local_is_alive_at(ret_local, loc))
+                {
                     // cloned value is mutated, and the clone is alive.
-                    if possible_borrower.local_is_alive_at(ret_local, loc) {
-                        continue;
-                    }
+                    continue;
                 }
                 clone_usage
             };
@@ -272,13 +271,16 @@ fn is_call_with_ref_arg<'tcx>(
 
 /// Finds the first `to = (&)from`, and returns
 /// ``Some((from, whether `from` cannot be moved out))``.
+///
+/// `from` keeps its field projections, so that uses of the other fields of the same local are not
+/// mistaken for uses of the cloned value.
 fn find_stmt_assigns_to<'tcx>(
     cx: &LateContext<'tcx>,
     mir: &mir::Body<'tcx>,
     to_local: mir::Local,
     by_ref: bool,
     bb: mir::BasicBlock,
-) -> Option<(mir::Local, CannotMoveOut)> {
+) -> Option<(mir::Place<'tcx>, CannotMoveOut)> {
     let rvalue = mir.basic_blocks[bb].statements.iter().rev().find_map(|stmt| {
         if let mir::StatementKind::Assign(box (mir::Place { local, .. }, v)) = &stmt.kind {
             return if *local == to_local { Some(v) } else { None };
@@ -289,11 +291,11 @@ fn find_stmt_assigns_to<'tcx>(
 
     match (by_ref, rvalue) {
         (true, mir::Rvalue::Ref(_, _, place)) | (false, mir::Rvalue::Use(mir::Operand::Copy(place))) => {
-            Some(base_local_and_movability(cx, mir, *place))
+            Some((*place, cannot_move_out(cx, mir, *place)))
         },
         (false, mir::Rvalue::Ref(_, _, place)) => {
             if let [mir::ProjectionElem::Deref] = place.as_ref().projection {
-                Some(base_local_and_movability(cx, mir, *place))
+                Some((place.local.into(), cannot_move_out(cx, mir, *place)))
             } else {
                 None
             }
@@ -302,15 +304,8 @@ fn find_stmt_assigns_to<'tcx>(
     }
 }
 
-/// Extracts and returns the undermost base `Local` of given `place`. Returns `place` itself
-/// if it is already a `Local`.
-///
-/// Also reports whether given `place` cannot be moved out.
-fn base_local_and_movability<'tcx>(
-    cx: &LateContext<'tcx>,
-    mir: &mir::Body<'tcx>,
-    place: mir::Place<'tcx>,
-) -> (mir::Local, CannotMoveOut) {
+/// Reports whether given `place` cannot be moved out.
+fn cannot_move_out<'tcx>(cx: &LateContext<'tcx>, mir: &mir::Body<'tcx>, place: mir::Place<'tcx>) -> CannotMoveOut {
     // Dereference. You cannot move things out from a borrowed value.
     let mut deref = false;
     // Accessing a field of an ADT that has `Drop`. Moving the field out will cause E0509.
@@ -326,21 +321,36 @@ fn base_local_and_movability<'tcx>(
         slice |= matches!(elem, mir::ProjectionElem::Index(..)) && !is_copy(cx, base_ty);
     }
 
-    (place.local, deref || field || slice)
+    deref || field || slice
 }
 
 #[derive(Default)]
 struct CloneUsage {
     /// Whether the cloned value is used after the clone.
     cloned_used: bool,
-    /// The first location where the cloned value is consumed or mutated, if any.
-    cloned_consume_or_mutate_loc: Option<mir::Location>,
+    /// The locations where the cloned value is consumed or mutated, if any.
+    cloned_consume_or_mutate_locs: Vec<mir::Location>,
     /// Whether the clone value is mutated.
     clone_consumed_or_mutated: bool,
 }
 
-fn visit_clone_usage(cloned: mir::Local, clone: mir::Local, mir: &mir::Body<'_>, bb: mir::BasicBlock) -> CloneUsage {
-    if let Some((
+fn visit_clone_usage<'tcx>(
+    cx: &LateContext<'tcx>,
+    cloned: mir::Place<'tcx>,
+    clone: mir::Local,
+    mir: &mir::Body<'tcx>,
+    bb: mir::BasicBlock,
+) -> CloneUsage {
+    let mut usage = visit_place_usage(
+        cx.tcx,
+        &[cloned, clone.into()],
+        mir,
+        mir::Location {
+            block: bb,
+            statement_index: mir.basic_blocks[bb].statements.len(),
+        },
+    );
+    let (
         LocalUsage {
             local_use_locs: cloned_use_locs,
             local_consume_or_mutate_locs: cloned_consume_or_mutate_locs,
@@ -349,29 +359,13 @@ fn visit_clone_usage(cloned: mir::Local, clone: mir::Local, mir: &mir::Body<'_>,
             local_use_locs: _,
             local_consume_or_mutate_locs: clone_consume_or_mutate_locs,
         },
-    )) = visit_local_usage(
-        &[cloned, clone],
-        mir,
-        mir::Location {
-            block: bb,
-            statement_index: mir.basic_blocks[bb].statements.len(),
-        },
-    )
-    .map(|mut vec| (vec.remove(0), vec.remove(0)))
-    {
-        CloneUsage {
-            cloned_used: !cloned_use_locs.is_empty(),
-            cloned_consume_or_mutate_loc: cloned_consume_or_mutate_locs.first().copied(),
-            // Consider non-temporary clones consumed.
-            // TODO: Actually check for mutation of non-temporaries.
-            clone_consumed_or_mutated: mir.local_kind(clone) != mir::LocalKind::Temp
-                || !clone_consume_or_mutate_locs.is_empty(),
-        }
-    } else {
-        CloneUsage {
-            cloned_used: true,
-            cloned_consume_or_mutate_loc: None,
-            clone_consumed_or_mutated: true,
-        }
+    ) = (usage.remove(0), usage.remove(0));
+    CloneUsage {
+        cloned_used: !cloned_use_locs.is_empty(),
+        cloned_consume_or_mutate_locs,
+        // Consider non-temporary clones consumed.
+        // TODO: Actually check for mutation of non-temporaries.
+        clone_consumed_or_mutated: mir.local_kind(clone) != mir::LocalKind::Temp
+            || !clone_consume_or_mutate_locs.is_empty(),
     }
 }
@@ -1,18 +1,22 @@
 use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::{is_from_proc_macro, is_in_test_function};
+use clippy_utils::{is_from_proc_macro, is_in_cfg_test, is_in_test_function, trait_ref_of_method};
 use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::intravisit::{walk_expr, FnKind, Visitor};
-use rustc_hir::{Body, Expr, ExprKind, FnDecl};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::nested_filter::OnlyBodies;
 use rustc_middle::lint::in_external_macro;
 use rustc_session::impl_lint_pass;
-use rustc_span::Span;
+use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for functions that are only used once. Does not lint tests.
+    /// Checks for functions that are only used once. Does not lint tests, and doesn't count
+    /// calls from tests unless `single-call-fn-count-test-calls` is enabled.
+    ///
+    /// Functions can also be ignored by their path with `single-call-fn-ignore-patterns`, or
+    /// for being `#[inline]` or methods of traits and their implementations.
     ///
     /// ### Why is this bad?
     /// It's usually not, splitting a function into multiple parts often improves readability and in
@@ -57,9 +61,48 @@
 #[derive(Clone)]
 pub struct SingleCallFn {
     pub avoid_breaking_exported_api: bool,
+    pub ignore_patterns: Vec<String>,
+    pub ignore_inline: bool,
+    pub ignore_trait_methods: bool,
+    pub count_test_calls: bool,
     pub def_id_to_usage: FxHashMap<LocalDefId, (Span, Vec<Span>)>,
 }
 
+impl SingleCallFn {
+    fn is_ignored(&self, cx: &LateContext<'_>, kind: FnKind<'_>, def_id: LocalDefId) -> bool {
+        (self.ignore_inline && cx.tcx.has_attr(def_id, sym::inline))
+            || (self.ignore_trait_methods
+                && matches!(kind, FnKind::Method(..))
+                && (cx.tcx.trait_of_item(def_id.to_def_id()).is_some() || trait_ref_of_method(cx, def_id).is_some()))
+            || (!self.ignore_patterns.is_empty() && {
+                let path = cx.tcx.def_path_str(def_id);
+                self.ignore_patterns
+                    .iter()
+                    .any(|pattern| matches_pattern(pattern, &path))
+            })
+    }
+}
+
+/// Whether `path` matches `pattern`, where `*` matches any sequence of characters.
+fn matches_pattern(pattern: &str, path: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = path.strip_prefix(first) else {
+        return false;
+    };
+    let Some(last) = parts.next_back() else {
+        // No `*` in the pattern.
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
 impl<'tcx> LateLintPass<'tcx> for SingleCallFn {
     fn check_fn(
         &mut self,
@@ -74,6 +117,7 @@ fn check_fn(
             || in_external_macro(cx.sess(), span)
             || is_from_proc_macro(cx, &(&kind, body, cx.tcx.local_def_id_to_hir_id(def_id), span))
             || is_in_test_function(cx.tcx, body.value.hir_id)
+            || self.is_ignored(cx, kind, def_id)
         {
             return;
         }
@@ -84,6 +128,7 @@ fn check_fn(
     fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
         let mut v = FnUsageVisitor {
             cx,
+            count_test_calls: self.count_test_calls,
             def_id_to_usage: &mut self.def_id_to_usage,
         };
         cx.tcx.hir().visit_all_item_likes_in_crate(&mut v);
@@ -106,6 +151,7 @@ fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
 
 struct FnUsageVisitor<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
+    count_test_calls: bool,
     def_id_to_usage: &'a mut FxHashMap<LocalDefId, (Span, Vec<Span>)>,
 }
 
@@ -124,6 +170,7 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
             && let Some(call_def_id) = res.opt_def_id()
             && let Some(def_id) = call_def_id.as_local()
             && let Some(usage) = self.def_id_to_usage.get_mut(&def_id)
+            && (self.count_test_calls || !is_in_test(cx, expr.hir_id))
         {
             usage.1.push(expr.span);
         }
@@ -131,3 +178,7 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
         walk_expr(self, expr);
     }
 }
+
+fn is_in_test(cx: &LateContext<'_>, id: HirId) -> bool {
+    is_in_test_function(cx.tcx, id) || is_in_cfg_test(cx.tcx, id)
+}
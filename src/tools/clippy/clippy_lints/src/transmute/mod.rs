@@ -11,7 +11,9 @@
 mod transmute_ref_to_ref;
 mod transmute_undefined_repr;
 mod transmutes_expressible_as_ptr_casts;
+mod transmuting_dyn_to_parts;
 mod transmuting_null;
+mod tuple_array_transmute;
 mod unsound_collection_transmute;
 mod useless_transmute;
 mod utils;
@@ -463,6 +465,58 @@
     "transmute results in a null function pointer, which is undefined behavior"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for transmutes of wide pointers, such as `&dyn Trait` or `*const [T]`, into a pair of
+    /// their parts, e.g. `transmute::<&dyn Trait, (*const (), *const ())>`.
+    ///
+    /// ### Why is this bad?
+    /// The layout of wide pointers is not guaranteed, so neither the order of the parts nor their
+    /// types can be relied on.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let value: &dyn std::fmt::Debug = &0u32;
+    /// let (data, vtable): (*const (), *const ()) = unsafe { std::mem::transmute(value) };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #![feature(ptr_metadata)]
+    /// let value: &dyn std::fmt::Debug = &0u32;
+    /// let (data, vtable) = (value as *const dyn std::fmt::Debug).to_raw_parts();
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub TRANSMUTING_DYN_TO_PARTS,
+    suspicious,
+    "transmutes from a wide pointer into a pair of its data pointer and metadata"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for transmutes between tuples and arrays with the same element types, like
+    /// `(u32, u32)` and `[u32; 2]`, including through pointer casts.
+    ///
+    /// ### Why is this bad?
+    /// Unlike arrays, tuples have an unspecified layout, even if all their fields have the same
+    /// type. Tuples and arrays of up to 12 elements can be converted with `From` and `Into`
+    /// instead.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let tuple = (1u32, 2u32);
+    /// let array: [u32; 2] = unsafe { std::mem::transmute(tuple) };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let tuple = (1u32, 2u32);
+    /// let array: [u32; 2] = <[_; 2]>::from(tuple);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub TUPLE_ARRAY_TRANSMUTE,
+    suspicious,
+    "transmutes between tuples and arrays, which rely on the unspecified layout of tuples"
+}
+
 pub struct Transmute {
     msrv: Msrv,
 }
@@ -484,6 +538,8 @@ pub struct Transmute {
     TRANSMUTE_UNDEFINED_REPR,
     TRANSMUTING_NULL,
     TRANSMUTE_NULL_TO_FN,
+    TRANSMUTING_DYN_TO_PARTS,
+    TUPLE_ARRAY_TRANSMUTE,
 ]);
 impl Transmute {
     #[must_use]
@@ -520,6 +576,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
                 | crosspointer_transmute::check(cx, e, from_ty, to_ty)
                 | transmuting_null::check(cx, e, arg, to_ty)
                 | transmute_null_to_fn::check(cx, e, arg, to_ty)
+                | transmuting_dyn_to_parts::check(cx, e, from_ty, to_ty)
                 | transmute_ptr_to_ref::check(cx, e, from_ty, to_ty, arg, path, &self.msrv)
                 | transmute_int_to_char::check(cx, e, from_ty, to_ty, arg, const_context)
                 | transmute_ref_to_ref::check(cx, e, from_ty, to_ty, arg, const_context)
@@ -530,11 +587,14 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
                 | transmute_float_to_int::check(cx, e, from_ty, to_ty, arg, const_context)
                 | transmute_num_to_bytes::check(cx, e, from_ty, to_ty, arg, const_context)
                 | (unsound_collection_transmute::check(cx, e, from_ty, to_ty)
+                    || tuple_array_transmute::check(cx, e, from_ty, to_ty, arg, &self.msrv)
                     || transmute_undefined_repr::check(cx, e, from_ty, to_ty));
 
             if !linted {
                 transmutes_expressible_as_ptr_casts::check(cx, e, from_ty, from_ty_adjusted, to_ty, arg);
             }
+        } else {
+            tuple_array_transmute::check_cast(cx, e);
         }
     }
 
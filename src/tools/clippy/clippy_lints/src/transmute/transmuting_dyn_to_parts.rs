@@ -0,0 +1,43 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+
+use super::TRANSMUTING_DYN_TO_PARTS;
+
+/// Checks for `transmuting_dyn_to_parts` lint.
+/// Returns `true` if it's triggered, otherwise returns `false`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, from_ty: Ty<'tcx>, to_ty: Ty<'tcx>) -> bool {
+    let pointee = match from_ty.kind() {
+        ty::Ref(_, pointee, _) => *pointee,
+        ty::RawPtr(from_ptr) => from_ptr.ty,
+        _ => return false,
+    };
+    let is_pair = match to_ty.kind() {
+        ty::Tuple(tys) => tys.len() == 2,
+        ty::Array(_, len) => len.try_eval_target_usize(cx.tcx, cx.param_env) == Some(2),
+        _ => false,
+    };
+    if !is_pair {
+        return false;
+    }
+
+    let help = match pointee.kind() {
+        ty::Dynamic(..) => {
+            "use `std::ptr::metadata` or `<*const T>::to_raw_parts` to get the data pointer and vtable instead"
+        },
+        ty::Slice(_) | ty::Str => "use `.as_ptr()` and `.len()` to get the data pointer and length instead",
+        _ => return false,
+    };
+    span_lint_and_then(
+        cx,
+        TRANSMUTING_DYN_TO_PARTS,
+        e.span,
+        &format!("transmute from a wide pointer `{from_ty}` into its parts"),
+        |diag| {
+            diag.note("the layout of wide pointers is not guaranteed");
+            diag.help(help);
+        },
+    );
+    true
+}
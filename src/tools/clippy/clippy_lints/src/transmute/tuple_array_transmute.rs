@@ -0,0 +1,109 @@
+use super::TUPLE_ARRAY_TRANSMUTE;
+use clippy_config::msrvs::{self, Msrv};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::get_enclosing_unsafe_block;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::visitors::is_expr_unsafe;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+
+const LAYOUT_NOTE: &str = "the layout of tuples is unspecified, even if all their fields have the same type";
+
+/// Checks for `tuple_array_transmute` lint.
+/// Returns `true` if it's triggered, otherwise returns `false`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'_>,
+    from_ty: Ty<'tcx>,
+    to_ty: Ty<'tcx>,
+    arg: &'tcx Expr<'_>,
+    msrv: &Msrv,
+) -> bool {
+    if let Some((from_tuple, len)) = tuple_array_len(cx, from_ty, to_ty) {
+        // The conversion doesn't need the `unsafe` block around the transmute, unless the argument
+        // does.
+        let span = if is_expr_unsafe(cx, arg) {
+            e.span
+        } else {
+            get_enclosing_unsafe_block(cx, e).unwrap_or(e.span)
+        };
+        span_lint_and_then(
+            cx,
+            TUPLE_ARRAY_TRANSMUTE,
+            span,
+            &format!("transmute from `{from_ty}` to `{to_ty}`"),
+            |diag| {
+                diag.note(LAYOUT_NOTE);
+                // `From` is only implemented up to 12 elements.
+                if len <= 12 && msrv.meets(msrvs::TUPLE_ARRAY_CONVERSIONS) {
+                    let mut app = Applicability::MachineApplicable;
+                    let arg = Sugg::hir_with_context(cx, arg, e.span.ctxt(), "..", &mut app);
+                    let to = if from_tuple {
+                        format!("[_; {len}]")
+                    } else if len == 1 {
+                        "(_,)".to_owned()
+                    } else {
+                        format!("({})", vec!["_"; len].join(", "))
+                    };
+                    diag.span_suggestion(span, "use `From` instead", format!("<{to}>::from({arg})"), app);
+                }
+            },
+        );
+        return true;
+    }
+    match (from_ty.kind(), to_ty.kind()) {
+        (ty::RawPtr(from), ty::RawPtr(to)) => check_pointees(cx, e, from.ty, to.ty),
+        (ty::Ref(_, from, _), ty::Ref(_, to, _)) => check_pointees(cx, e, *from, *to),
+        _ => false,
+    }
+}
+
+/// Checks for `tuple_array_transmute` on pointer casts, like `p as *const [T; N]` or
+/// `p.cast::<[T; N]>()`.
+pub(super) fn check_cast<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
+    let from = match e.kind {
+        ExprKind::Cast(from, _) => from,
+        ExprKind::MethodCall(path, recv, [], _) if path.ident.as_str() == "cast" => recv,
+        _ => return,
+    };
+    if let ty::RawPtr(from_ptr) = cx.typeck_results().expr_ty(from).kind()
+        && let ty::RawPtr(to_ptr) = cx.typeck_results().expr_ty(e).kind()
+        && !e.span.from_expansion()
+    {
+        check_pointees(cx, e, from_ptr.ty, to_ptr.ty);
+    }
+}
+
+fn check_pointees<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, from_ty: Ty<'tcx>, to_ty: Ty<'tcx>) -> bool {
+    if tuple_array_len(cx, from_ty, to_ty).is_some() {
+        span_lint_and_then(
+            cx,
+            TUPLE_ARRAY_TRANSMUTE,
+            e.span,
+            &format!("reinterpreting a `{from_ty}` as a `{to_ty}` through a pointer"),
+            |diag| {
+                diag.note(LAYOUT_NOTE);
+                diag.help("convert the value with `From` or `Into` instead");
+            },
+        );
+        true
+    } else {
+        false
+    }
+}
+
+/// If one of the types is a tuple and the other an array of the same length with the same type
+/// for all elements, returns whether the tuple is `from_ty`, and the length.
+fn tuple_array_len<'tcx>(cx: &LateContext<'tcx>, from_ty: Ty<'tcx>, to_ty: Ty<'tcx>) -> Option<(bool, usize)> {
+    let (fields, elem_ty, len, from_tuple) = match (from_ty.kind(), to_ty.kind()) {
+        (ty::Tuple(fields), ty::Array(elem_ty, len)) => (fields, elem_ty, len, true),
+        (ty::Array(elem_ty, len), ty::Tuple(fields)) => (fields, elem_ty, len, false),
+        _ => return None,
+    };
+    (!fields.is_empty()
+        && len.try_eval_target_usize(cx.tcx, cx.param_env) == Some(fields.len() as u64)
+        && fields.iter().all(|field| field == *elem_ty))
+    .then_some((from_tuple, fields.len()))
+}
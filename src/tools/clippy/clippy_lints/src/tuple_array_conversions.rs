@@ -1,14 +1,22 @@
 use clippy_config::msrvs::{self, Msrv};
-use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::visitors::for_each_local_use_after_expr;
-use clippy_utils::{is_from_proc_macro, path_to_local};
+use clippy_utils::check_proc_macro::ProcMacroCache;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::visitors::{for_each_local_use_after_expr, local_used_once};
+use clippy_utils::{fn_def_id, get_parent_expr, get_parent_node, path_to_local};
 use itertools::Itertools;
 use rustc_ast::LitKind;
-use rustc_hir::{Expr, ExprKind, Node, PatKind};
+use rustc_errors::{Applicability, Diagnostic};
+use rustc_hir::intravisit::{walk_body, Visitor};
+use rustc_hir::{BindingAnnotation, Body, Expr, ExprKind, HirId, Node, PatKind, QPath};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::hir::nested_filter;
 use rustc_middle::lint::in_external_macro;
-use rustc_middle::ty::{self, Ty};
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::{self, Ty, TypeVisitableExt};
 use rustc_session::impl_lint_pass;
+use rustc_span::symbol::Ident;
+use rustc_span::{Span, Symbol};
 use std::iter::once;
 use std::ops::ControlFlow;
 
@@ -35,6 +43,12 @@
     /// let t1 = &[(1, 2), (3, 4)];
     /// let v1: Vec<[u32; 2]> = t1.iter().map(|&t| t.into()).collect();
     /// ```
+    ///
+    /// This includes tuples and arrays built only to be passed to a function taking the other
+    /// kind, e.g. `takes_array([t.0, t.1])`, which can be `takes_array(t.into())`.
+    ///
+    /// Where the target type cannot be inferred from the context, the lint suggests
+    /// `<[_; N]>::from(t)` or `<(_, ..., _)>::from(a)` instead.
     #[clippy::version = "1.72.0"]
     pub TUPLE_ARRAY_CONVERSIONS,
     nursery,
@@ -44,7 +58,25 @@
 
 #[derive(Clone)]
 pub struct TupleArrayConversions {
-    pub msrv: Msrv,
+    msrv: Msrv,
+    min_size: u64,
+    ignore_heterogeneous_names: bool,
+    proc_macro_cache: ProcMacroCache,
+}
+
+impl TupleArrayConversions {
+    pub fn new(msrv: Msrv, min_size: u64, ignore_heterogeneous_names: bool) -> Self {
+        Self {
+            msrv,
+            min_size,
+            ignore_heterogeneous_names,
+            proc_macro_cache: ProcMacroCache::default(),
+        }
+    }
+
+    fn is_checked_len(&self, len: usize) -> bool {
+        (self.min_size.max(1)..=12).contains(&(len as u64))
+    }
 }
 
 impl LateLintPass<'_> for TupleArrayConversions {
@@ -54,8 +86,24 @@ fn check_expr<'tcx>(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
         }
 
         match expr.kind {
-            ExprKind::Array(elements) if (1..=12).contains(&elements.len()) => check_array(cx, expr, elements),
-            ExprKind::Tup(elements) if (1..=12).contains(&elements.len()) => check_tuple(cx, expr, elements),
+            ExprKind::Array(elements) if self.is_checked_len(elements.len()) => {
+                check_array(
+                    cx,
+                    expr,
+                    elements,
+                    self.ignore_heterogeneous_names,
+                    &mut self.proc_macro_cache,
+                );
+            },
+            ExprKind::Tup(elements) if self.is_checked_len(elements.len()) => {
+                check_tuple(
+                    cx,
+                    expr,
+                    elements,
+                    self.ignore_heterogeneous_names,
+                    &mut self.proc_macro_cache,
+                );
+            },
             _ => {},
         }
     }
@@ -63,75 +111,330 @@ fn check_expr<'tcx>(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
     extract_msrv_attr!(LateContext);
 }
 
-fn check_array<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, elements: &'tcx [Expr<'tcx>]) {
+fn check_array<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    elements: &'tcx [Expr<'tcx>],
+    ignore_heterogeneous_names: bool,
+    proc_macro_cache: &mut ProcMacroCache,
+) {
     let (ty::Array(ty, _) | ty::Slice(ty)) = cx.typeck_results().expr_ty(expr).kind() else {
         unreachable!("`expr` must be an array or slice due to `ExprKind::Array`");
     };
 
     if let [first, ..] = elements
-        && let Some(locals) = (match first.kind {
+        && let Some((locals, source)) = (match first.kind {
             ExprKind::Field(_, _) => elements
                 .iter()
                 .enumerate()
-                .map(|(i, f)| -> Option<&'tcx Expr<'tcx>> {
-                    let ExprKind::Field(lhs, ident) = f.kind else {
-                        return None;
-                    };
-                    (ident.name.as_str() == i.to_string()).then_some(lhs)
-                })
-                .collect::<Option<Vec<_>>>(),
-            ExprKind::Path(_) => Some(elements.iter().collect()),
+                .map(|(i, f)| field_projection(f, i))
+                .collect::<Option<Vec<_>>>()
+                .map(|locals| (locals, Source::Projection)),
+            ExprKind::Path(_) => Some(binding_source(cx, elements, field_projection)),
             _ => None,
         })
         && all_bindings_are_for_conv(cx, &[*ty], expr, elements, &locals, ToType::Array)
-        && !is_from_proc_macro(cx, expr)
+        && !(ignore_heterogeneous_names && source.is_named() && has_heterogeneous_names(elements))
+        && !proc_macro_cache.is_from_proc_macro(cx, expr)
     {
-        span_lint_and_help(
-            cx,
-            TUPLE_ARRAY_CONVERSIONS,
-            expr.span,
-            "it looks like you're trying to convert a tuple to an array",
-            None,
-            "use `.into()` instead, or `<[T; N]>::from` if type annotations are needed",
-        );
+        emit_lint(cx, expr, &locals, source, ToType::Array);
     }
 }
 
-fn check_tuple<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, elements: &'tcx [Expr<'tcx>]) {
+fn check_tuple<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    elements: &'tcx [Expr<'tcx>],
+    ignore_heterogeneous_names: bool,
+    proc_macro_cache: &mut ProcMacroCache,
+) {
     if let ty::Tuple(tys) = cx.typeck_results().expr_ty(expr).kind()
         && let [first, ..] = elements
         // Fix #11100
         && tys.iter().all_equal()
-        && let Some(locals) = (match first.kind {
+        && let Some((locals, source)) = (match first.kind {
             ExprKind::Index(..) => elements
                 .iter()
                 .enumerate()
-                .map(|(i, i_expr)| -> Option<&'tcx Expr<'tcx>> {
-                    if let ExprKind::Index(lhs, index, _) = i_expr.kind
-                        && let ExprKind::Lit(lit) = index.kind
-                        && let LitKind::Int(val, _) = lit.node
-                    {
-                        return (val == i as u128).then_some(lhs);
-                    };
-
-                    None
-                })
-                .collect::<Option<Vec<_>>>(),
-            ExprKind::Path(_) => Some(elements.iter().collect()),
+                .map(|(i, i_expr)| index_projection(i_expr, i))
+                .collect::<Option<Vec<_>>>()
+                .map(|locals| (locals, Source::Projection)),
+            ExprKind::Path(_) => Some(binding_source(cx, elements, index_projection)),
             _ => None,
         })
         && all_bindings_are_for_conv(cx, tys, expr, elements, &locals, ToType::Tuple)
-        && !is_from_proc_macro(cx, expr)
+        && !(ignore_heterogeneous_names && source.is_named() && has_heterogeneous_names(elements))
+        && !proc_macro_cache.is_from_proc_macro(cx, expr)
     {
-        span_lint_and_help(
-            cx,
-            TUPLE_ARRAY_CONVERSIONS,
-            expr.span,
+        emit_lint(cx, expr, &locals, source, ToType::Tuple);
+    }
+}
+
+/// `t.i`, returning `t`
+pub(crate) fn field_projection<'tcx>(expr: &'tcx Expr<'tcx>, i: usize) -> Option<&'tcx Expr<'tcx>> {
+    let ExprKind::Field(lhs, ident) = expr.kind else {
+        return None;
+    };
+    (ident.name.as_str() == i.to_string()).then_some(lhs)
+}
+
+/// `a[i]`, returning `a`
+pub(crate) fn index_projection<'tcx>(expr: &'tcx Expr<'tcx>, i: usize) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Index(lhs, index, _) = expr.kind
+        && let ExprKind::Lit(lit) = index.kind
+        && let LitKind::Int(val, _) = lit.node
+    {
+        return (val == i as u128).then_some(lhs);
+    };
+
+    None
+}
+
+/// Works out where the bindings in `[a, b]` or `(a, b)` come from. If each one was initialized
+/// from the matching projection of the same value, e.g. `let a = t.0; let b = t.1;`, and isn't
+/// used anywhere else, the conversion is really of that value.
+fn binding_source<'tcx>(
+    cx: &LateContext<'tcx>,
+    elements: &'tcx [Expr<'tcx>],
+    projection: fn(&'tcx Expr<'tcx>, usize) -> Option<&'tcx Expr<'tcx>>,
+) -> (Vec<&'tcx Expr<'tcx>>, Source) {
+    let rebound = elements
+        .iter()
+        .enumerate()
+        .map(|(i, e)| {
+            let id = path_to_local(e)?;
+            if let Some(Node::Local(local)) = get_parent_node(cx.tcx, id)
+                && local.pat.hir_id == id
+                && let PatKind::Binding(BindingAnnotation::NONE, _, _, None) = local.pat.kind
+                && local.els.is_none()
+                && !local.span.from_expansion()
+                && let Some(init) = local.init
+                && local_used_once(cx, enclosing_body(cx, id).value, id).is_some()
+            {
+                projection(init, i)
+            } else {
+                None
+            }
+        })
+        .collect::<Option<Vec<_>>>();
+
+    match rebound {
+        Some(locals) => (locals, Source::Rebindings),
+        None => (elements.iter().collect(), Source::Bindings),
+    }
+}
+
+fn emit_lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    locals: &[&'tcx Expr<'tcx>],
+    source: Source,
+    kind: ToType,
+) {
+    let (msg, from_path) = match kind {
+        ToType::Array => (
+            "it looks like you're trying to convert a tuple to an array",
+            "<[T; N]>::from",
+        ),
+        ToType::Tuple => (
             "it looks like you're trying to convert an array to a tuple",
-            None,
-            "use `.into()` instead, or `<(T0, T1, ..., Tn)>::from` if type annotations are needed",
-        );
+            "<(T0, T1, ..., Tn)>::from",
+        ),
+    };
+
+    span_lint_and_then(cx, TUPLE_ARRAY_CONVERSIONS, expr.span, msg, |diag| {
+        let mut app = Applicability::MachineApplicable;
+        let Some((recv, mut edits)) = (match source {
+            Source::Projection => projection_receiver(cx, expr, locals, &mut app).map(|recv| (recv, vec![])),
+            Source::Bindings => bindings_receiver(cx, locals, kind.binding_name(), &mut app),
+            // The `let`s would have to be removed as well, leave that to the user.
+            Source::Rebindings => None,
+        }) else {
+            diag.help(format!(
+                "use `.into()` instead, or `{from_path}` if type annotations are needed"
+            ));
+            return;
+        };
+
+        if is_conversion_target_known(cx, expr, kind) {
+            // That `.into()` knows what to convert to is only guessed from where the expression
+            // is, e.g. in the body of a closure passed to a function.
+            app = Applicability::MaybeIncorrect;
+            edits.push((expr.span, format!("{}.into()", recv.maybe_par())));
+            emit_suggestion(diag, "use `.into()` instead", edits, app);
+        } else {
+            let placeholders = match kind {
+                ToType::Array => format!("[_; {}]", locals.len()),
+                ToType::Tuple if locals.len() == 1 => "(_,)".to_owned(),
+                ToType::Tuple => format!("({})", vec!["_"; locals.len()].join(", ")),
+            };
+            edits.push((expr.span, format!("<{placeholders}>::from({recv})")));
+            emit_suggestion(diag, &format!("use `{from_path}` instead"), edits, app);
+        }
+    });
+}
+
+pub(crate) fn emit_suggestion(diag: &mut Diagnostic, msg: &str, mut edits: Vec<(Span, String)>, app: Applicability) {
+    if let [(span, sugg)] = &mut edits[..] {
+        diag.span_suggestion(*span, msg.to_owned(), std::mem::take(sugg), app);
+    } else {
+        diag.multipart_suggestion(msg.to_owned(), edits, app);
+    }
+}
+
+/// Builds the value to convert for `[t.0, t.1]` or `(a[0], a[1])`, i.e. `t` or `a` with any
+/// auto-deref made explicit.
+fn projection_receiver<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    locals: &[&'tcx Expr<'tcx>],
+    app: &mut Applicability,
+) -> Option<Sugg<'tcx>> {
+    let [first, rest @ ..] = locals else { return None };
+    let local = path_to_local(first)?;
+    if !rest.iter().all(|e| path_to_local(e) == Some(local)) {
+        return None;
+    }
+
+    let mut recv = Sugg::hir_with_context(cx, first, expr.span.ctxt(), "..", app);
+    for adjustment in cx.typeck_results().expr_adjustments(first) {
+        if let Adjust::Deref(_) = adjustment.kind {
+            recv = recv.deref();
+        }
     }
+    Some(recv)
+}
+
+/// Builds the value to convert for `[a, b]` where `a` and `b` were bound by the same tuple or
+/// slice pattern. The pattern is replaced by a single binding called `name`, which is only possible
+/// if it binds exactly the converted elements, in order, and nothing else uses them.
+pub(crate) fn bindings_receiver<'tcx>(
+    cx: &LateContext<'tcx>,
+    locals: &[&'tcx Expr<'tcx>],
+    name: &'static str,
+    app: &mut Applicability,
+) -> Option<(Sugg<'tcx>, Vec<(Span, String)>)> {
+    let ids = locals.iter().map(|e| path_to_local(e)).collect::<Option<Vec<_>>>()?;
+    let Some(Node::Pat(pat)) = get_parent_node(cx.tcx, *ids.first()?) else {
+        return None;
+    };
+    let sub_pats = match pat.kind {
+        PatKind::Tuple(pats, dotdot) if dotdot.as_opt_usize().is_none() => pats,
+        PatKind::Slice(pats, None, []) => pats,
+        _ => return None,
+    };
+    if pat.span.from_expansion()
+        || sub_pats.len() != ids.len()
+        || !sub_pats.iter().zip(&ids).all(|(sub_pat, &id)| {
+            matches!(sub_pat.kind, PatKind::Binding(BindingAnnotation::NONE, binding, _, None) if binding == id)
+        })
+    {
+        return None;
+    }
+
+    let body = enclosing_body(cx, pat.hir_id);
+    if !ids.iter().all(|&id| local_used_once(cx, body.value, id).is_some()) {
+        return None;
+    }
+
+    if is_name_used(cx, body, Symbol::intern(name)) {
+        // The new binding could shadow, or be shadowed by, something else.
+        *app = Applicability::MaybeIncorrect;
+    }
+    Some((Sugg::NonParen(name.into()), vec![(pat.span, name.to_owned())]))
+}
+
+/// Whether an `.into()` call in place of `expr` would know what to convert to. Anything not
+/// covered here gets the fully qualified `From` call instead.
+pub(crate) fn is_conversion_target_known(cx: &LateContext<'_>, expr: &Expr<'_>, kind: ToType) -> bool {
+    match get_parent_node(cx.tcx, expr.hir_id) {
+        Some(Node::Local(local)) => local.ty.is_some(),
+        Some(Node::Expr(
+            call @ Expr {
+                kind: ExprKind::Call(..) | ExprKind::MethodCall(..),
+                ..
+            },
+        )) => is_concrete_call_arg(cx, call, expr, kind),
+        // The body of a closure passed to a function, e.g. `iter.map(|&(a, b)| [a, b]).collect()`
+        Some(Node::Expr(
+            closure @ Expr {
+                kind: ExprKind::Closure(_),
+                ..
+            },
+        )) => get_parent_expr(cx, closure)
+            .is_some_and(|e| matches!(e.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))),
+        _ => false,
+    }
+}
+
+/// Whether `arg` is passed to `call` for a parameter declared as a concrete array or tuple type
+/// (matching `kind`), e.g. `fn f(a: [u8; 2])`. A generic parameter, even `[T; 2]`, wouldn't
+/// constrain what `.into()` converts to.
+fn is_concrete_call_arg(cx: &LateContext<'_>, call: &Expr<'_>, arg: &Expr<'_>, kind: ToType) -> bool {
+    let (args, offset) = match call.kind {
+        ExprKind::Call(_, args) => (args, 0),
+        ExprKind::MethodCall(_, _, args, _) => (args, 1),
+        _ => return false,
+    };
+    let Some(i) = args.iter().position(|a| a.hir_id == arg.hir_id) else {
+        return false;
+    };
+    let Some(def_id) = fn_def_id(cx, call) else {
+        return false;
+    };
+
+    // The declared signature, not the one instantiated at this call, so generic parameters show up.
+    let sig = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder();
+    sig.inputs().get(i + offset).is_some_and(|ty| {
+        !ty.has_non_region_param()
+            && matches!(
+                (kind, ty.kind()),
+                (ToType::Array, ty::Array(..)) | (ToType::Tuple, ty::Tuple(..))
+            )
+    })
+}
+
+fn enclosing_body<'tcx>(cx: &LateContext<'tcx>, id: HirId) -> &'tcx Body<'tcx> {
+    let hir = cx.tcx.hir();
+    hir.body(hir.body_owned_by(hir.enclosing_body_owner(id)))
+}
+
+fn is_name_used<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>, name: Symbol) -> bool {
+    struct V<'a, 'tcx> {
+        cx: &'a LateContext<'tcx>,
+        name: Symbol,
+        found: bool,
+    }
+    impl<'tcx> Visitor<'tcx> for V<'_, 'tcx> {
+        type NestedFilter = nested_filter::OnlyBodies;
+        fn nested_visit_map(&mut self) -> Self::Map {
+            self.cx.tcx.hir()
+        }
+
+        fn visit_ident(&mut self, ident: Ident) {
+            self.found |= ident.name == self.name;
+        }
+    }
+
+    let mut v = V { cx, name, found: false };
+    walk_body(&mut v, body);
+    v.found
+}
+
+/// Whether the bindings in `elements` are named differently enough to suggest each one means
+/// something on its own, e.g. `(x, y)`, rather than being indices into the same collection, e.g.
+/// `(a0, a1)`.
+fn has_heterogeneous_names(elements: &[Expr<'_>]) -> bool {
+    elements
+        .iter()
+        .filter_map(|e| match e.kind {
+            ExprKind::Path(QPath::Resolved(None, path)) => path.segments.last(),
+            _ => None,
+        })
+        .map(|segment| segment.ident.as_str())
+        .map(|name| name.trim_end_matches(|c: char| c.is_ascii_digit() || c == '_'))
+        .tuple_windows()
+        .any(|(a, b)| a != b)
 }
 
 /// Checks that every binding in `elements` comes from the same parent `Pat` with the kind if there
@@ -197,12 +500,40 @@ fn all_bindings_are_for_conv<'tcx>(
         })
 }
 
+/// Where the converted elements come from.
+#[derive(Clone, Copy)]
+enum Source {
+    /// `[t.0, t.1]` or `(a[0], a[1])`
+    Projection,
+    /// `[a, b]` or `(a, b)`, bound by a tuple or slice pattern
+    Bindings,
+    /// `[a, b]` or `(a, b)`, bound by `let a = t.0;` or `let a = x[0];`
+    Rebindings,
+}
+
+impl Source {
+    /// Whether the elements are named bindings.
+    fn is_named(self) -> bool {
+        matches!(self, Self::Bindings | Self::Rebindings)
+    }
+}
+
 #[derive(Clone, Copy)]
-enum ToType {
+pub(crate) enum ToType {
     Array,
     Tuple,
 }
 
+impl ToType {
+    /// The name to bind the whole value being converted to, when replacing a pattern.
+    fn binding_name(self) -> &'static str {
+        match self {
+            ToType::Array => "t",
+            ToType::Tuple => "arr",
+        }
+    }
+}
+
 impl PartialEq<PatKind<'_>> for ToType {
     fn eq(&self, other: &PatKind<'_>) -> bool {
         match self {
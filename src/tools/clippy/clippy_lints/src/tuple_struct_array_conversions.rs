@@ -0,0 +1,159 @@
+use crate::tuple_array_conversions::{field_projection, is_conversion_target_known, ToType};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{implements_trait, is_copy};
+use clippy_utils::{is_from_proc_macro, path_to_local, trait_ref_of_method};
+use rustc_errors::Applicability;
+use rustc_hir::def::CtorKind;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::{self, AdtDef};
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for arrays built from every field of a tuple struct in order, e.g. `[p.0, p.1, p.2]`
+    /// where `p` is a `struct Point(f32, f32, f32)`.
+    ///
+    /// Types listed in the `tuple-struct-conversion-allowed-types` configuration are not linted.
+    /// An entry can also name a whole crate or module, such as a geometry crate whose types are
+    /// meant to be taken apart like this.
+    ///
+    /// ### Why is this bad?
+    /// When the struct converts to the array with `From`, using it is shorter and can't mix up the
+    /// order of the fields. When it doesn't, implementing `From` keeps that order in one place
+    /// instead of at every conversion.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// struct Point(f32, f32, f32);
+    ///
+    /// impl From<Point> for [f32; 3] {
+    ///     fn from(p: Point) -> Self {
+    ///         [p.0, p.1, p.2]
+    ///     }
+    /// }
+    ///
+    /// fn upload(p: Point) {
+    ///     let vertex: [f32; 3] = [p.0, p.1, p.2];
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # struct Point(f32, f32, f32);
+    /// # impl From<Point> for [f32; 3] {
+    /// #     fn from(p: Point) -> Self {
+    /// #         [p.0, p.1, p.2]
+    /// #     }
+    /// # }
+    /// fn upload(p: Point) {
+    ///     let vertex: [f32; 3] = p.into();
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub TUPLE_STRUCT_ARRAY_CONVERSIONS,
+    pedantic,
+    "building an array from the fields of a tuple struct one by one"
+}
+
+pub struct TupleStructArrayConversions {
+    allowed_types: Vec<String>,
+}
+
+impl TupleStructArrayConversions {
+    pub fn new(allowed_types: Vec<String>) -> Self {
+        Self { allowed_types }
+    }
+
+    /// Whether `adt` or a crate or module containing it is listed in the configuration.
+    fn is_allowed(&self, cx: &LateContext<'_>, adt: AdtDef<'_>) -> bool {
+        let path = cx.tcx.def_path_str(adt.did());
+        self.allowed_types.iter().any(|allowed| {
+            path.strip_prefix(allowed.as_str())
+                .map_or(false, |rest| rest.is_empty() || rest.starts_with("::"))
+        })
+    }
+}
+
+impl_lint_pass!(TupleStructArrayConversions => [TUPLE_STRUCT_ARRAY_CONVERSIONS]);
+
+impl<'tcx> LateLintPass<'tcx> for TupleStructArrayConversions {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Array(elements) = expr.kind
+            && let [first, _, ..] = elements
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(recv) = field_projection(first, 0)
+            && let Some(local) = path_to_local(recv)
+            && elements
+                .iter()
+                .enumerate()
+                .all(|(i, e)| field_projection(e, i).and_then(path_to_local) == Some(local))
+            && let ty::Array(elem_ty, _) = cx.typeck_results().expr_ty(expr).kind()
+            && let ty::Adt(adt, args) = cx.typeck_results().expr_ty(recv).peel_refs().kind()
+            && adt.is_struct()
+            && adt.non_enum_variant().ctor_kind() == Some(CtorKind::Fn)
+            // Every field, and no element coerced on its way into the array.
+            && adt.non_enum_variant().fields.len() == elements.len()
+            && adt
+                .non_enum_variant()
+                .fields
+                .iter()
+                .all(|field| field.ty(cx.tcx, args) == *elem_ty)
+            && let Some(from_trait) = cx.tcx.get_diagnostic_item(sym::From)
+            && !self.is_allowed(cx, *adt)
+            && !is_in_conversion_impl(cx, expr)
+            && !is_from_proc_macro(cx, expr)
+        {
+            let array_ty = cx.typeck_results().expr_ty(expr);
+            let struct_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+            let has_from = implements_trait(cx, array_ty, from_trait, &[struct_ty.into()]);
+            // Without a `From` impl, there is only something to do if the struct can be given one.
+            if !has_from && !adt.did().is_local() {
+                return;
+            }
+            span_lint_and_then(
+                cx,
+                TUPLE_STRUCT_ARRAY_CONVERSIONS,
+                expr.span,
+                "building an array from the fields of a tuple struct one by one",
+                |diag| {
+                    if !has_from {
+                        diag.help(format!("consider implementing `From<{struct_ty}>` for `{array_ty}`"));
+                        return;
+                    }
+                    // Converting takes the whole struct, which only leaves it usable afterwards if it's `Copy`.
+                    let mut app = if is_copy(cx, struct_ty) {
+                        Applicability::MachineApplicable
+                    } else {
+                        Applicability::MaybeIncorrect
+                    };
+                    let mut recv_sugg = Sugg::hir_with_context(cx, recv, expr.span.ctxt(), "..", &mut app);
+                    for adjustment in cx.typeck_results().expr_adjustments(recv) {
+                        if let Adjust::Deref(_) = adjustment.kind {
+                            recv_sugg = recv_sugg.deref();
+                        }
+                    }
+                    let sugg = if is_conversion_target_known(cx, expr, ToType::Array) {
+                        format!("{}.into()", recv_sugg.maybe_par())
+                    } else {
+                        format!("<[_; {}]>::from({recv_sugg})", elements.len())
+                    };
+                    diag.span_suggestion(expr.span, "use the `From` implementation instead", sugg, app);
+                },
+            );
+        }
+    }
+}
+
+/// Whether `expr` is inside an implementation of `From` or `Into`, which may well be the very
+/// implementation the lint would suggest using.
+fn is_in_conversion_impl(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let owner = cx.tcx.hir().get_parent_item(expr.hir_id).def_id;
+    trait_ref_of_method(cx, owner)
+        .and_then(|trait_ref| trait_ref.trait_def_id())
+        .and_then(|trait_id| cx.tcx.get_diagnostic_name(trait_id))
+        .map_or(false, |name| matches!(name, sym::From | sym::Into))
+}
@@ -0,0 +1,71 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{def_path_def_ids, fn_def_id};
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::Expr;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to functions that create unbounded channels: `std::sync::mpsc::channel`,
+    /// `tokio::sync::mpsc::unbounded_channel` and `crossbeam_channel::unbounded` by default, or
+    /// the functions listed in the `unbounded-channel-constructors` configuration.
+    ///
+    /// ### Why is this bad?
+    /// Sending on an unbounded channel never waits, so when the receiver can't keep up, messages
+    /// pile up in memory until the process runs out of it. Services that rely on backpressure
+    /// may want to ban them entirely.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let (tx, rx) = std::sync::mpsc::channel::<u32>();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let (tx, rx) = std::sync::mpsc::sync_channel::<u32>(64);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub UNBOUNDED_CHANNELS,
+    restriction,
+    "creating a channel without a capacity limit"
+}
+
+pub struct UnboundedChannels {
+    constructors: Vec<String>,
+    def_ids: DefIdSet,
+}
+
+impl UnboundedChannels {
+    pub fn new(constructors: Vec<String>) -> Self {
+        Self {
+            constructors,
+            def_ids: DefIdSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(UnboundedChannels => [UNBOUNDED_CHANNELS]);
+
+impl<'tcx> LateLintPass<'tcx> for UnboundedChannels {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for path in &self.constructors {
+            let segs: Vec<_> = path.split("::").collect();
+            self.def_ids.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(def_id) = fn_def_id(cx, expr)
+            && self.def_ids.contains(&def_id)
+        {
+            span_lint_and_help(
+                cx,
+                UNBOUNDED_CHANNELS,
+                expr.span,
+                &format!("creating an unbounded channel with `{}`", cx.tcx.def_path_str(def_id)),
+                None,
+                "use a bounded channel, so that senders wait when the receiver falls behind",
+            );
+        }
+    }
+}
@@ -1,13 +1,18 @@
-use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_then};
 use clippy_utils::higher::{get_vec_init_kind, VecInitKind};
 use clippy_utils::ty::{is_type_diagnostic_item, is_uninit_value_valid_for_ty};
-use clippy_utils::{is_integer_literal, is_lint_allowed, path_to_local_id, peel_hir_expr_while, SpanlessEq};
-use rustc_hir::{Block, Expr, ExprKind, HirId, PatKind, PathSegment, Stmt, StmtKind};
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{
+    get_parent_expr, is_integer_literal, is_lint_allowed, path_to_local, path_to_local_id, peel_blocks,
+    peel_hir_expr_while, SpanlessEq,
+};
+use core::ops::ControlFlow;
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, HirId, HirIdSet, Node, PatKind, PathSegment, Stmt, StmtKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
-use rustc_session::declare_lint_pass;
-use rustc_span::{sym, Span};
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
 
 // TODO: add `ReadBuf` (RFC 2930) in "How to fix" once it is available in std
 declare_clippy_lint! {
@@ -58,7 +63,55 @@
     "Vec with uninitialized data"
 }
 
-declare_lint_pass!(UninitVec => [UNINIT_VEC]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Vec::set_len()` calls that grow a vector when nothing was done to initialize
+    /// the new elements beforehand: the vector was just created, or it was reserved in the same
+    /// function and the new length is computed from its `len()` or `capacity()`, and it wasn't
+    /// written to through `spare_capacity_mut()`,
+    /// `as_mut_ptr()` (e.g. with `ptr::copy_nonoverlapping`), `resize()` or anything else first.
+    ///
+    /// Unlike [`uninit_vec`], the call doesn't need to directly follow the allocation.
+    ///
+    /// ### Why is this bad?
+    /// The elements between the old and the new length are uninitialized memory, which is
+    /// undefined behavior to read, drop or even hold for most types.
+    ///
+    /// ### Known problems
+    /// Only what happens before the call within the same function is taken into account. A
+    /// `Vec` that is neither created nor reserved there, like a `&mut Vec` parameter, is assumed
+    /// to have had its spare capacity written by the caller.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// fn read_more(reader: &mut impl Read, buf: &mut Vec<u8>, n: usize) -> io::Result<()> {
+    ///     buf.reserve(n);
+    ///     let len = buf.len();
+    ///     unsafe { buf.set_len(len + n) };
+    ///     reader.read_exact(&mut buf[len..])
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn read_more(reader: &mut impl Read, buf: &mut Vec<u8>, n: usize) -> io::Result<()> {
+    ///     let len = buf.len();
+    ///     buf.resize(len + n, 0);
+    ///     reader.read_exact(&mut buf[len..])
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub VEC_SET_LEN_UNINIT,
+    correctness,
+    "growing a `Vec` with `set_len()` over uninitialized elements"
+}
+
+#[derive(Default)]
+pub struct UninitVec {
+    /// `set_len()` calls already handled by `UNINIT_VEC`.
+    set_len_after_reserve: HirIdSet,
+}
+
+impl_lint_pass!(UninitVec => [UNINIT_VEC, VEC_SET_LEN_UNINIT]);
 
 // FIXME: update to a visitor-based implementation.
 // Threads: https://github.com/rust-lang/rust-clippy/pull/7682#discussion_r710998368
@@ -67,55 +120,88 @@ fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
         if !in_external_macro(cx.tcx.sess, block.span) {
             for w in block.stmts.windows(2) {
                 if let StmtKind::Expr(expr) | StmtKind::Semi(expr) = w[1].kind {
-                    handle_uninit_vec_pair(cx, &w[0], expr);
+                    self.handle_uninit_vec_pair(cx, &w[0], expr);
                 }
             }
 
             if let (Some(stmt), Some(expr)) = (block.stmts.last(), block.expr) {
-                handle_uninit_vec_pair(cx, stmt, expr);
+                self.handle_uninit_vec_pair(cx, stmt, expr);
             }
         }
     }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::MethodCall(path, recv, [new_len], _) = expr.kind
+            && path.ident.name.as_str() == "set_len"
+            && !self.set_len_after_reserve.contains(&expr.hir_id)
+            && !in_external_macro(cx.tcx.sess, expr.span)
+            && let vec_ty = cx.typeck_results().expr_ty(recv).peel_refs()
+            && is_type_diagnostic_item(cx, vec_ty, sym::Vec)
+            && let ty::Adt(_, args) = vec_ty.kind()
+            && !is_integer_literal(new_len, 0)
+            && !is_uninit_value_valid_for_ty(cx, args.type_at(0))
+            && let Some(root) = place_root(recv)
+            && let Some(body) = cx.enclosing_body
+            && let body = cx.tcx.hir().body(body).value
+            && (is_fresh_vec(cx, recv)
+                || (is_len_past_end(cx, recv, new_len) && is_reserved_before(cx, body, recv, expr)))
+            && !may_be_initialized_before(cx, body, recv, root, expr)
+        {
+            span_lint_and_help(
+                cx,
+                VEC_SET_LEN_UNINIT,
+                expr.span,
+                "calling `set_len()` to grow a `Vec` over elements that were never initialized",
+                None,
+                "initialize the new elements first, e.g. through `spare_capacity_mut()`, or use `resize()` or `extend()`",
+            );
+        }
+    }
 }
 
-fn handle_uninit_vec_pair<'tcx>(
-    cx: &LateContext<'tcx>,
-    maybe_init_or_reserve: &'tcx Stmt<'tcx>,
-    maybe_set_len: &'tcx Expr<'tcx>,
-) {
-    if let Some(vec) = extract_init_or_reserve_target(cx, maybe_init_or_reserve)
-        && let Some((set_len_self, call_span)) = extract_set_len_self(cx, maybe_set_len)
-        && vec.location.eq_expr(cx, set_len_self)
-        && let ty::Ref(_, vec_ty, _) = cx.typeck_results().expr_ty_adjusted(set_len_self).kind()
-        && let ty::Adt(_, args) = vec_ty.kind()
-        // `#[allow(...)]` attribute can be set on enclosing unsafe block of `set_len()`
-        && !is_lint_allowed(cx, UNINIT_VEC, maybe_set_len.hir_id)
-    {
-        if vec.has_capacity() {
-            // with_capacity / reserve -> set_len
-
-            // Check T of Vec<T>
-            if !is_uninit_value_valid_for_ty(cx, args.type_at(0)) {
-                // FIXME: #7698, false positive of the internal lints
-                #[expect(clippy::collapsible_span_lint_calls)]
-                span_lint_and_then(
+impl UninitVec {
+    fn handle_uninit_vec_pair<'tcx>(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        maybe_init_or_reserve: &'tcx Stmt<'tcx>,
+        maybe_set_len: &'tcx Expr<'tcx>,
+    ) {
+        if let Some(vec) = extract_init_or_reserve_target(cx, maybe_init_or_reserve)
+            && let Some((set_len_self, set_len_call)) = extract_set_len_self(cx, maybe_set_len)
+            && vec.location.eq_expr(cx, set_len_self)
+            && let ty::Ref(_, vec_ty, _) = cx.typeck_results().expr_ty_adjusted(set_len_self).kind()
+            && let ty::Adt(_, args) = vec_ty.kind()
+            // `#[allow(...)]` attribute can be set on enclosing unsafe block of `set_len()`
+            && !is_lint_allowed(cx, UNINIT_VEC, maybe_set_len.hir_id)
+            && self.set_len_after_reserve.insert(set_len_call.hir_id)
+        {
+            let call_span = set_len_call.span;
+            if vec.has_capacity() {
+                // with_capacity / reserve -> set_len
+
+                // Check T of Vec<T>
+                if !is_uninit_value_valid_for_ty(cx, args.type_at(0)) {
+                    // FIXME: #7698, false positive of the internal lints
+                    #[expect(clippy::collapsible_span_lint_calls)]
+                    span_lint_and_then(
+                        cx,
+                        UNINIT_VEC,
+                        vec![call_span, maybe_init_or_reserve.span],
+                        "calling `set_len()` immediately after reserving a buffer creates uninitialized values",
+                        |diag| {
+                            diag.help("initialize the buffer or wrap the content in `MaybeUninit`");
+                        },
+                    );
+                }
+            } else {
+                // new / default -> set_len
+                span_lint(
                     cx,
                     UNINIT_VEC,
                     vec![call_span, maybe_init_or_reserve.span],
-                    "calling `set_len()` immediately after reserving a buffer creates uninitialized values",
-                    |diag| {
-                        diag.help("initialize the buffer or wrap the content in `MaybeUninit`");
-                    },
+                    "calling `set_len()` on empty `Vec` creates out-of-bound values",
                 );
             }
-        } else {
-            // new / default -> set_len
-            span_lint(
-                cx,
-                UNINIT_VEC,
-                vec![call_span, maybe_init_or_reserve.span],
-                "calling `set_len()` on empty `Vec` creates out-of-bound values",
-            );
         }
     }
 }
@@ -191,8 +277,11 @@ fn is_reserve(cx: &LateContext<'_>, path: &PathSegment<'_>, self_expr: &Expr<'_>
         && path.ident.name.as_str() == "reserve"
 }
 
-/// Returns self if the expression is `Vec::set_len()`
-fn extract_set_len_self<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'_>) -> Option<(&'tcx Expr<'tcx>, Span)> {
+/// Returns self and the call if the expression is `Vec::set_len()`
+fn extract_set_len_self<'tcx>(
+    cx: &LateContext<'_>,
+    expr: &'tcx Expr<'_>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
     // peel unsafe blocks in `unsafe { vec.set_len() }`
     let expr = peel_hir_expr_while(expr, |e| {
         if let ExprKind::Block(block, _) = e.kind {
@@ -213,7 +302,7 @@ fn extract_set_len_self<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'_>) -> Opt
                 && path.ident.name.as_str() == "set_len"
                 && !is_integer_literal(arg, 0)
             {
-                Some((self_expr, expr.span))
+                Some((self_expr, expr))
             } else {
                 None
             }
@@ -221,3 +310,95 @@ fn extract_set_len_self<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'_>) -> Opt
         _ => None,
     }
 }
+
+/// The local that `place` is a field of, or `place` itself, like `v` for `v.buf`.
+fn place_root(mut place: &Expr<'_>) -> Option<HirId> {
+    while let ExprKind::Field(base, _) | ExprKind::Unary(UnOp::Deref, base) = place.kind {
+        place = base;
+    }
+    path_to_local(place)
+}
+
+/// Whether `vec` is a local initialized with `Vec::new()`, `Vec::with_capacity(..)` or the like,
+/// which starts out empty.
+fn is_fresh_vec(cx: &LateContext<'_>, vec: &Expr<'_>) -> bool {
+    if let Some(local) = path_to_local(vec)
+        && let Some(Node::Local(decl)) = cx.tcx.hir().find_parent(local)
+        && let Some(init) = decl.init
+    {
+        get_vec_init_kind(cx, init).is_some()
+    } else {
+        false
+    }
+}
+
+/// Whether `new_len` is `vec.capacity()` or `vec.len() + n`, so it can only grow `vec`.
+fn is_len_past_end<'tcx>(cx: &LateContext<'tcx>, vec: &'tcx Expr<'_>, new_len: &'tcx Expr<'_>) -> bool {
+    let is_call_on_vec = |e: &Expr<'_>, name: &str| {
+        matches!(peel_blocks(e).kind, ExprKind::MethodCall(path, recv, [], _)
+            if path.ident.name.as_str() == name && SpanlessEq::new(cx).eq_expr(recv, vec))
+    };
+    match new_len.kind {
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::Add => {
+            is_call_on_vec(lhs, "len") || is_call_on_vec(rhs, "len")
+        },
+        _ => is_call_on_vec(new_len, "capacity"),
+    }
+}
+
+/// Whether `vec` is `reserve()`d anywhere before `set_len_call` in `body`.
+fn is_reserved_before<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &'tcx Expr<'tcx>,
+    vec: &Expr<'_>,
+    set_len_call: &Expr<'_>,
+) -> bool {
+    for_each_expr_with_closures(cx, body, |e| {
+        if e.hir_id == set_len_call.hir_id {
+            return ControlFlow::Break(false);
+        }
+        match e.kind {
+            ExprKind::MethodCall(path, recv, [_], _)
+                if matches!(path.ident.name.as_str(), "reserve" | "reserve_exact")
+                    && SpanlessEq::new(cx).eq_expr(recv, vec) =>
+            {
+                ControlFlow::Break(true)
+            },
+            _ => ControlFlow::Continue(()),
+        }
+    })
+    .unwrap_or(false)
+}
+
+/// Whether anything before `set_len_call` in `body` may have written to `vec`, the receiver of
+/// the call: anything that uses it, other than to reserve memory or to read its length.
+fn may_be_initialized_before<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &'tcx Expr<'tcx>,
+    vec: &'tcx Expr<'tcx>,
+    root: HirId,
+    set_len_call: &Expr<'_>,
+) -> bool {
+    let is_projection = !path_to_local_id(vec, root);
+    for_each_expr_with_closures(cx, body, |e| {
+        if e.hir_id == set_len_call.hir_id {
+            return ControlFlow::Break(false);
+        }
+        let is_use = if SpanlessEq::new(cx).eq_expr(e, vec) {
+            !matches!(get_parent_expr(cx, e).map(|parent| parent.kind), Some(ExprKind::MethodCall(path, recv, ..))
+                if recv.hir_id == e.hir_id
+                    && matches!(path.ident.name.as_str(), "reserve" | "reserve_exact" | "len" | "capacity" | "is_empty"))
+        } else {
+            // The whole of `root`, rather than one of its fields, like `self` in `self.fill()`.
+            is_projection
+                && path_to_local_id(e, root)
+                && !matches!(get_parent_expr(cx, e).map(|parent| parent.kind), Some(ExprKind::Field(..)))
+        };
+        if is_use {
+            ControlFlow::Break(true)
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .unwrap_or(false)
+}
@@ -0,0 +1,136 @@
+use std::ops::ControlFlow;
+
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::higher::VecArgs;
+use clippy_utils::source::{snippet_opt, snippet_with_context};
+use clippy_utils::visitors::for_each_expr;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Node, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `[expr; 0]` and `vec![expr; 0]` where `expr` has side effects, such as calling
+    /// a function that isn't `const`.
+    ///
+    /// ### Why is this bad?
+    /// The expression is still evaluated once, and its value dropped right away, which is easy
+    /// to miss when reading the code.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn f() -> i32 {
+    ///     println!("side effect");
+    ///     0
+    /// }
+    /// let a = [f(); 0];
+    /// let v = vec![f(); 0];
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn f() -> i32 {
+    /// #     println!("side effect");
+    /// #     0
+    /// # }
+    /// f();
+    /// let a: [i32; 0] = [];
+    /// f();
+    /// let v: Vec<i32> = Vec::new();
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub ZERO_REPEAT_SIDE_EFFECTS,
+    suspicious,
+    "repeating an expression with side effects zero times"
+}
+
+declare_lint_pass!(ZeroRepeatSideEffects => [ZERO_REPEAT_SIDE_EFFECTS]);
+
+impl<'tcx> LateLintPass<'tcx> for ZeroRepeatSideEffects {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let (elem, span, empty) = if let Some(VecArgs::Repeat(elem, len)) = VecArgs::hir(cx, expr)
+            && matches!(constant(cx, cx.typeck_results(), len), Some(Constant::Int(0)))
+        {
+            (elem, expr.span.ctxt().outer_expn_data().call_site, "Vec::new()")
+        } else if let ExprKind::Repeat(elem, _) = expr.kind
+            && let ty::Array(_, len) = cx.typeck_results().expr_ty(expr).kind()
+            && len.try_eval_target_usize(cx.tcx, cx.param_env) == Some(0)
+        {
+            (elem, expr.span, "[]")
+        } else {
+            return;
+        };
+        if span.from_expansion() || !has_side_effects(cx, elem) {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            ZERO_REPEAT_SIDE_EFFECTS,
+            span,
+            "expression with side effects repeated zero times",
+            |diag| {
+                diag.note("the expression is still evaluated once, and its value dropped");
+                let mut app = Applicability::MachineApplicable;
+                let elem = snippet_with_context(cx, elem.span, span.ctxt(), "..", &mut app).0;
+                match cx.tcx.hir().get_parent(expr.hir_id) {
+                    Node::Local(local) if local.els.is_none() && !local.span.from_expansion() => {
+                        // `[]` and `Vec::new()` may need the type to be spelled out.
+                        let ty = if let Some(ty) = local.ty {
+                            snippet_opt(cx, ty.span).unwrap_or_default()
+                        } else {
+                            app = Applicability::MaybeIncorrect;
+                            cx.typeck_results().expr_ty(expr).to_string()
+                        };
+                        let pat = snippet_with_context(cx, local.pat.span, span.ctxt(), "..", &mut app).0;
+                        diag.span_suggestion(
+                            local.span,
+                            "evaluate the expression on its own",
+                            format!("{elem}; let {pat}: {ty} = {empty};"),
+                            app,
+                        );
+                    },
+                    Node::Stmt(stmt) if matches!(stmt.kind, StmtKind::Semi(_)) => {
+                        diag.span_suggestion(span, "evaluate the expression on its own", elem, app);
+                    },
+                    _ => {
+                        diag.span_suggestion(
+                            span,
+                            "evaluate the expression on its own",
+                            format!("{{ {elem}; {empty} }}"),
+                            Applicability::MaybeIncorrect,
+                        );
+                    },
+                }
+            },
+        );
+    }
+}
+
+/// Whether evaluating `expr` can do anything besides producing a value, such as calling a
+/// function that isn't `const` or assigning to a place.
+fn has_side_effects<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+    for_each_expr(expr, |e| {
+        let callee = match e.kind {
+            ExprKind::Call(func, _) => match func.kind {
+                ExprKind::Path(ref qpath) => cx.qpath_res(qpath, func.hir_id).opt_def_id(),
+                // Closures and function pointers.
+                _ => None,
+            },
+            ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(e.hir_id),
+            ExprKind::Binary(..) if cx.typeck_results().is_method_call(e) => {
+                cx.typeck_results().type_dependent_def_id(e.hir_id)
+            },
+            ExprKind::Assign(..) | ExprKind::AssignOp(..) | ExprKind::InlineAsm(_) | ExprKind::Yield(..) => None,
+            _ => return ControlFlow::Continue(()),
+        };
+        if callee.is_some_and(|id| cx.tcx.is_const_fn(id)) {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    })
+    .is_some()
+}
@@ -11,10 +11,15 @@
 //! must exist at both the start and the end of an item (e.g. an expression or a path) assuming the
 //! code was written, and check if the span contains that text. Note this will only work correctly
 //! if the span is not from a `macro_rules` based macro.
+//!
+//! Lint passes that check many nodes sharing a span, like the output of a proc macro that gives
+//! every token the call site's span, can keep a [`ProcMacroCache`] to only search the text once.
 
+use crate::source::{get_source_text, SourceFileRange};
 use rustc_ast::ast::{AttrKind, Attribute, IntTy, LitIntType, LitKind, StrStyle, UintTy};
 use rustc_ast::token::CommentKind;
 use rustc_ast::AttrStyle;
+use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::{
     Block, BlockCheckMode, Body, Closure, Destination, Expr, ExprKind, FieldDef, FnHeader, HirId, Impl, ImplItem,
@@ -23,13 +28,12 @@
 };
 use rustc_lint::{LateContext, LintContext};
 use rustc_middle::ty::TyCtxt;
-use rustc_session::Session;
 use rustc_span::symbol::Ident;
 use rustc_span::{Span, Symbol};
 use rustc_target::spec::abi::Abi;
 
 /// The search pattern to look for. Used by `span_matches_pat`
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Pat {
     /// A single string.
     Str(&'static str),
@@ -47,31 +51,32 @@ pub enum Pat {
 
 /// Checks if the start and the end of the span's text matches the patterns. This will return false
 /// if the span crosses multiple files or if source is not available.
-fn span_matches_pat(sess: &Session, span: Span, start_pat: Pat, end_pat: Pat) -> bool {
-    let pos = sess.source_map().lookup_byte_offset(span.lo());
-    let Some(ref src) = pos.sf.src else {
-        return false;
-    };
-    let end = span.hi() - pos.sf.start_pos;
-    src.get(pos.pos.0 as usize..end.0 as usize).map_or(false, |s| {
-        // Spans can be wrapped in a mixture or parenthesis, whitespace, and trailing commas.
-        let start_str = s.trim_start_matches(|c: char| c.is_whitespace() || c == '(');
-        let end_str = s.trim_end_matches(|c: char| c.is_whitespace() || c == ')' || c == ',');
-        (match start_pat {
-            Pat::Str(text) => start_str.starts_with(text),
-            Pat::OwnedStr(text) => start_str.starts_with(&text),
-            Pat::MultiStr(texts) => texts.iter().any(|s| start_str.starts_with(s)),
-            Pat::OwnedMultiStr(texts) => texts.iter().any(|s| start_str.starts_with(s)),
-            Pat::Sym(sym) => start_str.starts_with(sym.as_str()),
-            Pat::Num => start_str.as_bytes().first().map_or(false, u8::is_ascii_digit),
-        } && match end_pat {
-            Pat::Str(text) => end_str.ends_with(text),
-            Pat::OwnedStr(text) => end_str.starts_with(&text),
-            Pat::MultiStr(texts) => texts.iter().any(|s| start_str.ends_with(s)),
-            Pat::OwnedMultiStr(texts) => texts.iter().any(|s| start_str.starts_with(s)),
-            Pat::Sym(sym) => end_str.ends_with(sym.as_str()),
-            Pat::Num => end_str.as_bytes().last().map_or(false, u8::is_ascii_hexdigit),
-        })
+fn span_matches_pat(cx: &impl LintContext, span: Span, start_pat: &Pat, end_pat: &Pat) -> bool {
+    get_source_text(cx, span)
+        .as_ref()
+        .and_then(SourceFileRange::as_str)
+        .map_or(false, |s| text_matches_pat(s, start_pat, end_pat))
+}
+
+/// Checks if the start and the end of the text matches the patterns.
+fn text_matches_pat(s: &str, start_pat: &Pat, end_pat: &Pat) -> bool {
+    // Spans can be wrapped in a mixture or parenthesis, whitespace, and trailing commas.
+    let start_str = s.trim_start_matches(|c: char| c.is_whitespace() || c == '(');
+    let end_str = s.trim_end_matches(|c: char| c.is_whitespace() || c == ')' || c == ',');
+    (match start_pat {
+        Pat::Str(text) => start_str.starts_with(text),
+        Pat::OwnedStr(text) => start_str.starts_with(text.as_str()),
+        Pat::MultiStr(texts) => texts.iter().any(|s| start_str.starts_with(s)),
+        Pat::OwnedMultiStr(texts) => texts.iter().any(|s| start_str.starts_with(s)),
+        Pat::Sym(sym) => start_str.starts_with(sym.as_str()),
+        Pat::Num => start_str.as_bytes().first().map_or(false, u8::is_ascii_digit),
+    } && match end_pat {
+        Pat::Str(text) => end_str.ends_with(text),
+        Pat::OwnedStr(text) => end_str.starts_with(text.as_str()),
+        Pat::MultiStr(texts) => texts.iter().any(|s| start_str.ends_with(s)),
+        Pat::OwnedMultiStr(texts) => texts.iter().any(|s| start_str.starts_with(s)),
+        Pat::Sym(sym) => end_str.ends_with(sym.as_str()),
+        Pat::Num => end_str.as_bytes().last().map_or(false, u8::is_ascii_hexdigit),
     })
 }
 
@@ -421,16 +426,55 @@ fn span(&self) -> Span {
 /// This should be called after `in_external_macro` and the initial pattern matching of the ast as
 /// it is significantly slower than both of those.
 pub fn is_from_proc_macro<'cx, T: WithSearchPat<'cx>>(cx: &T::Context, item: &T) -> bool {
+    let Some(src) = item_source(cx, item) else {
+        return true;
+    };
     let (start_pat, end_pat) = item.search_pat(cx);
-    !span_matches_pat(cx.sess(), item.span(), start_pat, end_pat)
+    !src.as_str()
+        .map_or(false, |s| text_matches_pat(s, &start_pat, &end_pat))
+}
+
+/// The source text of the item, or `None` if there is none for it to have been written in: the
+/// span is a dummy one, crosses files or points into a file whose source isn't loaded. This only
+/// looks at the span, so it's checked before building the search patterns.
+fn item_source<'cx, T: WithSearchPat<'cx>>(cx: &T::Context, item: &T) -> Option<SourceFileRange> {
+    let span = item.span();
+    if span.is_dummy() {
+        return None;
+    }
+    get_source_text(cx, span).filter(|src| src.as_str().is_some())
+}
+
+/// The results of [`is_from_proc_macro`] for a lint pass. It is meant to be a field of the pass,
+/// so that it is dropped along with it once the crate has been linted.
+#[derive(Clone, Default)]
+pub struct ProcMacroCache {
+    results: FxHashMap<(Span, Pat, Pat), bool>,
+}
+
+impl ProcMacroCache {
+    /// Same as [`is_from_proc_macro`], but only searches the source text once for every span and
+    /// pair of search patterns.
+    pub fn is_from_proc_macro<'cx, T: WithSearchPat<'cx>>(&mut self, cx: &T::Context, item: &T) -> bool {
+        let Some(src) = item_source(cx, item) else {
+            return true;
+        };
+        let (start_pat, end_pat) = item.search_pat(cx);
+        *self
+            .results
+            .entry((item.span(), start_pat, end_pat))
+            .or_insert_with_key(|(_, start_pat, end_pat)| {
+                !src.as_str().map_or(false, |s| text_matches_pat(s, start_pat, end_pat))
+            })
+    }
 }
 
 /// Checks if the span actually refers to a match expression
 pub fn is_span_match(cx: &impl LintContext, span: Span) -> bool {
-    span_matches_pat(cx.sess(), span, Pat::Str("match"), Pat::Str("}"))
+    span_matches_pat(cx, span, &Pat::Str("match"), &Pat::Str("}"))
 }
 
 /// Checks if the span actually refers to an if expression
 pub fn is_span_if(cx: &impl LintContext, span: Span) -> bool {
-    span_matches_pat(cx.sess(), span, Pat::Str("if"), Pat::Str("}"))
+    span_matches_pat(cx, span, &Pat::Str("if"), &Pat::Str("}"))
 }
@@ -90,7 +90,7 @@
     self as hir, def, Arm, ArrayLen, BindingAnnotation, Block, BlockCheckMode, Body, Closure, Destination, Expr,
     ExprField, ExprKind, FnDecl, FnRetTy, GenericArgs, HirId, Impl, ImplItem, ImplItemKind, ImplItemRef, Item,
     ItemKind, LangItem, Local, MatchSource, Mutability, Node, OwnerId, Param, Pat, PatKind, Path, PathSegment, PrimTy,
-    QPath, Stmt, StmtKind, TraitItem, TraitItemKind, TraitItemRef, TraitRef, TyKind, UnOp,
+    QPath, Stmt, StmtKind, TraitItem, TraitItemKind, TraitItemRef, TraitRef, TyKind, UnOp, UnsafeSource,
 };
 use rustc_lexer::{tokenize, TokenKind};
 use rustc_lint::{LateContext, Level, Lint, LintContext};
@@ -1337,6 +1337,22 @@ pub fn get_enclosing_block<'tcx>(cx: &LateContext<'tcx>, hir_id: HirId) -> Optio
     })
 }
 
+/// Gets the span of the `unsafe` block holding nothing but `expr`, if any. A suggestion replacing
+/// `expr` with safe code should replace the block as well, so that it isn't left unused.
+pub fn get_enclosing_unsafe_block(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Span> {
+    let mut parents = cx.tcx.hir().parent_iter(expr.hir_id);
+    if let Some((_, Node::Block(block))) = parents.next()
+        && block.stmts.is_empty()
+        && block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+        && let Some((_, Node::Expr(block_expr))) = parents.next()
+        && !block_expr.span.from_expansion()
+    {
+        Some(block_expr.span)
+    } else {
+        None
+    }
+}
+
 /// Gets the loop or closure enclosing the given expression, if any.
 pub fn get_enclosing_loop_or_multi_call_closure<'tcx>(
     cx: &LateContext<'tcx>,
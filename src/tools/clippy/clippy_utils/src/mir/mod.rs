@@ -2,7 +2,8 @@
 use rustc_index::bit_set::BitSet;
 use rustc_middle::mir::visit::{MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor};
 use rustc_middle::mir::{
-    traversal, BasicBlock, Body, InlineAsmOperand, Local, Location, Place, StatementKind, TerminatorKind, START_BLOCK,
+    traversal, BasicBlock, Body, InlineAsmOperand, Local, Location, Place, ProjectionElem, StatementKind,
+    TerminatorKind, START_BLOCK,
 };
 use rustc_middle::ty::TyCtxt;
 
@@ -86,6 +87,117 @@ fn visit_place(&mut self, place: &Place<'tcx>, ctx: PlaceContext, loc: Location)
     }
 }
 
+/// Like [`visit_local_usage`], but tracks each of `places` rather than whole locals, and follows
+/// every path from `location`, loops included, until the place is overwritten.
+///
+/// An access to another place counts as a use if the two may overlap, so a use of `x.1` is not a
+/// use of `x.0`. Drops are not counted as uses.
+pub fn visit_place_usage<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    places: &[Place<'tcx>],
+    mir: &Body<'tcx>,
+    location: Location,
+) -> Vec<LocalUsage> {
+    places
+        .iter()
+        .map(|&place| {
+            let mut v = PlaceUsageVisitor {
+                tcx,
+                mir,
+                place,
+                usage: LocalUsage::default(),
+                overwritten: false,
+            };
+            let mut seen = BitSet::new_empty(mir.basic_blocks.len());
+            let mut to_visit = Vec::new();
+
+            // The rest of the block `location` is in. The block is not marked as seen, so that the part
+            // before `location` is visited if a loop leads back to it.
+            if v.visit_block_from(location.block, location.statement_index + 1) {
+                to_visit.extend(mir.basic_blocks[location.block].terminator().successors());
+            }
+            while let Some(bb) = to_visit.pop() {
+                if seen.insert(bb) && v.visit_block_from(bb, 0) {
+                    to_visit.extend(mir.basic_blocks[bb].terminator().successors());
+                }
+            }
+            v.usage
+        })
+        .collect()
+}
+
+struct PlaceUsageVisitor<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    mir: &'a Body<'tcx>,
+    place: Place<'tcx>,
+    usage: LocalUsage,
+    /// Whether the place is overwritten by the statement or terminator being visited.
+    overwritten: bool,
+}
+
+impl<'a, 'tcx> PlaceUsageVisitor<'a, 'tcx> {
+    /// Visits `block` from its `start`th statement on. Returns whether the place still holds the
+    /// same value at the end of the block.
+    fn visit_block_from(&mut self, block: BasicBlock, start: usize) -> bool {
+        let data = &self.mir.basic_blocks[block];
+        for (statement_index, statement) in data.statements.iter().enumerate().skip(start) {
+            self.visit_statement(statement, Location { block, statement_index });
+            if self.overwritten {
+                return false;
+            }
+        }
+        if start <= data.statements.len() {
+            let statement_index = data.statements.len();
+            self.visit_terminator(data.terminator(), Location { block, statement_index });
+        }
+        !self.overwritten
+    }
+
+    /// Whether `other` may refer to memory that overlaps with the tracked place.
+    fn overlaps(&self, other: Place<'tcx>) -> bool {
+        if other.local != self.place.local {
+            return false;
+        }
+        for ((base, elem), other_elem) in self.place.as_ref().iter_projections().zip(other.projection) {
+            if elem != other_elem {
+                // Different fields of a struct or tuple are disjoint, but those of a union aren't.
+                let disjoint_fields = matches!(elem, ProjectionElem::Field(..))
+                    && matches!(other_elem, ProjectionElem::Field(..))
+                    && !base.ty(self.mir, self.tcx).ty.is_union();
+                return !disjoint_fields;
+            }
+        }
+        true
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for PlaceUsageVisitor<'a, 'tcx> {
+    fn visit_place(&mut self, place: &Place<'tcx>, ctx: PlaceContext, loc: Location) {
+        if !self.overlaps(*place) {
+            return;
+        }
+        match ctx {
+            PlaceContext::NonUse(_) | PlaceContext::MutatingUse(MutatingUseContext::Drop) => {},
+            // Assigning to the place or one that contains it. The operands are read before the
+            // assignment, so the place stays tracked until the end of the statement.
+            PlaceContext::MutatingUse(
+                MutatingUseContext::Store | MutatingUseContext::Call | MutatingUseContext::AsmOutput,
+            ) if place.projection.len() <= self.place.projection.len() && !place.is_indirect() => {
+                self.overwritten = true;
+            },
+            _ => {
+                self.usage.local_use_locs.push(loc);
+                if matches!(
+                    ctx,
+                    PlaceContext::NonMutatingUse(NonMutatingUseContext::Move) | PlaceContext::MutatingUse(_)
+                ) {
+                    self.usage.local_consume_or_mutate_locs.push(loc);
+                }
+            },
+        }
+    }
+}
+
 /// Checks if the block is part of a cycle
 pub fn block_in_cycle(body: &Body<'_>, block: BasicBlock) -> bool {
     let mut seen = BitSet::new_empty(body.basic_blocks.len());
@@ -15,7 +15,8 @@
 extern crate rustc_session;
 extern crate rustc_span;
 
-use rustc_interface::interface;
+use rustc_driver::Compilation;
+use rustc_interface::{interface, Queries};
 use rustc_session::config::ErrorOutputType;
 use rustc_session::parse::ParseSess;
 use rustc_session::EarlyErrorHandler;
@@ -120,6 +121,9 @@ fn config(&mut self, config: &mut interface::Config) {
 
 struct ClippyCallbacks {
     clippy_args_var: Option<String>,
+    /// The outcome of the checks that need MIR from before `mir_promoted` steals it, shared with
+    /// the lint passes of this session.
+    dry_runs: clippy_lints::DryRuns,
 }
 
 impl rustc_driver::Callbacks for ClippyCallbacks {
@@ -129,6 +133,7 @@ fn config(&mut self, config: &mut interface::Config) {
         let conf_path = clippy_config::lookup_conf_file();
         let previous = config.register_lints.take();
         let clippy_args_var = self.clippy_args_var.take();
+        let dry_runs = self.dry_runs.clone();
         config.parse_sess_created = Some(Box::new(move |parse_sess| {
             track_clippy_args(parse_sess, &clippy_args_var);
             track_files(parse_sess);
@@ -148,7 +153,7 @@ fn config(&mut self, config: &mut interface::Config) {
             }
 
             let conf = clippy_config::Conf::read(sess, &conf_path);
-            clippy_lints::register_lints(lint_store, conf);
+            clippy_lints::register_lints(lint_store, conf, &dry_runs);
             clippy_lints::register_pre_expansion_lints(lint_store, conf);
             clippy_lints::register_renamed(lint_store);
         }));
@@ -162,6 +167,15 @@ fn config(&mut self, config: &mut interface::Config) {
         // Disable flattening and inlining of format_args!(), so the HIR matches with the AST.
         config.opts.unstable_opts.flatten_format_args = false;
     }
+
+    // Some lints need the MIR from before promotion, which `mir_promoted` steals during analysis.
+    fn after_expansion<'tcx>(&mut self, _: &interface::Compiler, queries: &'tcx Queries<'tcx>) -> Compilation {
+        queries
+            .global_ctxt()
+            .unwrap()
+            .enter(|tcx| clippy_lints::check_before_promotion(tcx, &self.dry_runs));
+        Compilation::Continue
+    }
 }
 
 #[allow(clippy::ignored_unit_patterns)]
@@ -265,7 +279,11 @@ pub fn main() {
         let clippy_enabled = !cap_lints_allow && (!no_deps || in_primary_package);
         if clippy_enabled {
             args.extend(clippy_args);
-            rustc_driver::RunCompiler::new(&args, &mut ClippyCallbacks { clippy_args_var })
+            let mut callbacks = ClippyCallbacks {
+                clippy_args_var,
+                dry_runs: clippy_lints::DryRuns::default(),
+            };
+            rustc_driver::RunCompiler::new(&args, &mut callbacks)
                 .set_using_internal_features(using_internal_features)
                 .run()
         } else {
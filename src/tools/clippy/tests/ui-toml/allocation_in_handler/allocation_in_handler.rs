@@ -0,0 +1,22 @@
+#![warn(clippy::allocation_in_handler)]
+
+fn timer_tick(ticks: &mut Vec<u64>) {
+    ticks.push(0);
+}
+
+mod irq {
+    pub fn uart() {
+        let _ = String::from("byte");
+    }
+
+    // Only `irq::uart` is listed.
+    pub fn spi() {
+        let _ = String::from("byte");
+    }
+}
+
+fn main() {
+    timer_tick(&mut Vec::new());
+    irq::uart();
+    irq::spi();
+}
@@ -0,0 +1,13 @@
+#![warn(clippy::blocking_op_in_async)]
+
+use std::net::TcpStream;
+use std::process::Command;
+
+async fn run() {
+    let _ = Command::new("ls").output();
+    let _ = TcpStream::connect("127.0.0.1:8080");
+}
+
+fn main() {
+    let _ = run();
+}
@@ -0,0 +1,8 @@
+#![crate_type = "lib"]
+#![warn(clippy::global_allocator_in_library)]
+
+use std::alloc::System;
+
+// This crate is on the allow-list, spelled with dashes like a Cargo package name.
+#[global_allocator]
+static GLOBAL: System = System;
@@ -0,0 +1,14 @@
+#![warn(clippy::mutex_atomic, clippy::mutex_integer)]
+#![allow(unused)]
+
+use std::sync::{Mutex, RwLock};
+
+static READY: Mutex<bool> = Mutex::new(false);
+//~^ ERROR: consider using an `AtomicBool` instead of a `Mutex` here
+static GENERATION: RwLock<u32> = RwLock::new(0);
+//~^ ERROR: consider using an `AtomicU32` instead of a `RwLock` here
+
+fn main() {
+    let ready = Mutex::new(true);
+    let count = Mutex::new(0u32);
+}
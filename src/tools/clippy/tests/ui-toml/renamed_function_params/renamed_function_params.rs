@@ -0,0 +1,23 @@
+#![warn(clippy::renamed_function_params)]
+
+use std::hash::{Hash, Hasher};
+
+struct A;
+
+impl From<A> for String {
+    fn from(a: A) -> Self {
+        String::new()
+    }
+}
+
+impl PartialEq for A {
+    fn eq(&self, rhs: &Self) -> bool {
+        true
+    }
+}
+
+impl Hash for A {
+    fn hash<H: Hasher>(&self, states: &mut H) {}
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+//@compile-flags: --test
+#![warn(clippy::single_call_fn)]
+
+fn called_once() {}
+
+fn foo_bindgen_bar() {}
+
+mod ffi {
+    pub(super) fn call() {}
+}
+
+#[inline]
+fn inlined() {}
+
+struct S;
+
+trait Trait {
+    fn provided(&self) {}
+    fn required(&self);
+}
+
+impl Trait for S {
+    fn required(&self) {}
+}
+
+impl S {
+    fn inherent(&self) {}
+}
+
+// Only called from tests.
+fn test_helper() {}
+
+fn caller() {
+    called_once();
+    foo_bindgen_bar();
+    ffi::call();
+    inlined();
+    Trait::provided(&S);
+    S::inherent(&S);
+    S::inherent(&S);
+}
+
+#[test]
+fn test() {
+    test_helper();
+    called_once();
+    caller();
+}
@@ -0,0 +1,10 @@
+//@no-rustfix: the suggested runtime isn't a dependency of the test
+#![warn(clippy::sleep_in_async)]
+
+use std::time::Duration;
+
+async fn retry() {
+    std::thread::sleep(Duration::from_millis(10));
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+#![warn(clippy::tuple_array_conversions)]
+#![allow(clippy::no_effect, unused)]
+
+fn main() {
+    // Fewer elements than `tuple-array-conversions-min-size`
+    let x = [1, 2];
+    let x = (x[0], x[1]);
+    let x = [x.0, x.1];
+
+    let x = [1, 2, 3];
+    let x = (x[0], x[1], x[2]);
+    let x = [x.0, x.1, x.2];
+
+    // Bindings with distinct names
+    let (x, y, z) = (1, 2, 3);
+    let _ = [x, y, z];
+    let [width, height, depth] = [1, 2, 3];
+    let _ = (width, height, depth);
+
+    // Bindings that only differ in their index
+    let [a0, a1, a2] = [1, 2, 3];
+    let _ = (a0, a1, a2);
+}
@@ -0,0 +1,21 @@
+#![warn(clippy::tuple_struct_array_conversions)]
+
+mod geometry {
+    pub struct Vec2(pub f32, pub f32);
+}
+
+mod geometry_ext {
+    pub struct Vec2(pub f32, pub f32);
+}
+
+struct Color(u8, u8, u8);
+
+fn main() {
+    let v = geometry::Vec2(1.0, 2.0);
+    let _ = [v.0, v.1];
+    let c = Color(0, 0, 0);
+    let _ = [c.0, c.1, c.2];
+
+    let w = geometry_ext::Vec2(1.0, 2.0);
+    let _ = [w.0, w.1];
+}
@@ -0,0 +1,18 @@
+#![warn(clippy::unbounded_channels)]
+
+mod queue {
+    use std::sync::mpsc;
+
+    pub fn unbounded<T>() -> (mpsc::Sender<T>, mpsc::Receiver<T>) {
+        mpsc::channel()
+    }
+
+    pub fn bounded<T>(cap: usize) -> (mpsc::SyncSender<T>, mpsc::Receiver<T>) {
+        mpsc::sync_channel(cap)
+    }
+}
+
+fn main() {
+    let (_tx, _rx) = queue::unbounded::<u32>();
+    let (_tx, _rx) = queue::bounded::<u32>(16);
+}
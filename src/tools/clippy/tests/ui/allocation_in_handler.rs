@@ -0,0 +1,39 @@
+#![feature(alloc_error_handler)]
+#![warn(clippy::allocation_in_handler)]
+#![crate_type = "lib"]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::alloc::Layout;
+use core::panic::PanicInfo;
+
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    let message = format!("{info}");
+    let _ = message.len();
+    log(String::from("panicked"));
+    record();
+    loop {}
+}
+
+#[alloc_error_handler]
+fn oom(layout: Layout) -> ! {
+    let _ = layout.size().to_string();
+    let _ = Vec::<u8>::new();
+    loop {}
+}
+
+fn log(_: String) {}
+
+fn record() {
+    let mut events = Vec::new();
+    events.push(1u8);
+}
+
+pub fn not_a_handler() -> String {
+    format!("{}", 1)
+}
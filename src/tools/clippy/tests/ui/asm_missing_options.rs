@@ -0,0 +1,43 @@
+//@only-target-x86_64
+#![warn(clippy::asm_missing_options)]
+
+use std::arch::asm;
+
+extern "C" fn f() {}
+
+unsafe fn lint(mut x: u64, y: u64) {
+    asm!("nop");
+    asm!("add {0}, {1}", inout(reg) x, in(reg) y);
+    asm!("add {0}, {1}", "xor {0}, {0}", inout(reg) x, in(reg) y,);
+    asm!("add {0}, {1}", inout(reg) x, in(reg) y, options(nostack));
+    asm!("2:", "dec {0}", "jnz 2b", inout(reg) x);
+}
+
+unsafe fn no_lint(mut x: u64, p: *const u64) {
+    // Compiler barrier.
+    asm!("");
+    // Already marked.
+    asm!("nop", options(nomem, nostack));
+    asm!("nop", options(readonly));
+    // Memory operands.
+    asm!("mov {}, [{}]", out(reg) x, in(reg) p);
+    asm!("movq ({}), {}", in(reg) p, out(reg) x, options(att_syntax));
+    asm!("mov {}, qword ptr fs:0x28", out(reg) x);
+    // Instructions accessing memory.
+    asm!("push rax", "pop rax");
+    asm!("call {}", sym f);
+    asm!("call {}", in(reg) f as extern "C" fn(), clobber_abi("C"));
+    asm!(".byte 0x90");
+    // Fences, interrupt masking and model specific registers.
+    asm!("mfence");
+    asm!("lfence", "sfence");
+    asm!("cli", "sti");
+    asm!("wrmsr", in("ecx") 0x10, in("eax") 0, in("edx") 0);
+}
+
+fn main() {
+    unsafe {
+        lint(1, 2);
+        no_lint(1, &0);
+    }
+}
@@ -0,0 +1,44 @@
+#![warn(clippy::blocking_op_in_async)]
+#![allow(dead_code, clippy::sleep_in_async)]
+
+use std::fs::{self, File};
+use std::thread;
+use std::time::Duration;
+
+async fn sleeps() {
+    thread::sleep(Duration::from_millis(10));
+}
+
+async fn reads() -> std::io::Result<String> {
+    let _file = File::open("config.toml")?;
+    fs::read_to_string("config.toml")
+}
+
+fn not_async() {
+    thread::sleep(Duration::from_millis(10));
+    let _ = fs::read("data.bin");
+}
+
+fn in_block() -> impl std::future::Future<Output = ()> {
+    async {
+        std::thread::sleep(Duration::from_millis(10));
+        // Closures may run elsewhere, like on a thread pool for blocking work.
+        let read = || fs::read("data.bin");
+        let _ = read();
+    }
+}
+
+async fn builders_and_accessors(metadata: fs::Metadata) {
+    // These don't access the file system.
+    let mut options = fs::OpenOptions::new();
+    options.read(true).write(true);
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    let _ = metadata.len();
+    let _ = metadata.is_dir();
+    let _ = metadata.permissions().readonly();
+
+    let _file = options.open("data.bin");
+}
+
+fn main() {}
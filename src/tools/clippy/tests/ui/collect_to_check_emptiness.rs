@@ -0,0 +1,39 @@
+#![warn(clippy::collect_to_check_emptiness)]
+#![allow(
+    clippy::len_zero,
+    clippy::needless_if,
+    clippy::nonminimal_bool,
+    clippy::suspicious_map
+)]
+
+use std::collections::{HashSet, VecDeque};
+
+fn log(x: &&i32) {
+    println!("{x}");
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let word = "abc";
+
+    let _ = v.iter().map(|x| x * 2).collect::<Vec<_>>().is_empty();
+    let _ = v.iter().map(|x| x * 2).collect::<VecDeque<_>>().len() == 0;
+    let _ = v.iter().map(|x| x * 2).collect::<HashSet<_>>().len() != 0;
+    let _ = 0 < v.iter().map(|x| x * 2).collect::<Vec<_>>().len();
+    let _ = word.chars().step_by(2).collect::<String>().is_empty();
+    if !v.iter().map(|x| x + 1).collect::<Vec<_>>().is_empty() {}
+
+    // every item is visited for its side effects
+    let mut seen = 0;
+    let _ = v.iter().map(|x| seen += x).collect::<Vec<_>>().is_empty();
+    let _ = v.iter().inspect(log).collect::<Vec<_>>().len() > 0;
+    if !v.iter().inspect(log).collect::<Vec<_>>().is_empty() {}
+
+    // a `String` collected from strings can be empty when the iterator isn't
+    let _ = ["", ""].into_iter().collect::<String>().is_empty();
+
+    // not an emptiness check
+    let doubled = v.iter().map(|x| x * 2).collect::<Vec<_>>();
+    let _ = doubled.is_empty();
+    let _ = v.iter().map(|x| x * 2).collect::<Vec<_>>().len() == 1;
+}
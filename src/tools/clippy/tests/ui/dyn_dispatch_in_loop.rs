@@ -0,0 +1,99 @@
+#![warn(clippy::dyn_dispatch_in_loop)]
+#![allow(clippy::clone_on_copy, unused)]
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+fn total(values: &[f64], weight: &dyn Fn(f64) -> f64) -> f64 {
+    let mut total = 0.0;
+    for &value in values {
+        total += weight(value);
+        //~^ ERROR: dynamically dispatched call in a loop
+    }
+    total
+}
+
+fn scaled_areas(shape: Box<dyn Shape>, scales: &[f64]) -> Vec<f64> {
+    let mut areas = Vec::new();
+    let mut i = 0;
+    while i < scales.len() {
+        areas.push(shape.area() * scales[i]);
+        //~^ ERROR: dynamically dispatched call in a loop
+        i += 1;
+    }
+    areas
+}
+
+fn count(mut next: Box<dyn FnMut() -> Option<u32>>) -> usize {
+    let mut n = 0;
+    loop {
+        if next().is_none() {
+            //~^ ERROR: dynamically dispatched call in a loop
+            return n;
+        }
+        n += 1;
+    }
+}
+
+fn different_shapes(shapes: &[Box<dyn Shape>]) -> f64 {
+    let mut total = 0.0;
+    for shape in shapes {
+        total += shape.area();
+    }
+    total
+}
+
+fn outer_loop(shape: &dyn Shape, rows: usize, cols: usize) -> f64 {
+    let mut total = 0.0;
+    for _ in 0..rows {
+        // only the inner loop is linted
+        for _ in 0..cols {
+            total += shape.area();
+            //~^ ERROR: dynamically dispatched call in a loop
+        }
+    }
+    total
+}
+
+fn reassigned(mut shape: &dyn Shape, other: &dyn Shape, n: usize) -> f64 {
+    let mut total = 0.0;
+    for _ in 0..n {
+        total += shape.area();
+        shape = other;
+    }
+    total
+}
+
+fn in_closure(shape: &dyn Shape, n: usize) -> Vec<f64> {
+    let mut areas = Vec::new();
+    for i in 0..n {
+        let area = || shape.area();
+        areas.push(i as f64);
+    }
+    areas
+}
+
+fn static_dispatch(shape: &Square, n: usize) -> f64 {
+    let mut total = 0.0;
+    for _ in 0..n {
+        total += shape.area();
+    }
+    total
+}
+
+fn clone_the_reference(shape: &dyn Shape, n: usize) {
+    for _ in 0..n {
+        let _ = shape.clone();
+    }
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+//@only-target-x86_64
+#![warn(clippy::feature_detection_in_loop)]
+
+fn main() {
+    let mut data = vec![1u32, 2, 3];
+
+    for x in data.iter_mut() {
+        if is_x86_feature_detected!("avx2") {
+            *x += 1;
+        }
+    }
+    while is_x86_feature_detected!("sse4.1") && data.len() > 1 {
+        data.pop();
+    }
+    let mut i = 0;
+    loop {
+        if i > 2 || std::arch::is_x86_feature_detected!("popcnt") {
+            break;
+        }
+        i += 1;
+    }
+    let _: Vec<u32> = data
+        .iter()
+        .map(|x| if is_x86_feature_detected!("avx2") { x * 2 } else { *x })
+        .collect();
+
+    // Don't lint
+    let has_avx2 = is_x86_feature_detected!("avx2");
+    for x in data.iter_mut() {
+        if has_avx2 {
+            *x += 1;
+        }
+    }
+    for _ in 0..(is_x86_feature_detected!("avx2") as usize) {}
+    let check = || is_x86_feature_detected!("avx2");
+    for _ in 0..3 {
+        let _ = || {
+            fn inner() -> bool {
+                is_x86_feature_detected!("avx2")
+            }
+            inner()
+        };
+    }
+    let _ = check();
+}
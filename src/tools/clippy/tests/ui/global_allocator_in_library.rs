@@ -0,0 +1,22 @@
+#![crate_type = "lib"]
+#![warn(clippy::global_allocator_in_library)]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+// Not an allocator, just an allocator type.
+pub static OTHER: Counting = Counting;
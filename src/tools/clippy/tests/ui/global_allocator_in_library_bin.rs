@@ -0,0 +1,9 @@
+#![warn(clippy::global_allocator_in_library)]
+
+use std::alloc::System;
+
+// Binaries are where the allocator should be chosen.
+#[global_allocator]
+static GLOBAL: System = System;
+
+fn main() {}
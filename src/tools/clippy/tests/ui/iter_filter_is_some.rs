@@ -0,0 +1,25 @@
+#![warn(clippy::iter_filter_is_some)]
+#![allow(clippy::option_filter_map)]
+
+fn results() -> impl Iterator<Item = Result<i32, ()>> {
+    vec![Ok(1), Err(()), Ok(2)].into_iter()
+}
+
+fn options() -> impl Iterator<Item = Option<i32>> {
+    vec![Some(1), None, Some(2)].into_iter()
+}
+
+fn main() {
+    let _ = results().filter(Result::is_ok).map(Result::unwrap);
+    let _ = results().filter(|r| r.is_ok()).map(Result::unwrap);
+    let _ = results().filter(|r| r.is_ok()).map(|r| r.unwrap());
+    let _ = options().filter(Option::is_some).map(Option::unwrap);
+    let _ = options().filter(|o| o.is_some()).map(|o| o.unwrap());
+
+    // Not the same variant.
+    let _ = results().filter(Result::is_err).map(Result::unwrap_err);
+    // Not the same type.
+    let _ = options().filter(|o| o.is_some()).map(|o| o.ok_or(()).unwrap());
+    // Not an iterator.
+    let _ = Some(Some(1)).filter(Option::is_some).map(Option::unwrap);
+}
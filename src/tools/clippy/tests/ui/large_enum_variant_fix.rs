@@ -0,0 +1,47 @@
+#![warn(clippy::large_enum_variant)]
+#![allow(dead_code)]
+
+enum Message {
+    Quit,
+    Data([u8; 8000]),
+}
+
+fn make() -> Message {
+    Message::Data([0; 8000])
+}
+
+fn handle(message: Message) -> usize {
+    match message {
+        Message::Quit => 0,
+        Message::Data(data) => data.len(),
+    }
+}
+
+fn peek(message: &Message) -> u8 {
+    match message {
+        Message::Data(data) => data[0],
+        Message::Quit => 0,
+    }
+}
+
+fn is_quit(message: &Message) -> bool {
+    !matches!(message, Message::Data(_))
+}
+
+enum Event {
+    Empty,
+    Payload { id: u32, bytes: [u8; 4000] },
+}
+
+fn event(bytes: [u8; 4000]) -> Event {
+    Event::Payload { id: 0, bytes }
+}
+
+fn bytes(event: &mut Event) -> Option<&mut [u8; 4000]> {
+    match event {
+        Event::Payload { bytes, .. } => Some(bytes),
+        Event::Empty => None,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,59 @@
+//@aux-build:proc_macros.rs
+#![warn(clippy::manual_abs_diff)]
+
+extern crate proc_macros;
+use proc_macros::external;
+use std::cmp::{max, min};
+
+fn main() {
+    let a: u32 = 3;
+    let b: u32 = 5;
+
+    let _ = if a > b { a - b } else { b - a };
+    let _ = if a >= b { a - b } else { b - a };
+    let _ = if a < b { b - a } else { a - b };
+    let _ = if b <= a { a - b } else { b - a };
+    let _ = a.max(b) - a.min(b);
+    let _ = b.max(a) - a.min(b);
+    let _ = max(a, b) - min(b, a);
+    let _ = if a * 2 > b { a * 2 - b } else { b - a * 2 };
+    let _ = if a == 0 {
+        0
+    } else if a > b {
+        a - b
+    } else {
+        b - a
+    };
+
+    let x: i32 = -3;
+    let y: i32 = 5;
+    let _ = if x > y { x - y } else { y - x };
+
+    // Do not lint
+    let _ = if a > b { b - a } else { a - b };
+    let _ = if a > b { a - b } else { b - 1 };
+    let _ = a.max(b) - b.min(5);
+    let _ = a.min(b) - a.max(b);
+    let f: f64 = 1.0;
+    let g: f64 = 2.0;
+    let _ = if f > g { f - g } else { g - f };
+    external! {
+        let a: u32 = 3;
+        let b: u32 = 5;
+        let _ = if a > b { a - b } else { b - a };
+    }
+}
+
+#[clippy::msrv = "1.59"]
+fn msrv_too_low() {
+    let a: u32 = 3;
+    let b: u32 = 5;
+    let _ = if a > b { a - b } else { b - a };
+}
+
+#[clippy::msrv = "1.60"]
+fn msrv_juust_right() {
+    let a: u32 = 3;
+    let b: u32 = 5;
+    let _ = if a > b { a - b } else { b - a };
+}
@@ -0,0 +1,60 @@
+//@aux-build:proc_macros.rs
+#![warn(clippy::manual_array_map)]
+#![allow(clippy::no_effect, unused)]
+
+extern crate proc_macros;
+use proc_macros::external;
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn to_string<T: ToString>(x: T) -> String {
+    x.to_string()
+}
+
+fn main() {
+    let a = [1, 2, 3];
+    let _ = [double(a[0]), double(a[1]), double(a[2])];
+    let _: [String; 3] = [to_string::<u32>(a[0]), to_string::<u32>(a[1]), to_string::<u32>(a[2])];
+    let _ = [Some(a[0]), Some(a[1]), Some(a[2])];
+    let [x, y] = [4, 5];
+    let _ = [double(x), double(y)];
+    let _: Vec<[u32; 2]> = [[1, 2]].into_iter().map(|[x, y]| [double(x), double(y)]).collect();
+
+    // Do not lint
+    let _ = [double(a[0]), double(a[1])];
+    let _ = [double(a[0]), double(a[2]), double(a[1])];
+    let _ = [double(a[0]), double(a[1]), u32::from(a[2])];
+    let b = [1, 2, 3];
+    let _ = [double(a[0]), double(b[1]), double(a[2])];
+    let v = vec![1, 2];
+    let _ = [double(v[0]), double(v[1])];
+    let (x, y) = (4, 5);
+    let _ = [double(x), double(y)];
+    let [x, y] = [4, 5];
+    let _ = [double(y), double(x)];
+    let [x, y] = [4, 5];
+    let _ = [double(x), double(y)];
+    let _ = x;
+    let f = |x: u32| x + 1;
+    let _ = [f(a[0]), f(a[1]), f(a[2])];
+    let s: [&String; 2] = [&String::new(), &String::new()];
+    let _ = [str::len(s[0]), str::len(s[1])];
+    external! {
+        let a = [1, 2];
+        let _ = [Some(a[0]), Some(a[1])];
+    }
+}
+
+#[clippy::msrv = "1.54"]
+fn msrv_too_low() {
+    let a = [1, 2];
+    let _ = [double(a[0]), double(a[1])];
+}
+
+#[clippy::msrv = "1.55"]
+fn msrv_juust_right() {
+    let a = [1, 2];
+    let _ = [double(a[0]), double(a[1])];
+}
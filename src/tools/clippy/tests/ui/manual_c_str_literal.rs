@@ -0,0 +1,41 @@
+#![warn(clippy::manual_c_str_literal)]
+#![allow(clippy::unnecessary_literal_unwrap)]
+
+use std::ffi::{CStr, CString};
+
+macro_rules! cstr {
+    ($s:literal) => {
+        CStr::from_bytes_with_nul($s).unwrap()
+    };
+}
+
+fn main() {
+    let _: &CStr = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+    let _: &CStr = CStr::from_bytes_with_nul(b"foo\x00").unwrap();
+    let _: &CStr = CStr::from_bytes_with_nul(b"\"quoted\"\\\n\0").expect("literal is nul-terminated");
+    let _: CString = CString::new("foo").unwrap();
+    let _: CString = CString::new(r"raw\n").unwrap();
+    let _: CString = CString::new(b"bytes").expect("no interior nul");
+    let _: CString = CString::new("foo").expect(&String::from("no interior nul"));
+
+    // Don't lint: these fail at runtime.
+    let _ = CStr::from_bytes_with_nul(b"foo").unwrap();
+    let _ = CStr::from_bytes_with_nul(b"f\0o\0").unwrap();
+    let _ = CString::new("f\0o").unwrap();
+
+    // Don't lint: not a literal, or not unwrapped.
+    let bytes = b"foo\0";
+    let _ = CStr::from_bytes_with_nul(bytes).unwrap();
+    let _ = CStr::from_bytes_with_nul(b"foo\0");
+    let _ = cstr!(b"foo\0");
+}
+
+#[clippy::msrv = "1.76"]
+fn msrv_1_76() {
+    let _ = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+}
+
+#[clippy::msrv = "1.77"]
+fn msrv_1_77() {
+    let _ = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+}
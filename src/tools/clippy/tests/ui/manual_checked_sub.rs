@@ -0,0 +1,49 @@
+//@aux-build:proc_macros.rs
+#![warn(clippy::manual_checked_sub)]
+
+extern crate proc_macros;
+use proc_macros::external;
+
+fn main() {
+    let a: u32 = 5;
+    let b: u32 = 3;
+
+    let _ = if a >= b { Some(a - b) } else { None };
+    let _ = if b <= a { Some(a - b) } else { None };
+    let _ = if a < b { None } else { Some(a - b) };
+    let _ = if a >= b { a - b } else { 0 };
+    let _ = if a > b { a - b } else { 0 };
+    let _ = if a <= b { 0 } else { a - b };
+    let _ = match a >= b {
+        true => Some(a - b),
+        false => None,
+    };
+    let _ = match a > b {
+        false => 0,
+        true => a - b,
+    };
+    let _ = match b < a {
+        true => a - b,
+        _ => 0,
+    };
+    let _ = if a == 0 {
+        0
+    } else if a + 1 >= b {
+        a + 1 - b
+    } else {
+        0
+    };
+
+    // Don't lint
+    let _ = if a > b { Some(a - b) } else { None };
+    let _ = if a >= b { b - a } else { 0 };
+    let _ = if a >= b { a - b } else { 1 };
+    let _ = if a >= b { Some(a - b) } else { Some(0) };
+    let (c, d) = (5i32, 3i32);
+    let _ = if c >= d { c - d } else { 0 };
+    let _ = match a >= b {
+        true if a > 1 => a - b,
+        _ => 0,
+    };
+    external!(if a >= b { a - b } else { 0 });
+}
@@ -0,0 +1,51 @@
+//@aux-build:proc_macros.rs
+#![warn(clippy::manual_div_ceil)]
+
+extern crate proc_macros;
+use proc_macros::external;
+
+fn main() {
+    let x: u32 = 7;
+    let y: u32 = 4;
+    let v: Vec<u8> = vec![1, 2, 3];
+
+    let _ = (x + y - 1) / y;
+    let _ = (y + x - 1) / y;
+    let _ = (x + (y - 1)) / y;
+    let _ = ((y - 1) + x) / y;
+    let _ = (x + 7) / 8;
+    let _ = (v.len() + 3) / 4;
+    let _ = (x * 2 + y - 1) / y;
+    let _ = (7 + y - 1) / y;
+    let _ = x / y + (x % y != 0) as u32;
+    let _ = (x % y != 0) as u32 + x / y;
+
+    let z = 7;
+    let _: u32 = (z + y - 1) / y;
+
+    // Do not lint
+    let a: i32 = 7;
+    let b: i32 = 4;
+    let _ = (a + b - 1) / b;
+    let _ = (x + y - 1) / (y + 1);
+    let _ = (x + y - 2) / y;
+    let _ = (x + 6) / 8;
+    let _ = (x + y) / y;
+    let _ = x / y + (x % 3 != 0) as u32;
+    external! {
+        let x: u32 = 7;
+        let _ = (x + 3) / 4;
+    }
+}
+
+#[clippy::msrv = "1.72"]
+fn msrv_too_low() {
+    let x: u32 = 7;
+    let _ = (x + 3) / 4;
+}
+
+#[clippy::msrv = "1.73"]
+fn msrv_juust_right() {
+    let x: u32 = 7;
+    let _ = (x + 3) / 4;
+}
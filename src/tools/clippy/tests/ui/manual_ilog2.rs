@@ -0,0 +1,59 @@
+//@aux-build:proc_macros.rs
+#![warn(clippy::manual_ilog2)]
+#![allow(clippy::unnecessary_cast)]
+
+extern crate proc_macros;
+use proc_macros::external;
+
+const PAGE_SIZE: usize = 4096;
+
+fn main() {
+    let x: u32 = 100;
+    let y: u64 = 100;
+    let z: usize = 100;
+
+    let _ = 31 - x.leading_zeros();
+    let _ = u32::BITS - 1 - x.leading_zeros();
+    let _ = (u64::BITS - 1) - y.leading_zeros();
+    let _ = u64::BITS - y.leading_zeros() - 1;
+    let _ = usize::BITS as u32 - 1 - z.leading_zeros();
+    let _ = (usize::BITS as u32) - z.leading_zeros() - 1;
+    let _ = 63 - (x as u64).leading_zeros();
+    let _ = PAGE_SIZE.trailing_zeros();
+    if z.is_power_of_two() {
+        let _ = z.trailing_zeros();
+    }
+    if z > 1 && z.is_power_of_two() {
+        let _ = z.trailing_zeros();
+    }
+
+    // Don't lint
+    let _ = 32 - x.leading_zeros();
+    let _ = 31 - y.leading_zeros();
+    let _ = u64::BITS - 1 - x.leading_zeros();
+    let _ = 31 - (x as i32).leading_zeros();
+    let _ = x.trailing_zeros();
+    let _ = 4095u32.trailing_zeros();
+    if y.is_power_of_two() {
+        let _ = z.trailing_zeros();
+    }
+    if z.is_power_of_two() || z == 3 {
+        let _ = z.trailing_zeros();
+    }
+    if !z.is_power_of_two() {
+        let _ = z.trailing_zeros();
+    }
+    external!(31 - x.leading_zeros());
+}
+
+#[clippy::msrv = "1.66"]
+fn msrv_1_66() {
+    let x: u32 = 100;
+    let _ = 31 - x.leading_zeros();
+}
+
+#[clippy::msrv = "1.67"]
+fn msrv_1_67() {
+    let x: u32 = 100;
+    let _ = 31 - x.leading_zeros();
+}
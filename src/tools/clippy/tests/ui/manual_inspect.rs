@@ -0,0 +1,90 @@
+#![warn(clippy::manual_inspect)]
+#![allow(clippy::no_effect)]
+
+fn takes_ref(_: &i32) {}
+fn takes_val(_: i32) {}
+
+fn main() {
+    let _ = Some(0).map(|x| {
+        println!("{x}");
+        x
+    });
+
+    let _ = Some(0).map(|x| {
+        takes_ref(&x);
+        let _ = x.is_positive();
+        x
+    });
+
+    let _ = Ok::<_, ()>(0).map(|x: i32| {
+        takes_ref(&x);
+        x
+    });
+
+    let _ = Err::<(), _>(String::new()).map_err(|e| {
+        println!("{}", e.len());
+        e
+    });
+
+    let _: Vec<_> = [1, 2]
+        .into_iter()
+        .map(|x| {
+            if x.is_positive() {
+                println!("big");
+            }
+            x
+        })
+        .collect();
+
+    // Don't lint: the value is changed, or returned early.
+    let _ = Some(0).map(|x| x);
+    let _ = Some(0).map(|x| {
+        println!("{x}");
+        x + 1
+    });
+    let _ = Some(0).map(|mut x| {
+        x += 1;
+        x
+    });
+    let _ = Some(0).map(|x| {
+        if x > 0 {
+            return 1;
+        }
+        x
+    });
+
+    // Don't lint: the value is used by value.
+    let _ = Some(0).map(|x| {
+        takes_val(x);
+        x
+    });
+
+    // Don't lint: `assert_eq!` compares the values themselves.
+    let _ = Some(0).map(|x| {
+        assert_eq!(x, 0);
+        x
+    });
+}
+
+#[clippy::msrv = "1.75"]
+fn msrv_1_75() {
+    let _ = Some(0).map(|x| {
+        println!("{x}");
+        x
+    });
+    let _: Vec<_> = [1, 2]
+        .into_iter()
+        .map(|x| {
+            println!("{x}");
+            x
+        })
+        .collect();
+}
+
+#[clippy::msrv = "1.76"]
+fn msrv_1_76() {
+    let _ = Some(0).map(|x| {
+        println!("{x}");
+        x
+    });
+}
@@ -0,0 +1,23 @@
+#![warn(clippy::manual_is_power_of_two)]
+
+fn main() {
+    let x: u32 = 8;
+    let y: u64 = 8;
+
+    let _ = x != 0 && x & (x - 1) == 0;
+    let _ = x & (x - 1) == 0 && x != 0;
+    let _ = 0 != x && (x - 1) & x == 0;
+    let _ = x > 0 && 0 == x & (x - 1);
+    let _ = x.count_ones() == 1;
+    let _ = 1 == y.count_ones();
+    let _ = (x * 2).count_ones() == 1;
+    let _ = x & (x - 1) == 0;
+
+    // Do not lint
+    let a: i32 = 8;
+    let _ = a != 0 && a & (a - 1) == 0;
+    let _ = a.count_ones() == 1;
+    let _ = x & (x - 2) == 0;
+    let _ = x & (y as u32 - 1) == 0;
+    let _ = x.count_ones() == 2;
+}
@@ -0,0 +1,27 @@
+#![warn(clippy::manual_is_variant_and)]
+
+fn main() {
+    let option = Some(1);
+    let result: Result<i32, ()> = Ok(1);
+
+    let _ = option.map(|x| x > 1).unwrap_or(false);
+    let _ = option.map_or(false, |x| x > 1);
+    let _ = result.map(|x| x > 1).unwrap_or(false);
+    let _ = result.map_or(false, |x| x > 1);
+    let _ = option.map(i32::is_positive).unwrap_or(false);
+
+    // Don't lint: the default isn't `false`, or the value isn't a `bool`.
+    let _ = option.map(|x| x > 1).unwrap_or(true);
+    let _ = option.map_or(true, |x| x > 1);
+    let _ = option.map(|x| x + 1).unwrap_or(0);
+}
+
+#[clippy::msrv = "1.69"]
+fn msrv_1_69() {
+    let _ = Some(1).map_or(false, |x| x > 1);
+}
+
+#[clippy::msrv = "1.70"]
+fn msrv_1_70() {
+    let _ = Some(1).map_or(false, |x| x > 1);
+}
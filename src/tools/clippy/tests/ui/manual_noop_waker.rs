@@ -0,0 +1,78 @@
+#![feature(noop_waker)]
+#![warn(clippy::manual_noop_waker)]
+#![allow(dead_code)]
+
+use std::ptr;
+use std::task::{RawWaker, RawWakerVTable, Waker};
+
+mod by_const {
+    use super::*;
+
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    unsafe fn clone(_: *const ()) -> RawWaker {
+        raw_waker()
+    }
+
+    unsafe fn noop(_: *const ()) {}
+
+    fn raw_waker() -> RawWaker {
+        RawWaker::new(ptr::null(), &VTABLE)
+    }
+
+    pub fn waker() -> Waker {
+        unsafe { Waker::from_raw(raw_waker()) }
+    }
+}
+
+mod by_closures {
+    use super::*;
+
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(|data| RawWaker::new(data, &VTABLE), |_| {}, |_| {}, |_| ());
+
+    pub fn waker() -> Waker {
+        unsafe { Waker::from_raw(RawWaker::new(ptr::null_mut::<()>() as *const (), &VTABLE)) }
+    }
+}
+
+mod counting {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static WAKES: AtomicUsize = AtomicUsize::new(0);
+
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake, drop);
+
+    unsafe fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(ptr::null(), &VTABLE)
+    }
+
+    unsafe fn wake(_: *const ()) {
+        WAKES.fetch_add(1, Ordering::Relaxed);
+    }
+
+    unsafe fn drop(_: *const ()) {}
+
+    pub fn waker() -> Waker {
+        // Not a no-op: waking it is counted
+        unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) }
+    }
+}
+
+mod boxed_data {
+    use super::*;
+
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| RawWaker::new(ptr::null(), &VTABLE), |_| {}, |_| {}, |_| {});
+
+    pub fn waker() -> Waker {
+        // Not a no-op: the box is leaked
+        unsafe { Waker::from_raw(RawWaker::new(Box::into_raw(Box::new(1u8)).cast(), &VTABLE)) }
+    }
+}
+
+fn main() {
+    let _ = by_const::waker();
+    let _ = by_closures::waker();
+    let _ = counting::waker();
+    let _ = boxed_data::waker();
+}
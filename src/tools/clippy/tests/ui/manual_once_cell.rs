@@ -0,0 +1,133 @@
+#![warn(clippy::manual_once_cell)]
+#![allow(unused)]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+struct Config {
+    verbose: bool,
+}
+
+impl Config {
+    fn load() -> Self {
+        Config { verbose: false }
+    }
+}
+
+static mut CONFIG: Option<Config> = None;
+static CONFIG_INIT: Once = Once::new();
+
+fn config() -> &'static Config {
+    unsafe {
+        CONFIG_INIT.call_once(|| {
+            CONFIG = Some(Config::load());
+        });
+        CONFIG.as_ref().unwrap()
+    }
+}
+
+static mut TABLE: Option<Vec<u32>> = None;
+static TABLE_READY: AtomicBool = AtomicBool::new(false);
+
+pub fn table() -> &'static Vec<u32> {
+    unsafe {
+        if !TABLE_READY.load(Ordering::Acquire) {
+            TABLE = Some((0..256).collect());
+            TABLE_READY.store(true, Ordering::Release);
+        }
+        TABLE.as_ref().unwrap()
+    }
+}
+
+// Changing the type of an exported `static` would break its users.
+pub static mut EXPORTED: Option<Vec<u32>> = None;
+static EXPORTED_READY: AtomicBool = AtomicBool::new(false);
+
+pub fn exported() -> &'static Vec<u32> {
+    unsafe {
+        if !EXPORTED_READY.load(Ordering::Acquire) {
+            EXPORTED = Some((0..256).collect());
+            EXPORTED_READY.store(true, Ordering::Release);
+        }
+        EXPORTED.as_ref().unwrap()
+    }
+}
+
+mod greeting {
+    static mut GREETING: Option<String> = None;
+    static GREETING_INIT: std::sync::Once = std::sync::Once::new();
+
+    pub fn greeting() -> &'static str {
+        unsafe {
+            GREETING_INIT.call_once(|| GREETING = Some(String::from("hello")));
+            GREETING.as_ref().unwrap()
+        }
+    }
+
+    // Resetting it is not something `OnceLock` can do.
+    pub fn reset() {
+        unsafe { GREETING = None };
+    }
+}
+
+mod shared_flag {
+    use std::sync::Once;
+
+    static mut NAME: Option<String> = None;
+    static INIT: Once = Once::new();
+
+    pub fn name() -> &'static String {
+        unsafe {
+            INIT.call_once(|| NAME = Some(String::from("name")));
+            NAME.as_ref().unwrap()
+        }
+    }
+
+    pub fn is_initialized() -> bool {
+        INIT.is_completed()
+    }
+}
+
+mod not_sync {
+    use std::cell::Cell;
+    use std::sync::Once;
+
+    // A `static` can't hold a `OnceLock<Cell<u32>>`.
+    static mut COUNTER: Option<Cell<u32>> = None;
+    static COUNTER_INIT: Once = Once::new();
+
+    pub fn counter() -> &'static Cell<u32> {
+        unsafe {
+            COUNTER_INIT.call_once(|| COUNTER = Some(Cell::new(0)));
+            COUNTER.as_ref().unwrap()
+        }
+    }
+}
+
+#[clippy::msrv = "1.69"]
+mod msrv_1_69 {
+    static mut ID: Option<u64> = None;
+    static ID_INIT: std::sync::Once = std::sync::Once::new();
+
+    pub fn id() -> &'static u64 {
+        unsafe {
+            ID_INIT.call_once(|| ID = Some(42));
+            ID.as_ref().unwrap()
+        }
+    }
+}
+
+#[clippy::msrv = "1.70"]
+mod msrv_1_70 {
+    static mut ID: Option<u64> = None;
+    static ID_INIT: std::sync::Once = std::sync::Once::new();
+
+    pub fn id() -> &'static u64 {
+        unsafe {
+            ID_INIT.call_once(|| ID = Some(42));
+            ID.as_ref().unwrap()
+        }
+    }
+}
+
+fn main() {}
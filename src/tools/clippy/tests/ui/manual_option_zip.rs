@@ -0,0 +1,78 @@
+#![warn(clippy::manual_option_zip)]
+#![allow(clippy::manual_map, unused)]
+
+fn side_effect() -> Option<u32> {
+    Some(1)
+}
+
+fn main() {
+    let a = Some(1u32);
+    let b = Some("one");
+
+    let _ = match (a, b) {
+        (Some(x), Some(y)) => Some((x, y)),
+        _ => None,
+    };
+    let _ = if let (Some(x), Some(y)) = (a, b) {
+        Some((x, y))
+    } else {
+        None
+    };
+    let _ = if let Some(x) = a {
+        if let Some(y) = b {
+            Some((x, y))
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+    let _ = match a {
+        Some(x) => match b {
+            Some(y) => Some((x, y)),
+            None => None,
+        },
+        None => None,
+    };
+    let _ = a.and_then(|x| b.map(|y| (x, y)));
+    let _ = Some(2u32).and_then(|x| b.map(|y| (x, y)));
+
+    // don't lint
+    let _ = match (a, b) {
+        (Some(x), Some(y)) => Some((y, x)),
+        _ => None,
+    };
+    let _ = match (a, b) {
+        (Some(x), Some(y)) => Some((x, y)),
+        _ => Some((0, "zero")),
+    };
+    let _ = match (&a, &b) {
+        (Some(x), Some(y)) => Some((x, y)),
+        _ => None,
+    };
+    // `side_effect()` would be called even when `a` is `None`
+    let _ = a.and_then(|x| side_effect().map(|y| (x, y)));
+    let _ = if let Some(x) = a {
+        if let Some(y) = side_effect() {
+            Some((x, y))
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+    // `b` depends on `x`
+    let _ = a.and_then(|x| x.checked_add(1).map(|y| (x, y)));
+}
+
+#[clippy::msrv = "1.45"]
+fn msrv_1_45() {
+    let (a, b) = (Some(1), Some(2));
+    let _ = a.and_then(|x| b.map(|y| (x, y)));
+}
+
+#[clippy::msrv = "1.46"]
+fn msrv_1_46() {
+    let (a, b) = (Some(1), Some(2));
+    let _ = a.and_then(|x| b.map(|y| (x, y)));
+}
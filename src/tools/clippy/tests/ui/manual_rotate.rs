@@ -0,0 +1,28 @@
+#![warn(clippy::manual_rotate)]
+
+fn main() {
+    let x: u32 = 0x1234_5678;
+    let n: u32 = 8;
+    let y: u8 = 0x12;
+    let k: usize = 3;
+
+    let _ = (x << 8) | (x >> 24);
+    let _ = (x >> 8) | (x << 24);
+    let _ = (x << n) | (x >> (32 - n));
+    let _ = (x >> (u32::BITS - n)) | (x << n);
+    let _ = (x >> n) | (x << (32 - n));
+    let _ = (x << (n & 31)) | (x >> (n.wrapping_neg() & 31));
+    let _ = (x << (n & 31)) | (x >> ((32 - n) & 31));
+    let _ = (y << k) | (y >> (8 - k));
+    let _ = ((x + 1) << 4) | ((x + 1) >> 28);
+
+    // Do not lint
+    let z: u32 = 0x8765_4321;
+    let _ = (x << 8) | (x >> 16);
+    let _ = (x << 8) | (z >> 24);
+    let _ = (x << n) | (x >> (31 - n));
+    let _ = (x << n) | (x >> n.wrapping_neg());
+    let _ = (x << 8) ^ (x >> 24);
+    let s: i32 = 0x1234_5678;
+    let _ = (s << 8) | (s >> 24);
+}
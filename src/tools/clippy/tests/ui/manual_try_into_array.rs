@@ -0,0 +1,55 @@
+//@aux-build:proc_macros.rs
+#![warn(clippy::manual_try_into_array)]
+#![allow(clippy::no_effect, unused)]
+
+extern crate proc_macros;
+use proc_macros::external;
+
+fn unguarded(s: &[u8], v: &mut [u16]) {
+    let _: [u8; 3] = [s[0], s[1], s[2]];
+    let _ = [s[0], s[1]];
+    let _: [u16; 1] = [v[0]];
+}
+
+fn guarded(s: &[u32]) -> Option<[u32; 2]> {
+    if s.len() == 2 {
+        Some([s[0], s[1]])
+    } else {
+        None
+    }
+}
+
+fn takes_array(_: [u8; 2]) {}
+
+fn call_arg(s: &[u8]) {
+    if 2 == s.len() {
+        takes_array([s[0], s[1]]);
+    }
+}
+
+fn no_lint(s: &[u8], t: &[u8], v: Vec<u8>) {
+    let _ = [s[0], s[2]];
+    let _ = [s[1], s[2]];
+    let _ = [s[0], t[1]];
+    let a = [1u8, 2, 3];
+    let _ = [a[0], a[1]];
+    let _ = [v[0], v[1]];
+    let _ = [s[0] as u16, s[1] as u16];
+    if s.len() != 2 {
+        return;
+    }
+    external! {
+        let s: &[u8] = &[1, 2];
+        let _ = [s[0], s[1]];
+    }
+}
+
+#[clippy::msrv = "1.46"]
+fn msrv_too_low(s: &[u8]) {
+    let _: [u8; 2] = [s[0], s[1]];
+}
+
+#[clippy::msrv = "1.47"]
+fn msrv_juust_right(s: &[u8]) {
+    let _: [u8; 2] = [s[0], s[1]];
+}
@@ -1,5 +1,5 @@
 #![warn(clippy::map_identity)]
-#![allow(clippy::needless_return)]
+#![allow(clippy::needless_return, clippy::redundant_clone)]
 
 fn main() {
     let x: [u16; 3] = [1, 2, 3];
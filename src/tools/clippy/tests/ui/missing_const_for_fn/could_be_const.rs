@@ -55,6 +55,7 @@ fn generic<T>(t: T) -> T {
 }
 
 fn sub(x: u32) -> usize {
+    //~^ ERROR: this could be a `const fn`
     unsafe { transmute(&x) }
 }
 
@@ -113,3 +114,19 @@ fn drop(&mut self) {
 // Lint this, since it can be dropped in const contexts
 // FIXME(effects)
 fn d(this: D) {}
+
+union U {
+    f: u32,
+}
+
+// Could be const, reading union fields is stable in const functions
+fn h(u: U) -> u32 {
+    //~^ ERROR: this could be a `const fn`
+    unsafe { u.f }
+}
+
+// Could be const, since the crate enables `const_mut_refs`
+fn mut_ref(x: &mut i32) -> i32 {
+    //~^ ERROR: this could be a `const fn`
+    *x
+}
@@ -4,7 +4,7 @@
 #![allow(clippy::borrow_as_ptr)]
 
 fn main() {
-    use std::sync::Mutex;
+    use std::sync::{Mutex, RwLock};
     Mutex::new(true);
     //~^ ERROR: consider using an `AtomicBool` instead of a `Mutex` here; if you just want
     //~| NOTE: `-D clippy::mutex-atomic` implied by `-D warnings`
@@ -18,9 +18,14 @@ fn main() {
     Mutex::new(&mut x as *mut u32);
     //~^ ERROR: consider using an `AtomicPtr` instead of a `Mutex` here; if you just want
     Mutex::new(0u32);
-    //~^ ERROR: consider using an `AtomicUsize` instead of a `Mutex` here; if you just wan
+    //~^ ERROR: consider using an `AtomicU32` instead of a `Mutex` here; if you just wan
     //~| NOTE: `-D clippy::mutex-integer` implied by `-D warnings`
     Mutex::new(0i32);
-    //~^ ERROR: consider using an `AtomicIsize` instead of a `Mutex` here; if you just wan
+    //~^ ERROR: consider using an `AtomicI32` instead of a `Mutex` here; if you just wan
     Mutex::new(0f32); // there are no float atomics, so this should not lint
+    Mutex::new(0u128); // `AtomicU128` is unstable, so this should not lint
+    RwLock::new(false);
+    //~^ ERROR: consider using an `AtomicBool` instead of a `RwLock` here; if you just wan
+    RwLock::new(1u64);
+    //~^ ERROR: consider using an `AtomicU64` instead of a `RwLock` here; if you just wan
 }
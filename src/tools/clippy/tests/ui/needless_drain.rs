@@ -0,0 +1,115 @@
+#![warn(clippy::needless_drain)]
+#![allow(clippy::drain_collect, unused)]
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::BuildHasher;
+
+fn dropped() {
+    let mut map = HashMap::from([(1, 2)]);
+    for (k, v) in map.drain() {
+        println!("{k} {v}");
+    }
+
+    let mut set = HashSet::from([1]);
+    let max = set.drain().max();
+
+    let mut heap = BinaryHeap::from([1, 2]);
+    let min = heap.drain().min();
+}
+
+fn reassigned() {
+    let mut set = HashSet::from([1, 2, 3]);
+    let sum: i32 = set.drain().sum();
+    set = HashSet::from([4]);
+    println!("{set:?}");
+}
+
+struct Batch {
+    items: Vec<u32>,
+}
+
+impl Batch {
+    fn flush(&mut self) {
+        for item in self.items.drain(..) {
+            println!("{item}");
+        }
+        self.items = Vec::with_capacity(16);
+    }
+
+    // don't lint, the collection is kept
+    fn flush_keep(&mut self) {
+        for item in self.items.drain(..) {
+            println!("{item}");
+        }
+        self.items.push(0);
+    }
+}
+
+struct FixedState;
+
+impl BuildHasher for FixedState {
+    type Hasher = DefaultHasher;
+
+    fn build_hasher(&self) -> DefaultHasher {
+        DefaultHasher::new()
+    }
+}
+
+struct Registry {
+    entries: HashMap<u32, u32, FixedState>,
+}
+
+impl Registry {
+    // don't lint, `std::mem::take` needs `Default`
+    fn reset(&mut self) {
+        for (k, v) in self.entries.drain() {
+            println!("{k} {v}");
+        }
+        self.entries = HashMap::with_hasher(FixedState);
+    }
+}
+
+fn dont_lint(outer: &mut Vec<u32>) {
+    // left to `iter_with_drain`
+    let mut v = vec![1, 2, 3];
+    for x in v.drain(..) {
+        println!("{x}");
+    }
+    let mut deque = VecDeque::from([1, 2, 3]);
+    let sum: i32 = deque.drain(..).sum();
+
+    // used again afterwards
+    let mut v = vec![1, 2, 3];
+    let sum: i32 = v.drain(..).sum();
+    v.push(4);
+
+    // only part of it
+    let mut v = vec![1, 2, 3];
+    let sum: i32 = v.drain(1..).sum();
+
+    // not owned
+    let sum: u32 = outer.drain(..).sum();
+
+    // drained again by the next iteration
+    let mut v = vec![1, 2, 3];
+    for _ in 0..2 {
+        let sum: i32 = v.drain(..).sum();
+    }
+
+    // moving it would make the closure `FnOnce`
+    let mut v = vec![1, 2, 3];
+    let mut f = || v.drain(..).count();
+    f();
+    f();
+
+    // left to `drain_collect`
+    let mut v = vec![1, 2, 3];
+    let w: Vec<i32> = v.drain(..).collect();
+
+    // `String` can't be turned into an iterator
+    let mut s = String::from("abc");
+    let n = s.drain(..).count();
+}
+
+fn main() {}
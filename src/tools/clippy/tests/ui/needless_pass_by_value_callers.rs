@@ -0,0 +1,31 @@
+//@rustfix-only-machine-applicable
+#![warn(clippy::needless_pass_by_value)]
+
+struct Config {
+    name: String,
+}
+
+fn describe(config: Config) -> usize {
+    config.name.len()
+}
+
+// The callers can't be changed, as the function is also used as a `fn` pointer.
+fn describe_ptr(config: Config) -> usize {
+    config.name.len()
+}
+
+// The body may need changes too.
+fn describe_ref(config: Config) -> usize {
+    let Config { name } = &config;
+    name.len()
+}
+
+fn main() {
+    let config = Config { name: String::new() };
+    let _ = describe(config);
+    let _ = describe(Config { name: String::new() });
+
+    let f: fn(Config) -> usize = describe_ptr;
+    let _ = f(Config { name: String::new() });
+    let _ = describe_ref(Config { name: String::new() });
+}
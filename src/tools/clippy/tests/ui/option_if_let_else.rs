@@ -4,7 +4,8 @@
     clippy::ref_option_ref,
     clippy::equatable_if_let,
     clippy::let_unit_value,
-    clippy::redundant_locals
+    clippy::redundant_locals,
+    clippy::unnecessary_result_map_or_else
 )]
 
 fn bad1(string: Option<&str>) -> (bool, &str) {
@@ -0,0 +1,26 @@
+//@only-target-x86_64
+#![warn(clippy::pointer_in_nomem_asm)]
+
+use std::arch::asm;
+
+unsafe fn lint(p: *const u64, mut q: *mut u64) {
+    asm!("mov {}, [{}]", out(reg) _, in(reg) p, options(nomem, nostack));
+    asm!("inc qword ptr [{}]", in(reg) q, options(nomem, nostack));
+    asm!("add {0}, 8", inout(reg) q, options(pure, nomem));
+    asm!("inc qword ptr [{}]", in(reg) q, options(readonly, nostack));
+}
+
+unsafe fn no_lint(p: *const u64, q: *mut u64, x: u64) {
+    asm!("mov {}, [{}]", out(reg) _, in(reg) p, options(nostack));
+    asm!("inc qword ptr [{}]", in(reg) q, options(nostack));
+    asm!("mov {}, [{}]", out(reg) _, in(reg) p, options(readonly, nostack));
+    asm!("add {0}, {0}", in(reg) x, options(nomem, nostack));
+}
+
+fn main() {
+    let mut x = 0;
+    unsafe {
+        lint(&x, &mut x);
+        no_lint(&x, &mut x, 1);
+    }
+}
@@ -0,0 +1,32 @@
+//@no-rustfix
+#![warn(clippy::push_absolute_path)]
+
+use std::path::{Path, PathBuf};
+
+const ETC: &str = "/etc";
+
+fn push_onto(base: &mut PathBuf) {
+    base.push(r"C:\Windows");
+}
+
+fn main() {
+    let mut path = PathBuf::from("/home/user");
+    path.push("/.config");
+    path.push("\\.config");
+    path.push(ETC);
+    path.push(Path::new("D:data"));
+
+    let var = "//var/log";
+    path.push(var);
+
+    push_onto(&mut path);
+
+    // Relative paths extend the path.
+    path.push(".config");
+    path.push("a/b");
+    path.push("C");
+
+    // Not known to be absolute.
+    let dir = std::env::args().next().unwrap();
+    path.push(dir);
+}
@@ -0,0 +1,64 @@
+#![warn(clippy::reader_in_loop)]
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+const CONFIG: &str = "config.toml";
+
+fn count_lines(path: &Path, patterns: &[&str]) -> io::Result<usize> {
+    let mut total = 0;
+    for pattern in patterns {
+        let reader = BufReader::new(File::open(path)?);
+        //~^ ERROR: reopening the same file on every iteration of a loop
+        total += reader
+            .lines()
+            .filter(|l| l.as_ref().is_ok_and(|l| l.contains(pattern)))
+            .count();
+    }
+    Ok(total)
+}
+
+fn poll() -> io::Result<()> {
+    let mut last = String::new();
+    loop {
+        let mut contents = String::new();
+        File::open(CONFIG).unwrap().read_to_string(&mut contents)?;
+        //~^ ERROR: reopening the same file on every iteration of a loop
+        if contents == last {
+            break;
+        }
+        last = contents;
+    }
+    let path = String::from("data.txt");
+    while last.is_empty() {
+        let _ = BufReader::new(File::open(&path).expect("missing file"));
+        //~^ ERROR: reopening the same file on every iteration of a loop
+        last.push('x');
+    }
+    Ok(())
+}
+
+fn different_files(paths: &[&Path]) -> io::Result<()> {
+    for path in paths {
+        let _ = BufReader::new(File::open(path)?);
+    }
+    let mut path = "a.txt";
+    for _ in 0..2 {
+        let _ = BufReader::new(File::open(path)?);
+        path = "b.txt";
+    }
+    Ok(())
+}
+
+fn opened_once() -> io::Result<()> {
+    let mut reader = BufReader::new(File::open(CONFIG)?);
+    let mut line = String::new();
+    for _ in 0..3 {
+        line.clear();
+        reader.read_line(&mut line)?;
+    }
+    Ok(())
+}
+
+fn main() {}
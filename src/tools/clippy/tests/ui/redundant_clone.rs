@@ -244,3 +244,40 @@ fn foo(_x: &Alpha, _y: &mut Alpha) {}
     let _z = x.clone(); // pr 7346 can't lint on `x`
     drop(y);
 }
+
+fn clone_in_loop(n: usize) {
+    for i in 0..n {
+        let s = i.to_string();
+        let t = s.clone();
+        println!("{t}");
+    }
+
+    let v = vec![1];
+    for _ in 0..n {
+        // ok, `v` is used again in the next iteration
+        let w = v.clone();
+        drop(w);
+    }
+}
+
+fn clone_field() {
+    struct Pair {
+        name: String,
+        id: u32,
+    }
+
+    let p = Pair {
+        name: String::from("foo"),
+        id: 1,
+    };
+    let name = p.name.clone();
+    println!("{name} {}", p.id);
+
+    let p = Pair {
+        name: String::from("foo"),
+        id: 1,
+    };
+    // ok, `p.name` is used afterwards
+    let name = p.name.clone();
+    println!("{name} {}", p.name);
+}
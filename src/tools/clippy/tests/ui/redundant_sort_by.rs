@@ -0,0 +1,38 @@
+#![warn(clippy::redundant_sort_by)]
+#![allow(clippy::useless_vec)]
+
+struct Item<'a> {
+    name: &'a str,
+    weight: f64,
+}
+
+fn main() {
+    let mut v = vec![2.0_f32, 1.0, 3.0];
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    v.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    v.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+    let mut items = vec![Item { name: "b", weight: 2.0 }, Item { name: "a", weight: 1.0 }];
+    items.sort_by(|a, b| a.weight.partial_cmp(&b.weight).unwrap());
+    items.sort_by(|a, b| a.name.cmp(b.name));
+    items.sort_unstable_by(|a, b| b.name.cmp(a.name));
+
+    // Do not lint
+    let mut ints = vec![2, 1, 3];
+    ints.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    items.sort_by(|a, b| a.name.cmp(b.name.trim()));
+    items.sort_by(|a, b| a.name.cmp(b.name).then(a.weight.total_cmp(&b.weight)));
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+}
+
+#[clippy::msrv = "1.61"]
+fn msrv_too_low() {
+    let mut v = vec![2.0_f64, 1.0];
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+}
+
+#[clippy::msrv = "1.62"]
+fn msrv_juust_right() {
+    let mut v = vec![2.0_f64, 1.0];
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+}
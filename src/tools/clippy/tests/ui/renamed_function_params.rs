@@ -0,0 +1,54 @@
+#![warn(clippy::renamed_function_params)]
+#![allow(clippy::partialeq_ne_impl)]
+
+use std::hash::{Hash, Hasher};
+
+struct A;
+
+impl From<A> for String {
+    fn from(a: A) -> Self {
+        String::new()
+    }
+}
+
+impl PartialEq for A {
+    fn eq(&self, rhs: &Self) -> bool {
+        true
+    }
+    fn ne(&self, other: &Self) -> bool {
+        false
+    }
+}
+
+impl Hash for A {
+    fn hash<H: Hasher>(&self, states: &mut H) {}
+    fn hash_slice<H: Hasher>(date: &[Self], states: &mut H) {}
+}
+
+trait MyTrait {
+    fn foo(&self, val: u8);
+    fn bar(a: u8, b: u8);
+    fn baz(self, _val: u8);
+    fn quz(&self, _: u8);
+    fn tup(&self, pair: (u8, u8));
+}
+
+impl MyTrait for A {
+    fn foo(&self, i_dont_wanna_use_your_name: u8) {}
+    // Names starting with `_` are ignored.
+    fn bar(_a: u8, _: u8) {}
+    fn baz(self, val: u8) {}
+    fn quz(&self, val: u8) {}
+    fn tup(&self, pair: (u8, u8)) {}
+}
+
+impl MyTrait for u8 {
+    fn foo(&self, val: u8) {}
+    fn bar(a: u8, b: u8) {}
+    fn baz(self, _: u8) {}
+    fn quz(&self, _val: u8) {}
+    // Patterns other than single bindings are ignored.
+    fn tup(&self, (a, b): (u8, u8)) {}
+}
+
+fn main() {}
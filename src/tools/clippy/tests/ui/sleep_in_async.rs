@@ -0,0 +1,27 @@
+//@no-rustfix: the suggested runtime isn't a dependency of the test
+#![warn(clippy::sleep_in_async)]
+#![allow(dead_code)]
+
+use std::future::Future;
+use std::thread;
+use std::time::Duration;
+
+async fn retry() {
+    thread::sleep(Duration::from_millis(10));
+}
+
+fn in_block() -> impl Future<Output = ()> {
+    async { std::thread::sleep(Duration::from_secs(1)) }
+}
+
+fn not_async() {
+    thread::sleep(Duration::from_millis(10));
+}
+
+async fn in_closure() {
+    // Closures may run elsewhere, like on a thread pool for blocking work.
+    let wait = || thread::sleep(Duration::from_millis(10));
+    wait();
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![warn(clippy::sleep_in_async)]
+#![allow(dead_code)]
+
+use std::time::Duration;
+use tokio::time::Instant;
+
+async fn retry(attempts: u32) -> Instant {
+    for attempt in 0..attempts {
+        std::thread::sleep(Duration::from_millis(10) * attempt);
+    }
+    Instant::now()
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+#![warn(clippy::transmuting_dyn_to_parts)]
+
+use std::fmt::Debug;
+use std::mem::transmute;
+
+struct Parts(*const (), *const ());
+
+fn main() {
+    let value: &dyn Debug = &0u32;
+    let raw: *const dyn Debug = value;
+    let slice: &[u8] = &[1, 2, 3];
+    unsafe {
+        let _: (*const (), *const ()) = transmute(value);
+        let _ = transmute::<&dyn Debug, (usize, usize)>(value);
+        let _: [usize; 2] = transmute(raw);
+        let _: (*const u8, usize) = transmute(slice);
+        let _: (*const u8, usize) = transmute("str");
+
+        // Do not lint
+        let _: (u32, u32) = transmute(0u64);
+        let _: Parts = transmute(value);
+    }
+}
@@ -52,10 +52,10 @@ fn main() {
         let v1: Vec<[u32; 2]> = t1.iter().map(|&(a, b)| [a, b]).collect();
         let t2: Vec<(u32, u32)> = v1.iter().map(|&[a, b]| (a, b)).collect();
     }
-    // FP #11082; needs discussion
+    // #11082: the array is coerced to a slice, so the array type has to be spelled out
     let (a, b) = (1.0f64, 2.0f64);
     let _: &[f64] = &[a, b];
-    // FP #11085; impossible to fix
+    // #11085: the pattern is replaced by a single binding
     let [src, dest]: [_; 2] = [1, 2];
     (src, dest);
     // FP #11100
@@ -106,3 +106,77 @@ fn msrv_juust_right() {
     let x = &[1, 2];
     let x = (x[0], x[1]);
 }
+
+fn rebindings() {
+    let t = (1, 2);
+    let a = t.0;
+    let b = t.1;
+    let _ = [a, b];
+    let x = [1, 2];
+    let a = x[0];
+    let b = x[1];
+    let _ = (a, b);
+    // Do not lint
+    let t = (1, 2);
+    let a = t.0;
+    let b = t.1;
+    let _ = [a, b];
+    let _ = a;
+    let t = (1, 2);
+    let a = t.1;
+    let b = t.0;
+    let _ = [a, b];
+}
+
+fn takes_array(_: [u32; 2]) {}
+fn takes_tuple(_: (u32, u32)) {}
+fn takes_generic<T>(_: T) {}
+
+struct S;
+
+impl S {
+    fn takes_array(&self, _: [u32; 2]) {}
+}
+
+fn call_args() {
+    let t = (1, 2);
+    takes_array([t.0, t.1]);
+    let x = [1, 2];
+    takes_tuple((x[0], x[1]));
+    let t = (1, 2);
+    S.takes_array([t.0, t.1]);
+    // `.into()` wouldn't know what to convert to
+    let t = (1, 2);
+    takes_generic([t.0, t.1]);
+}
+
+#[clippy::msrv = "1.70.0"]
+mod msrv_module {
+    fn msrv_too_low() {
+        let x = [1, 2];
+        let x = (x[0], x[1]);
+    }
+}
+
+struct MsrvImpl;
+
+#[clippy::msrv = "1.70.0"]
+impl MsrvImpl {
+    fn msrv_too_low() {
+        let x = [1, 2];
+        let x = (x[0], x[1]);
+    }
+
+    #[clippy::msrv = "1.71.0"]
+    fn msrv_juust_right() {
+        let x = [1, 2];
+        let x = (x[0], x[1]);
+    }
+}
+
+impl MsrvImpl {
+    fn msrv_restored() {
+        let x = [1, 2];
+        let x = (x[0], x[1]);
+    }
+}
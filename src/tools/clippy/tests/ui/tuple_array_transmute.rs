@@ -0,0 +1,27 @@
+#![warn(clippy::tuple_array_transmute)]
+#![allow(clippy::borrow_as_ptr, clippy::transmute_ptr_to_ptr)]
+
+use std::mem::transmute;
+
+fn main() {
+    let _: [u32; 2] = unsafe { transmute((1u32, 2u32)) };
+    let t = (1u8, 2u8, 3u8);
+    let _ = unsafe { transmute::<(u8, u8, u8), [u8; 3]>(t) };
+    let a = [1i64, 2];
+    let _: (i64, i64) = unsafe { transmute(a) };
+
+    let _: &[u8; 3] = unsafe { transmute(&t) };
+    let p = &t as *const (u8, u8, u8) as *const [u8; 3];
+    let _ = p.cast::<(u8, u8, u8)>();
+
+    // The argument still needs the `unsafe` block
+    let _: [u32; 2] = unsafe { transmute(pair()) };
+
+    // Don't lint
+    let _: [u32; 2] = unsafe { transmute((1u32, 2i32)) };
+    let _ = &a as *const [i64; 2] as *const [u64; 2];
+}
+
+unsafe fn pair() -> (u32, u32) {
+    (1, 2)
+}
@@ -0,0 +1,59 @@
+//@aux-build:proc_macros.rs
+#![warn(clippy::tuple_struct_array_conversions)]
+#![allow(clippy::no_effect, unused)]
+
+extern crate proc_macros;
+use proc_macros::external;
+
+#[derive(Clone, Copy)]
+struct Point(f32, f32, f32);
+
+impl From<Point> for [f32; 3] {
+    fn from(p: Point) -> Self {
+        [p.0, p.1, p.2]
+    }
+}
+
+struct Pair(u8, u8);
+
+struct Named {
+    x: u8,
+    y: u8,
+}
+
+struct Mixed(u8, u16);
+
+struct Triple(u8, u8, u8);
+
+fn takes_array(_: [f32; 3]) {}
+
+fn main() {
+    let p = Point(1.0, 2.0, 3.0);
+    let a: [f32; 3] = [p.0, p.1, p.2];
+    takes_array([p.0, p.1, p.2]);
+    let b = [p.0, p.1, p.2];
+    let r = &p;
+    let c: [f32; 3] = [r.0, r.1, r.2];
+
+    // no `From` impl, but one can be added
+    let pair = Pair(1, 2);
+    let d = [pair.0, pair.1];
+
+    // don't lint
+    let e = [p.1, p.0, p.2];
+    let f = [p.0, p.1];
+    let named = Named { x: 1, y: 2 };
+    let g = [named.x, named.y];
+    let t = (1u8, 2u8);
+    let h = [t.0, t.1];
+    let q = Point(0.0, 0.0, 0.0);
+    let i = [p.0, q.1, p.2];
+    let mixed = Mixed(1, 2);
+    let j = [mixed.0 as u16, mixed.1];
+    let triple = Triple(1, 2, 3);
+    let k = [triple.0, triple.1, triple.1];
+    external! {
+        let p = Point(1.0, 2.0, 3.0);
+        let l = [p.0, p.1, p.2];
+    }
+}
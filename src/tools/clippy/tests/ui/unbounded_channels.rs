@@ -0,0 +1,12 @@
+#![warn(clippy::unbounded_channels)]
+
+use std::sync::mpsc;
+
+fn main() {
+    let (_tx, _rx) = mpsc::channel::<u32>();
+    let (_tx, _rx) = mpsc::sync_channel::<u32>(64);
+
+    // Naming the constructor without calling it isn't linted.
+    let make: fn() -> (mpsc::Sender<u8>, mpsc::Receiver<u8>) = mpsc::channel;
+    let _ = make;
+}
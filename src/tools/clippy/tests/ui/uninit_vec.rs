@@ -82,7 +82,7 @@ fn main() {
 
     // Test `#[allow(...)]` attributes on inner unsafe block (shouldn't trigger)
     let mut vec: Vec<u8> = Vec::with_capacity(1000);
-    #[allow(clippy::uninit_vec)]
+    #[allow(clippy::uninit_vec, clippy::vec_set_len_uninit)]
     unsafe {
         vec.set_len(200);
     }
@@ -99,12 +99,12 @@ fn main() {
         vec.set_len(200);
     }
 
-    // known false negative
+    // not adjacent, so left to `vec_set_len_uninit`
     let mut vec1: Vec<u8> = Vec::with_capacity(1000);
     let mut vec2: Vec<u8> = Vec::with_capacity(1000);
     unsafe {
-        vec1.set_len(200);
-        vec2.set_len(200);
+        vec1.set_len(200); //~ ERROR: calling `set_len()` to grow a `Vec` over elements that were never
+        vec2.set_len(200); //~ ERROR: calling `set_len()` to grow a `Vec` over elements that were never
     }
 
     // set_len(0) should not be detected
@@ -0,0 +1,26 @@
+#![warn(clippy::unnecessary_result_map_or_else)]
+#![allow(clippy::result_map_or_into_option)]
+
+fn main() {
+    let x: Result<(), ()> = Ok(());
+    x.map_or_else(|err| err, |n| n); //~ ERROR: called `map_or_else` with an identity function
+
+    let y: Result<u32, u32> = Ok(0);
+    let _ = y.map_or_else(|err| err, std::convert::identity); //~ ERROR: called `map_or_else` with an identity function
+
+    let z: Result<(u32, u32), ()> = Ok((0, 1));
+    let _ = z.map_or_else(|_| (0, 0), |(a, b)| (a, b)); //~ ERROR: called `map_or_else` with an identity function
+
+    let _ = y.map_or_else(|_| 0, |n| n + 1); //~ ERROR: called `map_or_else` with a default that ignores the error
+    let _ = y.map_or_else(|_| fallback(), u32::count_ones); //~ ERROR: called `map_or_else` with a default that ignores the error
+
+    // Should not lint.
+    let _ = y.map_or_else(|err| err + 1, |n| n + 1);
+    let _ = y.map_or_else(|err| err, |n: u32| n);
+    let _ = Some(0).map_or_else(|| 0, |n| n);
+    let _ = y.map_or_else(|_| None, Some);
+}
+
+fn fallback() -> u32 {
+    0
+}
@@ -94,8 +94,26 @@ pub fn test() {
     }
 }
 
+// Fields are compared against the fields of the other closure parameter
+mod fields {
+    struct Test {
+        key: usize,
+    }
+
+    pub fn test() {
+        let mut args: Vec<Test> = vec![];
+        let key = Test { key: 0 };
+
+        args.sort_by(|a, b| a.key.cmp(&b.key));
+        args.sort_unstable_by(|a, b| b.key.cmp(&a.key));
+        // Not mirrored, `key` is not the second closure parameter
+        args.sort_by(|a, _b| a.key.cmp(&key.key));
+    }
+}
+
 fn main() {
     unnecessary_sort_by();
     issue_5754::test();
     issue_6001::test();
+    fields::test();
 }
@@ -0,0 +1,103 @@
+#![allow(clippy::uninit_vec, unused)]
+
+use std::mem::MaybeUninit;
+use std::ptr;
+
+struct Buffer {
+    data: Vec<u8>,
+    len: usize,
+}
+
+impl Buffer {
+    fn grow(&mut self, n: usize) {
+        self.data.reserve(n);
+        self.len += n;
+        unsafe { self.data.set_len(self.data.len() + n) };
+        //~^ ERROR: calling `set_len()` to grow a `Vec` over elements that were never initialized
+    }
+
+    fn grow_filled(&mut self, n: usize) {
+        self.fill(n);
+        unsafe { self.data.set_len(self.data.len() + n) };
+    }
+
+    fn fill(&mut self, n: usize) {}
+}
+
+fn read_more(buf: &mut Vec<u8>, n: usize) {
+    buf.reserve(n);
+    let len = buf.len();
+    unsafe { buf.set_len(buf.len() + n) };
+    //~^ ERROR: calling `set_len()` to grow a `Vec` over elements that were never initialized
+}
+
+fn fresh(n: usize) -> Vec<u32> {
+    let mut v = Vec::with_capacity(n);
+    println!("allocated");
+    unsafe { v.set_len(n) };
+    //~^ ERROR: calling `set_len()` to grow a `Vec` over elements that were never initialized
+    v
+}
+
+fn to_capacity(v: &mut Vec<String>, n: usize) {
+    v.reserve_exact(n);
+    unsafe { v.set_len(v.capacity()) };
+    //~^ ERROR: calling `set_len()` to grow a `Vec` over elements that were never initialized
+}
+
+// `uninit_vec` is allowed, so this one is left to `vec_set_len_uninit`
+fn adjacent(n: usize) -> Vec<u32> {
+    let mut v = Vec::with_capacity(n);
+    unsafe { v.set_len(n) };
+    //~^ ERROR: calling `set_len()` to grow a `Vec` over elements that were never initialized
+    v
+}
+
+// don't lint
+
+fn spare_capacity(n: usize) -> Vec<u8> {
+    let mut v = Vec::with_capacity(n);
+    for slot in &mut v.spare_capacity_mut()[..n] {
+        slot.write(0);
+    }
+    unsafe { v.set_len(n) };
+    v
+}
+
+fn copied(src: &[u8]) -> Vec<u8> {
+    let mut v = Vec::with_capacity(src.len());
+    unsafe {
+        ptr::copy_nonoverlapping(src.as_ptr(), v.as_mut_ptr(), src.len());
+        v.set_len(src.len());
+    }
+    v
+}
+
+fn resized(n: usize) -> Vec<u8> {
+    let mut v = Vec::new();
+    v.resize(n, 0);
+    unsafe { v.set_len(n) };
+    v
+}
+
+// the caller may have written to the spare capacity
+fn written_by_caller(v: &mut Vec<String>, n: usize) {
+    unsafe { v.set_len(v.len() + n) };
+}
+
+fn written_to_capacity(v: &mut Vec<String>) {
+    unsafe { v.set_len(v.capacity()) };
+}
+
+fn shrink(v: &mut Vec<u8>, n: usize) {
+    unsafe { v.set_len(n) };
+}
+
+fn maybe_uninit(n: usize) -> Vec<MaybeUninit<u8>> {
+    let mut v = Vec::with_capacity(n);
+    println!("allocated");
+    unsafe { v.set_len(n) };
+    v
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![warn(clippy::zero_repeat_side_effects)]
+#![allow(clippy::unnecessary_operation, clippy::useless_vec)]
+
+fn f() -> i32 {
+    println!("side effect");
+    0
+}
+
+fn take(_: [i32; 0]) {}
+
+fn main() {
+    const N: usize = 0;
+
+    let a = [f(); 0];
+    let b: [i32; 0] = [f(); N];
+    let mut v: Vec<i32> = vec![f(); 0];
+    v.push(1);
+    [f(); 0];
+    vec![f(); 0];
+    take([f(); 0]);
+
+    let g = || f() + 1;
+    let _: [i32; 0] = [g(); 0];
+
+    // Don't lint: no side effects, or repeated more than zero times.
+    let _ = [String::new(); 0];
+    let _ = [Some(1); 0];
+    let _ = vec![Vec::<i32>::new(); 0];
+    let _ = [f(); 1];
+    let _ = vec![f(); 2];
+}
@@ -178,6 +178,9 @@ pub struct TestProps {
     // Whether to tell `rustc` to remap the "src base" directory to a fake
     // directory.
     pub remap_src_base: bool,
+    // Link the counting allocator from `tests/auxiliary/counting_alloc.rs`
+    // into the test, aborting it when it makes more allocations than this.
+    pub check_alloc_count: Option<usize>,
 }
 
 mod directives {
@@ -216,6 +219,7 @@ mod directives {
     pub const MIR_UNIT_TEST: &'static str = "unit-test";
     pub const REMAP_SRC_BASE: &'static str = "remap-src-base";
     pub const COMPARE_OUTPUT_LINES_BY_SUBSET: &'static str = "compare-output-lines-by-subset";
+    pub const CHECK_ALLOC_COUNT: &'static str = "check-alloc-count";
     // This isn't a real directive, just one that is probably mistyped often
     pub const INCORRECT_COMPILER_FLAGS: &'static str = "compiler-flags";
 }
@@ -265,6 +269,7 @@ pub fn new() -> Self {
             stderr_per_bitwidth: false,
             mir_unit_test: None,
             remap_src_base: false,
+            check_alloc_count: None,
         }
     }
 
@@ -488,9 +493,25 @@ fn load_from(&mut self, testfile: &Path, cfg: Option<&str>, config: &Config) {
                     COMPARE_OUTPUT_LINES_BY_SUBSET,
                     &mut self.compare_output_lines_by_subset,
                 );
+
+                if let Some(limit) = config.parse_name_value_directive(ln, CHECK_ALLOC_COUNT) {
+                    let limit = limit.trim();
+                    match limit.parse::<usize>() {
+                        Ok(limit) => self.check_alloc_count = Some(limit),
+                        Err(_) => panic!(
+                            "Invalid check-alloc-count value: {limit}\nIt requires the maximum number of allocations the test may make."
+                        ),
+                    }
+                }
             });
         }
 
+        // The counting allocator is linked statically, which a dynamically
+        // linked standard library would ignore.
+        if self.check_alloc_count.is_some() {
+            self.no_prefer_dynamic = true;
+        }
+
         if self.should_ice {
             self.failure_status = Some(101);
         }
@@ -2118,7 +2118,7 @@ fn is_vxworks_pure_dynamic(&self) -> bool {
     fn build_all_auxiliary(&self, rustc: &mut Command) -> PathBuf {
         let aux_dir = self.aux_output_dir_name();
 
-        if !self.props.aux_builds.is_empty() {
+        if !self.props.aux_builds.is_empty() || self.props.check_alloc_count.is_some() {
             let _ = fs::remove_dir_all(&aux_dir);
             create_dir_all(&aux_dir).unwrap();
         }
@@ -2134,9 +2134,39 @@ fn build_all_auxiliary(&self, rustc: &mut Command) -> PathBuf {
             rustc.arg("--extern").arg(format!("{}={}/{}", aux_name, aux_dir.display(), lib_name));
         }
 
+        if let Some(limit) = self.props.check_alloc_count {
+            self.build_counting_allocator(rustc, &aux_dir, limit);
+        }
+
         aux_dir
     }
 
+    /// Builds the counting allocator of `tests/auxiliary/counting_alloc.rs`
+    /// with the limit of a `check-alloc-count` directive, and links it into
+    /// the test even though the test does not mention it.
+    fn build_counting_allocator(&self, rustc: &mut Command, aux_dir: &Path, limit: usize) {
+        let file = self.config.src_base.parent().unwrap().join("auxiliary/counting_alloc.rs");
+        let aux_testpaths = TestPaths {
+            file,
+            relative_dir: self
+                .testpaths
+                .relative_dir
+                .join(self.output_testname_unique())
+                .join("auxiliary"),
+        };
+        self.build_auxiliary_file(
+            &aux_testpaths,
+            aux_dir,
+            &[("COMPILETEST_ALLOC_COUNT_LIMIT".to_string(), limit.to_string())],
+        );
+        // The counting allocator is always an rlib, see its `no-prefer-dynamic`.
+        rustc.args(&["-Z", "unstable-options", "--extern"]).arg(format!(
+            "force:counting_alloc={}/{}",
+            aux_dir.display(),
+            get_lib_name("counting_alloc", false)
+        ));
+    }
+
     fn compose_and_run_compiler(&self, mut rustc: Command, input: Option<String>) -> ProcRes {
         let aux_dir = self.build_all_auxiliary(&mut rustc);
         self.props.unset_rustc_env.iter().fold(&mut rustc, Command::env_remove);
@@ -2154,12 +2184,25 @@ fn compose_and_run_compiler(&self, mut rustc: Command, input: Option<String>) ->
     /// Returns whether or not it is a dylib.
     fn build_auxiliary(&self, source_path: &str, aux_dir: &Path) -> bool {
         let aux_testpaths = self.compute_aux_test_paths(source_path);
+        self.build_auxiliary_file(&aux_testpaths, aux_dir, &[])
+    }
+
+    /// Builds the aux dependency at `aux_testpaths`, with `rustc_env` added to
+    /// the environment of the compiler.
+    ///
+    /// Returns whether or not it is a dylib.
+    fn build_auxiliary_file(
+        &self,
+        aux_testpaths: &TestPaths,
+        aux_dir: &Path,
+        rustc_env: &[(String, String)],
+    ) -> bool {
         let aux_props = self.props.from_aux_file(&aux_testpaths.file, self.revision, self.config);
         let aux_output = TargetLocation::ThisDirectory(self.aux_output_dir_name());
         let aux_cx = TestCx {
             config: self.config,
             props: &aux_props,
-            testpaths: &aux_testpaths,
+            testpaths: aux_testpaths,
             revision: self.revision,
         };
         // Create the directory for the stdout/stderr files.
@@ -2178,6 +2221,7 @@ fn build_auxiliary(&self, source_path: &str, aux_dir: &Path) -> bool {
             aux_rustc.env_remove(key);
         }
         aux_rustc.envs(aux_props.rustc_env.clone());
+        aux_rustc.envs(rustc_env.iter().cloned());
 
         let (dylib, crate_type) = if aux_props.no_prefer_dynamic {
             (true, None)
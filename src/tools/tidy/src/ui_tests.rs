@@ -36,6 +36,7 @@
     "tests/ui/unused-crate-deps/test.mk", // why would you use make
     "tests/ui/proc-macro/auxiliary/included-file.txt", // more include
     "tests/ui/invalid/foo.natvis.xml", // sample debugger visualizer
+    "tests/ui/traits/vtable/profile-guided-layout.profile", // method call counts for vtable layout
 ];
 
 fn check_entries(tests_path: &Path, bad: &mut bool) {
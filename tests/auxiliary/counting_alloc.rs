@@ -0,0 +1,72 @@
+// no-prefer-dynamic
+
+//! A global allocator counting the allocations a test makes, and aborting it as soon as it makes
+//! more than the limit of its `check-alloc-count` directive.
+//!
+//! compiletest builds this crate with the limit in the `COMPILETEST_ALLOC_COUNT_LIMIT` environment
+//! variable, and links it into the tests with that directive, which may also call
+//! `counting_alloc::allocations()` themselves.
+
+#![crate_type = "rlib"]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::Write;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const LIMIT: usize = parse_limit(env!("COMPILETEST_ALLOC_COUNT_LIMIT"));
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+/// Returns the number of allocations, zeroed allocations and reallocations made so far.
+pub fn allocations() -> usize {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+const fn parse_limit(limit: &str) -> usize {
+    let limit = limit.as_bytes();
+    let mut value = 0;
+    let mut i = 0;
+    while i < limit.len() {
+        assert!(limit[i].is_ascii_digit(), "the allocation limit is not a number");
+        value = value * 10 + (limit[i] - b'0') as usize;
+        i += 1;
+    }
+    value
+}
+
+struct CountingAlloc;
+
+impl CountingAlloc {
+    fn count(&self) {
+        if ALLOCATIONS.fetch_add(1, Ordering::Relaxed) == LIMIT {
+            // Neither writing to stderr nor aborting allocates.
+            let _ = writeln!(std::io::stderr(), "the test made more than {LIMIT} allocations");
+            process::abort();
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.count();
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.count();
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        self.count();
+        System.realloc(ptr, layout, new_size)
+    }
+}
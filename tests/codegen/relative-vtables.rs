@@ -0,0 +1,38 @@
+// compile-flags: -O -Z relative-vtables
+// only-64bit
+
+#![crate_type = "lib"]
+
+// Check that the method entries of vtables are offsets from the vtable, and that calls load them.
+
+pub trait Trait {
+    fn method(&self) -> u32;
+}
+
+pub struct S;
+
+impl Trait for S {
+    fn method(&self) -> u32 {
+        1
+    }
+}
+
+// CHECK: @[[VTABLE:vtable\.[0-9a-zA-Z]+]] = private unnamed_addr constant { { ptr, i64, i64 }, [1 x i32] } { { ptr, i64, i64 } { ptr @{{.+}}, i64 0, i64 1 }, [1 x i32] [i32 trunc (i64 sub (i64 ptrtoint (ptr @{{.+}} to i64), i64 ptrtoint (ptr @[[VTABLE]] to i64)) to i32)] }, align 8
+
+// CHECK-LABEL: @as_dyn
+#[no_mangle]
+pub fn as_dyn(s: &S) -> &dyn Trait {
+    // CHECK: ptr @[[VTABLE]]
+    s
+}
+
+// CHECK-LABEL: @call
+#[no_mangle]
+pub fn call(x: &dyn Trait) -> u32 {
+    // CHECK: [[OFFSET_PTR:%.+]] = getelementptr inbounds i8, ptr %[[VTABLE_PTR:.+]], i64 24
+    // CHECK: [[OFFSET:%.+]] = load i32, ptr [[OFFSET_PTR]], align 4
+    // CHECK: [[OFFSET_EXT:%.+]] = sext i32 [[OFFSET]] to i64
+    // CHECK: [[METHOD:%.+]] = getelementptr inbounds i8, ptr %[[VTABLE_PTR]], i64 [[OFFSET_EXT]]
+    // CHECK: call noundef i32 [[METHOD]](
+    x.method()
+}
@@ -0,0 +1,110 @@
+// Verifies that calls through vtables and the functions written into vtables use the same type
+// metadata identifiers, whatever the receiver type, the auto traits of the trait object or the
+// trait the method is declared in are.
+//
+// revisions: aarch64 x86_64
+// [aarch64] compile-flags: --target aarch64-unknown-none
+// [aarch64] needs-llvm-components: aarch64
+// [x86_64] compile-flags: --target x86_64-unknown-none
+// [x86_64] needs-llvm-components:
+// compile-flags: -Cno-prepopulate-passes -Zsanitizer=kcfi -Copt-level=0
+
+#![crate_type="lib"]
+#![feature(arbitrary_self_types, no_core, lang_items, auto_traits)]
+#![no_core]
+
+#[lang="sized"]
+trait Sized { }
+#[lang="copy"]
+trait Copy { }
+#[lang="receiver"]
+trait Receiver { }
+#[lang="dispatch_from_dyn"]
+trait DispatchFromDyn<T> { }
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<&'a U> for &'a T {}
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<&'a mut U> for &'a mut T {}
+#[lang = "unsize"]
+trait Unsize<T: ?Sized> { }
+#[lang = "coerce_unsized"]
+pub trait CoerceUnsized<T: ?Sized> { }
+impl<'a, 'b: 'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<&'a U> for &'b T {}
+impl<'a, 'b: 'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<&'a mut U> for &'b mut T {}
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<*mut U> for *mut T {}
+#[lang="freeze"]
+trait Freeze { }
+#[lang="drop"]
+pub trait Drop {
+    fn drop(&mut self);
+}
+#[lang="drop_in_place"]
+pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
+    drop_in_place(to_drop)
+}
+#[lang="send"]
+pub unsafe auto trait Send { }
+
+pub trait Super {
+    fn super_method(&self);
+}
+
+pub trait Trait1: Super {
+    fn foo(&self);
+    fn bar(&mut self) {
+    }
+}
+
+pub struct Type1;
+
+impl Super for Type1 {
+    fn super_method(&self) {
+    }
+}
+
+impl Trait1 for Type1 {
+    fn foo(&self) {
+    }
+}
+
+impl Drop for Type1 {
+    fn drop(&mut self) {
+    }
+}
+
+pub fn foo1(a: &(dyn Trait1 + Send)) {
+    a.foo();
+    // CHECK-LABEL: define{{.*}}4foo1{{.*}}!{{<unknown kind #36>|kcfi_type}} !{{[0-9]+}}
+    // CHECK:       call void %{{[0-9]}}(ptr align 1 {{%[a-z]\.0|%_[0-9]}}){{.*}}[ "kcfi"(i32 [[TYPE1:[[:print:]]+]]) ]
+}
+
+pub fn bar1(a: &mut dyn Trait1) {
+    a.bar();
+    // CHECK-LABEL: define{{.*}}4bar1{{.*}}!{{<unknown kind #36>|kcfi_type}} !{{[0-9]+}}
+    // CHECK:       call void %{{[0-9]}}(ptr align 1 {{%[a-z]\.0|%_[0-9]}}){{.*}}[ "kcfi"(i32 [[TYPE2:[[:print:]]+]]) ]
+}
+
+pub fn baz1(a: &dyn Trait1) {
+    a.super_method();
+    // CHECK-LABEL: define{{.*}}4baz1{{.*}}!{{<unknown kind #36>|kcfi_type}} !{{[0-9]+}}
+    // CHECK:       call void %{{[0-9]}}(ptr align 1 {{%[a-z]\.0|%_[0-9]}}){{.*}}[ "kcfi"(i32 [[TYPE3:[[:print:]]+]]) ]
+}
+
+pub unsafe fn qux1(a: *mut dyn Trait1) {
+    drop_in_place(a);
+    // CHECK-LABEL: define{{.*}}4qux1{{.*}}!{{<unknown kind #36>|kcfi_type}} !{{[0-9]+}}
+    // CHECK:       call void %{{[0-9]}}(ptr {{%[a-z]\.0|%_[0-9]}}){{.*}}[ "kcfi"(i32 [[TYPE4:[[:print:]]+]]) ]
+}
+
+pub fn quux1() {
+    let mut a = Type1;
+    foo1(&a);
+    bar1(&mut a);
+    baz1(&a);
+    unsafe { qux1(&mut a as *mut Type1) };
+}
+
+// The shims written into the vtable of `Type1`, the default method and the drop glue included,
+// carry the type metadata identifiers the calls through the vtable are checked against.
+// CHECK-DAG: !{{[0-9]+}} = !{i32 [[TYPE1]]}
+// CHECK-DAG: !{{[0-9]+}} = !{i32 [[TYPE2]]}
+// CHECK-DAG: !{{[0-9]+}} = !{i32 [[TYPE3]]}
+// CHECK-DAG: !{{[0-9]+}} = !{i32 [[TYPE4]]}
@@ -0,0 +1,35 @@
+// compile-flags: -Zvirtual-function-elimination -Copt-level=0 -Csymbol-mangling-version=v0
+
+// Without fat LTO, `-Zvirtual-function-elimination` leaves the vtable entries of methods of
+// private traits that are never called through a vtable vacant, so their bodies aren't codegened.
+
+#![crate_type = "lib"]
+
+// CHECK-NOT: ; <virtual_function_elimination_no_lto::S as virtual_function_elimination_no_lto::T>::unused
+
+trait T {
+    fn used(&self) -> i32 {
+        1
+    }
+    fn used_generically(&self) -> i32 {
+        2
+    }
+    fn unused(&self) -> i32 {
+        3
+    }
+}
+
+pub struct S;
+
+impl T for S {}
+
+fn call_generic<X: T + ?Sized>(x: &X) -> i32 {
+    x.used_generically()
+}
+
+#[no_mangle]
+pub fn call_dyn(s: &S) -> i32 {
+    let t: &dyn T = s;
+    t.used() + call_generic(t)
+}
+
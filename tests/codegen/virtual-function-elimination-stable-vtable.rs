@@ -0,0 +1,34 @@
+// compile-flags: -Zvirtual-function-elimination -Clto -O -Ccodegen-units=1
+// ignore-32bit
+
+// Check that the vtables of `#[stable_vtable]` traits are public for LLVM, even when the trait is
+// private and there is a single CGU, as they may be used by code compiled separately.
+
+// CHECK: @vtable.0 = {{.*}}, !vcall_visibility ![[VCALL_VIS:[0-9]+]]
+
+#![crate_type = "lib"]
+#![feature(stable_vtable)]
+
+#[stable_vtable]
+trait Stable {
+    fn stable(&self) -> i32;
+}
+
+struct S;
+
+impl Stable for S {
+    fn stable(&self) -> i32 {
+        1
+    }
+}
+
+#[inline(never)]
+fn taking_stable(s: &dyn Stable) -> i32 {
+    s.stable()
+}
+
+pub fn main() -> i32 {
+    taking_stable(&S)
+}
+
+// CHECK: ![[VCALL_VIS]] = !{i64 0}
@@ -0,0 +1,28 @@
+// compile-flags: -O
+
+#![crate_type = "lib"]
+
+// Check that trait objects which only differ in their auto traits share one vtable, as auto
+// traits don't add any entries.
+
+pub trait Trait {
+    fn method(&self);
+}
+
+pub struct S;
+
+impl Trait for S {
+    fn method(&self) {}
+}
+
+// CHECK: @[[VTABLE:vtable\.[0-9a-zA-Z]+]] = private
+// CHECK-NOT: @vtable.{{[0-9a-zA-Z]+}} = private
+
+// CHECK-LABEL: @with_auto_traits
+#[no_mangle]
+pub fn with_auto_traits(s: &S) -> (&dyn Trait, &(dyn Trait + Send), &(dyn Trait + Send + Sync)) {
+    // CHECK: store ptr @[[VTABLE]]
+    // CHECK: store ptr @[[VTABLE]]
+    // CHECK: store ptr @[[VTABLE]]
+    (s, s, s)
+}
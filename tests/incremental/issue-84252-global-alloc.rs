@@ -1,5 +1,6 @@
 // revisions: cfail1 cfail2
 // build-pass
+// no-prefer-dynamic
 
 #![crate_type="lib"]
 #![crate_type="cdylib"]
@@ -0,0 +1,29 @@
+// unit-test: Devirtualize
+// compile-flags: --crate-type=lib -C panic=abort
+
+trait Shape {
+    fn area(&self) -> u32;
+}
+
+struct Square(u32);
+
+impl Shape for Square {
+    fn area(&self) -> u32 {
+        self.0 * self.0
+    }
+}
+
+// EMIT_MIR devirtualize.known_type.Devirtualize.diff
+pub fn known_type(square: &Square) -> u32 {
+    // CHECK-LABEL: fn known_type(
+    // CHECK: _0 = <Square as Shape>::area(_3)
+    let shape: &dyn Shape = square;
+    shape.area()
+}
+
+// EMIT_MIR devirtualize.unknown_type.Devirtualize.diff
+pub fn unknown_type(shape: &dyn Shape) -> u32 {
+    // CHECK-LABEL: fn unknown_type(
+    // CHECK: _0 = <dyn Shape as Shape>::area(
+    shape.area()
+}
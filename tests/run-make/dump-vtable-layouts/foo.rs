@@ -0,0 +1,13 @@
+pub trait Trait {
+    fn method(&self);
+}
+
+pub struct S;
+
+impl Trait for S {
+    fn method(&self) {}
+}
+
+pub fn as_dyn() -> &'static dyn Trait {
+    &S
+}
@@ -0,0 +1,60 @@
+// Produces a vtable profile for `shapes.rs`, by running a workload on a `Shape` trait object that
+// counts the calls of each method, and printing the counts in the format expected by
+// `-Zprofile-guided-vtable-layout`.
+
+extern crate shapes;
+
+use shapes::{Shape, Square};
+use std::cell::Cell;
+
+struct Counted<'a> {
+    inner: &'a dyn Shape,
+    name: Cell<u64>,
+    sides: Cell<u64>,
+    area: Cell<u64>,
+}
+
+fn count(calls: &Cell<u64>) {
+    calls.set(calls.get() + 1);
+}
+
+impl Shape for Counted<'_> {
+    fn name(&self) -> &'static str {
+        count(&self.name);
+        self.inner.name()
+    }
+
+    fn sides(&self) -> u32 {
+        count(&self.sides);
+        self.inner.sides()
+    }
+
+    fn area(&self) -> f64 {
+        count(&self.area);
+        self.inner.area()
+    }
+}
+
+fn main() {
+    let square = Square(2.0);
+    let counted = Counted {
+        inner: shapes::as_dyn(&square),
+        name: Cell::new(0),
+        sides: Cell::new(0),
+        area: Cell::new(0),
+    };
+    let shape: &dyn Shape = &counted;
+
+    let mut total = 0.0;
+    for _ in 0..1000 {
+        total += shape.area();
+    }
+    for _ in 0..10 {
+        total += f64::from(shape.sides());
+    }
+    assert_eq!(total, 4040.0);
+
+    println!("Shape::name {}", counted.name.get());
+    println!("Shape::sides {}", counted.sides.get());
+    println!("Shape::area {}", counted.area.get());
+}
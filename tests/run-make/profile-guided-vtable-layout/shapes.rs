@@ -0,0 +1,27 @@
+#![crate_type = "rlib"]
+
+pub trait Shape {
+    fn name(&self) -> &'static str;
+    fn sides(&self) -> u32;
+    fn area(&self) -> f64;
+}
+
+pub struct Square(pub f64);
+
+impl Shape for Square {
+    fn name(&self) -> &'static str {
+        "square"
+    }
+
+    fn sides(&self) -> u32 {
+        4
+    }
+
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+pub fn as_dyn(square: &Square) -> &dyn Shape {
+    square
+}
@@ -1,3 +1,5 @@
+// no-prefer-dynamic
+
 use std::alloc::System;
 use std::collections::VecDeque;
 
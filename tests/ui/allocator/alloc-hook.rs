@@ -0,0 +1,70 @@
+// run-pass
+// revisions: default custom
+// no-prefer-dynamic
+// Checks that the allocation hook sees the allocations made through the default global allocator
+// and through one registered with `#[global_allocator]`.
+
+#![feature(alloc_hook)]
+
+use std::alloc::{set_alloc_hook, take_alloc_hook, AllocKind, Layout};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(custom)]
+mod custom {
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    struct Forward;
+
+    unsafe impl GlobalAlloc for Forward {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: Forward = Forward;
+}
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+static DEALLOCS: AtomicUsize = AtomicUsize::new(0);
+static REALLOCS: AtomicUsize = AtomicUsize::new(0);
+static LAST_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+fn hook(layout: Layout, kind: AllocKind, ptr: *mut u8) {
+    assert!(!ptr.is_null());
+    let counter = match kind {
+        AllocKind::Alloc | AllocKind::AllocZeroed => &ALLOCS,
+        AllocKind::Dealloc => &DEALLOCS,
+        AllocKind::Realloc { old_size, .. } => {
+            assert_eq!(old_size, 4);
+            &REALLOCS
+        }
+        _ => unreachable!(),
+    };
+    counter.fetch_add(1, Ordering::Relaxed);
+    LAST_SIZE.store(layout.size(), Ordering::Relaxed);
+}
+
+fn main() {
+    set_alloc_hook(hook);
+
+    let mut v: Vec<u32> = Vec::with_capacity(1);
+    assert_eq!(ALLOCS.load(Ordering::Relaxed), 1);
+    assert_eq!(LAST_SIZE.load(Ordering::Relaxed), 4);
+
+    v.reserve_exact(2);
+    assert_eq!(REALLOCS.load(Ordering::Relaxed), 1);
+    assert_eq!(LAST_SIZE.load(Ordering::Relaxed), 8);
+
+    drop(v);
+    assert_eq!(DEALLOCS.load(Ordering::Relaxed), 1);
+    assert_eq!(LAST_SIZE.load(Ordering::Relaxed), 8);
+
+    assert!(take_alloc_hook().is_some());
+    drop(Box::new(1));
+    assert_eq!(ALLOCS.load(Ordering::Relaxed), 1);
+}
@@ -0,0 +1,12 @@
+// run-pass
+// check-alloc-count: 100
+// Checks that the `check-alloc-count` directive links the counting allocator into the test.
+
+fn main() {
+    let before = counting_alloc::allocations();
+    let boxed = Box::new(1);
+    let mut vec = vec![0u8; 4];
+    vec.reserve(64);
+    assert_eq!(counting_alloc::allocations(), before + 3);
+    drop((boxed, vec));
+}
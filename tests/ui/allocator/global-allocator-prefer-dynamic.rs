@@ -0,0 +1,13 @@
+// build-fail
+// needs-dynamic-linking
+// compile-flags: -C prefer-dynamic
+// error-pattern: `#[global_allocator]` in global_allocator_prefer_dynamic would be ignored
+// Checks that a `#[global_allocator]` which would be ignored because the standard library is
+// linked dynamically, and provides the allocator, is rejected.
+
+use std::alloc::System;
+
+#[global_allocator]
+static A: System = System;
+
+fn main() {}
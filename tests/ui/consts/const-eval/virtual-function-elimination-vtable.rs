@@ -0,0 +1,32 @@
+// run-pass
+// compile-flags: -Zvirtual-function-elimination
+
+// Without fat LTO, `-Zvirtual-function-elimination` looks at the MIR of the whole crate, constants
+// included, to find the vtable entries it can leave vacant. Evaluating a constant that needs a
+// vtable must not depend on that, or it would be a query cycle.
+
+#![feature(const_size_of_val)]
+
+use std::mem;
+
+trait T {
+    fn used(&self) -> u32;
+    fn unused(&self) -> u32;
+}
+
+impl T for u32 {
+    fn used(&self) -> u32 {
+        *self
+    }
+    fn unused(&self) -> u32 {
+        0
+    }
+}
+
+const C: &dyn T = &1u32;
+const SIZE: usize = mem::size_of_val(C);
+
+fn main() {
+    assert_eq!(SIZE, 4);
+    assert_eq!(C.used(), 1);
+}
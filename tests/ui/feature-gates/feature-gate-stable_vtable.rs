@@ -0,0 +1,7 @@
+#[stable_vtable]
+//~^ ERROR the `#[stable_vtable]` attribute is an experimental feature
+trait Plugin {
+    fn run(&self);
+}
+
+fn main() {}
@@ -0,0 +1,61 @@
+// run-pass
+
+// Check that the misaligned values of vtable pointers are used as a niche, so that small enums
+// wrapping trait objects don't need a separate tag, while enums needing a single niche value, like
+// `Option<&dyn Trait>`, keep using the null value of the data pointer.
+
+#![allow(dead_code)]
+
+use std::mem::size_of;
+
+trait Trait {
+    fn value(&self) -> u32;
+}
+
+impl Trait for u32 {
+    fn value(&self) -> u32 {
+        *self
+    }
+}
+
+enum Small<'a> {
+    A(&'a dyn Trait),
+    B,
+    C,
+    D,
+}
+
+const NESTED: Option<Option<&dyn Trait>> = Some(Some(&7u32));
+const NESTED_NONE: Option<Option<&dyn Trait>> = Some(None);
+
+fn value(x: Option<Option<&dyn Trait>>) -> Option<Option<u32>> {
+    x.map(|x| x.map(|x| x.value()))
+}
+
+fn main() {
+    const PTR_SIZE: usize = size_of::<usize>();
+    assert_eq!(size_of::<Option<&dyn Trait>>(), PTR_SIZE * 2);
+    assert_eq!(size_of::<Option<Box<dyn Trait>>>(), PTR_SIZE * 2);
+    // The null value of the data pointer is used by the inner option, which leaves no niche.
+    assert_eq!(size_of::<Option<Option<&dyn Trait>>>(), PTR_SIZE * 3);
+    // Raw pointers have no niche in their data pointer, so the vtable pointer is used.
+    assert_eq!(size_of::<Option<Option<*const dyn Trait>>>(), PTR_SIZE * 2);
+    assert_eq!(size_of::<Small<'_>>(), PTR_SIZE * 2);
+
+    assert_eq!(value(NESTED), Some(Some(7)));
+    assert_eq!(value(NESTED_NONE), Some(None));
+    assert_eq!(value(None), None);
+    assert_eq!(value(Some(Some(&42u32))), Some(Some(42)));
+
+    let small = [Small::A(&1u32), Small::B, Small::C, Small::D];
+    let tags: Vec<_> = small
+        .iter()
+        .map(|s| match s {
+            Small::A(x) => x.value(),
+            Small::B => 10,
+            Small::C => 20,
+            Small::D => 30,
+        })
+        .collect();
+    assert_eq!(tags, [1, 10, 20, 30]);
+}
@@ -0,0 +1,36 @@
+// Imports that are only reachable through `$crate` paths in exported macros are
+// used by downstream expansions and must not be reported as unused.
+
+#![deny(unused_imports)]
+#![crate_type = "lib"]
+
+mod inner {
+    pub fn used_by_exported() {}
+    pub fn used_by_local() {}
+
+    pub mod nested {
+        pub fn deep() {}
+    }
+}
+
+mod reexports {
+    pub(crate) use crate::inner::nested::deep;
+}
+
+use inner::used_by_exported;
+use inner::used_by_local; //~ ERROR unused import: `inner::used_by_local`
+
+#[macro_export]
+macro_rules! exported {
+    () => {
+        $crate::used_by_exported();
+        $crate::reexports::deep();
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! local {
+    () => {
+        $crate::used_by_local()
+    };
+}
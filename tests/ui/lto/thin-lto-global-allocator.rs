@@ -1,5 +1,6 @@
 // run-pass
 // compile-flags: -Z thinlto -C codegen-units=2
+// no-prefer-dynamic
 
 #[global_allocator]
 static A: std::alloc::System = std::alloc::System;
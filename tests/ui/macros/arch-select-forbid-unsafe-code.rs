@@ -0,0 +1,28 @@
+// run-pass
+// Checks that `arch_select!` can be used in crates that forbid `unsafe_code`, although the
+// function it expands to loads the selected implementation with `unsafe` code.
+
+#![feature(arch_select)]
+#![forbid(unsafe_code)]
+
+use std::arch::arch_select;
+
+fn double_fast(x: u32) -> u32 {
+    x << 1
+}
+
+fn double_slow(x: u32) -> u32 {
+    x * 2
+}
+
+arch_select! {
+    fn double(x: u32) -> u32 {
+        false => double_fast,
+        _ => double_slow,
+    }
+}
+
+fn main() {
+    assert_eq!(double(3), 6);
+    assert_eq!(double(4), 8);
+}
@@ -0,0 +1,45 @@
+// Checks that the calls the `Devirtualize` MIR pass makes direct pass a receiver of the right
+// type, and that it doesn't run into a query cycle with `-Zvirtual-function-elimination`.
+
+// revisions: opt vfe
+// [opt] compile-flags: -O -Zvalidate-mir
+// [vfe] compile-flags: -O -Zvalidate-mir -Zvirtual-function-elimination
+// run-pass
+
+trait Shape {
+    fn area(&self) -> u32;
+}
+
+struct Square(u32);
+
+impl Shape for Square {
+    fn area(&self) -> u32 {
+        self.0 * self.0
+    }
+}
+
+#[inline(never)]
+fn known_type(square: &Square) -> u32 {
+    let shape: &dyn Shape = square;
+    shape.area()
+}
+
+// The trait object is a raw pointer, while the method takes a reference.
+#[inline(never)]
+fn from_raw_pointer(square: &Square) -> u32 {
+    let shape: *const dyn Shape = square as *const Square;
+    unsafe { (*shape).area() }
+}
+
+#[inline(never)]
+fn from_constant() -> u32 {
+    let shape: &dyn Shape = &Square(4);
+    shape.area()
+}
+
+fn main() {
+    let square = Square(3);
+    assert_eq!(known_type(&square), 9);
+    assert_eq!(from_raw_pointer(&square), 9);
+    assert_eq!(from_constant(), 16);
+}
@@ -0,0 +1,67 @@
+// run-pass
+// Checks that the runtime does not register a CPU feature detection hook before `main`, so that
+// runtime initialization does as little work as possible, and that the features detected through
+// `std::arch` come from the hook the program registers and are cached until it changes.
+
+#![feature(cpu_feature_hook)]
+#![feature(cpu_features_snapshot)]
+
+use std::arch::{
+    clear_cpu_feature_cache, cpu_features, detect_cpu_feature, set_cpu_feature_hook,
+    take_cpu_feature_hook,
+};
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+// The bit `n` is set if the fake CPU supports the feature with the index `n`.
+static SUPPORTED: AtomicU32 = AtomicU32::new(0b101);
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn fake_hook(feature: u32) -> bool {
+    CALLS.fetch_add(1, Ordering::Relaxed);
+    feature < 32 && SUPPORTED.load(Ordering::Relaxed) & (1 << feature) != 0
+}
+
+fn other_hook(_feature: u32) -> bool {
+    true
+}
+
+fn calls() -> usize {
+    CALLS.load(Ordering::Relaxed)
+}
+
+fn main() {
+    assert!(take_cpu_feature_hook().is_none());
+
+    // The standard library does not replace a hook registered by the program.
+    assert!(set_cpu_feature_hook(fake_hook).is_none());
+    assert!(detect_cpu_feature(0));
+    assert!(!detect_cpu_feature(1));
+    assert!(detect_cpu_feature(2));
+    assert_eq!(calls(), 3);
+
+    // The results are cached.
+    SUPPORTED.store(0b010, Ordering::Relaxed);
+    assert!(detect_cpu_feature(0));
+    assert!(!detect_cpu_feature(1));
+    assert_eq!(calls(), 3);
+
+    // Clearing the cache queries the hook again.
+    clear_cpu_feature_cache();
+    assert!(!detect_cpu_feature(0));
+    assert!(detect_cpu_feature(1));
+    assert_eq!(calls(), 5);
+
+    let features = cpu_features();
+    assert!(!features.contains(0));
+    assert!(features.contains(1));
+    assert!(!features.contains(2));
+
+    // Registering another hook clears the cache too.
+    assert!(set_cpu_feature_hook(other_hook).is_some());
+    assert!(detect_cpu_feature(0));
+    assert!(detect_cpu_feature(2));
+
+    // So does removing it, after which no feature is detected.
+    assert!(take_cpu_feature_hook().is_some());
+    assert!(!detect_cpu_feature(0));
+}
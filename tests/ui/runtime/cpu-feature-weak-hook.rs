@@ -0,0 +1,25 @@
+// run-pass
+// only-linux
+// Checks that `detect_cpu_feature` falls back to the `__rust_cpu_feature_detect` function defined
+// by the program when no hook is registered, and that a registered hook takes precedence.
+
+#![feature(cpu_feature_hook)]
+
+use std::arch::{detect_cpu_feature, set_cpu_feature_hook, take_cpu_feature_hook};
+
+#[no_mangle]
+extern "C" fn __rust_cpu_feature_detect(feature: u32) -> bool {
+    feature == 3
+}
+
+fn main() {
+    assert!(detect_cpu_feature(3));
+    assert!(!detect_cpu_feature(4));
+
+    set_cpu_feature_hook(|feature| feature == 4);
+    assert!(!detect_cpu_feature(3));
+    assert!(detect_cpu_feature(4));
+
+    take_cpu_feature_hook();
+    assert!(detect_cpu_feature(3));
+}
@@ -0,0 +1,10 @@
+// run-pass
+// ignore-emscripten no threads support
+// Checks that the main thread is named "main", although its `Thread` handle is created lazily.
+
+use std::thread;
+
+fn main() {
+    assert_eq!(thread::current().name(), Some("main"));
+    thread::spawn(|| assert_eq!(thread::current().name(), None)).join().unwrap();
+}
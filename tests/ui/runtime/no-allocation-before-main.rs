@@ -0,0 +1,17 @@
+// run-pass
+// only-unix
+// ignore-android thread locals allocate on first use without `#[thread_local]`
+// ignore-freebsd thread locals allocate on first use without `#[thread_local]`
+// ignore-netbsd thread locals allocate on first use without `#[thread_local]`
+// ignore-nto thread locals allocate on first use without `#[thread_local]`
+// ignore-ohos thread locals allocate on first use without `#[thread_local]`
+// ignore-openbsd thread locals allocate on first use without `#[thread_local]`
+// check-alloc-count: 0
+// Checks that the runtime starts up, and shuts down, without allocating: the arguments and the
+// environment are only read when asked for, and the `Thread` handle of the main thread, which
+// names it, is only created when first needed. The targets without `#[thread_local]` support are
+// ignored, since the runtime still uses a thread local before `main`.
+
+fn main() {
+    assert_eq!(counting_alloc::allocations(), 0);
+}
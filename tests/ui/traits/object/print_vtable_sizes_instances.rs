@@ -0,0 +1,37 @@
+// build-pass
+// only-64bit
+// compile-flags: -Z print-vtable-sizes
+#![crate_type = "lib"]
+#![feature(negative_impls)]
+
+pub trait A {
+    fn a(&self) {}
+
+    // Vacant in the vtable for `NotSend`
+    fn b(&self)
+    where
+        Self: Send,
+    {
+    }
+}
+
+// Not instantiated
+pub trait B {
+    fn c(&self);
+}
+
+pub struct NotSend;
+
+impl !Send for NotSend {}
+
+impl A for NotSend {}
+impl A for u8 {}
+
+pub fn to_dyn<'a>(x: &'a NotSend, y: &'a u8) -> [&'a dyn A; 2] {
+    [x, y]
+}
+
+// Foreign traits are listed after the ones of the crate.
+pub fn to_debug(x: &u8) -> &dyn std::fmt::Debug {
+    x
+}
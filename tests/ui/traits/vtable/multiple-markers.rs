@@ -4,6 +4,7 @@
 // same pointer for upcasting.
 //
 // build-fail
+// stderr-per-bitwidth
 #![crate_type = "lib"]
 #![feature(rustc_attrs)]
 
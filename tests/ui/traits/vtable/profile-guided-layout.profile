@@ -0,0 +1,3 @@
+A::foo_a3 100
+A::foo_a2 10
+B::foo_b3 5
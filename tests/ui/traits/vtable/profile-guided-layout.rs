@@ -0,0 +1,34 @@
+// Check that `-Zprofile-guided-vtable-layout` moves the most called methods of each trait first,
+// and keeps the other methods in definition order.
+//
+// build-fail
+// stderr-per-bitwidth
+// compile-flags: -Zprofile-guided-vtable-layout={{src-base}}/traits/vtable/profile-guided-layout.profile
+#![feature(rustc_attrs)]
+
+trait A {
+    fn foo_a1(&self) {}
+    fn foo_a2(&self) {}
+    fn foo_a3(&self) {}
+}
+
+#[rustc_dump_vtable]
+trait B: A {
+    //~^ error vtable
+    fn foo_b1(&self) {}
+    fn foo_b2(&self) {}
+    fn foo_b3(&self) {}
+}
+
+struct S;
+
+impl A for S {}
+impl B for S {}
+
+fn foo(b: &dyn B) {
+    b.foo_b2();
+}
+
+fn main() {
+    foo(&S);
+}
@@ -0,0 +1,35 @@
+// run-pass
+#![feature(stable_vtable, core_intrinsics, const_vtable_layout_hash)]
+
+use std::intrinsics::vtable_layout_hash;
+
+#[stable_vtable]
+trait Base {
+    fn base(&self) -> u32;
+}
+
+#[stable_vtable]
+trait Plugin: Base {
+    fn run(&self) -> u32;
+}
+
+#[stable_vtable]
+trait Other: Base {
+    fn other(&self) -> u64;
+}
+
+trait Unstable {
+    fn unstable(&self);
+}
+
+// The hash can be embedded in the binary, for the other side to check it.
+static PLUGIN_LAYOUT: u64 = vtable_layout_hash::<dyn Plugin>();
+
+fn main() {
+    assert_ne!(PLUGIN_LAYOUT, 0);
+    assert_eq!(PLUGIN_LAYOUT, vtable_layout_hash::<dyn Plugin + Send>());
+    assert_ne!(PLUGIN_LAYOUT, vtable_layout_hash::<dyn Base>());
+    assert_ne!(PLUGIN_LAYOUT, vtable_layout_hash::<dyn Other>());
+    assert_eq!(vtable_layout_hash::<dyn Unstable>(), 0);
+    assert_eq!(vtable_layout_hash::<u32>(), 0);
+}
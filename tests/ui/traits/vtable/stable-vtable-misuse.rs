@@ -0,0 +1,29 @@
+#![feature(stable_vtable)]
+#![allow(dead_code)]
+
+#[stable_vtable]
+//~^ ERROR attribute should be applied to a trait
+struct NotATrait;
+
+#[stable_vtable]
+trait NotObjectSafe {
+    //~^ ERROR `#[stable_vtable]` can only be applied to object safe traits
+    fn new() -> Self;
+}
+
+trait Unstable {
+    fn unstable(&self);
+}
+
+#[stable_vtable]
+trait Stable {
+    fn stable(&self);
+}
+
+#[stable_vtable]
+trait Sub: Stable + Unstable + Send {
+    //~^ ERROR the supertrait `Unstable` of a `#[stable_vtable]` trait must also be `#[stable_vtable]`
+    fn sub(&self);
+}
+
+fn main() {}
@@ -1,4 +1,5 @@
 // build-fail
+// stderr-per-bitwidth
 #![feature(rustc_attrs)]
 
 #[rustc_dump_vtable]
@@ -1,4 +1,5 @@
 // build-fail
+// stderr-per-bitwidth
 #![feature(rustc_attrs)]
 
 // Ensure that non-object-safe methods in Iterator does not generate
@@ -0,0 +1,44 @@
+// Check that a super trait gets no vptr when its vtable is a prefix of the
+// vtable emitted so far, as upcasting to it can reuse the same pointer.
+//
+// revisions: sharing no_sharing
+//[no_sharing] compile-flags: -Zno-vtable-prefix-sharing
+// build-fail
+// stderr-per-bitwidth
+#![feature(rustc_attrs)]
+
+// D --> A
+//   \-> C
+//   \-> B --> A
+
+trait A {
+    fn foo_a(&self) {}
+}
+
+// `B` has no methods of its own, so its vtable is the one of `A`.
+trait B: A {}
+
+trait C {
+    fn foo_c(&self) {}
+}
+
+#[rustc_dump_vtable]
+trait D: A + C + B {
+    //~^ error vtable
+    fn foo_d(&self) {}
+}
+
+struct S;
+
+impl A for S {}
+impl B for S {}
+impl C for S {}
+impl D for S {}
+
+fn foo(d: &dyn D) {
+    d.foo_d();
+}
+
+fn main() {
+    foo(&S);
+}
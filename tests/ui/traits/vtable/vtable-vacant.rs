@@ -1,4 +1,5 @@
 // build-fail
+// stderr-per-bitwidth
 #![feature(rustc_attrs)]
 #![feature(negative_impls)]
 #![allow(where_clauses_object_safety)]
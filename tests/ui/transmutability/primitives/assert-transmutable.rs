@@ -0,0 +1,21 @@
+// Tests the `const`-usable `mem::assert_transmutable` wrapper around the transmutability check.
+
+#![feature(transmutability)]
+#![feature(inline_const)]
+
+use std::mem::assert_transmutable;
+
+#[repr(C)]
+struct Header {
+    tag: u8,
+    flag: bool,
+    len: u16,
+}
+
+fn main() {
+    const { assert_transmutable::<u32, [u8; 4]>() };
+    const { assert_transmutable::<bool, u8>() };
+    const { assert_transmutable::<Header, [u8; 4]>() };
+    const { assert_transmutable::<u8, bool>() }; //~ ERROR cannot be safely transmuted
+    const { assert_transmutable::<[u8; 4], Header>() }; //~ ERROR cannot be safely transmuted
+}